@@ -0,0 +1,449 @@
+//! Assembly of sparse matrices from dense element matrices.
+//!
+//! In finite element methods and related discretizations, a global sparse matrix is the sum of
+//! many small dense *element matrices*, each of which couples the degrees of freedom of the nodes
+//! of a single element. Since the element connectivity typically does not change between
+//! time steps or iterations, the sparsity pattern of the global matrix is fixed.
+//! [`CsrAssembler`] exploits this by computing the pattern, together with a map from the entries
+//! of every element matrix to the values of the CSR matrix, only once. Subsequent assemblies
+//! directly scatter-add element matrices into [`CsrMatrix::values_mut`], without any sorting,
+//! searching or allocation.
+use crate::cs::convert_counts_to_offsets;
+use crate::csr::CsrMatrix;
+use crate::pattern::SparsityPattern;
+
+use nalgebra::storage::Storage;
+use nalgebra::{ClosedAdd, Dim, Matrix, Scalar};
+use num_traits::Zero;
+
+/// Assembles CSR matrices from dense element matrices into a fixed sparsity pattern.
+///
+/// The assembler is constructed from the *connectivity* of the elements: for each element,
+/// the list of (global) indices of the nodes it consists of. Each node has `dofs_per_node`
+/// degrees of freedom, and the degrees of freedom of node `i` are the global indices
+/// `i * dofs_per_node .. (i + 1) * dofs_per_node`. The element matrix of an element with `k`
+/// nodes is a square `k * dofs_per_node` matrix, whose degrees of freedom are ordered node by
+/// node in the order given by the connectivity.
+///
+/// The sparsity pattern of the assembled matrix contains an entry for every pair of degrees of
+/// freedom that share an element. Element matrices can be given either as dynamically sized
+/// matrices (e.g. `DMatrix`) or as statically sized matrices (e.g. `Matrix4`).
+///
+/// # Example
+///
+/// ```rust
+/// use nalgebra::{DMatrix, Matrix2};
+/// use nalgebra_sparse::assembly::CsrAssembler;
+///
+/// // A 1D mesh with four nodes and three line elements
+/// let connectivity = vec![[0, 1], [1, 2], [2, 3]];
+/// let assembler = CsrAssembler::from_connectivity(4, 1, &connectivity);
+/// assert_eq!(assembler.pattern().nnz(), 10);
+///
+/// // The sparsity pattern is computed only once, and can be reused for every assembly
+/// let element_stiffness = Matrix2::new(1.0, -1.0, -1.0, 1.0);
+/// let stiffness = assembler.assemble(|_| element_stiffness);
+///
+/// let expected = DMatrix::from_row_slice(4, 4, &[
+///      1.0, -1.0,  0.0,  0.0,
+///     -1.0,  2.0, -1.0,  0.0,
+///      0.0, -1.0,  2.0, -1.0,
+///      0.0,  0.0, -1.0,  1.0,
+/// ]);
+/// assert_eq!(DMatrix::from(&stiffness), expected);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrAssembler {
+    pattern: SparsityPattern,
+    /// The number of degrees of freedom of each element.
+    element_dofs: Vec<usize>,
+    /// Offsets into `element_value_indices` for each element.
+    element_offsets: Vec<usize>,
+    /// For each element, the index into the CSR values of every entry of the element matrix,
+    /// in column-major order.
+    element_value_indices: Vec<usize>,
+    /// Groups of elements such that no two elements in the same group share a node.
+    #[cfg(feature = "rayon")]
+    colors: Vec<Vec<usize>>,
+}
+
+impl CsrAssembler {
+    /// Constructs an assembler for elements with the given connectivity.
+    ///
+    /// Nodes may appear in several elements, and a node may also be repeated within a single
+    /// element, in which case the corresponding entries of the element matrix are summed.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if a node index is not smaller than `num_nodes`.
+    pub fn from_connectivity<C>(num_nodes: usize, dofs_per_node: usize, connectivity: &[C]) -> Self
+    where
+        C: AsRef<[usize]>,
+    {
+        let pattern = pattern_from_connectivity(num_nodes, dofs_per_node, connectivity);
+
+        let mut element_dofs = Vec::with_capacity(connectivity.len());
+        let mut element_offsets = Vec::with_capacity(connectivity.len() + 1);
+        let mut element_value_indices = Vec::new();
+        let mut dofs = Vec::new();
+
+        element_offsets.push(0);
+        for element in connectivity {
+            dofs.clear();
+            dofs.extend(
+                element
+                    .as_ref()
+                    .iter()
+                    .flat_map(|&node| node * dofs_per_node..(node + 1) * dofs_per_node),
+            );
+            // Column-major order, to match the storage order of the element matrices
+            for &col in &dofs {
+                for &row in &dofs {
+                    let row_begin = pattern.major_offsets()[row];
+                    let local_index = pattern
+                        .lane(row)
+                        .binary_search(&col)
+                        .expect("Internal error: Element entry must be part of the pattern.");
+                    element_value_indices.push(row_begin + local_index);
+                }
+            }
+            element_dofs.push(dofs.len());
+            element_offsets.push(element_value_indices.len());
+        }
+
+        Self {
+            pattern,
+            element_dofs,
+            element_offsets,
+            element_value_indices,
+            #[cfg(feature = "rayon")]
+            colors: color_elements(num_nodes, connectivity),
+        }
+    }
+
+    /// The sparsity pattern of the assembled matrices.
+    #[inline]
+    pub fn pattern(&self) -> &SparsityPattern {
+        &self.pattern
+    }
+
+    /// The number of elements.
+    #[inline]
+    pub fn num_elements(&self) -> usize {
+        self.element_dofs.len()
+    }
+
+    /// The number of degrees of freedom of the given element, which is the number of rows and
+    /// columns of its element matrix.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the element index is out of bounds.
+    #[inline]
+    pub fn element_dim(&self, element: usize) -> usize {
+        self.element_dofs[element]
+    }
+
+    /// Returns a new matrix with the sparsity pattern of the assembler, where all explicitly
+    /// stored entries are zero.
+    pub fn new_matrix<T>(&self) -> CsrMatrix<T>
+    where
+        T: Scalar + Zero,
+    {
+        let values = vec![T::zero(); self.pattern.nnz()];
+        CsrMatrix::try_from_pattern_and_values(self.pattern.clone(), values)
+            .expect("Internal error: Pattern and values must be compatible.")
+    }
+
+    /// Adds the element matrix of the given element to `matrix`.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the element index is out of bounds, if the element matrix does not have the
+    /// dimensions given by [`element_dim`](Self::element_dim), or if `matrix` is not compatible
+    /// with the pattern of the assembler.
+    ///
+    /// `matrix` must have been created with the sparsity pattern of this assembler, for example
+    /// with [`new_matrix`](Self::new_matrix). For efficiency reasons, only the dimensions and the
+    /// number of explicitly stored entries of the matrix are checked.
+    pub fn add_element_matrix<T, R, C, S>(
+        &self,
+        matrix: &mut CsrMatrix<T>,
+        element: usize,
+        element_matrix: &Matrix<T, R, C, S>,
+    ) where
+        T: Scalar + ClosedAdd,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        self.assert_compatible_matrix(matrix);
+        self.assert_element_matrix_dims(element, element_matrix);
+        let values = matrix.values_mut();
+        for (&index, value) in self
+            .element_value_indices(element)
+            .iter()
+            .zip(element_matrix.iter())
+        {
+            values[index] += value.inlined_clone();
+        }
+    }
+
+    /// Adds the element matrices of all elements to `matrix`.
+    ///
+    /// The element matrix of each element is obtained by calling `element_matrix` with the
+    /// index of the element. The elements are processed in order.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics under the same conditions as [`add_element_matrix`](Self::add_element_matrix).
+    pub fn add_element_matrices<T, R, C, S, F>(
+        &self,
+        matrix: &mut CsrMatrix<T>,
+        mut element_matrix: F,
+    ) where
+        T: Scalar + ClosedAdd,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+        F: FnMut(usize) -> Matrix<T, R, C, S>,
+    {
+        for element in 0..self.num_elements() {
+            self.add_element_matrix(matrix, element, &element_matrix(element));
+        }
+    }
+
+    /// Assembles a new matrix from the element matrices of all elements.
+    ///
+    /// This is equivalent to calling [`add_element_matrices`](Self::add_element_matrices) on
+    /// the result of [`new_matrix`](Self::new_matrix).
+    pub fn assemble<T, R, C, S, F>(&self, element_matrix: F) -> CsrMatrix<T>
+    where
+        T: Scalar + ClosedAdd + Zero,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+        F: FnMut(usize) -> Matrix<T, R, C, S>,
+    {
+        let mut matrix = self.new_matrix();
+        self.add_element_matrices(&mut matrix, element_matrix);
+        matrix
+    }
+
+    /// Adds the element matrices of all elements to `matrix` in parallel.
+    ///
+    /// This requires the `rayon` feature. The elements are partitioned into colors, such that
+    /// no two elements of the same color share a node. The colors are processed one after
+    /// another, while the elements of each color are processed in parallel: both the evaluation
+    /// of `element_matrix` and the accumulation into `matrix` happen concurrently, without
+    /// any locking.
+    ///
+    /// The result is deterministic and does not depend on the number of threads. However,
+    /// entries may be summed in a different order than in
+    /// [`add_element_matrices`](Self::add_element_matrices), so results for floating-point
+    /// types may differ by rounding.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics under the same conditions as [`add_element_matrix`](Self::add_element_matrix).
+    #[cfg(feature = "rayon")]
+    pub fn par_add_element_matrices<T, R, C, S, F>(
+        &self,
+        matrix: &mut CsrMatrix<T>,
+        element_matrix: F,
+    ) where
+        T: Scalar + ClosedAdd + Send + Sync,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+        F: Fn(usize) -> Matrix<T, R, C, S> + Sync,
+    {
+        use rayon::prelude::*;
+
+        self.assert_compatible_matrix(matrix);
+        let values = SharedValues(matrix.values_mut().as_mut_ptr());
+        for color in &self.colors {
+            color.par_iter().for_each(|&element| {
+                let element_matrix = element_matrix(element);
+                self.assert_element_matrix_dims(element, &element_matrix);
+                let indices = self.element_value_indices(element);
+                for (&index, value) in indices.iter().zip(element_matrix.iter()) {
+                    // SAFETY: The value indices were computed from the pattern of the assembler,
+                    // which has the same number of entries as `matrix`, so they are in bounds.
+                    // Elements of the same color share no node, so they touch disjoint rows
+                    // and therefore disjoint values. Hence no value is accessed concurrently.
+                    unsafe {
+                        *values.0.add(index) += value.inlined_clone();
+                    }
+                }
+            });
+        }
+    }
+
+    /// Assembles a new matrix from the element matrices of all elements in parallel.
+    ///
+    /// This requires the `rayon` feature. See
+    /// [`par_add_element_matrices`](Self::par_add_element_matrices) for details.
+    #[cfg(feature = "rayon")]
+    pub fn par_assemble<T, R, C, S, F>(&self, element_matrix: F) -> CsrMatrix<T>
+    where
+        T: Scalar + ClosedAdd + Zero + Send + Sync,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+        F: Fn(usize) -> Matrix<T, R, C, S> + Sync,
+    {
+        let mut matrix = self.new_matrix();
+        self.par_add_element_matrices(&mut matrix, element_matrix);
+        matrix
+    }
+
+    /// The partition of elements used by
+    /// [`par_add_element_matrices`](Self::par_add_element_matrices).
+    ///
+    /// No two elements in the same color share a node. This requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn element_colors(&self) -> &[Vec<usize>] {
+        &self.colors
+    }
+
+    fn element_value_indices(&self, element: usize) -> &[usize] {
+        &self.element_value_indices
+            [self.element_offsets[element]..self.element_offsets[element + 1]]
+    }
+
+    fn assert_compatible_matrix<T>(&self, matrix: &CsrMatrix<T>) {
+        assert!(
+            matrix.nrows() == self.pattern.major_dim()
+                && matrix.ncols() == self.pattern.minor_dim()
+                && matrix.nnz() == self.pattern.nnz(),
+            "Matrix must have the sparsity pattern of the assembler."
+        );
+    }
+
+    fn assert_element_matrix_dims<T, R, C, S>(
+        &self,
+        element: usize,
+        element_matrix: &Matrix<T, R, C, S>,
+    ) where
+        T: Scalar,
+        R: Dim,
+        C: Dim,
+        S: Storage<T, R, C>,
+    {
+        let n = self.element_dim(element);
+        assert_eq!(
+            element_matrix.shape(),
+            (n, n),
+            "Element matrix dimensions must match the number of element degrees of freedom."
+        );
+    }
+}
+
+/// Computes the sparsity pattern that couples all degrees of freedom that share an element.
+fn pattern_from_connectivity<C>(
+    num_nodes: usize,
+    dofs_per_node: usize,
+    connectivity: &[C],
+) -> SparsityPattern
+where
+    C: AsRef<[usize]>,
+{
+    // Collect the elements that each node belongs to, in CSR-like format
+    let mut node_offsets = vec![0; num_nodes + 1];
+    for element in connectivity {
+        for &node in element.as_ref() {
+            assert!(node < num_nodes, "Node index out of bounds.");
+            node_offsets[node] += 1;
+        }
+    }
+    convert_counts_to_offsets(&mut node_offsets);
+    let mut node_elements = vec![0; node_offsets[num_nodes]];
+    let mut next = node_offsets.clone();
+    for (element_index, element) in connectivity.iter().enumerate() {
+        for &node in element.as_ref() {
+            node_elements[next[node]] = element_index;
+            next[node] += 1;
+        }
+    }
+
+    let mut offsets = Vec::with_capacity(num_nodes * dofs_per_node + 1);
+    let mut indices = Vec::new();
+    let mut neighbors = Vec::new();
+
+    offsets.push(0);
+    for node in 0..num_nodes {
+        neighbors.clear();
+        for &element in &node_elements[node_offsets[node]..node_offsets[node + 1]] {
+            neighbors.extend_from_slice(connectivity[element].as_ref());
+        }
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        // All degrees of freedom of a node share the same neighbors
+        for _ in 0..dofs_per_node {
+            for &neighbor in &neighbors {
+                indices.extend(neighbor * dofs_per_node..(neighbor + 1) * dofs_per_node);
+            }
+            offsets.push(indices.len());
+        }
+    }
+
+    let n = num_nodes * dofs_per_node;
+    // TODO: Avoid checks here
+    SparsityPattern::try_from_offsets_and_indices(n, n, offsets, indices)
+        .expect("Internal error: Sparsity pattern must always be valid.")
+}
+
+/// Greedily assigns each element the smallest color not used by any element it shares a node
+/// with, and returns the elements of each color.
+#[cfg(feature = "rayon")]
+fn color_elements<C>(num_nodes: usize, connectivity: &[C]) -> Vec<Vec<usize>>
+where
+    C: AsRef<[usize]>,
+{
+    let mut colors: Vec<Vec<usize>> = Vec::new();
+    let mut node_colors = vec![Vec::new(); num_nodes];
+    let mut is_taken = Vec::new();
+
+    for (element_index, element) in connectivity.iter().enumerate() {
+        let nodes = element.as_ref();
+        is_taken.clear();
+        is_taken.resize(colors.len(), false);
+        for &node in nodes {
+            for &color in &node_colors[node] {
+                is_taken[color] = true;
+            }
+        }
+
+        let color = is_taken
+            .iter()
+            .position(|&taken| !taken)
+            .unwrap_or(colors.len());
+        if color == colors.len() {
+            colors.push(Vec::new());
+        }
+        colors[color].push(element_index);
+
+        for &node in nodes {
+            // A node may be repeated within an element
+            if node_colors[node].last() != Some(&color) {
+                node_colors[node].push(color);
+            }
+        }
+    }
+
+    colors
+}
+
+/// A pointer to the values of a matrix that are written to concurrently, but never at the
+/// same index.
+#[cfg(feature = "rayon")]
+struct SharedValues<T>(*mut T);
+
+#[cfg(feature = "rayon")]
+unsafe impl<T: Send> Sync for SharedValues<T> {}
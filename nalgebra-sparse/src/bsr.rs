@@ -0,0 +1,332 @@
+//! An implementation of the BSR sparse matrix format.
+//!
+//! This is the module-level documentation. See [`BsrMatrix`] for the main documentation of the
+//! BSR implementation.
+use crate::pattern::{SparsityPattern, SparsityPatternIter};
+use crate::{SparseFormatError, SparseFormatErrorKind};
+
+use nalgebra::allocator::Allocator;
+use nalgebra::{DefaultAllocator, Dim, Dynamic, MatrixMN, MatrixSliceMN, MatrixSliceMutMN, Scalar};
+
+use std::slice::{Chunks, ChunksMut};
+
+/// A BSR representation of a sparse matrix.
+///
+/// The Block Sparse Row (BSR) format stores a sparse matrix as a sparse matrix of small dense
+/// blocks of a fixed size. It is well-suited for matrices that arise from systems with several
+/// coupled unknowns per node, such as the displacements and rotations in elasticity and
+/// multibody problems, where it stores only one column index per block instead of one per
+/// entry, and lets the blocks be processed with dense kernels.
+///
+/// The block dimensions `R` and `C` are nalgebra dimensions. With type-level dimensions such as
+/// `U3`, the size of the blocks is known at compile time, and each block is accessed as a
+/// statically sized matrix slice. With `Dynamic`, which is the default, the size of the blocks
+/// is determined at runtime.
+///
+/// # Usage
+///
+/// ```rust
+/// use nalgebra_sparse::bsr::BsrMatrix;
+/// use nalgebra_sparse::pattern::SparsityPattern;
+/// use nalgebra::{Matrix2, U2};
+///
+/// // A 6x4 matrix made up of 2x2 blocks, with blocks at the block positions
+/// // (0, 0), (0, 1) and (2, 1)
+/// let pattern = SparsityPattern::try_from_offsets_and_indices(3, 2, vec![0, 2, 2, 3], vec![0, 1, 1])
+///     .unwrap();
+/// let blocks = vec![Matrix2::new(1.0, 2.0, 3.0, 4.0),
+///                   Matrix2::identity(),
+///                   Matrix2::new(5.0, 0.0, 0.0, 6.0)];
+/// let mut bsr = BsrMatrix::try_from_pattern_and_blocks(pattern, U2, U2, blocks)
+///     .expect("The pattern and blocks must be compatible");
+///
+/// assert_eq!(bsr.nrows(), 6);
+/// assert_eq!(bsr.ncols(), 4);
+/// assert_eq!(bsr.block_count(), 3);
+/// assert_eq!(bsr.nnz(), 12);
+///
+/// // Blocks are accessed as matrix slices
+/// let block = bsr.get_block(2, 1).unwrap();
+/// assert_eq!(block, Matrix2::new(5.0, 0.0, 0.0, 6.0));
+/// bsr.get_block_mut(0, 1).unwrap().fill(2.0);
+///
+/// for (block_row, block_col, block) in bsr.block_iter() {
+///     assert_eq!(block.shape(), (2, 2));
+/// }
+/// ```
+///
+/// BSR matrices can be converted from and to the other formats with the routines in the
+/// [`convert`](crate::convert) module, and multiplied with dense matrices with
+/// [`spmm_bsr_dense`](crate::ops::serial::spmm_bsr_dense).
+///
+/// # Format
+///
+/// An `m x n` BSR matrix with `r x c` blocks consists of `(m / r) x (n / c)` block rows and
+/// block columns. It is represented by
+///
+/// - a [`SparsityPattern`] of dimensions `(m / r) x (n / c)`, which stores the positions of the
+///   blocks in CSR fashion, with block rows as the major dimension;
+/// - `values`, an array of length `r * c * b`, where `b` is the number of blocks in the pattern.
+///
+/// The values of the `k`-th block in the pattern are stored contiguously in column-major order
+/// in `values[k * r * c .. (k + 1) * r * c]`. All entries of a block are explicitly stored,
+/// including any zeros within the block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BsrMatrix<T, R: Dim = Dynamic, C: Dim = R> {
+    // Block rows are major, block columns are minor in the sparsity pattern
+    pattern: SparsityPattern,
+    block_nrows: R,
+    block_ncols: C,
+    values: Vec<T>,
+}
+
+impl<T, R: Dim, C: Dim> BsrMatrix<T, R, C> {
+    /// Try to construct a BSR matrix from a block sparsity pattern and the values of the blocks.
+    ///
+    /// The values of each block must be stored contiguously in column-major order, and the
+    /// blocks must appear in the same order as in the pattern.
+    ///
+    /// Returns an error if a block dimension is zero, or if the number of values is not equal to
+    /// the number of blocks in the pattern times the number of entries in a block.
+    pub fn try_from_pattern_and_values(
+        pattern: SparsityPattern,
+        block_nrows: R,
+        block_ncols: C,
+        values: Vec<T>,
+    ) -> Result<Self, SparseFormatError> {
+        let block_size = block_nrows.value() * block_ncols.value();
+        if block_size == 0 {
+            Err(SparseFormatError::from_kind_and_msg(
+                SparseFormatErrorKind::InvalidStructure,
+                "Block dimensions must be non-zero.",
+            ))
+        } else if pattern.nnz() * block_size != values.len() {
+            Err(SparseFormatError::from_kind_and_msg(
+                SparseFormatErrorKind::InvalidStructure,
+                "Number of values must be equal to the number of blocks times the block size.",
+            ))
+        } else {
+            Ok(Self {
+                pattern,
+                block_nrows,
+                block_ncols,
+                values,
+            })
+        }
+    }
+
+    /// The number of rows in the matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.pattern.major_dim() * self.block_nrows.value()
+    }
+
+    /// The number of columns in the matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.pattern.minor_dim() * self.block_ncols.value()
+    }
+
+    /// The number of rows and columns of each block.
+    #[inline]
+    pub fn block_shape(&self) -> (usize, usize) {
+        (self.block_nrows.value(), self.block_ncols.value())
+    }
+
+    /// The number of rows and columns of each block, as nalgebra dimensions.
+    #[inline]
+    pub fn block_shape_generic(&self) -> (R, C) {
+        (self.block_nrows, self.block_ncols)
+    }
+
+    /// The number of block rows in the matrix.
+    #[inline]
+    pub fn block_row_count(&self) -> usize {
+        self.pattern.major_dim()
+    }
+
+    /// The number of block columns in the matrix.
+    #[inline]
+    pub fn block_col_count(&self) -> usize {
+        self.pattern.minor_dim()
+    }
+
+    /// The number of explicitly stored blocks in the matrix.
+    #[inline]
+    pub fn block_count(&self) -> usize {
+        self.pattern.nnz()
+    }
+
+    /// The number of explicitly stored entries in the matrix.
+    ///
+    /// This is the number of stored blocks times the number of entries in a block, and
+    /// includes any zeros within the stored blocks.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The sparsity pattern of the blocks.
+    ///
+    /// The major and minor dimensions of the pattern are the block rows and block columns.
+    #[inline]
+    pub fn pattern(&self) -> &SparsityPattern {
+        &self.pattern
+    }
+
+    /// The values of all blocks, stored block by block in column-major order.
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// A mutable slice of the values of all blocks, stored block by block in column-major order.
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Disassembles the BSR matrix into its block sparsity pattern and values.
+    pub fn into_pattern_and_values(self) -> (SparsityPattern, Vec<T>) {
+        (self.pattern, self.values)
+    }
+
+    /// The index of the given block in the pattern, if it is explicitly stored.
+    fn block_index(&self, block_row: usize, block_col: usize) -> Option<usize> {
+        let offset = *self.pattern.major_offsets().get(block_row)?;
+        let lane = self.pattern.get_lane(block_row)?;
+        let local_index = lane.binary_search(&block_col).ok()?;
+        Some(offset + local_index)
+    }
+}
+
+impl<T: Scalar, R: Dim, C: Dim> BsrMatrix<T, R, C> {
+    /// Try to construct a BSR matrix from a block sparsity pattern and a collection of blocks.
+    ///
+    /// The blocks must appear in the same order as in the pattern.
+    ///
+    /// Returns an error if a block dimension is zero, if the number of blocks does not match the
+    /// number of blocks in the pattern, or if a block does not have the given dimensions.
+    pub fn try_from_pattern_and_blocks<I>(
+        pattern: SparsityPattern,
+        block_nrows: R,
+        block_ncols: C,
+        blocks: I,
+    ) -> Result<Self, SparseFormatError>
+    where
+        I: IntoIterator<Item = MatrixMN<T, R, C>>,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let block_shape = (block_nrows.value(), block_ncols.value());
+        let mut values = Vec::with_capacity(pattern.nnz() * block_shape.0 * block_shape.1);
+        for block in blocks {
+            if block.shape() != block_shape {
+                return Err(SparseFormatError::from_kind_and_msg(
+                    SparseFormatErrorKind::InvalidStructure,
+                    "All blocks must have the given block dimensions.",
+                ));
+            }
+            values.extend(block.iter().cloned());
+        }
+        Self::try_from_pattern_and_values(pattern, block_nrows, block_ncols, values)
+    }
+
+    /// An iterator over the explicitly stored blocks in the matrix.
+    ///
+    /// Each item is a triplet `(block_row, block_col, block)`. The blocks are visited in
+    /// row-major order of their block positions.
+    pub fn block_iter(&self) -> BsrBlockIter<'_, T, R, C> {
+        BsrBlockIter {
+            pattern_iter: self.pattern.entries(),
+            values_iter: self
+                .values
+                .chunks(self.block_nrows.value() * self.block_ncols.value()),
+            block_nrows: self.block_nrows,
+            block_ncols: self.block_ncols,
+        }
+    }
+
+    /// A mutable iterator over the explicitly stored blocks in the matrix.
+    ///
+    /// The blocks are visited in the same order as with [`block_iter`](Self::block_iter).
+    pub fn block_iter_mut(&mut self) -> BsrBlockIterMut<'_, T, R, C> {
+        let block_size = self.block_nrows.value() * self.block_ncols.value();
+        BsrBlockIterMut {
+            pattern_iter: self.pattern.entries(),
+            values_iter: self.values.chunks_mut(block_size),
+            block_nrows: self.block_nrows,
+            block_ncols: self.block_ncols,
+        }
+    }
+
+    /// Returns the block at the given block position, or `None` if the block is not explicitly
+    /// stored or the position is out of bounds.
+    pub fn get_block(&self, block_row: usize, block_col: usize) -> Option<MatrixSliceMN<T, R, C>> {
+        let index = self.block_index(block_row, block_col)?;
+        let block_size = self.block_nrows.value() * self.block_ncols.value();
+        let values = &self.values[index * block_size..(index + 1) * block_size];
+        Some(MatrixSliceMN::from_slice_generic(
+            values,
+            self.block_nrows,
+            self.block_ncols,
+        ))
+    }
+
+    /// Returns the block at the given block position mutably, or `None` if the block is not
+    /// explicitly stored or the position is out of bounds.
+    pub fn get_block_mut(
+        &mut self,
+        block_row: usize,
+        block_col: usize,
+    ) -> Option<MatrixSliceMutMN<T, R, C>> {
+        let index = self.block_index(block_row, block_col)?;
+        let block_size = self.block_nrows.value() * self.block_ncols.value();
+        let values = &mut self.values[index * block_size..(index + 1) * block_size];
+        Some(MatrixSliceMutMN::from_slice_generic(
+            values,
+            self.block_nrows,
+            self.block_ncols,
+        ))
+    }
+}
+
+/// An iterator over the blocks of a BSR matrix.
+#[derive(Debug)]
+pub struct BsrBlockIter<'a, T, R: Dim, C: Dim> {
+    pattern_iter: SparsityPatternIter<'a>,
+    values_iter: Chunks<'a, T>,
+    block_nrows: R,
+    block_ncols: C,
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim> Iterator for BsrBlockIter<'a, T, R, C> {
+    type Item = (usize, usize, MatrixSliceMN<'a, T, R, C>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, j) = self.pattern_iter.next()?;
+        let values = self.values_iter.next()?;
+        let block = MatrixSliceMN::from_slice_generic(values, self.block_nrows, self.block_ncols);
+        Some((i, j, block))
+    }
+}
+
+/// A mutable iterator over the blocks of a BSR matrix.
+#[derive(Debug)]
+pub struct BsrBlockIterMut<'a, T, R: Dim, C: Dim> {
+    pattern_iter: SparsityPatternIter<'a>,
+    values_iter: ChunksMut<'a, T>,
+    block_nrows: R,
+    block_ncols: C,
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim> Iterator for BsrBlockIterMut<'a, T, R, C> {
+    type Item = (usize, usize, MatrixSliceMutMN<'a, T, R, C>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, j) = self.pattern_iter.next()?;
+        let values = self.values_iter.next()?;
+        let block =
+            MatrixSliceMutMN::from_slice_generic(values, self.block_nrows, self.block_ncols);
+        Some((i, j, block))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::bsr::BsrMatrix;
 use crate::convert::serial::*;
 use crate::coo::CooMatrix;
 use crate::csc::CscMatrix;
@@ -122,3 +123,36 @@ where
         convert_csr_csc(matrix)
     }
 }
+
+impl<'a, T, R, C> From<&'a BsrMatrix<T, R, C>> for CooMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+{
+    fn from(matrix: &'a BsrMatrix<T, R, C>) -> Self {
+        convert_bsr_coo(matrix)
+    }
+}
+
+impl<'a, T, R, C> From<&'a BsrMatrix<T, R, C>> for CsrMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+{
+    fn from(matrix: &'a BsrMatrix<T, R, C>) -> Self {
+        convert_bsr_csr(matrix)
+    }
+}
+
+impl<'a, T, R, C> From<&'a BsrMatrix<T, R, C>> for DMatrix<T>
+where
+    T: Scalar + Zero,
+    R: Dim,
+    C: Dim,
+{
+    fn from(matrix: &'a BsrMatrix<T, R, C>) -> Self {
+        convert_bsr_dense(matrix)
+    }
+}
@@ -10,10 +10,12 @@ use num_traits::Zero;
 use nalgebra::storage::Storage;
 use nalgebra::{ClosedAdd, DMatrix, Dim, Matrix, Scalar};
 
+use crate::bsr::BsrMatrix;
 use crate::coo::CooMatrix;
 use crate::cs;
 use crate::csc::CscMatrix;
 use crate::csr::CsrMatrix;
+use crate::pattern::SparsityPattern;
 
 /// Converts a dense matrix to [`CooMatrix`].
 pub fn convert_dense_coo<T, R, C, S>(dense: &Matrix<T, R, C, S>) -> CooMatrix<T>
@@ -233,6 +235,191 @@ where
         .expect("Internal error: Invalid CSR data during CSC->CSR conversion")
 }
 
+/// Converts a [`BsrMatrix`] to a [`CooMatrix`].
+///
+/// All entries of the stored blocks are explicitly stored in the result, including zeros.
+pub fn convert_bsr_coo<T, R, C>(bsr: &BsrMatrix<T, R, C>) -> CooMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+{
+    let (r, c) = bsr.block_shape();
+    let mut coo = CooMatrix::new(bsr.nrows(), bsr.ncols());
+    for (block_row, block_col, block) in bsr.block_iter() {
+        for (index, v) in block.iter().enumerate() {
+            // Blocks are stored in column-major order
+            coo.push(
+                block_row * r + index % r,
+                block_col * c + index / r,
+                v.inlined_clone(),
+            );
+        }
+    }
+    coo
+}
+
+/// Converts a [`BsrMatrix`] to a [`CsrMatrix`].
+///
+/// All entries of the stored blocks are explicitly stored in the result, including zeros.
+pub fn convert_bsr_csr<T, R, C>(bsr: &BsrMatrix<T, R, C>) -> CsrMatrix<T>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+{
+    let (r, c) = bsr.block_shape();
+    let pattern = bsr.pattern();
+    let mut row_offsets = Vec::with_capacity(bsr.nrows() + 1);
+    let mut col_indices = Vec::with_capacity(bsr.nnz());
+    let mut values = Vec::with_capacity(bsr.nnz());
+
+    row_offsets.push(0);
+    for block_row in 0..pattern.major_dim() {
+        let block_range =
+            pattern.major_offsets()[block_row]..pattern.major_offsets()[block_row + 1];
+        for local_row in 0..r {
+            for block_index in block_range.clone() {
+                let block_col = pattern.minor_indices()[block_index];
+                let block_values = &bsr.values()[block_index * r * c..(block_index + 1) * r * c];
+                for local_col in 0..c {
+                    col_indices.push(block_col * c + local_col);
+                    values.push(block_values[local_col * r + local_row].inlined_clone());
+                }
+            }
+            row_offsets.push(col_indices.len());
+        }
+    }
+
+    // TODO: Consider circumventing the data validity check here
+    CsrMatrix::try_from_csr_data(bsr.nrows(), bsr.ncols(), row_offsets, col_indices, values)
+        .expect("Internal error: Invalid CSR data during BSR->CSR conversion")
+}
+
+/// Converts a [`BsrMatrix`] to a dense matrix.
+pub fn convert_bsr_dense<T, R, C>(bsr: &BsrMatrix<T, R, C>) -> DMatrix<T>
+where
+    T: Scalar + Zero,
+    R: Dim,
+    C: Dim,
+{
+    let (r, c) = bsr.block_shape_generic();
+    let mut output = DMatrix::zeros(bsr.nrows(), bsr.ncols());
+    for (block_row, block_col, block) in bsr.block_iter() {
+        let start = (block_row * r.value(), block_col * c.value());
+        output.generic_slice_mut(start, (r, c)).copy_from(&block);
+    }
+    output
+}
+
+/// Converts a [`CsrMatrix`] to a [`BsrMatrix`] with blocks of the given dimensions.
+///
+/// Every block that contains at least one explicitly stored entry of the CSR matrix is stored
+/// in the result, with the remaining entries of the block set to zero.
+///
+/// # Panics
+///
+/// Panics if a block dimension is zero, or if the dimensions of the matrix are not multiples of
+/// the block dimensions.
+pub fn convert_csr_bsr<T, R, C>(
+    csr: &CsrMatrix<T>,
+    block_nrows: R,
+    block_ncols: C,
+) -> BsrMatrix<T, R, C>
+where
+    T: Scalar + Zero,
+    R: Dim,
+    C: Dim,
+{
+    let (r, c) = (block_nrows.value(), block_ncols.value());
+    assert!(r > 0 && c > 0, "Block dimensions must be non-zero.");
+    assert_eq!(
+        csr.nrows() % r,
+        0,
+        "The number of rows must be a multiple of the block row dimension."
+    );
+    assert_eq!(
+        csr.ncols() % c,
+        0,
+        "The number of columns must be a multiple of the block column dimension."
+    );
+    let (block_row_count, block_col_count) = (csr.nrows() / r, csr.ncols() / c);
+
+    let mut block_offsets = Vec::with_capacity(block_row_count + 1);
+    let mut block_cols = Vec::new();
+    let mut values = Vec::new();
+    // The local index of each block column within the current block row, or `usize::MAX` if the
+    // block row has no block in the column
+    let mut local_block_index = vec![usize::MAX; block_col_count];
+
+    block_offsets.push(0);
+    for block_row in 0..block_row_count {
+        let rows = block_row * r..(block_row + 1) * r;
+        let begin = block_cols.len();
+        for i in rows.clone() {
+            for &j in csr.row(i).col_indices() {
+                if local_block_index[j / c] == usize::MAX {
+                    local_block_index[j / c] = 0;
+                    block_cols.push(j / c);
+                }
+            }
+        }
+        block_cols[begin..].sort_unstable();
+        for (local_index, &block_col) in block_cols[begin..].iter().enumerate() {
+            local_block_index[block_col] = local_index;
+        }
+
+        let block_row_values_begin = values.len();
+        values.resize(values.len() + (block_cols.len() - begin) * r * c, T::zero());
+        let block_row_values = &mut values[block_row_values_begin..];
+        for i in rows {
+            let row = csr.row(i);
+            for (&j, v) in row.col_indices().iter().zip(row.values()) {
+                let block_index = local_block_index[j / c];
+                let local_index = (j % c) * r + i % r;
+                block_row_values[block_index * r * c + local_index] = v.inlined_clone();
+            }
+        }
+
+        for &block_col in &block_cols[begin..] {
+            local_block_index[block_col] = usize::MAX;
+        }
+        block_offsets.push(block_cols.len());
+    }
+
+    // TODO: Consider circumventing the data validity check here
+    let pattern = SparsityPattern::try_from_offsets_and_indices(
+        block_row_count,
+        block_col_count,
+        block_offsets,
+        block_cols,
+    )
+    .expect("Internal error: Invalid block pattern during CSR->BSR conversion");
+    BsrMatrix::try_from_pattern_and_values(pattern, block_nrows, block_ncols, values)
+        .expect("Internal error: Invalid BSR data during CSR->BSR conversion")
+}
+
+/// Converts a [`CooMatrix`] to a [`BsrMatrix`] with blocks of the given dimensions.
+///
+/// Duplicate entries are summed, as in the conversion to [`CsrMatrix`].
+///
+/// # Panics
+///
+/// Panics if a block dimension is zero, or if the dimensions of the matrix are not multiples of
+/// the block dimensions.
+pub fn convert_coo_bsr<T, R, C>(
+    coo: &CooMatrix<T>,
+    block_nrows: R,
+    block_ncols: C,
+) -> BsrMatrix<T, R, C>
+where
+    T: Scalar + Zero,
+    R: Dim,
+    C: Dim,
+{
+    convert_csr_bsr(&convert_coo_csr(coo), block_nrows, block_ncols)
+}
+
 fn convert_coo_cs<T>(
     major_dim: usize,
     major_indices: &[usize],
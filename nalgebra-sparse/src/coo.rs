@@ -206,3 +206,58 @@ impl<T> CooMatrix<T> {
         (self.row_indices, self.col_indices, self.values)
     }
 }
+
+#[cfg(feature = "serde-serialize")]
+mod serde_serialize {
+    use super::CooMatrix;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "CooMatrix")]
+    struct CooMatrixRef<'a, T> {
+        nrows: usize,
+        ncols: usize,
+        row_indices: &'a [usize],
+        col_indices: &'a [usize],
+        values: &'a [T],
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "CooMatrix")]
+    struct CooMatrixData<T> {
+        nrows: usize,
+        ncols: usize,
+        row_indices: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    }
+
+    impl<T: Serialize> Serialize for CooMatrix<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            CooMatrixRef {
+                nrows: self.nrows,
+                ncols: self.ncols,
+                row_indices: &self.row_indices,
+                col_indices: &self.col_indices,
+                values: &self.values,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for CooMatrix<T> {
+        /// Deserializes a COO matrix, returning an error if the triplets are inconsistent or
+        /// out of bounds.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = CooMatrixData::deserialize(deserializer)?;
+            CooMatrix::try_from_triplets(
+                data.nrows,
+                data.ncols,
+                data.row_indices,
+                data.col_indices,
+                data.values,
+            )
+            .map_err(de::Error::custom)
+        }
+    }
+}
@@ -214,6 +214,224 @@ impl<T> CsMatrix<T> {
         // TODO: This might be faster with a binary search for each diagonal entry
         self.filter(|i, j, _| i == j)
     }
+
+    /// Returns the submatrix consisting of the given contiguous ranges of lanes and
+    /// minor indices.
+    ///
+    /// Panics if either of the ranges is out of bounds.
+    pub fn slice(&self, major_range: Range<usize>, minor_range: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        assert!(
+            major_range.start <= major_range.end && major_range.end <= major_dim,
+            "Major index range out of bounds."
+        );
+        assert!(
+            minor_range.start <= minor_range.end && minor_range.end <= minor_dim,
+            "Minor index range out of bounds."
+        );
+
+        let mut new_offsets = Vec::with_capacity(major_range.len() + 1);
+        let mut new_indices = Vec::new();
+        let mut new_values = Vec::new();
+
+        new_offsets.push(0);
+        for i in major_range.clone() {
+            let lane = self.get_lane(i).unwrap();
+            let minor_indices = lane.minor_indices();
+            // The minor indices are sorted, so the entries within the range are contiguous
+            let begin = minor_indices.partition_point(|&j| j < minor_range.start);
+            let end = minor_indices.partition_point(|&j| j < minor_range.end);
+            new_indices.extend(
+                minor_indices[begin..end]
+                    .iter()
+                    .map(|j| j - minor_range.start),
+            );
+            new_values.extend_from_slice(&lane.values()[begin..end]);
+            new_offsets.push(new_indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
+            major_range.len(),
+            minor_range.len(),
+            new_offsets,
+            new_indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
+
+    /// Returns the submatrix consisting of the given lanes and minor indices, in the given order.
+    ///
+    /// If `minor_indices` is `None`, all minor indices are retained. Indices may be repeated.
+    /// Panics if any index is out of bounds.
+    pub fn select(&self, major_indices: &[usize], minor_indices: Option<&[usize]>) -> Self
+    where
+        T: Clone,
+    {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        assert!(
+            major_indices.iter().all(|&i| i < major_dim),
+            "Major index out of bounds."
+        );
+
+        // For each old minor index, store the (ascending) new minor indices it maps to,
+        // using the same offset-based layout as the sparsity pattern itself
+        let minor_map = minor_indices.map(|minor_indices| {
+            assert!(
+                minor_indices.iter().all(|&j| j < minor_dim),
+                "Minor index out of bounds."
+            );
+            let mut offsets = vec![0; minor_dim + 1];
+            for &j in minor_indices {
+                offsets[j] += 1;
+            }
+            convert_counts_to_offsets(&mut offsets);
+            let mut targets = vec![0; minor_indices.len()];
+            let mut next = offsets.clone();
+            for (new_j, &j) in minor_indices.iter().enumerate() {
+                targets[next[j]] = new_j;
+                next[j] += 1;
+            }
+            let is_increasing = minor_indices.windows(2).all(|w| w[0] < w[1]);
+            (offsets, targets, is_increasing)
+        });
+
+        let mut new_offsets = Vec::with_capacity(major_indices.len() + 1);
+        let mut new_indices = Vec::new();
+        let mut new_values = Vec::new();
+        let mut lane_entries = Vec::new();
+
+        new_offsets.push(0);
+        for &i in major_indices {
+            let lane = self.get_lane(i).unwrap();
+            match &minor_map {
+                None => {
+                    new_indices.extend_from_slice(lane.minor_indices());
+                    new_values.extend_from_slice(lane.values());
+                }
+                Some((offsets, targets, is_increasing)) => {
+                    lane_entries.clear();
+                    for (&j, v) in lane.minor_indices.iter().zip(lane.values) {
+                        for &new_j in &targets[offsets[j]..offsets[j + 1]] {
+                            lane_entries.push((new_j, v));
+                        }
+                    }
+                    if !is_increasing {
+                        lane_entries.sort_unstable_by_key(|&(new_j, _)| new_j);
+                    }
+                    for &(new_j, v) in &lane_entries {
+                        new_indices.push(new_j);
+                        new_values.push(v.clone());
+                    }
+                }
+            }
+            new_offsets.push(new_indices.len());
+        }
+
+        let new_minor_dim = minor_indices.map_or(minor_dim, <[usize]>::len);
+        // TODO: Avoid checks here
+        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
+            major_indices.len(),
+            new_minor_dim,
+            new_offsets,
+            new_indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
+
+    /// Returns a copy of this matrix in which the submatrix given by the lanes `major_indices`
+    /// and the minor indices `minor_indices` is replaced by `block`.
+    ///
+    /// Explicit entries of this matrix that lie within the submatrix are removed, and
+    /// the explicit entries of `block` are inserted in their place.
+    ///
+    /// Panics if any index is out of bounds or repeated, or if the dimensions of `block`
+    /// do not match the number of indices.
+    pub fn insert_block(
+        &self,
+        major_indices: &[usize],
+        minor_indices: &[usize],
+        block: &CsMatrix<T>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        assert_eq!(
+            (block.pattern().major_dim(), block.pattern().minor_dim()),
+            (major_indices.len(), minor_indices.len()),
+            "Block dimensions must match the number of indices."
+        );
+
+        // Maps global indices to the local indices of the block, if they are part of it
+        let local_indices = |indices: &[usize], dim| {
+            let mut local = vec![None; dim];
+            for (local_idx, &idx) in indices.iter().enumerate() {
+                assert!(idx < dim, "Index out of bounds.");
+                assert!(local[idx].is_none(), "Indices must not be repeated.");
+                local[idx] = Some(local_idx);
+            }
+            local
+        };
+        let local_major = local_indices(major_indices, major_dim);
+        let local_minor = local_indices(minor_indices, minor_dim);
+
+        let mut new_offsets = Vec::with_capacity(major_dim + 1);
+        let mut new_indices = Vec::new();
+        let mut new_values = Vec::new();
+        let mut lane_entries = Vec::new();
+
+        new_offsets.push(0);
+        for (i, lane) in self.lane_iter().enumerate() {
+            match local_major[i] {
+                None => {
+                    new_indices.extend_from_slice(lane.minor_indices());
+                    new_values.extend_from_slice(lane.values());
+                }
+                Some(local_i) => {
+                    lane_entries.clear();
+                    let retained = lane
+                        .minor_indices
+                        .iter()
+                        .zip(lane.values)
+                        .filter(|&(&j, _)| local_minor[j].is_none())
+                        .map(|(&j, v)| (j, v));
+                    lane_entries.extend(retained);
+                    let block_lane = block.get_lane(local_i).unwrap();
+                    let inserted = block_lane
+                        .minor_indices
+                        .iter()
+                        .zip(block_lane.values)
+                        .map(|(&local_j, v)| (minor_indices[local_j], v));
+                    lane_entries.extend(inserted);
+                    lane_entries.sort_unstable_by_key(|&(j, _)| j);
+                    for &(j, v) in &lane_entries {
+                        new_indices.push(j);
+                        new_values.push(v.clone());
+                    }
+                }
+            }
+            new_offsets.push(new_indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
+            major_dim,
+            minor_dim,
+            new_offsets,
+            new_indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
 }
 
 impl<T: Scalar + One> CsMatrix<T> {
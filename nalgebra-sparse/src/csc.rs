@@ -10,6 +10,7 @@ use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKin
 
 use nalgebra::Scalar;
 use num_traits::One;
+use std::ops::Range;
 use std::slice::{Iter, IterMut};
 
 /// A CSC representation of a sparse matrix.
@@ -504,6 +505,95 @@ impl<T> CscMatrix<T> {
     {
         CsrMatrix::from(self).transpose_as_csc()
     }
+
+    /// Returns the submatrix consisting of the given contiguous ranges of rows and columns.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if either of the ranges is out of bounds.
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.slice(cols, rows),
+        }
+    }
+
+    /// Returns the matrix consisting of the given rows, in the given order.
+    ///
+    /// Rows may be selected more than once. For example, constrained degrees of freedom can be
+    /// removed by selecting all the unconstrained rows.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any row index is out of bounds.
+    pub fn select_rows(&self, rows: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        let all_cols: Vec<_> = (0..self.ncols()).collect();
+        Self {
+            cs: self.cs.select(&all_cols, Some(rows)),
+        }
+    }
+
+    /// Returns the matrix consisting of the given columns, in the given order.
+    ///
+    /// Columns may be selected more than once.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any column index is out of bounds.
+    pub fn select_columns(&self, cols: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.select(cols, None),
+        }
+    }
+
+    /// Extracts the submatrix consisting of the entries at the intersection of the given rows
+    /// and columns.
+    ///
+    /// The rows and columns of the submatrix are ordered as in `rows` and `cols`, and
+    /// indices may be repeated. This is equivalent to, but faster than,
+    /// `self.select_rows(rows).select_columns(cols)`.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any row or column index is out of bounds.
+    pub fn submatrix(&self, rows: &[usize], cols: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.select(cols, Some(rows)),
+        }
+    }
+
+    /// Returns a copy of this matrix in which the submatrix at the intersection of the given
+    /// rows and columns is replaced by `submatrix`.
+    ///
+    /// This is the reverse of [`submatrix`](Self::submatrix): entry `(i, j)` of `submatrix` is
+    /// scattered to `(rows[i], cols[j])`. Explicitly stored entries of this matrix inside the
+    /// submatrix are removed, and the explicitly stored entries of `submatrix` take their place.
+    /// Entries outside of the submatrix are unaffected. In particular,
+    /// `m.insert_submatrix(rows, cols, &m.submatrix(rows, cols))` is equal to `m`.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any row or column index is out of bounds or repeated, or if the dimensions
+    /// of `submatrix` are not `rows.len() x cols.len()`.
+    pub fn insert_submatrix(&self, rows: &[usize], cols: &[usize], submatrix: &CscMatrix<T>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.insert_block(cols, rows, &submatrix.cs),
+        }
+    }
 }
 
 /// Convert pattern format errors into more meaningful CSC-specific errors.
@@ -702,3 +792,58 @@ where
         self.lane_iter.next().map(|lane| CscColMut { lane })
     }
 }
+
+#[cfg(feature = "serde-serialize")]
+mod serde_serialize {
+    use super::CscMatrix;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "CscMatrix")]
+    struct CscMatrixRef<'a, T> {
+        nrows: usize,
+        ncols: usize,
+        col_offsets: &'a [usize],
+        row_indices: &'a [usize],
+        values: &'a [T],
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "CscMatrix")]
+    struct CscMatrixData<T> {
+        nrows: usize,
+        ncols: usize,
+        col_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    }
+
+    impl<T: Serialize> Serialize for CscMatrix<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            CscMatrixRef {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+                col_offsets: self.col_offsets(),
+                row_indices: self.row_indices(),
+                values: self.values(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for CscMatrix<T> {
+        /// Deserializes a CSC matrix, returning an error if the data does not form a valid
+        /// CSC matrix.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = CscMatrixData::deserialize(deserializer)?;
+            CscMatrix::try_from_csc_data(
+                data.nrows,
+                data.ncols,
+                data.col_offsets,
+                data.row_indices,
+                data.values,
+            )
+            .map_err(de::Error::custom)
+        }
+    }
+}
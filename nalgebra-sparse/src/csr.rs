@@ -10,6 +10,7 @@ use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKin
 use nalgebra::Scalar;
 use num_traits::One;
 
+use std::ops::Range;
 use std::slice::{Iter, IterMut};
 
 /// A CSR representation of a sparse matrix.
@@ -504,6 +505,95 @@ impl<T> CsrMatrix<T> {
     {
         CscMatrix::from(self).transpose_as_csr()
     }
+
+    /// Returns the submatrix consisting of the given contiguous ranges of rows and columns.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if either of the ranges is out of bounds.
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.slice(rows, cols),
+        }
+    }
+
+    /// Returns the matrix consisting of the given rows, in the given order.
+    ///
+    /// Rows may be selected more than once. For example, constrained degrees of freedom can be
+    /// removed by selecting all the unconstrained rows.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any row index is out of bounds.
+    pub fn select_rows(&self, rows: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.select(rows, None),
+        }
+    }
+
+    /// Returns the matrix consisting of the given columns, in the given order.
+    ///
+    /// Columns may be selected more than once.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any column index is out of bounds.
+    pub fn select_columns(&self, cols: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        let all_rows: Vec<_> = (0..self.nrows()).collect();
+        Self {
+            cs: self.cs.select(&all_rows, Some(cols)),
+        }
+    }
+
+    /// Extracts the submatrix consisting of the entries at the intersection of the given rows
+    /// and columns.
+    ///
+    /// The rows and columns of the submatrix are ordered as in `rows` and `cols`, and
+    /// indices may be repeated. This is equivalent to, but faster than,
+    /// `self.select_rows(rows).select_columns(cols)`.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any row or column index is out of bounds.
+    pub fn submatrix(&self, rows: &[usize], cols: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.select(rows, Some(cols)),
+        }
+    }
+
+    /// Returns a copy of this matrix in which the submatrix at the intersection of the given
+    /// rows and columns is replaced by `submatrix`.
+    ///
+    /// This is the reverse of [`submatrix`](Self::submatrix): entry `(i, j)` of `submatrix` is
+    /// scattered to `(rows[i], cols[j])`. Explicitly stored entries of this matrix inside the
+    /// submatrix are removed, and the explicitly stored entries of `submatrix` take their place.
+    /// Entries outside of the submatrix are unaffected. In particular,
+    /// `m.insert_submatrix(rows, cols, &m.submatrix(rows, cols))` is equal to `m`.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if any row or column index is out of bounds or repeated, or if the dimensions
+    /// of `submatrix` are not `rows.len() x cols.len()`.
+    pub fn insert_submatrix(&self, rows: &[usize], cols: &[usize], submatrix: &CsrMatrix<T>) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.insert_block(rows, cols, &submatrix.cs),
+        }
+    }
 }
 
 /// Convert pattern format errors into more meaningful CSR-specific errors.
@@ -706,3 +796,58 @@ where
         self.lane_iter.next().map(|lane| CsrRowMut { lane })
     }
 }
+
+#[cfg(feature = "serde-serialize")]
+mod serde_serialize {
+    use super::CsrMatrix;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "CsrMatrix")]
+    struct CsrMatrixRef<'a, T> {
+        nrows: usize,
+        ncols: usize,
+        row_offsets: &'a [usize],
+        col_indices: &'a [usize],
+        values: &'a [T],
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "CsrMatrix")]
+    struct CsrMatrixData<T> {
+        nrows: usize,
+        ncols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    }
+
+    impl<T: Serialize> Serialize for CsrMatrix<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            CsrMatrixRef {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+                row_offsets: self.row_offsets(),
+                col_indices: self.col_indices(),
+                values: self.values(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for CsrMatrix<T> {
+        /// Deserializes a CSR matrix, returning an error if the data does not form a valid
+        /// CSR matrix.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = CsrMatrixData::deserialize(deserializer)?;
+            CsrMatrix::try_from_csr_data(
+                data.nrows,
+                data.ncols,
+                data.row_offsets,
+                data.col_indices,
+                data.values,
+            )
+            .map_err(de::Error::custom)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::csc::CscMatrix;
 use crate::ops::serial::spsolve_csc_lower_triangular;
 use crate::ops::Op;
+use crate::ordering::{OrderingMethod, Permutation};
 use crate::pattern::SparsityPattern;
 use core::{iter, mem};
 use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, RealField, Scalar};
@@ -9,13 +10,24 @@ use std::fmt::{Display, Formatter};
 /// A symbolic sparse Cholesky factorization of a CSC matrix.
 ///
 /// The symbolic factorization computes the sparsity pattern of `L`, the Cholesky factor.
+/// If a fill-reducing ordering is used, `L` is the Cholesky factor of the permuted matrix
+/// `P A P^T`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CscSymbolicCholesky {
-    // Pattern of the original matrix that was decomposed
+    // Pattern of the (permuted) matrix that was decomposed
     m_pattern: SparsityPattern,
     l_pattern: SparsityPattern,
     // u in this context is L^T, so that M = L L^T
     u_pattern: SparsityPattern,
+    ordering: Option<SymmetricOrdering>,
+}
+
+/// The permutation `P` applied to the matrix, and the index in the values of the original matrix
+/// of each non-zero of `P A P^T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SymmetricOrdering {
+    permutation: Permutation,
+    value_indices: Vec<usize>,
 }
 
 impl CscSymbolicCholesky {
@@ -38,6 +50,34 @@ impl CscSymbolicCholesky {
             m_pattern: pattern,
             l_pattern,
             u_pattern,
+            ordering: None,
+        }
+    }
+
+    /// Compute the symbolic factorization of the symmetrically permuted matrix `P A P^T`, where
+    /// the permutation `P` is computed by the given ordering method from the sparsity pattern of
+    /// the CSC matrix `A`.
+    ///
+    /// The sparsity pattern must be symmetric. However, this is not enforced, and it is the
+    /// responsibility of the user to ensure that this property holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sparsity pattern is not square, or if the dimension of a custom
+    /// permutation does not match the dimension of the pattern.
+    pub fn factor_with_ordering(pattern: SparsityPattern, ordering: &OrderingMethod) -> Self {
+        if *ordering == OrderingMethod::Natural {
+            return Self::factor(pattern);
+        }
+
+        let permutation = ordering.compute(&pattern);
+        let (permuted_pattern, value_indices) = permutation.symmetric_permutation_map(&pattern);
+        Self {
+            ordering: Some(SymmetricOrdering {
+                permutation,
+                value_indices,
+            }),
+            ..Self::factor(permuted_pattern)
         }
     }
 
@@ -45,6 +85,11 @@ impl CscSymbolicCholesky {
     pub fn l_pattern(&self) -> &SparsityPattern {
         &self.l_pattern
     }
+
+    /// The fill-reducing permutation `P`, if an ordering was applied.
+    pub fn permutation(&self) -> Option<&Permutation> {
+        self.ordering.as_ref().map(|ordering| &ordering.permutation)
+    }
 }
 
 /// A sparse Cholesky factorization `A = L L^T` of a [`CscMatrix`].
@@ -53,9 +98,11 @@ impl CscSymbolicCholesky {
 /// more information.
 ///
 /// The implementation is a port of the `CsCholesky` implementation in `nalgebra`. It is similar
-/// to Tim Davis' [`CSparse`]. The current implementation performs no fill-in reduction, and can
-/// therefore be expected to produce much too dense Cholesky factors for many matrices.
-/// It is therefore not currently recommended to use this implementation for serious projects.
+/// to Tim Davis' [`CSparse`]. By default, no fill-in reduction is performed, which can
+/// produce much too dense Cholesky factors for many matrices. A fill-reducing ordering from the
+/// [`ordering`](crate::ordering) module can be applied with [`CscCholesky::factor_with_ordering`],
+/// in which case the factorization `P A P^T = L L^T` of the symmetrically permuted matrix is
+/// computed, and the permutation is transparently applied when solving systems.
 ///
 /// [`CSparse`]: https://epubs.siam.org/doi/book/10.1137/1.9780898718881
 /// [Wikipedia]: https://en.wikipedia.org/wiki/Cholesky_decomposition
@@ -67,6 +114,7 @@ pub struct CscCholesky<T> {
     m_pattern: SparsityPattern,
     l_factor: CscMatrix<T>,
     u_pattern: SparsityPattern,
+    ordering: Option<SymmetricOrdering>,
     work_x: Vec<T>,
     work_c: Vec<usize>,
 }
@@ -124,6 +172,7 @@ impl<T: RealField> CscCholesky<T> {
             m_pattern: symbolic.m_pattern,
             l_factor,
             u_pattern: symbolic.u_pattern,
+            ordering: symbolic.ordering,
             work_x: vec![T::zero(); nrows],
             // Fill with MAX so that things hopefully totally fail if values are not
             // overwritten. Might be easier to debug this way
@@ -152,10 +201,35 @@ impl<T: RealField> CscCholesky<T> {
         Self::factor_numerical(symbolic, matrix.values())
     }
 
+    /// Computes the Cholesky factorization `P A P^T = L L^T` of the provided matrix, after
+    /// applying the permutation computed by the given ordering method.
+    ///
+    /// The matrix must be symmetric positive definite. Symmetry is not checked, and it is up
+    /// to the user to enforce this property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the numerical factorization fails. This can occur if the matrix is not
+    /// symmetric positive definite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if the dimension of a custom permutation does not
+    /// match the dimension of the matrix.
+    pub fn factor_with_ordering(
+        matrix: &CscMatrix<T>,
+        ordering: &OrderingMethod,
+    ) -> Result<Self, CholeskyError> {
+        let symbolic =
+            CscSymbolicCholesky::factor_with_ordering(matrix.pattern().clone(), ordering);
+        Self::factor_numerical(symbolic, matrix.values())
+    }
+
     /// Re-computes the factorization for a new set of non-zero values.
     ///
     /// This is useful when the values of a matrix changes, but the sparsity pattern remains
-    /// constant.
+    /// constant. The values correspond to the non-zeros of the original matrix, even if
+    /// a fill-reducing ordering is applied.
     ///
     /// # Errors
     ///
@@ -167,14 +241,35 @@ impl<T: RealField> CscCholesky<T> {
     /// Panics if the number of values does not match the number of non-zeros in the sparsity
     /// pattern.
     pub fn refactor(&mut self, values: &[T]) -> Result<(), CholeskyError> {
-        self.decompose_left_looking(values)
+        assert_eq!(
+            values.len(),
+            self.m_pattern.nnz(),
+            "The number of values must match the number of non-zeros of the pattern."
+        );
+
+        match &self.ordering {
+            Some(ordering) => {
+                let permuted_values: Vec<T> =
+                    ordering.value_indices.iter().map(|&p| values[p]).collect();
+                self.decompose_left_looking(&permuted_values)
+            }
+            None => self.decompose_left_looking(values),
+        }
     }
 
     /// Returns a reference to the Cholesky factor `L`.
+    ///
+    /// If a fill-reducing ordering is applied, this is the factor of the permuted matrix
+    /// `P A P^T`.
     pub fn l(&self) -> &CscMatrix<T> {
         &self.l_factor
     }
 
+    /// The fill-reducing permutation `P`, if an ordering was applied.
+    pub fn permutation(&self) -> Option<&Permutation> {
+        self.ordering.as_ref().map(|ordering| &ordering.permutation)
+    }
+
     /// Returns the Cholesky factor `L`.
     pub fn take_l(self) -> CscMatrix<T> {
         self.l_factor
@@ -277,13 +372,22 @@ impl<T: RealField> CscCholesky<T> {
     pub fn solve_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
         let expect_msg = "If the Cholesky factorization succeeded,\
             then the triangular solve should never fail";
+        let mut b = b.into();
+        if let Some(ordering) = &self.ordering {
+            ordering.permutation.permute_rows_mut(&mut b);
+        }
+
         // Solve LY = B
-        let mut y = b.into();
+        let mut y = b;
         spsolve_csc_lower_triangular(Op::NoOp(self.l()), &mut y).expect(expect_msg);
 
         // Solve L^T X = Y
         let mut x = y;
         spsolve_csc_lower_triangular(Op::Transpose(self.l()), &mut x).expect(expect_msg);
+
+        if let Some(ordering) = &self.ordering {
+            ordering.permutation.inverse_permute_rows_mut(&mut x);
+        }
     }
 }
 
@@ -0,0 +1,576 @@
+use crate::csc::CscMatrix;
+use crate::pattern::SparsityPattern;
+use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, RealField};
+use std::fmt::{Display, Formatter};
+
+/// A symbolic sparse LU factorization of a CSC matrix.
+///
+/// The sparsity pattern of the LU factors depends on the row interchanges chosen during the
+/// numerical factorization, so the symbolic factorization only estimates their number of
+/// non-zeros to preallocate their storage. Like `cs_lu` in CSparse, this estimate is
+/// `4 * nnz(A) + n`, and the storage grows as needed if the fill-in is larger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CscSymbolicLu {
+    // Pattern of the original matrix that was decomposed
+    m_pattern: SparsityPattern,
+    factor_nnz_estimate: usize,
+}
+
+impl CscSymbolicLu {
+    /// Compute the symbolic factorization for a sparsity pattern belonging to a CSC matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sparsity pattern is not square.
+    pub fn factor(pattern: SparsityPattern) -> Self {
+        assert_eq!(
+            pattern.major_dim(),
+            pattern.minor_dim(),
+            "Major and minor dimensions must be the same (square matrix)."
+        );
+        let factor_nnz_estimate = pattern
+            .nnz()
+            .saturating_mul(4)
+            .saturating_add(pattern.major_dim());
+        Self {
+            m_pattern: pattern,
+            factor_nnz_estimate,
+        }
+    }
+
+    /// An estimate of the number of non-zeros of each of the factors `L` and `U`.
+    pub fn factor_nnz_estimate(&self) -> usize {
+        self.factor_nnz_estimate
+    }
+}
+
+/// A sparse LU factorization `P A = L U` of a [`CscMatrix`] with threshold partial pivoting.
+///
+/// The factor `L` is a sparse, unit lower-triangular matrix, `U` is a sparse upper-triangular
+/// matrix and `P` is a row permutation.
+///
+/// The numerical factorization is a left-looking Gilbert-Peierls algorithm, similar to the
+/// `cs_lu` function of Tim Davis' [`CSparse`]. At each column, the diagonal entry is
+/// preferred as the pivot whenever its magnitude is at least `pivot_threshold` times the largest
+/// magnitude among the candidate entries, which helps preserving the sparsity of matrices with a
+/// dominant diagonal. The current implementation performs no column reordering to reduce fill-in.
+///
+/// [`CSparse`]: https://epubs.siam.org/doi/book/10.1137/1.9780898718881
+#[derive(Debug, Clone)]
+pub struct CscLu<T> {
+    // Pattern of the original matrix
+    m_pattern: SparsityPattern,
+    l_factor: CscMatrix<T>,
+    u_factor: CscMatrix<T>,
+    // Row `k` of `P A` is row `perm[k]` of `A`, and `perm_inv` is the inverse permutation.
+    perm: Vec<usize>,
+    perm_inv: Vec<usize>,
+    work_x: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+/// Possible errors produced by the LU factorization.
+pub enum LuError {
+    /// The matrix is singular.
+    Singular,
+}
+
+impl Display for LuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Matrix is singular")
+    }
+}
+
+impl std::error::Error for LuError {}
+
+impl<T: RealField> CscLu<T> {
+    /// Computes the numerical LU factorization associated with the given symbolic factorization
+    /// and the provided values.
+    ///
+    /// The values correspond to the non-zero values of the CSC matrix for which the
+    /// symbolic factorization was computed. The `pivot_threshold` must lie in `[0, 1]`: a
+    /// threshold of `1` performs the usual partial pivoting, while smaller thresholds favor the
+    /// diagonal entries as pivots.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values differ from the number of non-zeros of the sparsity pattern
+    /// of the matrix that was symbolically factored, or if `pivot_threshold` is not in `[0, 1]`.
+    pub fn factor_numerical(
+        symbolic: CscSymbolicLu,
+        values: &[T],
+        pivot_threshold: T,
+    ) -> Result<Self, LuError> {
+        assert_eq!(
+            values.len(),
+            symbolic.m_pattern.nnz(),
+            "The number of values must match the number of non-zeros of the pattern."
+        );
+        assert!(
+            pivot_threshold >= T::zero() && pivot_threshold <= T::one(),
+            "The pivot threshold must lie in [0, 1]."
+        );
+
+        let m_pattern = symbolic.m_pattern;
+        let n = m_pattern.major_dim();
+        let capacity = symbolic.factor_nnz_estimate;
+
+        let mut l_offsets = Vec::with_capacity(n + 1);
+        let mut l_indices = Vec::with_capacity(capacity);
+        let mut l_values = Vec::with_capacity(capacity);
+        let mut u_offsets = Vec::with_capacity(n + 1);
+        let mut u_indices = Vec::with_capacity(capacity);
+        let mut u_values = Vec::with_capacity(capacity);
+
+        let mut perm_inv = vec![usize::MAX; n];
+        let mut work_x = vec![T::zero(); n];
+        let mut marks = vec![false; n];
+        let mut stack = Vec::new();
+        let mut reach = Vec::new();
+
+        for k in 0..n {
+            l_offsets.push(l_indices.len());
+            u_offsets.push(u_indices.len());
+
+            // Until the end of the factorization, the row indices of `L` refer to the rows of
+            // `A`, and the columns of `L` are located through `perm_inv`.
+            let l = FactorColumns {
+                offsets: &l_offsets,
+                indices: &l_indices,
+                perm_inv: &perm_inv,
+            };
+
+            // The rows of `L \ A(:, k)` that can be non-zero, in reverse topological order.
+            reach.clear();
+            for &i in m_pattern.lane(k) {
+                if !marks[i] {
+                    l.depth_first_search(i, &mut marks, &mut stack, &mut reach);
+                }
+            }
+
+            // Solve `L x = A(:, k)` for the rows in the reach.
+            let range_k = m_pattern.major_offsets()[k]..m_pattern.major_offsets()[k + 1];
+            for (&i, v) in m_pattern.lane(k).iter().zip(&values[range_k]) {
+                work_x[i] = *v;
+            }
+
+            for &i in reach.iter().rev() {
+                marks[i] = false;
+                let j = perm_inv[i];
+
+                if j != usize::MAX {
+                    // NOTE: the first entry of each column of `L` is its unit diagonal.
+                    let x_i = work_x[i];
+                    for p in (l_offsets[j] + 1)..l_offsets[j + 1] {
+                        work_x[l_indices[p]] -= l_values[p] * x_i;
+                    }
+                }
+            }
+
+            // Entries on already pivotal rows belong to `U`, the others are candidate pivots.
+            let mut pivot_row = None;
+            let mut max_magnitude = T::zero();
+
+            for &i in &reach {
+                let j = perm_inv[i];
+
+                if j == usize::MAX {
+                    let magnitude = work_x[i].abs();
+                    if magnitude > max_magnitude {
+                        max_magnitude = magnitude;
+                        pivot_row = Some(i);
+                    }
+                } else {
+                    u_indices.push(j);
+                    u_values.push(work_x[i]);
+                }
+            }
+
+            let mut pivot_row = pivot_row.ok_or(LuError::Singular)?;
+
+            if perm_inv[k] == usize::MAX
+                && !work_x[k].is_zero()
+                && work_x[k].abs() >= pivot_threshold * max_magnitude
+            {
+                pivot_row = k;
+            }
+
+            let pivot = work_x[pivot_row];
+            perm_inv[pivot_row] = k;
+            u_indices.push(k);
+            u_values.push(pivot);
+            l_indices.push(pivot_row);
+            l_values.push(T::one());
+
+            for &i in &reach {
+                if perm_inv[i] == usize::MAX {
+                    l_indices.push(i);
+                    l_values.push(work_x[i] / pivot);
+                }
+                work_x[i] = T::zero();
+            }
+        }
+
+        l_offsets.push(l_indices.len());
+        u_offsets.push(u_indices.len());
+
+        // Renumber the rows of `L` so that it is lower triangular.
+        for i in &mut l_indices {
+            *i = perm_inv[*i];
+        }
+
+        sort_lanes(&l_offsets, &mut l_indices, &mut l_values);
+        sort_lanes(&u_offsets, &mut u_indices, &mut u_values);
+
+        let l_factor = CscMatrix::try_from_csc_data(n, n, l_offsets, l_indices, l_values)
+            .expect("Internal error: the factor L must be valid by construction");
+        let u_factor = CscMatrix::try_from_csc_data(n, n, u_offsets, u_indices, u_values)
+            .expect("Internal error: the factor U must be valid by construction");
+
+        let mut perm = vec![0; n];
+        for (i, &k) in perm_inv.iter().enumerate() {
+            perm[k] = i;
+        }
+
+        Ok(CscLu {
+            m_pattern,
+            l_factor,
+            u_factor,
+            perm,
+            perm_inv,
+            work_x,
+        })
+    }
+
+    /// Computes the LU factorization of the provided matrix with the usual partial pivoting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor(matrix: &CscMatrix<T>) -> Result<Self, LuError> {
+        Self::factor_with_threshold(matrix, T::one())
+    }
+
+    /// Computes the LU factorization of the provided matrix with threshold partial pivoting.
+    ///
+    /// See [`CscLu::factor_numerical`] for the meaning of `pivot_threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if `pivot_threshold` is not in `[0, 1]`.
+    pub fn factor_with_threshold(
+        matrix: &CscMatrix<T>,
+        pivot_threshold: T,
+    ) -> Result<Self, LuError> {
+        let symbolic = CscSymbolicLu::factor(matrix.pattern().clone());
+        Self::factor_numerical(symbolic, matrix.values(), pivot_threshold)
+    }
+
+    /// Re-computes the factorization for a new set of non-zero values.
+    ///
+    /// This is useful when the values of a matrix changes, but the sparsity pattern remains
+    /// constant. The row permutation and the sparsity patterns of the factors computed by the
+    /// initial factorization are reused, so no pivoting is performed: the factorization may be
+    /// inaccurate if the new values would have required different row interchanges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a zero pivot is encountered. The factorization is then left in an
+    /// invalid state and must not be used before a successful call to `refactor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values does not match the number of non-zeros in the sparsity
+    /// pattern.
+    pub fn refactor(&mut self, values: &[T]) -> Result<(), LuError> {
+        assert_eq!(
+            values.len(),
+            self.m_pattern.nnz(),
+            "The number of values must match the number of non-zeros of the pattern."
+        );
+
+        let n = self.m_pattern.major_dim();
+        let (l_offsets, l_indices, l_values) = self.l_factor.csc_data_mut();
+        let (u_offsets, u_indices, u_values) = self.u_factor.csc_data_mut();
+        let work_x = &mut self.work_x;
+
+        for k in 0..n {
+            // Scatter the k-th column of `P A`.
+            let range_k = self.m_pattern.major_offsets()[k]..self.m_pattern.major_offsets()[k + 1];
+            for (&i, v) in self.m_pattern.lane(k).iter().zip(&values[range_k]) {
+                work_x[self.perm_inv[i]] = *v;
+            }
+
+            // The row indices of `U(:, k)` are sorted, so its last entry is the diagonal and the
+            // off-diagonal entries are visited in a valid order for the triangular solve.
+            let u_range = u_offsets[k]..u_offsets[k + 1];
+            let l_range = l_offsets[k]..l_offsets[k + 1];
+
+            for p in u_range.start..(u_range.end - 1) {
+                let j = u_indices[p];
+                let x_j = work_x[j];
+                u_values[p] = x_j;
+
+                for q in (l_offsets[j] + 1)..l_offsets[j + 1] {
+                    work_x[l_indices[q]] -= l_values[q] * x_j;
+                }
+            }
+
+            let pivot = work_x[k];
+            u_values[u_range.end - 1] = pivot;
+
+            for q in (l_range.start + 1)..l_range.end {
+                l_values[q] = work_x[l_indices[q]] / pivot;
+            }
+
+            for &i in u_indices[u_range].iter().chain(&l_indices[l_range]) {
+                work_x[i] = T::zero();
+            }
+
+            if pivot.is_zero() {
+                return Err(LuError::Singular);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reference to the unit lower-triangular factor `L`.
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l_factor
+    }
+
+    /// Returns a reference to the upper-triangular factor `U`.
+    pub fn u(&self) -> &CscMatrix<T> {
+        &self.u_factor
+    }
+
+    /// Returns the row permutation `P`, such that row `i` of `P A` is row `perm[i]` of `A`.
+    pub fn row_permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns the factors `L` and `U`.
+    pub fn take_l_u(self) -> (CscMatrix<T>, CscMatrix<T>) {
+        (self.l_factor, self.u_factor)
+    }
+
+    /// Solves the system `A X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` differs from the dimension of `A`.
+    pub fn solve<'a>(&'a self, b: impl Into<DMatrixSlice<'a, T>>) -> DMatrix<T> {
+        let b = b.into();
+        let mut output = b.clone_owned();
+        self.solve_mut(&mut output);
+        output
+    }
+
+    /// Solves the system `A X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// The result is stored in-place in `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` differs from the dimension of `A`.
+    pub fn solve_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        let mut b = b.into();
+        assert_eq!(
+            b.nrows(),
+            self.perm.len(),
+            "Dimension mismatch in sparse LU solver."
+        );
+        let mut work = vec![T::zero(); self.perm.len()];
+
+        for j in 0..b.ncols() {
+            let mut b_j = b.column_mut(j);
+
+            // Solve `L U X = P B`.
+            for (w, &i) in work.iter_mut().zip(&self.perm) {
+                *w = b_j[i];
+            }
+
+            solve_unit_lower_triangular(&self.l_factor, &mut work);
+            solve_upper_triangular(&self.u_factor, &mut work);
+            b_j.copy_from_slice(&work);
+        }
+    }
+
+    /// Solves the system `A^T X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` differs from the dimension of `A`.
+    pub fn solve_transpose<'a>(&'a self, b: impl Into<DMatrixSlice<'a, T>>) -> DMatrix<T> {
+        let b = b.into();
+        let mut output = b.clone_owned();
+        self.solve_transpose_mut(&mut output);
+        output
+    }
+
+    /// Solves the system `A^T X = B`, where `X` and `B` are dense matrices.
+    ///
+    /// The result is stored in-place in `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` differs from the dimension of `A`.
+    pub fn solve_transpose_mut<'a>(&'a self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        let mut b = b.into();
+        assert_eq!(
+            b.nrows(),
+            self.perm.len(),
+            "Dimension mismatch in sparse LU solver."
+        );
+        let mut work = vec![T::zero(); self.perm.len()];
+
+        for j in 0..b.ncols() {
+            let mut b_j = b.column_mut(j);
+
+            // Solve `U^T L^T P X = B`.
+            for (w, x) in work.iter_mut().zip(b_j.iter()) {
+                *w = *x;
+            }
+            solve_upper_triangular_transpose(&self.u_factor, &mut work);
+            solve_unit_lower_triangular_transpose(&self.l_factor, &mut work);
+
+            for (w, &i) in work.iter().zip(&self.perm) {
+                b_j[i] = *w;
+            }
+        }
+    }
+}
+
+/// The columns of the factor `L` being built by the numerical factorization.
+struct FactorColumns<'a> {
+    offsets: &'a [usize],
+    indices: &'a [usize],
+    perm_inv: &'a [usize],
+}
+
+impl FactorColumns<'_> {
+    /// Appends to `reach` the unmarked rows reachable from row `i` in the graph of `L`, in
+    /// postorder, and marks them.
+    fn depth_first_search(
+        &self,
+        i: usize,
+        marks: &mut [bool],
+        stack: &mut Vec<(usize, usize)>,
+        reach: &mut Vec<usize>,
+    ) {
+        marks[i] = true;
+        stack.push((i, 0));
+
+        while let Some((node, next)) = stack.last_mut() {
+            // The rows of the column of `L` associated with `node`, without its diagonal.
+            let children = match self.perm_inv[*node] {
+                usize::MAX => &[][..],
+                j => &self.indices[(self.offsets[j] + 1)..self.offsets[j + 1]],
+            };
+
+            if let Some(&child) = children.get(*next) {
+                *next += 1;
+                if !marks[child] {
+                    marks[child] = true;
+                    stack.push((child, 0));
+                }
+            } else {
+                reach.push(*node);
+                let _ = stack.pop();
+            }
+        }
+    }
+}
+
+/// Sorts the minor indices of each lane, together with their values.
+fn sort_lanes<T: Copy>(offsets: &[usize], indices: &mut [usize], values: &mut [T]) {
+    let mut entries = Vec::new();
+
+    for lane in offsets.windows(2) {
+        let range = lane[0]..lane[1];
+        entries.clear();
+        entries.extend(
+            indices[range.clone()]
+                .iter()
+                .copied()
+                .zip(values[range.clone()].iter().copied()),
+        );
+        entries.sort_unstable_by_key(|&(i, _)| i);
+
+        for ((i, v), (new_i, new_v)) in indices[range.clone()]
+            .iter_mut()
+            .zip(&mut values[range])
+            .zip(&entries)
+        {
+            *i = *new_i;
+            *v = *new_v;
+        }
+    }
+}
+
+fn solve_unit_lower_triangular<T: RealField>(l: &CscMatrix<T>, x: &mut [T]) {
+    for j in 0..l.ncols() {
+        let col_j = l.col(j);
+        let x_j = x[j];
+
+        // NOTE: the first entry is the unit diagonal.
+        for (&i, &l_ij) in col_j.row_indices().iter().zip(col_j.values()).skip(1) {
+            x[i] -= l_ij * x_j;
+        }
+    }
+}
+
+fn solve_unit_lower_triangular_transpose<T: RealField>(l: &CscMatrix<T>, x: &mut [T]) {
+    for j in (0..l.ncols()).rev() {
+        let col_j = l.col(j);
+        let mut x_j = x[j];
+
+        for (&i, &l_ij) in col_j.row_indices().iter().zip(col_j.values()).skip(1) {
+            x_j -= l_ij * x[i];
+        }
+
+        x[j] = x_j;
+    }
+}
+
+fn solve_upper_triangular<T: RealField>(u: &CscMatrix<T>, x: &mut [T]) {
+    for j in (0..u.ncols()).rev() {
+        let col_j = u.col(j);
+        // NOTE: the last entry is the diagonal.
+        let (diag, above) = col_j.values().split_last().unwrap();
+        let x_j = x[j] / *diag;
+        x[j] = x_j;
+
+        for (&i, &u_ij) in col_j.row_indices().iter().zip(above) {
+            x[i] -= u_ij * x_j;
+        }
+    }
+}
+
+fn solve_upper_triangular_transpose<T: RealField>(u: &CscMatrix<T>, x: &mut [T]) {
+    for j in 0..u.ncols() {
+        let col_j = u.col(j);
+        let (diag, above) = col_j.values().split_last().unwrap();
+        let mut x_j = x[j];
+
+        for (&i, &u_ij) in col_j.row_indices().iter().zip(above) {
+            x_j -= u_ij * x[i];
+        }
+
+        x[j] = x_j / *diag;
+    }
+}
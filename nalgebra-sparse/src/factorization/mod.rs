@@ -1,6 +1,9 @@
 //! Matrix factorization for sparse matrices.
 //!
-//! Currently, the only factorization provided here is the [`CscCholesky`] factorization.
+//! Currently, the factorizations provided here are the [`CscCholesky`] factorization of
+//! symmetric positive definite matrices and the [`CscLu`] factorization of general square matrices.
 mod cholesky;
+mod lu;
 
 pub use cholesky::*;
+pub use lu::*;
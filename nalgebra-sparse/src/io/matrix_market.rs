@@ -0,0 +1,918 @@
+use crate::coo::CooMatrix;
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use nalgebra::storage::Storage;
+use nalgebra::{ClosedAdd, Complex, DMatrix, Dim, Matrix};
+use num_traits::Zero;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Errors produced when reading a Matrix Market file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixMarketError {
+    kind: MatrixMarketErrorKind,
+    line: Option<usize>,
+    message: String,
+}
+
+impl MatrixMarketError {
+    /// The type of error.
+    pub fn kind(&self) -> &MatrixMarketErrorKind {
+        &self.kind
+    }
+
+    /// The line at which the error was detected, counted from 1.
+    ///
+    /// Returns `None` if the error is not associated with a particular line, e.g. if the file
+    /// could not be read.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// A description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn new(kind: MatrixMarketErrorKind, line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+/// The type of error described by a [`MatrixMarketError`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixMarketErrorKind {
+    /// The file could not be read.
+    IoError,
+    /// The header line is missing or malformed, or describes an unsupported combination of
+    /// format, field and symmetry.
+    InvalidHeader,
+    /// A line could not be parsed, e.g. because it contains a malformed number or the wrong
+    /// number of values.
+    ParsingError,
+    /// The field of the file cannot be represented by the requested scalar type.
+    TypeMismatch,
+    /// A row or column index is zero or exceeds the dimensions of the matrix.
+    IndexOutOfBounds,
+    /// The number of entries differs from the number given in the size line.
+    EntryCountMismatch,
+    /// The file describes a symmetric, skew-symmetric or hermitian matrix that is not square.
+    NonSquare,
+    /// The file describes a symmetric, skew-symmetric or hermitian matrix, and an entry lies
+    /// above the diagonal.
+    NotLowerTriangle,
+    /// The file describes a skew-symmetric matrix and an entry lies on the diagonal, or a
+    /// hermitian matrix and a diagonal entry is not real.
+    DiagonalError,
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "Matrix Market error on line {}: {}", line, self.message),
+            None => write!(f, "Matrix Market error: {}", self.message),
+        }
+    }
+}
+
+impl Error for MatrixMarketError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Format {
+    Coordinate,
+    Array,
+}
+
+/// The field of a Matrix Market file, i.e. the type of its values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Field {
+    /// Real values.
+    Real,
+    /// Complex values, given by their real and imaginary parts.
+    Complex,
+    /// Integer values.
+    Integer,
+    /// No values, only the positions of the entries.
+    Pattern,
+}
+
+impl Field {
+    fn name(self) -> &'static str {
+        match self {
+            Field::Real => "real",
+            Field::Complex => "complex",
+            Field::Integer => "integer",
+            Field::Pattern => "pattern",
+        }
+    }
+
+    /// The number of tokens of each value.
+    fn token_count(self) -> usize {
+        match self {
+            Field::Real | Field::Integer => 1,
+            Field::Complex => 2,
+            Field::Pattern => 0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+mod internal {
+    use super::Field;
+    use nalgebra::{Complex, Scalar};
+    use std::io::{self, Write};
+
+    /// A value parsed from a Matrix Market file.
+    pub enum Value {
+        Real(f64),
+        Complex(Complex<f64>),
+        Integer(i128),
+        Pattern,
+    }
+
+    /// The operations required to read and write the values of a Matrix Market file.
+    pub trait SupportedMatrixMarketScalar: Scalar {
+        /// The field used when writing values of this type.
+        fn field() -> Field;
+
+        /// Whether values of the given field can be read into this type.
+        fn supports_field(field: Field) -> bool;
+
+        /// Converts a value of a supported field, or returns `None` if it is out of range.
+        fn from_value(value: Value) -> Option<Self>;
+
+        /// Returns the negation, or `None` if it is out of range.
+        fn negate(&self) -> Option<Self>;
+
+        /// Returns the complex conjugate.
+        fn conjugate(&self) -> Self;
+
+        /// Whether the imaginary part is zero.
+        fn is_real(&self) -> bool;
+
+        /// Writes the tokens of the value, each preceded by a space.
+        fn write_value(&self, w: &mut dyn Write) -> io::Result<()>;
+    }
+}
+
+use internal::{SupportedMatrixMarketScalar, Value};
+
+/// Scalar types which can be read from and written to Matrix Market files.
+///
+/// This trait is sealed, and implemented for the following types:
+///
+/// | Type                          | Fields that can be read       | Field when written |
+/// | ----------------------------- | ----------------------------- | ------------------ |
+/// | `i8`, `i16`, `i32`, `i64`, `isize` | integer                  | integer            |
+/// | `f32`, `f64`                  | integer, real                 | real               |
+/// | `Complex<f32>`, `Complex<f64>` | integer, real, complex       | complex            |
+/// | `()`                          | pattern                       | pattern            |
+pub trait MatrixMarketScalar: SupportedMatrixMarketScalar {}
+
+macro_rules! impl_matrix_market_scalar_for_integer {
+    ($($int:ty),*) => {
+        $(
+            impl SupportedMatrixMarketScalar for $int {
+                fn field() -> Field {
+                    Field::Integer
+                }
+
+                fn supports_field(field: Field) -> bool {
+                    field == Field::Integer
+                }
+
+                fn from_value(value: Value) -> Option<Self> {
+                    match value {
+                        Value::Integer(i) => <$int>::try_from(i).ok(),
+                        _ => None,
+                    }
+                }
+
+                fn negate(&self) -> Option<Self> {
+                    self.checked_neg()
+                }
+
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+
+                fn is_real(&self) -> bool {
+                    true
+                }
+
+                fn write_value(&self, w: &mut dyn Write) -> io::Result<()> {
+                    write!(w, " {}", self)
+                }
+            }
+
+            impl MatrixMarketScalar for $int {}
+        )*
+    }
+}
+
+impl_matrix_market_scalar_for_integer!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_matrix_market_scalar_for_real {
+    ($($real:ty),*) => {
+        $(
+            impl SupportedMatrixMarketScalar for $real {
+                fn field() -> Field {
+                    Field::Real
+                }
+
+                fn supports_field(field: Field) -> bool {
+                    field == Field::Real || field == Field::Integer
+                }
+
+                fn from_value(value: Value) -> Option<Self> {
+                    match value {
+                        Value::Real(x) => Some(x as $real),
+                        Value::Integer(i) => Some(i as $real),
+                        _ => None,
+                    }
+                }
+
+                fn negate(&self) -> Option<Self> {
+                    Some(-*self)
+                }
+
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+
+                fn is_real(&self) -> bool {
+                    true
+                }
+
+                fn write_value(&self, w: &mut dyn Write) -> io::Result<()> {
+                    // The `Debug` representation round-trips, and switches to scientific notation
+                    // for very large and very small magnitudes.
+                    write!(w, " {:?}", self)
+                }
+            }
+
+            impl SupportedMatrixMarketScalar for Complex<$real> {
+                fn field() -> Field {
+                    Field::Complex
+                }
+
+                fn supports_field(field: Field) -> bool {
+                    field != Field::Pattern
+                }
+
+                fn from_value(value: Value) -> Option<Self> {
+                    match value {
+                        Value::Complex(z) => Some(Complex::new(z.re as $real, z.im as $real)),
+                        Value::Real(x) => Some(Complex::new(x as $real, 0.0)),
+                        Value::Integer(i) => Some(Complex::new(i as $real, 0.0)),
+                        Value::Pattern => None,
+                    }
+                }
+
+                fn negate(&self) -> Option<Self> {
+                    Some(-*self)
+                }
+
+                fn conjugate(&self) -> Self {
+                    self.conj()
+                }
+
+                fn is_real(&self) -> bool {
+                    self.im == 0.0
+                }
+
+                fn write_value(&self, w: &mut dyn Write) -> io::Result<()> {
+                    write!(w, " {:?} {:?}", self.re, self.im)
+                }
+            }
+
+            impl MatrixMarketScalar for $real {}
+            impl MatrixMarketScalar for Complex<$real> {}
+        )*
+    }
+}
+
+impl_matrix_market_scalar_for_real!(f32, f64);
+
+impl SupportedMatrixMarketScalar for () {
+    fn field() -> Field {
+        Field::Pattern
+    }
+
+    fn supports_field(field: Field) -> bool {
+        field == Field::Pattern
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::Pattern => Some(()),
+            _ => None,
+        }
+    }
+
+    fn negate(&self) -> Option<Self> {
+        Some(())
+    }
+
+    fn conjugate(&self) -> Self {}
+
+    fn is_real(&self) -> bool {
+        true
+    }
+
+    fn write_value(&self, _: &mut dyn Write) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl MatrixMarketScalar for () {}
+
+/// The contents of a Matrix Market file, with the entries implied by the symmetry included.
+struct Entries<T> {
+    nrows: usize,
+    ncols: usize,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: MatrixMarketScalar> Entries<T> {
+    /// Adds the stored entry `(i, j)`, and its mirror image for matrices with symmetry.
+    fn push(
+        &mut self,
+        symmetry: Symmetry,
+        i: usize,
+        j: usize,
+        value: T,
+        line: usize,
+    ) -> Result<(), MatrixMarketError> {
+        if i != j {
+            let mirrored = match symmetry {
+                Symmetry::General => None,
+                Symmetry::Symmetric => Some(value.clone()),
+                Symmetry::Hermitian => Some(value.conjugate()),
+                Symmetry::SkewSymmetric => Some(value.negate().ok_or_else(|| {
+                    MatrixMarketError::new(
+                        MatrixMarketErrorKind::ParsingError,
+                        Some(line),
+                        "The negation of the value is out of range for the scalar type.",
+                    )
+                })?),
+            };
+            if let Some(mirrored) = mirrored {
+                self.rows.push(j);
+                self.cols.push(i);
+                self.values.push(mirrored);
+            }
+        }
+        self.rows.push(i);
+        self.cols.push(j);
+        self.values.push(value);
+        Ok(())
+    }
+}
+
+/// Iterates over the lines which are neither comments nor blank, with their line numbers.
+fn data_lines(data: &str) -> impl Iterator<Item = (usize, &str)> {
+    data.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('%'))
+}
+
+/// Parses the header, and returns its line number together with its contents.
+fn parse_header(data: &str) -> Result<(usize, Format, Field, Symmetry), MatrixMarketError> {
+    let invalid_header = |line, message: String| {
+        MatrixMarketError::new(MatrixMarketErrorKind::InvalidHeader, line, message)
+    };
+
+    let (line, header) = data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .find(|(_, line)| !line.is_empty())
+        .ok_or_else(|| invalid_header(None, "The file is empty.".to_string()))?;
+
+    let tokens: Vec<_> = header.split_whitespace().map(str::to_lowercase).collect();
+    if tokens.len() != 5 || tokens[0] != "%%matrixmarket" || tokens[1] != "matrix" {
+        return Err(invalid_header(
+            Some(line),
+            "Expected a header of the form `%%MatrixMarket matrix <format> <field> <symmetry>`."
+                .to_string(),
+        ));
+    }
+
+    let format = match tokens[2].as_str() {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        other => {
+            return Err(invalid_header(
+                Some(line),
+                format!("Unknown format `{}`.", other),
+            ))
+        }
+    };
+    let field = match tokens[3].as_str() {
+        "real" | "double" => Field::Real,
+        "complex" => Field::Complex,
+        "integer" => Field::Integer,
+        "pattern" => Field::Pattern,
+        other => {
+            return Err(invalid_header(
+                Some(line),
+                format!("Unknown field `{}`.", other),
+            ))
+        }
+    };
+    let symmetry = match tokens[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => Symmetry::Hermitian,
+        other => {
+            return Err(invalid_header(
+                Some(line),
+                format!("Unknown symmetry `{}`.", other),
+            ))
+        }
+    };
+
+    if field == Field::Pattern && format == Format::Array {
+        return Err(invalid_header(
+            Some(line),
+            "The pattern field is not supported by the array format.".to_string(),
+        ));
+    }
+    if field == Field::Pattern && symmetry == Symmetry::SkewSymmetric {
+        return Err(invalid_header(
+            Some(line),
+            "The pattern field cannot be skew-symmetric.".to_string(),
+        ));
+    }
+    if field != Field::Complex && symmetry == Symmetry::Hermitian {
+        return Err(invalid_header(
+            Some(line),
+            "Only the complex field can be hermitian.".to_string(),
+        ));
+    }
+
+    Ok((line, format, field, symmetry))
+}
+
+fn parse_usize(token: &str, line: usize) -> Result<usize, MatrixMarketError> {
+    token.parse().map_err(|_| {
+        MatrixMarketError::new(
+            MatrixMarketErrorKind::ParsingError,
+            Some(line),
+            format!("Expected a non-negative integer, found `{}`.", token),
+        )
+    })
+}
+
+fn parse_value<T: MatrixMarketScalar>(
+    field: Field,
+    tokens: &[&str],
+    line: usize,
+) -> Result<T, MatrixMarketError> {
+    let parsing_error = |token: &str| {
+        MatrixMarketError::new(
+            MatrixMarketErrorKind::ParsingError,
+            Some(line),
+            format!("Expected a {} value, found `{}`.", field.name(), token),
+        )
+    };
+    let parse_real = |token: &str| token.parse::<f64>().map_err(|_| parsing_error(token));
+
+    let value = match field {
+        Field::Real => Value::Real(parse_real(tokens[0])?),
+        Field::Complex => {
+            Value::Complex(Complex::new(parse_real(tokens[0])?, parse_real(tokens[1])?))
+        }
+        Field::Integer => Value::Integer(tokens[0].parse().map_err(|_| parsing_error(tokens[0]))?),
+        Field::Pattern => Value::Pattern,
+    };
+    T::from_value(value).ok_or_else(|| {
+        MatrixMarketError::new(
+            MatrixMarketErrorKind::ParsingError,
+            Some(line),
+            format!(
+                "The value `{}` is out of range for the scalar type.",
+                tokens.join(" ")
+            ),
+        )
+    })
+}
+
+/// Splits a line into the expected number of tokens.
+fn split_tokens(
+    line: &str,
+    line_number: usize,
+    count: usize,
+) -> Result<Vec<&str>, MatrixMarketError> {
+    let tokens: Vec<_> = line.split_whitespace().collect();
+    if tokens.len() == count {
+        Ok(tokens)
+    } else {
+        Err(MatrixMarketError::new(
+            MatrixMarketErrorKind::ParsingError,
+            Some(line_number),
+            format!("Expected {} tokens, found {}.", count, tokens.len()),
+        ))
+    }
+}
+
+fn parse_entries<T: MatrixMarketScalar>(data: &str) -> Result<Entries<T>, MatrixMarketError> {
+    let (header_line, format, field, symmetry) = parse_header(data)?;
+    if !T::supports_field(field) {
+        return Err(MatrixMarketError::new(
+            MatrixMarketErrorKind::TypeMismatch,
+            Some(header_line),
+            format!(
+                "The {} field cannot be read into the scalar type `{}`.",
+                field.name(),
+                std::any::type_name::<T>()
+            ),
+        ));
+    }
+
+    let mut lines = data_lines(data);
+    let (size_line, size) = lines.next().ok_or_else(|| {
+        MatrixMarketError::new(
+            MatrixMarketErrorKind::ParsingError,
+            None,
+            "The size line is missing.",
+        )
+    })?;
+    let size_tokens = match format {
+        Format::Coordinate => 3,
+        Format::Array => 2,
+    };
+    let size = split_tokens(size, size_line, size_tokens)?;
+    let nrows = parse_usize(size[0], size_line)?;
+    let ncols = parse_usize(size[1], size_line)?;
+    if symmetry != Symmetry::General && nrows != ncols {
+        return Err(MatrixMarketError::new(
+            MatrixMarketErrorKind::NonSquare,
+            Some(size_line),
+            format!(
+                "A matrix with symmetry must be square, but has dimensions {} x {}.",
+                nrows, ncols
+            ),
+        ));
+    }
+
+    // The positions of the stored entries, in the order in which they appear in the file
+    let mut positions: Box<dyn Iterator<Item = (usize, usize)>> = match format {
+        Format::Coordinate => Box::new(std::iter::empty()),
+        Format::Array => match symmetry {
+            Symmetry::General => {
+                Box::new((0..ncols).flat_map(move |j| (0..nrows).map(move |i| (i, j))))
+            }
+            Symmetry::Symmetric | Symmetry::Hermitian => {
+                Box::new((0..ncols).flat_map(move |j| (j..nrows).map(move |i| (i, j))))
+            }
+            Symmetry::SkewSymmetric => {
+                Box::new((0..ncols).flat_map(move |j| (j + 1..nrows).map(move |i| (i, j))))
+            }
+        },
+    };
+    let expected_count = match format {
+        Format::Coordinate => parse_usize(size[2], size_line)?,
+        Format::Array => match symmetry {
+            Symmetry::General => nrows.checked_mul(ncols),
+            Symmetry::Symmetric | Symmetry::Hermitian => nrows
+                .checked_add(1)
+                .and_then(|n| n.checked_mul(nrows))
+                .map(|n| n / 2),
+            Symmetry::SkewSymmetric => nrows.checked_mul(nrows.saturating_sub(1)).map(|n| n / 2),
+        }
+        .ok_or_else(|| {
+            MatrixMarketError::new(
+                MatrixMarketErrorKind::ParsingError,
+                Some(size_line),
+                format!("The dimensions {} x {} are too large.", nrows, ncols),
+            )
+        })?,
+    };
+
+    // The size line is not trusted: every entry takes at least two bytes of input.
+    let capacity = expected_count.min(data.len() / 2);
+    let mut entries = Entries {
+        nrows,
+        ncols,
+        rows: Vec::with_capacity(capacity),
+        cols: Vec::with_capacity(capacity),
+        values: Vec::with_capacity(capacity),
+    };
+    let mut count = 0;
+    let mut last_line = size_line;
+
+    for (line, content) in lines {
+        last_line = line;
+        count += 1;
+        if count > expected_count {
+            return Err(MatrixMarketError::new(
+                MatrixMarketErrorKind::EntryCountMismatch,
+                Some(line),
+                format!("Expected {} entries, found more.", expected_count),
+            ));
+        }
+
+        let (i, j, value) = match format {
+            Format::Coordinate => {
+                let tokens = split_tokens(content, line, 2 + field.token_count())?;
+                let i = parse_usize(tokens[0], line)?;
+                let j = parse_usize(tokens[1], line)?;
+                if i == 0 || i > nrows || j == 0 || j > ncols {
+                    return Err(MatrixMarketError::new(
+                        MatrixMarketErrorKind::IndexOutOfBounds,
+                        Some(line),
+                        format!(
+                            "The entry ({}, {}) is out of bounds for a {} x {} matrix, with \
+                             indices starting at 1.",
+                            i, j, nrows, ncols
+                        ),
+                    ));
+                }
+                let value = parse_value::<T>(field, &tokens[2..], line)?;
+                (i - 1, j - 1, value)
+            }
+            Format::Array => {
+                let tokens = split_tokens(content, line, field.token_count())?;
+                let (i, j) = positions
+                    .next()
+                    .expect("The number of positions equals the expected number of entries");
+                (i, j, parse_value(field, &tokens, line)?)
+            }
+        };
+
+        match symmetry {
+            Symmetry::General => {}
+            Symmetry::SkewSymmetric if i == j => {
+                return Err(MatrixMarketError::new(
+                    MatrixMarketErrorKind::DiagonalError,
+                    Some(line),
+                    "A skew-symmetric matrix cannot store diagonal entries.",
+                ))
+            }
+            Symmetry::Hermitian if i == j && !value.is_real() => {
+                return Err(MatrixMarketError::new(
+                    MatrixMarketErrorKind::DiagonalError,
+                    Some(line),
+                    "The diagonal entries of a hermitian matrix must be real.",
+                ))
+            }
+            _ if i < j => {
+                return Err(MatrixMarketError::new(
+                    MatrixMarketErrorKind::NotLowerTriangle,
+                    Some(line),
+                    format!(
+                        "The entry ({}, {}) lies above the diagonal of a matrix with symmetry.",
+                        i + 1,
+                        j + 1
+                    ),
+                ))
+            }
+            _ => {}
+        }
+        entries.push(symmetry, i, j, value, line)?;
+    }
+
+    if count != expected_count {
+        return Err(MatrixMarketError::new(
+            MatrixMarketErrorKind::EntryCountMismatch,
+            Some(last_line),
+            format!("Expected {} entries, found {}.", expected_count, count),
+        ));
+    }
+
+    Ok(entries)
+}
+
+fn read_file<P: AsRef<Path>>(path: P) -> Result<String, MatrixMarketError> {
+    fs::read_to_string(path).map_err(|err| {
+        MatrixMarketError::new(MatrixMarketErrorKind::IoError, None, err.to_string())
+    })
+}
+
+/// Reads a sparse matrix from a string in Matrix Market format.
+///
+/// Both the coordinate and array formats are supported. The entries implied by the symmetry
+/// of the matrix are included, so that the returned matrix has general structure. Duplicate
+/// entries are kept as they appear in the file.
+///
+/// # Errors
+///
+/// Returns an error if the data does not follow the Matrix Market format, or if its field
+/// cannot be represented by the scalar type `T` (see [`MatrixMarketScalar`]).
+pub fn load_coo_from_matrix_market_str<T: MatrixMarketScalar>(
+    data: &str,
+) -> Result<CooMatrix<T>, MatrixMarketError> {
+    let entries = parse_entries(data)?;
+    Ok(CooMatrix::try_from_triplets(
+        entries.nrows,
+        entries.ncols,
+        entries.rows,
+        entries.cols,
+        entries.values,
+    )
+    .expect("The indices are checked during parsing"))
+}
+
+/// Reads a sparse matrix from a Matrix Market file.
+///
+/// See [`load_coo_from_matrix_market_str`].
+pub fn load_coo_from_matrix_market_file<T: MatrixMarketScalar, P: AsRef<Path>>(
+    path: P,
+) -> Result<CooMatrix<T>, MatrixMarketError> {
+    load_coo_from_matrix_market_str(&read_file(path)?)
+}
+
+/// Reads a dense matrix from a string in Matrix Market format.
+///
+/// Both the coordinate and array formats are supported. The entries implied by the symmetry
+/// of the matrix are included, and duplicate entries are summed.
+///
+/// # Errors
+///
+/// Returns an error if the data does not follow the Matrix Market format, or if its field
+/// cannot be represented by the scalar type `T` (see [`MatrixMarketScalar`]).
+pub fn load_dense_from_matrix_market_str<T>(data: &str) -> Result<DMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar + ClosedAdd + Zero,
+{
+    let entries = parse_entries(data)?;
+    let mut matrix = DMatrix::zeros(entries.nrows, entries.ncols);
+    for ((i, j), v) in entries
+        .rows
+        .into_iter()
+        .zip(entries.cols)
+        .zip(entries.values)
+    {
+        matrix[(i, j)] += v;
+    }
+    Ok(matrix)
+}
+
+/// Reads a dense matrix from a Matrix Market file.
+///
+/// See [`load_dense_from_matrix_market_str`].
+pub fn load_dense_from_matrix_market_file<T, P>(path: P) -> Result<DMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar + ClosedAdd + Zero,
+    P: AsRef<Path>,
+{
+    load_dense_from_matrix_market_str(&read_file(path)?)
+}
+
+/// Matrices which can be written in Matrix Market format.
+///
+/// Sparse matrices are written in coordinate format, and dense matrices in array format, both
+/// with general symmetry.
+pub trait MatrixMarketExport<T: MatrixMarketScalar> {
+    /// Writes the matrix in Matrix Market format.
+    fn write_matrix_market<W: Write>(&self, w: W) -> io::Result<()>;
+}
+
+fn write_coordinate<'a, T, W>(
+    mut w: W,
+    nrows: usize,
+    ncols: usize,
+    nnz: usize,
+    entries: impl Iterator<Item = (usize, usize, &'a T)>,
+) -> io::Result<()>
+where
+    T: 'a + MatrixMarketScalar,
+    W: Write,
+{
+    writeln!(
+        w,
+        "%%MatrixMarket matrix coordinate {} general",
+        T::field().name()
+    )?;
+    writeln!(w, "{} {} {}", nrows, ncols, nnz)?;
+    for (i, j, v) in entries {
+        write!(w, "{} {}", i + 1, j + 1)?;
+        v.write_value(&mut w)?;
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+impl<T: MatrixMarketScalar> MatrixMarketExport<T> for CooMatrix<T> {
+    fn write_matrix_market<W: Write>(&self, w: W) -> io::Result<()> {
+        write_coordinate(
+            w,
+            self.nrows(),
+            self.ncols(),
+            self.nnz(),
+            self.triplet_iter(),
+        )
+    }
+}
+
+impl<T: MatrixMarketScalar> MatrixMarketExport<T> for CsrMatrix<T> {
+    fn write_matrix_market<W: Write>(&self, w: W) -> io::Result<()> {
+        write_coordinate(
+            w,
+            self.nrows(),
+            self.ncols(),
+            self.nnz(),
+            self.triplet_iter(),
+        )
+    }
+}
+
+impl<T: MatrixMarketScalar> MatrixMarketExport<T> for CscMatrix<T> {
+    fn write_matrix_market<W: Write>(&self, w: W) -> io::Result<()> {
+        write_coordinate(
+            w,
+            self.nrows(),
+            self.ncols(),
+            self.nnz(),
+            self.triplet_iter(),
+        )
+    }
+}
+
+impl<T, R, C, S> MatrixMarketExport<T> for Matrix<T, R, C, S>
+where
+    T: MatrixMarketScalar,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+{
+    fn write_matrix_market<W: Write>(&self, mut w: W) -> io::Result<()> {
+        if T::field() == Field::Pattern {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The array format does not support the pattern field.",
+            ));
+        }
+        writeln!(
+            w,
+            "%%MatrixMarket matrix array {} general",
+            T::field().name()
+        )?;
+        writeln!(w, "{} {}", self.nrows(), self.ncols())?;
+        // The entries are stored in column-major order.
+        for v in self.iter() {
+            v.write_value(&mut w)?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a matrix to a string in Matrix Market format.
+///
+/// # Panics
+///
+/// Panics if a dense matrix of `()` is written, as the array format does not support the
+/// pattern field.
+pub fn save_to_matrix_market_str<T, M>(matrix: &M) -> String
+where
+    T: MatrixMarketScalar,
+    M: MatrixMarketExport<T>,
+{
+    let mut bytes = Vec::new();
+    matrix
+        .write_matrix_market(&mut bytes)
+        .expect("Writing to a vector never fails for supported matrices");
+    String::from_utf8(bytes).expect("Matrix Market output is valid UTF-8")
+}
+
+/// Writes a matrix to a file in Matrix Market format.
+///
+/// The file is created if it does not exist, and truncated otherwise.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written, or if a dense matrix of `()` is written, as
+/// the array format does not support the pattern field.
+pub fn save_to_matrix_market_file<T, M, P>(matrix: &M, path: P) -> io::Result<()>
+where
+    T: MatrixMarketScalar,
+    M: MatrixMarketExport<T>,
+    P: AsRef<Path>,
+{
+    let mut file = BufWriter::new(fs::File::create(path)?);
+    matrix.write_matrix_market(&mut file)?;
+    file.flush()
+}
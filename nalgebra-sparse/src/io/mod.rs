@@ -0,0 +1,62 @@
+//! Reading and writing of sparse matrices in the Matrix Market format.
+//!
+//! The [Matrix Market format](https://math.nist.gov/MatrixMarket/formats.html) is a plain text
+//! format for sparse and dense matrices, used for instance by the
+//! [SuiteSparse Matrix Collection](https://sparse.tamu.edu/). A file starts with a header of the
+//! form
+//!
+//! ```text
+//! %%MatrixMarket matrix <format> <field> <symmetry>
+//! ```
+//!
+//! where
+//!
+//! - the format is either `coordinate`, which lists the explicitly stored entries with their
+//!   indices, or `array`, which lists all the entries of a dense matrix in column-major order,
+//! - the field is `real`, `integer`, `complex` or `pattern` (no values), and
+//! - the symmetry is `general`, `symmetric`, `skew-symmetric` or `hermitian`. For the latter
+//!   three, only the lower triangle of the matrix is stored.
+//!
+//! All combinations allowed by the format are supported when reading, with
+//! [`load_coo_from_matrix_market_str`] into a [`CooMatrix`](crate::coo::CooMatrix), and with
+//! [`load_dense_from_matrix_market_str`] into a [`DMatrix`](nalgebra::DMatrix). The entries
+//! implied by the symmetry are always included in the returned matrix. Errors are reported as a
+//! [`MatrixMarketError`] with the line at which they were detected.
+//!
+//! Sparse matrices are written in coordinate format and dense matrices in array format, both
+//! with general symmetry, by [`save_to_matrix_market_str`] and [`save_to_matrix_market_file`].
+//! The supported scalar types are described by [`MatrixMarketScalar`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use nalgebra_sparse::csr::CsrMatrix;
+//! use nalgebra_sparse::io::{load_coo_from_matrix_market_str, save_to_matrix_market_str};
+//!
+//! let data = "\
+//! %%MatrixMarket matrix coordinate real symmetric
+//! % A 3 x 3 symmetric matrix with 4 entries in its lower triangle
+//! 3 3 4
+//! 1 1 2.0
+//! 2 1 -1.0
+//! 2 2 2.0
+//! 3 3 2.0
+//! ";
+//! let coo = load_coo_from_matrix_market_str::<f64>(data).unwrap();
+//! let csr = CsrMatrix::from(&coo);
+//! assert_eq!(csr.nnz(), 5);
+//! assert_eq!(csr.get_entry(0, 1).unwrap().into_value(), -1.0);
+//!
+//! let written = save_to_matrix_market_str(&csr);
+//! assert!(written.starts_with("%%MatrixMarket matrix coordinate real general\n3 3 5\n"));
+//! let coo_reloaded = load_coo_from_matrix_market_str::<f64>(&written).unwrap();
+//! assert_eq!(CsrMatrix::from(&coo_reloaded), csr);
+//! ```
+mod matrix_market;
+
+pub use self::matrix_market::{
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    load_dense_from_matrix_market_file, load_dense_from_matrix_market_str,
+    save_to_matrix_market_file, save_to_matrix_market_str, MatrixMarketError,
+    MatrixMarketErrorKind, MatrixMarketExport, MatrixMarketScalar,
+};
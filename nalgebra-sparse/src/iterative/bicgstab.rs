@@ -0,0 +1,100 @@
+use super::{residual, LinearOperator, Monitor, SolverOptions, SolverReport, SolverStatus};
+use nalgebra::{DVector, RealField};
+
+/// Solves `A x = b` with the stabilized biconjugate gradient method (BiCGSTAB) of van der Vorst.
+///
+/// The matrix `A` may be any square matrix. The method is right-preconditioned, so that the
+/// residual norms are those of the original system. Each iteration requires two products with
+/// `A` and two applications of the preconditioner. The vector `x` holds the initial guess on
+/// input, and the last iterate on output.
+///
+/// A [`SolverStatus::Breakdown`] is reported if one of the inner products of the recurrences
+/// vanishes, in which case restarting the solver from the last iterate may help.
+///
+/// # Panics
+///
+/// Panics if the dimensions of `b` and `x` differ.
+pub fn bicgstab<T, A, M>(
+    a: &A,
+    preconditioner: &M,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: LinearOperator<T> + ?Sized,
+{
+    assert_eq!(b.len(), x.len(), "Dimension mismatch in iterative solver.");
+    let n = b.len();
+    let mut monitor = Monitor::new(options, b.norm());
+
+    let mut r = DVector::zeros(n);
+    residual(a, b, x, &mut r);
+    if monitor.record(r.norm()) {
+        return monitor.finish(SolverStatus::Converged);
+    }
+
+    // The shadow residual
+    let r_hat = r.clone();
+    let mut p = DVector::zeros(n);
+    let mut p_hat = DVector::zeros(n);
+    let mut v = DVector::zeros(n);
+    let mut s_hat = DVector::zeros(n);
+    let mut t = DVector::zeros(n);
+
+    let mut rho = T::one();
+    let mut alpha = T::one();
+    let mut omega = T::one();
+
+    while monitor.next_iteration() {
+        let rho_new = r_hat.dot(&r);
+        if rho_new.is_zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+
+        // p = r + beta * (p - omega * v)
+        let beta = (rho_new / rho) * (alpha / omega);
+        p.axpy(-omega, &v, T::one());
+        p.axpy(T::one(), &r, beta);
+        rho = rho_new;
+
+        preconditioner.apply(&p, &mut p_hat);
+        a.apply(&p_hat, &mut v);
+        let r_hat_v = r_hat.dot(&v);
+        if r_hat_v.is_zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+        alpha = rho / r_hat_v;
+
+        // The intermediate residual `s` is stored in `r`.
+        r.axpy(-alpha, &v, T::one());
+        x.axpy(alpha, &p_hat, T::one());
+        let s_norm = r.norm();
+        if monitor.is_converged(s_norm) {
+            let _ = monitor.record(s_norm);
+            return monitor.finish(SolverStatus::Converged);
+        }
+
+        preconditioner.apply(&r, &mut s_hat);
+        a.apply(&s_hat, &mut t);
+        let t_norm_squared = t.norm_squared();
+        if t_norm_squared.is_zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+        omega = t.dot(&r) / t_norm_squared;
+
+        x.axpy(omega, &s_hat, T::one());
+        r.axpy(-omega, &t, T::one());
+
+        if monitor.record(r.norm()) {
+            return monitor.finish(SolverStatus::Converged);
+        }
+        if omega.is_zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+    }
+
+    monitor.finish(SolverStatus::MaxIterationsReached)
+}
@@ -0,0 +1,71 @@
+use super::{residual, LinearOperator, Monitor, SolverOptions, SolverReport, SolverStatus};
+use nalgebra::{DVector, RealField};
+
+/// Solves `A x = b` with the preconditioned conjugate gradient method.
+///
+/// Both the matrix `A` and the preconditioner must be symmetric positive definite. The vector `x`
+/// holds the initial guess on input, and the last iterate on output.
+///
+/// A [`SolverStatus::Breakdown`] is reported if a direction of non-positive curvature of `A` or
+/// of the preconditioner is encountered, which indicates that one of them is not positive
+/// definite.
+///
+/// # Panics
+///
+/// Panics if the dimensions of `b` and `x` differ.
+pub fn conjugate_gradient<T, A, M>(
+    a: &A,
+    preconditioner: &M,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: LinearOperator<T> + ?Sized,
+{
+    assert_eq!(b.len(), x.len(), "Dimension mismatch in iterative solver.");
+    let n = b.len();
+    let mut monitor = Monitor::new(options, b.norm());
+
+    let mut r = DVector::zeros(n);
+    residual(a, b, x, &mut r);
+    if monitor.record(r.norm()) {
+        return monitor.finish(SolverStatus::Converged);
+    }
+
+    let mut z = DVector::zeros(n);
+    preconditioner.apply(&r, &mut z);
+    let mut rz = r.dot(&z);
+    let mut p = z.clone();
+    let mut q = DVector::zeros(n);
+
+    while monitor.next_iteration() {
+        if rz <= T::zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+
+        a.apply(&p, &mut q);
+        let pq = p.dot(&q);
+        if pq <= T::zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+
+        let alpha = rz / pq;
+        x.axpy(alpha, &p, T::one());
+        r.axpy(-alpha, &q, T::one());
+
+        if monitor.record(r.norm()) {
+            return monitor.finish(SolverStatus::Converged);
+        }
+
+        preconditioner.apply(&r, &mut z);
+        let rz_new = r.dot(&z);
+        let beta = rz_new / rz;
+        rz = rz_new;
+        p.axpy(T::one(), &z, beta);
+    }
+
+    monitor.finish(SolverStatus::MaxIterationsReached)
+}
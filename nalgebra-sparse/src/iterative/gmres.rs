@@ -0,0 +1,136 @@
+use super::{residual, LinearOperator, Monitor, SolverOptions, SolverReport, SolverStatus};
+use nalgebra::{DMatrix, DVector, RealField};
+
+/// Solves `A x = b` with the restarted generalized minimal residual method, GMRES(`restart`).
+///
+/// The matrix `A` may be any square matrix. The method is right-preconditioned, so that the
+/// residual norms are those of the original system. The Arnoldi basis is orthogonalized with the
+/// modified Gram-Schmidt process, and the iteration is restarted from the current iterate after
+/// `restart` iterations, which bounds the memory usage to `restart + 1` vectors. The vector `x`
+/// holds the initial guess on input, and the last iterate on output.
+///
+/// The residual norms are estimated by the Givens rotations used to solve the least-squares
+/// problems, and the true residual is recomputed at each restart.
+///
+/// # Panics
+///
+/// Panics if the dimensions of `b` and `x` differ, or if `restart` is zero.
+pub fn gmres<T, A, M>(
+    a: &A,
+    preconditioner: &M,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    restart: usize,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: LinearOperator<T> + ?Sized,
+{
+    assert_eq!(b.len(), x.len(), "Dimension mismatch in iterative solver.");
+    assert!(restart > 0, "The restart length must be positive.");
+    let n = b.len();
+    let mut monitor = Monitor::new(options, b.norm());
+
+    // The Arnoldi basis `V`, the Hessenberg matrix `H` reduced to triangular form by the
+    // rotations `(cs, sn)`, and the rotated right-hand side `g` of the least-squares problem.
+    let mut basis = DMatrix::zeros(n, restart + 1);
+    let mut hessenberg = DMatrix::zeros(restart + 1, restart);
+    let mut cs = vec![T::zero(); restart];
+    let mut sn = vec![T::zero(); restart];
+    let mut g = DVector::zeros(restart + 1);
+
+    let mut r = DVector::zeros(n);
+    let mut z = DVector::zeros(n);
+    let mut w = DVector::zeros(n);
+
+    residual(a, b, x, &mut r);
+    let mut beta = r.norm();
+    if monitor.record(beta) {
+        return monitor.finish(SolverStatus::Converged);
+    }
+
+    loop {
+        basis.column_mut(0).copy_from(&(&r / beta));
+        g.fill(T::zero());
+        g[0] = beta;
+
+        let mut k = 0;
+        let mut status = None;
+
+        while k < restart {
+            if !monitor.next_iteration() {
+                status = Some(SolverStatus::MaxIterationsReached);
+                break;
+            }
+
+            // Arnoldi step
+            preconditioner.apply(&basis.column(k).clone_owned(), &mut z);
+            a.apply(&z, &mut w);
+            for i in 0..=k {
+                let h_ik = basis.column(i).dot(&w);
+                w.axpy(-h_ik, &basis.column(i), T::one());
+                hessenberg[(i, k)] = h_ik;
+            }
+            let h_next = w.norm();
+
+            // Apply the previous rotations to the new column, and eliminate `h_next`.
+            for i in 0..k {
+                let (h_i, h_i1) = (hessenberg[(i, k)], hessenberg[(i + 1, k)]);
+                hessenberg[(i, k)] = cs[i] * h_i + sn[i] * h_i1;
+                hessenberg[(i + 1, k)] = cs[i] * h_i1 - sn[i] * h_i;
+            }
+
+            let h_kk = hessenberg[(k, k)];
+            let norm = h_kk.hypot(h_next);
+            if norm.is_zero() {
+                // The Hessenberg matrix is singular, which can only happen if `A` is singular.
+                status = Some(SolverStatus::Breakdown);
+                break;
+            }
+            cs[k] = h_kk / norm;
+            sn[k] = h_next / norm;
+            hessenberg[(k, k)] = norm;
+            hessenberg[(k + 1, k)] = T::zero();
+            g[k + 1] = -sn[k] * g[k];
+            g[k] *= cs[k];
+            k += 1;
+
+            if monitor.record(g[k].abs()) {
+                status = Some(SolverStatus::Converged);
+                break;
+            }
+
+            if h_next.is_zero() {
+                // The Krylov subspace is invariant, so the residual is exactly zero in exact
+                // arithmetic.
+                status = Some(SolverStatus::Converged);
+                break;
+            }
+            basis.column_mut(k).copy_from(&(&w / h_next));
+        }
+
+        // Update the solution with `x += M V y`, where `H y = g`.
+        if k > 0 {
+            let y = hessenberg
+                .slice((0, 0), (k, k))
+                .solve_upper_triangular(&g.rows(0, k))
+                .expect("The triangular factor of the Hessenberg matrix must be invertible");
+            let update = basis.columns(0, k) * y;
+            preconditioner.apply(&update, &mut z);
+            *x += &z;
+        }
+
+        if let Some(status) = status {
+            return monitor.finish(status);
+        }
+
+        // Restart from the true residual.
+        residual(a, b, x, &mut r);
+        beta = r.norm();
+        if beta.is_zero() {
+            return monitor.finish(SolverStatus::Converged);
+        }
+    }
+}
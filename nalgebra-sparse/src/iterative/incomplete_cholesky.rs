@@ -0,0 +1,96 @@
+use super::{LinearOperator, PreconditionerError};
+use crate::csc::CscMatrix;
+use crate::ops::serial::spsolve_csc_lower_triangular;
+use crate::ops::Op;
+use nalgebra::{DVector, RealField};
+
+/// The zero fill-in incomplete Cholesky preconditioner IC(0).
+///
+/// The factor `L` has the sparsity pattern of the lower triangle of `A`, and its entries are
+/// chosen such that `L L^T` agrees with `A` on this pattern. The preconditioner applies
+/// `(L L^T)^-1` with two triangular solves.
+///
+/// If the pattern of the lower triangle of `A` is closed under the fill-in of the Cholesky
+/// factorization, as for tridiagonal or arrow matrices, `L` is the exact Cholesky factor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T> {
+    l: CscMatrix<T>,
+}
+
+impl<T: RealField> IncompleteCholesky<T> {
+    /// Computes the IC(0) factorization of the given symmetric matrix.
+    ///
+    /// Only the lower triangle of the matrix is accessed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreconditionerError::NotPositiveDefinite`] if a non-positive pivot is
+    /// encountered. This can happen even if the matrix is positive definite, but not if it is
+    /// an M-matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor(matrix: &CscMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "Matrix must be square.");
+        let mut l = matrix.lower_triangle();
+        let n = l.ncols();
+
+        // Maps the row indices of the column being updated to the indices of their values
+        let mut position = vec![usize::MAX; n];
+        let (offsets, rows, values) = l.csc_data_mut();
+
+        for k in 0..n {
+            let (start, end) = (offsets[k], offsets[k + 1]);
+            if start == end || rows[start] != k || values[start] <= T::zero() {
+                return Err(PreconditionerError::NotPositiveDefinite);
+            }
+            let l_kk = values[start].sqrt();
+            values[start] = l_kk;
+            for l_ik in &mut values[start + 1..end] {
+                *l_ik /= l_kk;
+            }
+
+            // Right-looking update of the columns `j > k` with `l_jk != 0`, restricted to the
+            // pattern of `L`
+            for p in start + 1..end {
+                let j = rows[p];
+                let l_jk = values[p];
+                for q in offsets[j]..offsets[j + 1] {
+                    position[rows[q]] = q;
+                }
+                for r in p..end {
+                    let target = position[rows[r]];
+                    if target != usize::MAX {
+                        let l_ik = values[r];
+                        values[target] -= l_ik * l_jk;
+                    }
+                }
+                for q in offsets[j]..offsets[j + 1] {
+                    position[rows[q]] = usize::MAX;
+                }
+            }
+        }
+
+        Ok(Self { l })
+    }
+
+    /// The incomplete Cholesky factor `L`.
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l
+    }
+
+    /// Returns the incomplete Cholesky factor `L`.
+    pub fn take_l(self) -> CscMatrix<T> {
+        self.l
+    }
+}
+
+impl<T: RealField> LinearOperator<T> for IncompleteCholesky<T> {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        let expect_msg = "The diagonal of L is positive by construction";
+        y.copy_from(x);
+        spsolve_csc_lower_triangular(Op::NoOp(&self.l), &mut *y).expect(expect_msg);
+        spsolve_csc_lower_triangular(Op::Transpose(&self.l), &mut *y).expect(expect_msg);
+    }
+}
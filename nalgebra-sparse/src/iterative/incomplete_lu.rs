@@ -0,0 +1,243 @@
+use super::{LinearOperator, PreconditionerError};
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::ops::serial::spsolve_csc_lower_triangular;
+use crate::ops::Op;
+use nalgebra::{DVector, RealField};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// An incomplete LU preconditioner, ILU(0) or ILUT.
+///
+/// The matrix is approximated by `A ≈ L U`, with `L` unit lower triangular and `U` upper
+/// triangular, without pivoting. The preconditioner applies `(L U)^-1` with two triangular
+/// solves. Two variants are provided:
+///
+/// - [`IncompleteLu::factor`] computes the zero fill-in factorization ILU(0), in which `L` and
+///   `U` have the sparsity pattern of the lower and upper triangle of `A`, and `L U` agrees with
+///   `A` on the pattern of `A`.
+/// - [`IncompleteLu::factor_with_threshold`] computes the dual threshold factorization
+///   ILUT(τ, p) of Saad, in which the fill-in is chosen by magnitude rather than position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteLu<T> {
+    // `L` with its unit diagonal explicitly stored
+    l: CscMatrix<T>,
+    u_transpose: CscMatrix<T>,
+}
+
+impl<T: RealField> IncompleteLu<T> {
+    /// Computes the ILU(0) factorization of the given square matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreconditionerError::Singular`] if a zero pivot is encountered, which happens
+    /// in particular if a diagonal entry of the matrix is not explicitly stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor(matrix: &CscMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "Matrix must be square.");
+        let mut lu = CsrMatrix::from(matrix);
+        let n = lu.nrows();
+
+        let mut diagonal = Vec::with_capacity(n);
+        for (i, row) in lu.row_iter().enumerate() {
+            let offset = lu.row_offsets()[i];
+            match row.col_indices().binary_search(&i) {
+                Ok(index) => diagonal.push(offset + index),
+                Err(_) => return Err(PreconditionerError::Singular),
+            }
+        }
+
+        // Maps the column indices of the current row to the indices of their values
+        let mut position = vec![usize::MAX; n];
+        let (offsets, cols, values) = lu.csr_data_mut();
+
+        // The IKJ variant of Gaussian elimination, restricted to the pattern of `A`
+        for i in 0..n {
+            for p in offsets[i]..offsets[i + 1] {
+                position[cols[p]] = p;
+            }
+            for p in offsets[i]..diagonal[i] {
+                let k = cols[p];
+                values[p] /= values[diagonal[k]];
+                let l_ik = values[p];
+                for q in diagonal[k] + 1..offsets[k + 1] {
+                    let target = position[cols[q]];
+                    if target != usize::MAX {
+                        let u_kj = values[q];
+                        values[target] -= l_ik * u_kj;
+                    }
+                }
+            }
+            for p in offsets[i]..offsets[i + 1] {
+                position[cols[p]] = usize::MAX;
+            }
+            if values[diagonal[i]].is_zero() {
+                return Err(PreconditionerError::Singular);
+            }
+        }
+
+        Ok(Self::from_combined_factors(lu))
+    }
+
+    /// Computes the ILUT(τ, p) factorization of the given square matrix, with drop tolerance
+    /// τ = `drop_tolerance` and at most p = `max_fill` entries in each row of `L` and of `U`,
+    /// besides the diagonal.
+    ///
+    /// During the elimination of row `i`, the entries whose magnitude is at most τ times the
+    /// Euclidean norm of row `i` of `A` are dropped. Among the remaining entries, only the
+    /// `max_fill` largest ones in the lower and upper part of the row are kept. With a drop
+    /// tolerance of zero and `max_fill` equal to the dimension of the matrix, the exact LU
+    /// factorization without pivoting is computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreconditionerError::Singular`] if a zero pivot is encountered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn factor_with_threshold(
+        matrix: &CscMatrix<T>,
+        drop_tolerance: T,
+        max_fill: usize,
+    ) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "Matrix must be square.");
+        let a = CsrMatrix::from(matrix);
+        let n = a.nrows();
+
+        // The combined factors, with the strictly lower part of `L`, followed by the diagonal
+        // and the strictly upper part of `U` in each row
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut cols: Vec<usize> = Vec::new();
+        let mut values = Vec::new();
+        let mut diagonal = Vec::with_capacity(n);
+        offsets.push(0);
+
+        // The working row, its non-zero pattern, and the queue of its lower entries
+        let mut w = vec![T::zero(); n];
+        let mut is_nonzero = vec![false; n];
+        let mut nonzeros = Vec::new();
+        let mut queue = BinaryHeap::new();
+
+        for (i, row) in a.row_iter().enumerate() {
+            let tau = drop_tolerance * norm(row.values());
+            for (&j, &a_ij) in row.col_indices().iter().zip(row.values()) {
+                w[j] = a_ij;
+                is_nonzero[j] = true;
+                nonzeros.push(j);
+                if j < i {
+                    queue.push(Reverse(j));
+                }
+            }
+            if !is_nonzero[i] {
+                is_nonzero[i] = true;
+                nonzeros.push(i);
+            }
+
+            // Eliminate the lower entries in increasing order of their column indices, which
+            // may create fill-in further right.
+            while let Some(Reverse(k)) = queue.pop() {
+                let w_k = w[k] / values[diagonal[k]];
+                if w_k.abs() <= tau {
+                    w[k] = T::zero();
+                    continue;
+                }
+                w[k] = w_k;
+                for q in diagonal[k] + 1..offsets[k + 1] {
+                    let j = cols[q];
+                    if !is_nonzero[j] {
+                        is_nonzero[j] = true;
+                        nonzeros.push(j);
+                        if j < i {
+                            queue.push(Reverse(j));
+                        }
+                    }
+                    w[j] -= w_k * values[q];
+                }
+            }
+
+            if w[i].is_zero() {
+                return Err(PreconditionerError::Singular);
+            }
+            let (mut lower, mut upper): (Vec<_>, Vec<_>) = nonzeros
+                .iter()
+                .filter(|&&j| j != i && w[j].abs() > tau)
+                .partition(|&&j| j < i);
+            keep_largest(&mut lower, &w, max_fill);
+            keep_largest(&mut upper, &w, max_fill);
+
+            diagonal.push(cols.len() + lower.len());
+            for j in lower.into_iter().chain(Some(i)).chain(upper) {
+                cols.push(j);
+                values.push(w[j]);
+            }
+            offsets.push(cols.len());
+
+            for &j in &nonzeros {
+                w[j] = T::zero();
+                is_nonzero[j] = false;
+            }
+            nonzeros.clear();
+        }
+
+        let lu = CsrMatrix::try_from_csr_data(n, n, offsets, cols, values)
+            .expect("The incomplete factors are valid by construction");
+        Ok(Self::from_combined_factors(lu))
+    }
+
+    /// Splits the factors stored in a single matrix, with the unit diagonal of `L` implicit.
+    fn from_combined_factors(lu: CsrMatrix<T>) -> Self {
+        let mut l = lu.lower_triangle();
+        for mut row in l.row_iter_mut() {
+            if let Some(l_ii) = row.values_mut().last_mut() {
+                *l_ii = T::one();
+            }
+        }
+        Self {
+            l: CscMatrix::from(&l),
+            u_transpose: lu.upper_triangle().transpose_as_csc(),
+        }
+    }
+
+    /// The unit lower triangular factor `L`.
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l
+    }
+
+    /// The upper triangular factor `U`.
+    ///
+    /// The factor is stored in transposed form, so this allocates a new matrix.
+    pub fn u(&self) -> CscMatrix<T> {
+        self.u_transpose.transpose()
+    }
+}
+
+impl<T: RealField> LinearOperator<T> for IncompleteLu<T> {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        let expect_msg = "The pivots of the incomplete factorization are non-zero by construction";
+        y.copy_from(x);
+        spsolve_csc_lower_triangular(Op::NoOp(&self.l), &mut *y).expect(expect_msg);
+        spsolve_csc_lower_triangular(Op::Transpose(&self.u_transpose), &mut *y).expect(expect_msg);
+    }
+}
+
+fn norm<T: RealField>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &v| acc + v * v).sqrt()
+}
+
+/// Keeps the `count` entries of `w` with the largest magnitudes among the given indices, and
+/// sorts the indices.
+fn keep_largest<T: RealField>(indices: &mut Vec<usize>, w: &[T], count: usize) {
+    if indices.len() > count {
+        indices.sort_unstable_by(|&a, &b| {
+            w[b].abs()
+                .partial_cmp(&w[a].abs())
+                .unwrap_or(Ordering::Equal)
+        });
+        indices.truncate(count);
+    }
+    indices.sort_unstable();
+}
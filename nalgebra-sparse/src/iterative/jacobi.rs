@@ -0,0 +1,118 @@
+use super::{LinearOperator, PreconditionerError};
+use crate::csc::CscMatrix;
+use nalgebra::{DMatrix, DVector, RealField, Scalar};
+
+/// The Jacobi preconditioner, which applies the inverse of the diagonal of `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jacobi<T: Scalar> {
+    inverse_diagonal: DVector<T>,
+}
+
+impl<T: RealField> Jacobi<T> {
+    /// Constructs the Jacobi preconditioner of the given square matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreconditionerError::Singular`] if a diagonal entry is zero or not explicitly
+    /// stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn new(matrix: &CscMatrix<T>) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "Matrix must be square.");
+        let mut inverse_diagonal = DVector::zeros(matrix.ncols());
+        for (j, d) in inverse_diagonal.iter_mut().enumerate() {
+            let a_jj = matrix.index_entry(j, j).into_value();
+            if a_jj.is_zero() {
+                return Err(PreconditionerError::Singular);
+            }
+            *d = T::one() / a_jj;
+        }
+        Ok(Self { inverse_diagonal })
+    }
+
+    /// The inverse of the diagonal of the matrix.
+    pub fn inverse_diagonal(&self) -> &DVector<T> {
+        &self.inverse_diagonal
+    }
+}
+
+impl<T: RealField> LinearOperator<T> for Jacobi<T> {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.copy_from(x);
+        y.component_mul_assign(&self.inverse_diagonal);
+    }
+}
+
+/// The block Jacobi preconditioner, which applies the inverses of the diagonal blocks of `A`.
+///
+/// The unknowns are grouped into consecutive blocks of a fixed size, such as the degrees of
+/// freedom associated with each node of a finite element mesh. The last block is smaller if the
+/// dimension of the matrix is not a multiple of the block size. The inverses of the blocks are
+/// computed and stored as dense matrices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockJacobi<T: Scalar> {
+    block_size: usize,
+    inverse_blocks: Vec<DMatrix<T>>,
+}
+
+impl<T: RealField> BlockJacobi<T> {
+    /// Constructs the block Jacobi preconditioner of the given square matrix, with blocks of
+    /// the given size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreconditionerError::Singular`] if a diagonal block is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if the block size is zero.
+    pub fn new(matrix: &CscMatrix<T>, block_size: usize) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "Matrix must be square.");
+        assert!(block_size > 0, "The block size must be positive.");
+        let n = matrix.ncols();
+
+        let mut inverse_blocks = Vec::new();
+        for start in (0..n).step_by(block_size) {
+            let end = n.min(start + block_size);
+            let mut block = DMatrix::zeros(end - start, end - start);
+            for j in start..end {
+                let col = matrix.col(j);
+                for (&i, &a_ij) in col.row_indices().iter().zip(col.values()) {
+                    if (start..end).contains(&i) {
+                        block[(i - start, j - start)] = a_ij;
+                    }
+                }
+            }
+            let inverse = block.try_inverse().ok_or(PreconditionerError::Singular)?;
+            inverse_blocks.push(inverse);
+        }
+
+        Ok(Self {
+            block_size,
+            inverse_blocks,
+        })
+    }
+
+    /// The size of the blocks.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// The inverses of the diagonal blocks.
+    pub fn inverse_blocks(&self) -> &[DMatrix<T>] {
+        &self.inverse_blocks
+    }
+}
+
+impl<T: RealField> LinearOperator<T> for BlockJacobi<T> {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        for (k, inverse) in self.inverse_blocks.iter().enumerate() {
+            let start = k * self.block_size;
+            let len = inverse.nrows();
+            y.rows_mut(start, len)
+                .gemv(T::one(), inverse, &x.rows(start, len), T::zero());
+        }
+    }
+}
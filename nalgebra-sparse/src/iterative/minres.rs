@@ -0,0 +1,124 @@
+use super::{residual, LinearOperator, Monitor, SolverOptions, SolverReport, SolverStatus};
+use nalgebra::{DVector, RealField};
+
+/// Solves `A x = b` with the preconditioned minimum residual method (MINRES) of Paige and
+/// Saunders.
+///
+/// The matrix `A` must be symmetric, but may be indefinite. The preconditioner must be
+/// symmetric positive definite. The vector `x` holds the initial guess on input, and the last
+/// iterate on output.
+///
+/// The residual norms are estimated by the recurrences of the method, without additional
+/// matrix-vector products. With a preconditioner `M`, they are measured in the norm
+/// `‖r‖_M = sqrt(r^T M r)` induced by the preconditioner, for both the residual and `b`.
+///
+/// A [`SolverStatus::Breakdown`] is reported if the preconditioner is found to be indefinite,
+/// or if the Krylov subspace becomes invariant before convergence, which can happen if `A` is
+/// singular.
+///
+/// # Panics
+///
+/// Panics if the dimensions of `b` and `x` differ.
+pub fn minres<T, A, M>(
+    a: &A,
+    preconditioner: &M,
+    b: &DVector<T>,
+    x: &mut DVector<T>,
+    options: &SolverOptions<T>,
+) -> SolverReport<T>
+where
+    T: RealField,
+    A: LinearOperator<T> + ?Sized,
+    M: LinearOperator<T> + ?Sized,
+{
+    assert_eq!(b.len(), x.len(), "Dimension mismatch in iterative solver.");
+    let n = b.len();
+
+    let mut y = DVector::zeros(n);
+    preconditioner.apply(b, &mut y);
+    let b_norm_squared = b.dot(&y);
+    if b_norm_squared < T::zero() {
+        return Monitor::new(options, T::zero()).finish(SolverStatus::Breakdown);
+    }
+    let mut monitor = Monitor::new(options, b_norm_squared.sqrt());
+
+    // The Lanczos vectors are `v_k = r_k / beta_k`, with `r_k` stored in `r1` and `r2`.
+    let mut r1 = DVector::zeros(n);
+    residual(a, b, x, &mut r1);
+    preconditioner.apply(&r1, &mut y);
+    let beta1 = r1.dot(&y);
+    if beta1 < T::zero() {
+        return monitor.finish(SolverStatus::Breakdown);
+    }
+    let beta1 = beta1.sqrt();
+    if monitor.record(beta1) {
+        return monitor.finish(SolverStatus::Converged);
+    }
+
+    let mut r2 = r1.clone();
+    let mut v = DVector::zeros(n);
+    let mut w = DVector::zeros(n);
+    let mut w1 = DVector::zeros(n);
+    let mut w2 = DVector::zeros(n);
+
+    let mut old_beta = T::zero();
+    let mut beta = beta1;
+    let mut dbar = T::zero();
+    let mut epsilon = T::zero();
+    let mut phibar = beta1;
+    let mut cs = -T::one();
+    let mut sn = T::zero();
+
+    while monitor.next_iteration() {
+        if beta.is_zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+
+        // Lanczos step
+        v.copy_from(&y);
+        v /= beta;
+        a.apply(&v, &mut y);
+        if monitor.iterations >= 2 {
+            y.axpy(-beta / old_beta, &r1, T::one());
+        }
+        let alpha = v.dot(&y);
+        y.axpy(-alpha / beta, &r2, T::one());
+        std::mem::swap(&mut r1, &mut r2);
+        r2.copy_from(&y);
+        preconditioner.apply(&r2, &mut y);
+        old_beta = beta;
+        beta = r2.dot(&y);
+        if beta < T::zero() {
+            return monitor.finish(SolverStatus::Breakdown);
+        }
+        beta = beta.sqrt();
+
+        // Apply the previous rotation, and compute the next one to eliminate `beta`.
+        let old_epsilon = epsilon;
+        let delta = cs * dbar + sn * alpha;
+        let gbar = sn * dbar - cs * alpha;
+        epsilon = sn * beta;
+        dbar = -cs * beta;
+
+        let gamma = gbar.hypot(beta).max(T::default_epsilon());
+        cs = gbar / gamma;
+        sn = beta / gamma;
+        let phi = cs * phibar;
+        phibar *= sn;
+
+        // Update the solution.
+        std::mem::swap(&mut w1, &mut w2);
+        std::mem::swap(&mut w2, &mut w);
+        w.copy_from(&v);
+        w.axpy(-old_epsilon, &w1, T::one());
+        w.axpy(-delta, &w2, T::one());
+        w /= gamma;
+        x.axpy(phi, &w, T::one());
+
+        if monitor.record(phibar.abs()) {
+            return monitor.finish(SolverStatus::Converged);
+        }
+    }
+
+    monitor.finish(SolverStatus::MaxIterationsReached)
+}
@@ -0,0 +1,285 @@
+//! Iterative Krylov solvers for sparse linear systems.
+//!
+//! The solvers only access the system matrix `A` and the preconditioner through the
+//! [`LinearOperator`] trait, which is implemented by [`CsrMatrix`], [`CscMatrix`], [`DMatrix`]
+//! and closures computing matrix-vector products. The following solvers are provided:
+//!
+//! | Solver                  | Requirements on `A`  | Requirements on the preconditioner |
+//! | ----------------------- | -------------------- | ---------------------------------- |
+//! | [`conjugate_gradient`]  | Symmetric positive definite | Symmetric positive definite |
+//! | [`minres`]              | Symmetric            | Symmetric positive definite        |
+//! | [`gmres`]               | None                 | None                               |
+//! | [`bicgstab`]            | None                 | None                               |
+//!
+//! The preconditioner `M` is an operator applying an approximation of `A^-1`. The
+//! [`IdentityOperator`] can be used to disable preconditioning. The following preconditioners
+//! are constructed from a [`CscMatrix`]:
+//!
+//! | Preconditioner           | Requirements on `A`                    | Symmetric |
+//! | ------------------------ | -------------------------------------- | --------- |
+//! | [`Jacobi`]               | Non-zero diagonal                      | Yes, if `A` is |
+//! | [`BlockJacobi`]          | Invertible diagonal blocks             | Yes, if `A` is |
+//! | [`Ssor`]                 | Non-zero diagonal                      | Yes, if `A` is |
+//! | [`IncompleteCholesky`]   | Symmetric positive definite            | Yes       |
+//! | [`IncompleteLu`]         | Non-zero pivots                        | No        |
+//!
+//! The incomplete factorizations may break down even if `A` satisfies the requirements, but are
+//! guaranteed to succeed for M-matrices, such as many discretizations of diffusion problems.
+//!
+//! Each solver starts from the
+//! initial guess stored in `x`, and stops once the relative residual `‖b - A x‖ / ‖b‖` drops
+//! below the tolerance given in the [`SolverOptions`], or once the maximum number of iterations
+//! is reached. The returned [`SolverReport`] describes the outcome, together with the history of
+//! the relative residual norms.
+//!
+//! # Example
+//!
+//! ```rust
+//! use nalgebra_sparse::csr::CsrMatrix;
+//! use nalgebra_sparse::iterative::{conjugate_gradient, IdentityOperator, SolverOptions, SolverStatus};
+//! use nalgebra::{DMatrix, DVector};
+//!
+//! let dense = DMatrix::from_row_slice(3, 3, &[
+//!     4.0, -1.0, 0.0,
+//!    -1.0, 4.0, -1.0,
+//!     0.0, -1.0, 4.0]);
+//! let a = CsrMatrix::from(&dense);
+//! let b = DVector::from_column_slice(&[1.0, 2.0, 3.0]);
+//!
+//! let mut x = DVector::zeros(3);
+//! let options = SolverOptions::new(1.0e-10, 100);
+//! let report = conjugate_gradient(&a, &IdentityOperator, &b, &mut x, &options);
+//!
+//! assert_eq!(report.status, SolverStatus::Converged);
+//! assert!((&dense * &x - &b).norm() <= 1.0e-10 * b.norm());
+//! ```
+mod bicgstab;
+mod cg;
+mod gmres;
+mod incomplete_cholesky;
+mod incomplete_lu;
+mod jacobi;
+mod minres;
+mod ssor;
+
+pub use self::bicgstab::bicgstab;
+pub use self::cg::conjugate_gradient;
+pub use self::gmres::gmres;
+pub use self::incomplete_cholesky::IncompleteCholesky;
+pub use self::incomplete_lu::IncompleteLu;
+pub use self::jacobi::{BlockJacobi, Jacobi};
+pub use self::minres::minres;
+pub use self::ssor::Ssor;
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::ops::serial::{spmm_csc_dense, spmm_csr_dense};
+use crate::ops::Op;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrix, DVector, RealField, Scalar};
+use num_traits::{One, Zero};
+use std::fmt::{Display, Formatter};
+
+/// A linear operator `A`, accessed through matrix-vector products.
+pub trait LinearOperator<T: Scalar> {
+    /// Computes `y = A x`.
+    ///
+    /// The vectors `x` and `y` have dimensions compatible with the operator.
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>);
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.fill(T::zero());
+        spmm_csr_dense(T::zero(), y, T::one(), Op::NoOp(self), Op::NoOp(x));
+    }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.fill(T::zero());
+        spmm_csc_dense(T::zero(), y, T::one(), Op::NoOp(self), Op::NoOp(x));
+    }
+}
+
+impl<T: RealField> LinearOperator<T> for DMatrix<T> {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.gemv(T::one(), self, x, T::zero());
+    }
+}
+
+impl<T, F> LinearOperator<T> for F
+where
+    T: Scalar,
+    F: Fn(&DVector<T>, &mut DVector<T>),
+{
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        self(x, y)
+    }
+}
+
+/// The identity operator, used as preconditioner to disable preconditioning.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IdentityOperator;
+
+impl<T: Scalar> LinearOperator<T> for IdentityOperator {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        y.copy_from(x);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+/// Possible errors produced by the construction of a preconditioner.
+pub enum PreconditionerError {
+    /// A zero diagonal entry, a zero pivot or a singular diagonal block was encountered.
+    Singular,
+    /// A non-positive pivot was encountered in an incomplete Cholesky factorization.
+    NotPositiveDefinite,
+}
+
+impl Display for PreconditionerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Singular => write!(f, "Preconditioner construction encountered a zero pivot"),
+            Self::NotPositiveDefinite => write!(f, "Matrix is not positive definite"),
+        }
+    }
+}
+
+impl std::error::Error for PreconditionerError {}
+
+/// Options controlling the termination of the iterative solvers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverOptions<T> {
+    /// The tolerance on the relative residual `‖b - A x‖ / ‖b‖`, or on the absolute residual
+    /// if `b` is zero.
+    pub tolerance: T,
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+}
+
+impl<T> SolverOptions<T> {
+    /// Creates options with the given tolerance on the relative residual and maximum number
+    /// of iterations.
+    pub fn new(tolerance: T, max_iterations: usize) -> Self {
+        Self {
+            tolerance,
+            max_iterations,
+        }
+    }
+}
+
+/// The outcome of an iterative solver.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SolverStatus {
+    /// The relative residual dropped below the tolerance.
+    Converged,
+    /// The maximum number of iterations was reached before convergence.
+    MaxIterationsReached,
+    /// The iteration could not be continued, e.g. because the matrix or the preconditioner do
+    /// not satisfy the requirements of the solver.
+    Breakdown,
+}
+
+/// A report on the execution of an iterative solver.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverReport<T> {
+    /// The outcome of the solver.
+    pub status: SolverStatus,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The relative residual norm of the initial guess, followed by the relative residual norm
+    /// after each iteration.
+    ///
+    /// For [`minres`], the norms are estimated by the recurrences of the method.
+    pub residual_history: Vec<T>,
+}
+
+impl<T: RealField> SolverReport<T> {
+    /// Whether the solver converged.
+    pub fn converged(&self) -> bool {
+        self.status == SolverStatus::Converged
+    }
+
+    /// The last relative residual norm.
+    ///
+    /// Returns `None` if the solver broke down before computing the initial residual.
+    pub fn residual_norm(&self) -> Option<T> {
+        self.residual_history.last().copied()
+    }
+}
+
+/// Tracks the relative residual norms of an iteration.
+struct Monitor<'a, T: RealField> {
+    options: &'a SolverOptions<T>,
+    reference_norm: T,
+    iterations: usize,
+    residual_history: Vec<T>,
+}
+
+impl<'a, T: RealField> Monitor<'a, T> {
+    fn new(options: &'a SolverOptions<T>, reference_norm: T) -> Self {
+        Self {
+            options,
+            reference_norm,
+            iterations: 0,
+            residual_history: Vec::new(),
+        }
+    }
+
+    fn relative(&self, residual_norm: T) -> T {
+        if self.reference_norm.is_zero() {
+            residual_norm
+        } else {
+            residual_norm / self.reference_norm
+        }
+    }
+
+    /// Whether the given residual norm satisfies the convergence criterion.
+    fn is_converged(&self, residual_norm: T) -> bool {
+        self.relative(residual_norm) <= self.options.tolerance
+    }
+
+    /// Records the residual norm of the current iterate, and returns `true` if it is converged.
+    fn record(&mut self, residual_norm: T) -> bool {
+        self.residual_history.push(self.relative(residual_norm));
+        self.is_converged(residual_norm)
+    }
+
+    /// Starts a new iteration, or returns `false` if the maximum number of iterations is reached.
+    fn next_iteration(&mut self) -> bool {
+        if self.iterations < self.options.max_iterations {
+            self.iterations += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn finish(self, status: SolverStatus) -> SolverReport<T> {
+        SolverReport {
+            status,
+            iterations: self.iterations,
+            residual_history: self.residual_history,
+        }
+    }
+}
+
+/// Computes `r = b - A x`.
+fn residual<T: RealField, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    x: &DVector<T>,
+    r: &mut DVector<T>,
+) {
+    a.apply(x, r);
+    r.neg_mut();
+    *r += b;
+}
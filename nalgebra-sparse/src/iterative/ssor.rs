@@ -0,0 +1,96 @@
+use super::{LinearOperator, PreconditionerError};
+use crate::csc::CscMatrix;
+use crate::ops::serial::spsolve_csc_lower_triangular;
+use crate::ops::Op;
+use nalgebra::{DVector, RealField, Scalar};
+
+/// The symmetric successive over-relaxation (SSOR) preconditioner.
+///
+/// With the splitting `A = L + D + U` into strictly lower triangular, diagonal and strictly upper
+/// triangular parts, the preconditioner is
+///
+/// ```text
+/// M = ω / (2 - ω) (D / ω + L) (D / ω)^-1 (D / ω + U)
+/// ```
+///
+/// for a relaxation parameter `0 < ω < 2`, and is applied with a forward and a backward
+/// triangular solve. The choice `ω = 1` gives the symmetric Gauss-Seidel preconditioner. If `A`
+/// is symmetric positive definite, so is `M`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ssor<T: Scalar> {
+    omega: T,
+    // `D / ω + L`
+    lower: CscMatrix<T>,
+    // `(D / ω + U)^T`
+    upper_transpose: CscMatrix<T>,
+    // `D / ω`
+    diagonal: DVector<T>,
+}
+
+impl<T: RealField> Ssor<T> {
+    /// Constructs the SSOR preconditioner of the given square matrix with relaxation parameter
+    /// `omega`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreconditionerError::Singular`] if a diagonal entry is zero or not explicitly
+    /// stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if `omega` does not lie in the open interval
+    /// `(0, 2)`.
+    pub fn new(matrix: &CscMatrix<T>, omega: T) -> Result<Self, PreconditionerError> {
+        assert_eq!(matrix.nrows(), matrix.ncols(), "Matrix must be square.");
+        assert!(
+            omega > T::zero() && omega < T::one() + T::one(),
+            "The relaxation parameter must lie in (0, 2)."
+        );
+
+        let mut lower = matrix.lower_triangle();
+        let mut upper_transpose = matrix.upper_triangle().transpose();
+        scale_diagonal(&mut lower, omega)?;
+        scale_diagonal(&mut upper_transpose, omega)?;
+        let diagonal =
+            DVector::from_iterator(lower.ncols(), lower.col_iter().map(|col| col.values()[0]));
+
+        Ok(Self {
+            omega,
+            lower,
+            upper_transpose,
+            diagonal,
+        })
+    }
+
+    /// The relaxation parameter.
+    pub fn omega(&self) -> T {
+        self.omega
+    }
+}
+
+impl<T: RealField> LinearOperator<T> for Ssor<T> {
+    fn apply(&self, x: &DVector<T>, y: &mut DVector<T>) {
+        let expect_msg = "The diagonal of the preconditioner is non-zero by construction";
+        y.copy_from(x);
+        spsolve_csc_lower_triangular(Op::NoOp(&self.lower), &mut *y).expect(expect_msg);
+        y.component_mul_assign(&self.diagonal);
+        spsolve_csc_lower_triangular(Op::Transpose(&self.upper_transpose), &mut *y)
+            .expect(expect_msg);
+        *y *= (T::one() + T::one() - self.omega) / self.omega;
+    }
+}
+
+/// Divides the diagonal entries of a lower triangular matrix by `omega`.
+///
+/// The diagonal entry of each column is its first entry. Returns an error if a diagonal entry
+/// is missing or zero.
+fn scale_diagonal<T: RealField>(l: &mut CscMatrix<T>, omega: T) -> Result<(), PreconditionerError> {
+    for (j, mut col) in l.col_iter_mut().enumerate() {
+        let (rows, values) = col.rows_and_values_mut();
+        match (rows.first(), values.first_mut()) {
+            (Some(&i), Some(l_jj)) if i == j && !l_jj.is_zero() => *l_jj /= omega,
+            _ => return Err(PreconditionerError::Singular),
+        }
+    }
+    Ok(())
+}
@@ -23,6 +23,13 @@
 //!   `proptest-support` is enabled.
 //! - [matrixcompare support](https://crates.io/crates/matrixcompare) for effortless
 //!   (approximate) comparison of matrices in test code (requires the `compare` feature).
+//! - Efficient [assembly](`assembly`) of matrices from dense element matrices into a fixed
+//!   sparsity pattern, as common in finite element methods.
+//! - Multithreaded matrix products, addition and transposition in `ops::parallel` when the
+//!   feature `rayon` is enabled.
+//! - [serde](https://crates.io/crates/serde) support for sparse matrices and sparsity patterns
+//!   (requires the `serde-serialize` feature). Deserialized data is validated, so invalid input
+//!   results in an error rather than a matrix with broken invariants.
 //!
 //! ## Current state
 //!
@@ -56,6 +63,7 @@
 //! | [COO](`coo::CooMatrix`) | Well-suited for matrix construction. <br /> Ill-suited for algebraic operations. |
 //! | [CSR](`csr::CsrMatrix`) | Immutable sparsity pattern, suitable for algebraic operations. <br /> Fast row access. |
 //! | [CSC](`csc::CscMatrix`) | Immutable sparsity pattern, suitable for algebraic operations. <br /> Fast column access. |
+//! | [BSR](`bsr::BsrMatrix`) | Immutable block sparsity pattern with dense blocks. <br /> Suitable for matrices with natural block structure. |
 //!
 //! What format is best to use depends on the application. The most common use case for sparse
 //! matrices in science is the solution of sparse linear systems. Here we can differentiate between
@@ -136,12 +144,17 @@
 #![deny(missing_docs)]
 
 pub extern crate nalgebra as na;
+pub mod assembly;
+pub mod bsr;
 pub mod convert;
 pub mod coo;
 pub mod csc;
 pub mod csr;
 pub mod factorization;
+pub mod io;
+pub mod iterative;
 pub mod ops;
+pub mod ordering;
 pub mod pattern;
 
 pub(crate) mod cs;
@@ -156,6 +169,7 @@ use num_traits::Zero;
 use std::error::Error;
 use std::fmt;
 
+pub use self::bsr::BsrMatrix;
 pub use self::coo::CooMatrix;
 pub use self::csc::CscMatrix;
 pub use self::csr::CsrMatrix;
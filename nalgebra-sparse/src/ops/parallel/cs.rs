@@ -0,0 +1,214 @@
+use crate::cs::{convert_counts_to_offsets, CsMatrix};
+use crate::ops::serial::cs::{spadd_cs_lane_prealloc, spmm_cs_dense_entry, spmm_cs_lane_prealloc};
+use crate::ops::serial::OperationError;
+use crate::ops::Op;
+use crate::pattern::SparsityPattern;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+
+/// Helper functionality for implementing parallel CSR/CSC SPMM.
+///
+/// See the serial `spmm_cs_prealloc` for the conventions. The lanes of `C` are computed in
+/// parallel.
+pub fn spmm_cs_prealloc<T>(
+    beta: T,
+    c: &mut CsMatrix<T>,
+    alpha: T,
+    a: &CsMatrix<T>,
+    b: &CsMatrix<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let c_lanes: Vec<_> = c.lane_iter_mut().collect();
+    c_lanes
+        .into_par_iter()
+        .enumerate()
+        .try_for_each(|(i, c_lane_i)| {
+            let a_lane_i = a.get_lane(i).unwrap();
+            spmm_cs_lane_prealloc(
+                beta.inlined_clone(),
+                c_lane_i,
+                alpha.inlined_clone(),
+                a_lane_i,
+                b,
+            )
+        })
+}
+
+/// Helper functionality for implementing parallel CSR/CSC SPADD.
+pub fn spadd_cs_prealloc<T>(
+    beta: T,
+    c: &mut CsMatrix<T>,
+    alpha: T,
+    a: Op<&CsMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let a_transpose;
+    let a = match a {
+        Op::NoOp(a) => a,
+        Op::Transpose(a) => {
+            a_transpose = transpose_cs(a);
+            &a_transpose
+        }
+    };
+
+    let c_lanes: Vec<_> = c.lane_iter_mut().collect();
+    c_lanes
+        .into_par_iter()
+        .enumerate()
+        .try_for_each(|(i, c_lane_i)| {
+            let a_lane_i = a.get_lane(i).unwrap();
+            spadd_cs_lane_prealloc(
+                beta.inlined_clone(),
+                c_lane_i,
+                alpha.inlined_clone(),
+                a_lane_i,
+            )
+        })
+}
+
+/// Helper functionality for implementing parallel CSR/CSC SPMM with a dense matrix.
+///
+/// The implementation essentially assumes that `a` is a CSR matrix. To use it with CSC matrices,
+/// the transposed operation must be specified for the CSC matrix. Each column of `C` is computed
+/// with its rows in parallel.
+pub fn spmm_cs_dense<T>(
+    beta: T,
+    mut c: DMatrixSliceMut<T>,
+    alpha: T,
+    a: Op<&CsMatrix<T>>,
+    b: Op<DMatrixSlice<T>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    match a {
+        Op::NoOp(a) => {
+            for j in 0..c.ncols() {
+                let mut c_col_j = c.column_mut(j);
+                c_col_j
+                    .as_mut_slice()
+                    .par_iter_mut()
+                    .enumerate()
+                    .for_each(|(i, c_ij)| {
+                        let a_row_i = a.get_lane(i).unwrap();
+                        spmm_cs_dense_entry(
+                            beta.inlined_clone(),
+                            c_ij,
+                            alpha.inlined_clone(),
+                            &a_row_i,
+                            &b,
+                            j,
+                        );
+                    });
+            }
+        }
+        Op::Transpose(a) => {
+            // The serial implementation scatters the rows of A into the rows of C. With the
+            // transpose of A at hand, every entry of C can instead be computed independently,
+            // accumulating the contributions in the same order.
+            let a_t = transpose_cs(a);
+            for j in 0..c.ncols() {
+                let mut c_col_j = c.column_mut(j);
+                c_col_j
+                    .as_mut_slice()
+                    .par_iter_mut()
+                    .enumerate()
+                    .for_each(|(i, c_ij)| {
+                        let a_t_row_i = a_t.get_lane(i).unwrap();
+                        *c_ij *= beta.inlined_clone();
+                        for (&k, a_ki) in a_t_row_i.minor_indices().iter().zip(a_t_row_i.values()) {
+                            let gamma_ki = alpha.inlined_clone() * a_ki.inlined_clone();
+                            let b_kj = match b {
+                                Op::NoOp(ref b) => b.index((k, j)),
+                                Op::Transpose(ref b) => b.index((j, k)),
+                            };
+                            *c_ij += gamma_ki * b_kj.inlined_clone();
+                        }
+                    });
+            }
+        }
+    }
+}
+
+/// Computes the transpose of a CS matrix in parallel, switching the roles of the major and minor
+/// dimensions.
+///
+/// Blocks of consecutive lanes are transposed independently with the serial algorithm, after
+/// which lane `j` of the result is the concatenation of lane `j` of each transposed block. The
+/// result does not depend on the number of blocks.
+pub fn transpose_cs<T>(a: &CsMatrix<T>) -> CsMatrix<T>
+where
+    T: Scalar + Send + Sync,
+{
+    let (major_dim, minor_dim) = (a.pattern().major_dim(), a.pattern().minor_dim());
+    let (offsets, indices, values) = a.cs_data();
+
+    let block_size = major_dim / rayon::current_num_threads() + 1;
+    let block_starts: Vec<_> = (0..major_dim).step_by(block_size).collect();
+    let blocks: Vec<_> = block_starts
+        .into_par_iter()
+        .map(|start| {
+            let end = major_dim.min(start + block_size);
+            let block_offsets: Vec<_> = offsets[start..=end]
+                .iter()
+                .map(|offset| offset - offsets[start])
+                .collect();
+            let range = offsets[start]..offsets[end];
+            let (t_offsets, t_indices, t_values) = crate::cs::transpose_cs(
+                end - start,
+                minor_dim,
+                &block_offsets,
+                &indices[range.clone()],
+                &values[range],
+            );
+            (start, t_offsets, t_indices, t_values)
+        })
+        .collect();
+
+    let mut target_offsets: Vec<usize> = (0..minor_dim)
+        .into_par_iter()
+        .map(|j| {
+            blocks
+                .iter()
+                .map(|(_, t_offsets, _, _)| t_offsets[j + 1] - t_offsets[j])
+                .sum()
+        })
+        .collect();
+    convert_counts_to_offsets(&mut target_offsets);
+    target_offsets.push(values.len());
+
+    let target_indices: Vec<usize> = (0..minor_dim)
+        .into_par_iter()
+        .flat_map_iter(|j| {
+            blocks
+                .iter()
+                .flat_map(move |(start, t_offsets, t_indices, _)| {
+                    t_indices[t_offsets[j]..t_offsets[j + 1]]
+                        .iter()
+                        .map(move |i| start + i)
+                })
+        })
+        .collect();
+    let target_values: Vec<T> = (0..minor_dim)
+        .into_par_iter()
+        .flat_map_iter(|j| {
+            blocks.iter().flat_map(move |(_, t_offsets, _, t_values)| {
+                t_values[t_offsets[j]..t_offsets[j + 1]].iter().cloned()
+            })
+        })
+        .collect();
+
+    // TODO: Skip checks
+    let pattern = SparsityPattern::try_from_offsets_and_indices(
+        minor_dim,
+        major_dim,
+        target_offsets,
+        target_indices,
+    )
+    .expect("Internal error: Transpose should never fail.");
+    CsMatrix::from_pattern_and_values(pattern, target_values)
+}
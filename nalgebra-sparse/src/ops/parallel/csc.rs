@@ -0,0 +1,123 @@
+use crate::csc::CscMatrix;
+use crate::ops::parallel::cs::{spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc, transpose_cs};
+use crate::ops::serial::OperationError;
+use crate::ops::Op;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use num_traits::{One, Zero};
+use std::borrow::Cow;
+
+/// Parallel sparse-dense matrix-matrix multiplication `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csc_dense<'a, T>(
+    beta: T,
+    c: impl Into<DMatrixSliceMut<'a, T>>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<impl Into<DMatrixSlice<'a, T>>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let b = b.convert();
+    spmm_csc_dense_(beta, c.into(), alpha, a, b)
+}
+
+fn spmm_csc_dense_<T>(
+    beta: T,
+    c: DMatrixSliceMut<T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<DMatrixSlice<T>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+    // Need to interpret matrix as transposed since the spmm_cs_dense function assumes CSR layout
+    let a = a.transposed().map_same_op(|a| &a.cs);
+    spmm_cs_dense(beta, c, alpha, a, b)
+}
+
+/// Parallel sparse matrix addition `C <- beta * C + alpha * op(A)`.
+///
+/// # Errors
+///
+/// If the pattern of `c` does not accommodate all the non-zero entries in `a`, an error is
+/// returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spadd_csc_prealloc<T>(
+    beta: T,
+    c: &mut CscMatrix<T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spadd_dims!(c, a);
+    spadd_cs_prealloc(beta, &mut c.cs, alpha, a.map_same_op(|a| &a.cs))
+}
+
+/// Parallel sparse-sparse matrix multiplication, `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// # Errors
+///
+/// If the pattern of `C` is not able to hold the result of the operation, an error is returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csc_prealloc<T>(
+    beta: T,
+    c: &mut CscMatrix<T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<&CscMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+
+    use Op::{NoOp, Transpose};
+
+    match (a, b) {
+        (NoOp(a), NoOp(b)) => {
+            // Note: We have to reverse the order for CSC matrices
+            spmm_cs_prealloc(beta, &mut c.cs, alpha, &b.cs, &a.cs)
+        }
+        _ => {
+            // As in the serial implementation, we handle transposition by explicitly
+            // precomputing transposed matrices
+            let (a, b) = {
+                use Cow::*;
+                match (a, b) {
+                    (NoOp(_), NoOp(_)) => unreachable!(),
+                    (Transpose(a), NoOp(b)) => (Owned(transpose_csc(a)), Borrowed(b)),
+                    (NoOp(a), Transpose(b)) => (Borrowed(a), Owned(transpose_csc(b))),
+                    (Transpose(a), Transpose(b)) => {
+                        (Owned(transpose_csc(a)), Owned(transpose_csc(b)))
+                    }
+                }
+            };
+
+            spmm_csc_prealloc(beta, c, alpha, NoOp(a.as_ref()), NoOp(b.as_ref()))
+        }
+    }
+}
+
+/// Parallel transposition of a CSC matrix.
+///
+/// The result is the same as the result of [`CscMatrix::transpose`].
+pub fn transpose_csc<T>(a: &CscMatrix<T>) -> CscMatrix<T>
+where
+    T: Scalar + Send + Sync,
+{
+    CscMatrix {
+        cs: transpose_cs(&a.cs),
+    }
+}
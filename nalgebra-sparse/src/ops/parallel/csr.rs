@@ -0,0 +1,118 @@
+use crate::csr::CsrMatrix;
+use crate::ops::parallel::cs::{spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc, transpose_cs};
+use crate::ops::serial::OperationError;
+use crate::ops::Op;
+use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
+use num_traits::{One, Zero};
+use std::borrow::Cow;
+
+/// Parallel sparse-dense matrix-matrix multiplication `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csr_dense<'a, T>(
+    beta: T,
+    c: impl Into<DMatrixSliceMut<'a, T>>,
+    alpha: T,
+    a: Op<&CsrMatrix<T>>,
+    b: Op<impl Into<DMatrixSlice<'a, T>>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    let b = b.convert();
+    spmm_csr_dense_(beta, c.into(), alpha, a, b)
+}
+
+fn spmm_csr_dense_<T>(
+    beta: T,
+    c: DMatrixSliceMut<T>,
+    alpha: T,
+    a: Op<&CsrMatrix<T>>,
+    b: Op<DMatrixSlice<T>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+    spmm_cs_dense(beta, c, alpha, a.map_same_op(|a| &a.cs), b)
+}
+
+/// Parallel sparse matrix addition `C <- beta * C + alpha * op(A)`.
+///
+/// # Errors
+///
+/// If the pattern of `c` does not accommodate all the non-zero entries in `a`, an error is
+/// returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spadd_csr_prealloc<T>(
+    beta: T,
+    c: &mut CsrMatrix<T>,
+    alpha: T,
+    a: Op<&CsrMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spadd_dims!(c, a);
+    spadd_cs_prealloc(beta, &mut c.cs, alpha, a.map_same_op(|a| &a.cs))
+}
+
+/// Parallel sparse-sparse matrix multiplication, `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// # Errors
+///
+/// If the pattern of `C` is not able to hold the result of the operation, an error is returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csr_prealloc<T>(
+    beta: T,
+    c: &mut CsrMatrix<T>,
+    alpha: T,
+    a: Op<&CsrMatrix<T>>,
+    b: Op<&CsrMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+
+    use Op::{NoOp, Transpose};
+
+    match (a, b) {
+        (NoOp(a), NoOp(b)) => spmm_cs_prealloc(beta, &mut c.cs, alpha, &a.cs, &b.cs),
+        _ => {
+            // As in the serial implementation, we handle transposition by explicitly
+            // precomputing transposed matrices
+            let (a, b) = {
+                use Cow::*;
+                match (a, b) {
+                    (NoOp(_), NoOp(_)) => unreachable!(),
+                    (Transpose(a), NoOp(b)) => (Owned(transpose_csr(a)), Borrowed(b)),
+                    (NoOp(a), Transpose(b)) => (Borrowed(a), Owned(transpose_csr(b))),
+                    (Transpose(a), Transpose(b)) => {
+                        (Owned(transpose_csr(a)), Owned(transpose_csr(b)))
+                    }
+                }
+            };
+
+            spmm_csr_prealloc(beta, c, alpha, NoOp(a.as_ref()), NoOp(b.as_ref()))
+        }
+    }
+}
+
+/// Parallel transposition of a CSR matrix.
+///
+/// The result is the same as the result of [`CsrMatrix::transpose`].
+pub fn transpose_csr<T>(a: &CsrMatrix<T>) -> CsrMatrix<T>
+where
+    T: Scalar + Send + Sync,
+{
+    CsrMatrix {
+        cs: transpose_cs(&a.cs),
+    }
+}
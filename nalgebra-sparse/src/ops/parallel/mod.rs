@@ -0,0 +1,23 @@
+//! Parallel sparse matrix arithmetic routines.
+//!
+//! This module requires the `rayon` feature. It provides parallel counterparts of the
+//! routines in [`serial`](crate::ops::serial), with the same arguments, the same semantics and
+//! the same error type ([`OperationError`](crate::ops::serial::OperationError)). The work is
+//! distributed over the threads of the current [rayon](https://crates.io/crates/rayon) thread
+//! pool, so the number of threads can be controlled with `rayon::ThreadPool::install`.
+//!
+//! Every entry of the output is computed by a single thread, which performs the same
+//! arithmetic operations in the same order as the serial routine. The results are therefore
+//! deterministic: they do not depend on the number of threads, and they are identical to the
+//! results of the serial backend, also for floating-point types.
+//!
+//! Operations that involve a transposed sparse matrix first compute the transpose explicitly
+//! (in parallel), which requires additional memory. The serial routines for sparse-sparse
+//! products do the same.
+
+mod cs;
+mod csc;
+mod csr;
+
+pub use csc::*;
+pub use csr::*;
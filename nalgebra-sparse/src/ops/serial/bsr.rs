@@ -0,0 +1,75 @@
+use crate::bsr::BsrMatrix;
+use crate::ops::Op;
+use nalgebra::{
+    ClosedAdd, ClosedMul, DMatrix, DMatrixSlice, DMatrixSliceMut, Dim, Dynamic, Scalar,
+};
+use num_traits::{One, Zero};
+
+/// Sparse-dense matrix-matrix multiplication `C <- beta * C + alpha * op(A) * op(B)` with a
+/// BSR matrix `A`.
+///
+/// Each block of `A` is multiplied with the corresponding rows of `op(B)` by a dense kernel.
+/// If `B` is transposed, `op(B)` is first computed explicitly.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_bsr_dense<'a, T, R, C>(
+    beta: T,
+    c: impl Into<DMatrixSliceMut<'a, T>>,
+    alpha: T,
+    a: Op<&BsrMatrix<T, R, C>>,
+    b: Op<impl Into<DMatrixSlice<'a, T>>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+    R: Dim,
+    C: Dim,
+{
+    let b = b.convert();
+    spmm_bsr_dense_(beta, c.into(), alpha, a, b)
+}
+
+fn spmm_bsr_dense_<T, R, C>(
+    beta: T,
+    mut c: DMatrixSliceMut<T>,
+    alpha: T,
+    a: Op<&BsrMatrix<T, R, C>>,
+    b: Op<DMatrixSlice<T>>,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+    R: Dim,
+    C: Dim,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+
+    let b_transpose: DMatrix<T>;
+    let b = match b {
+        Op::NoOp(b) => b,
+        Op::Transpose(b) => {
+            b_transpose = b.transpose();
+            DMatrixSlice::from(&b_transpose)
+        }
+    };
+
+    c *= beta;
+    let ncols = Dynamic::new(c.ncols());
+    match a {
+        Op::NoOp(a) => {
+            let (r, c_block) = a.block_shape_generic();
+            for (block_row, block_col, block) in a.block_iter() {
+                let b_rows = b.generic_slice((block_col * c_block.value(), 0), (c_block, ncols));
+                let mut c_rows = c.generic_slice_mut((block_row * r.value(), 0), (r, ncols));
+                c_rows.gemm(alpha.inlined_clone(), &block, &b_rows, T::one());
+            }
+        }
+        Op::Transpose(a) => {
+            let (r, c_block) = a.block_shape_generic();
+            for (block_row, block_col, block) in a.block_iter() {
+                let b_rows = b.generic_slice((block_row * r.value(), 0), (r, ncols));
+                let mut c_rows =
+                    c.generic_slice_mut((block_col * c_block.value(), 0), (c_block, ncols));
+                c_rows.gemm_tr(alpha.inlined_clone(), &block, &b_rows, T::one());
+            }
+        }
+    }
+}
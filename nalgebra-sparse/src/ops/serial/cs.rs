@@ -1,4 +1,4 @@
-use crate::cs::CsMatrix;
+use crate::cs::{CsLane, CsLaneMut, CsMatrix};
 use crate::ops::serial::{OperationError, OperationErrorKind};
 use crate::ops::Op;
 use crate::SparseEntryMut;
@@ -32,27 +32,53 @@ where
 {
     for i in 0..c.pattern().major_dim() {
         let a_lane_i = a.get_lane(i).unwrap();
-        let mut c_lane_i = c.get_lane_mut(i).unwrap();
-        for c_ij in c_lane_i.values_mut() {
-            *c_ij = beta.inlined_clone() * c_ij.inlined_clone();
-        }
+        let c_lane_i = c.get_lane_mut(i).unwrap();
+        spmm_cs_lane_prealloc(
+            beta.inlined_clone(),
+            c_lane_i,
+            alpha.inlined_clone(),
+            a_lane_i,
+            b,
+        )?;
+    }
 
-        for (&k, a_ik) in a_lane_i.minor_indices().iter().zip(a_lane_i.values()) {
-            let b_lane_k = b.get_lane(k).unwrap();
-            let (mut c_lane_i_cols, mut c_lane_i_values) = c_lane_i.indices_and_values_mut();
-            let alpha_aik = alpha.inlined_clone() * a_ik.inlined_clone();
-            for (j, b_kj) in b_lane_k.minor_indices().iter().zip(b_lane_k.values()) {
-                // Determine the location in C to append the value
-                let (c_local_idx, _) = c_lane_i_cols
-                    .iter()
-                    .enumerate()
-                    .find(|(_, c_col)| *c_col == j)
-                    .ok_or_else(spmm_cs_unexpected_entry)?;
-
-                c_lane_i_values[c_local_idx] += alpha_aik.inlined_clone() * b_kj.inlined_clone();
-                c_lane_i_cols = &c_lane_i_cols[c_local_idx..];
-                c_lane_i_values = &mut c_lane_i_values[c_local_idx..];
-            }
+    Ok(())
+}
+
+/// Computes a single lane of `C <- beta * C + alpha * A * B`, given the corresponding lanes of
+/// `C` and `A`.
+///
+/// Lanes are computed independently of each other, which is what allows the parallel backend
+/// to share this kernel with the serial implementation.
+pub fn spmm_cs_lane_prealloc<T>(
+    beta: T,
+    mut c_lane_i: CsLaneMut<T>,
+    alpha: T,
+    a_lane_i: CsLane<T>,
+    b: &CsMatrix<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    for c_ij in c_lane_i.values_mut() {
+        *c_ij = beta.inlined_clone() * c_ij.inlined_clone();
+    }
+
+    for (&k, a_ik) in a_lane_i.minor_indices().iter().zip(a_lane_i.values()) {
+        let b_lane_k = b.get_lane(k).unwrap();
+        let (mut c_lane_i_cols, mut c_lane_i_values) = c_lane_i.indices_and_values_mut();
+        let alpha_aik = alpha.inlined_clone() * a_ik.inlined_clone();
+        for (j, b_kj) in b_lane_k.minor_indices().iter().zip(b_lane_k.values()) {
+            // Determine the location in C to append the value
+            let (c_local_idx, _) = c_lane_i_cols
+                .iter()
+                .enumerate()
+                .find(|(_, c_col)| *c_col == j)
+                .ok_or_else(spmm_cs_unexpected_entry)?;
+
+            c_lane_i_values[c_local_idx] += alpha_aik.inlined_clone() * b_kj.inlined_clone();
+            c_lane_i_cols = &c_lane_i_cols[c_local_idx..];
+            c_lane_i_values = &mut c_lane_i_values[c_local_idx..];
         }
     }
 
@@ -78,29 +104,13 @@ where
 {
     match a {
         Op::NoOp(a) => {
-            for (mut c_lane_i, a_lane_i) in c.lane_iter_mut().zip(a.lane_iter()) {
-                if beta != T::one() {
-                    for c_ij in c_lane_i.values_mut() {
-                        *c_ij *= beta.inlined_clone();
-                    }
-                }
-
-                let (mut c_minors, mut c_vals) = c_lane_i.indices_and_values_mut();
-                let (a_minors, a_vals) = (a_lane_i.minor_indices(), a_lane_i.values());
-
-                for (a_col, a_val) in a_minors.iter().zip(a_vals) {
-                    // TODO: Use exponential search instead of linear search.
-                    // If C has substantially more entries in the row than A, then a line search
-                    // will needlessly visit many entries in C.
-                    let (c_idx, _) = c_minors
-                        .iter()
-                        .enumerate()
-                        .find(|(_, c_col)| *c_col == a_col)
-                        .ok_or_else(spadd_cs_unexpected_entry)?;
-                    c_vals[c_idx] += alpha.inlined_clone() * a_val.inlined_clone();
-                    c_minors = &c_minors[c_idx..];
-                    c_vals = &mut c_vals[c_idx..];
-                }
+            for (c_lane_i, a_lane_i) in c.lane_iter_mut().zip(a.lane_iter()) {
+                spadd_cs_lane_prealloc(
+                    beta.inlined_clone(),
+                    c_lane_i,
+                    alpha.inlined_clone(),
+                    a_lane_i,
+                )?;
             }
         }
         Op::Transpose(a) => {
@@ -125,6 +135,43 @@ where
     Ok(())
 }
 
+/// Computes a single lane of `C <- beta * C + alpha * A`, given the corresponding lanes of `C`
+/// and `A`.
+pub fn spadd_cs_lane_prealloc<T>(
+    beta: T,
+    mut c_lane_i: CsLaneMut<T>,
+    alpha: T,
+    a_lane_i: CsLane<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    if beta != T::one() {
+        for c_ij in c_lane_i.values_mut() {
+            *c_ij *= beta.inlined_clone();
+        }
+    }
+
+    let (mut c_minors, mut c_vals) = c_lane_i.indices_and_values_mut();
+    let (a_minors, a_vals) = (a_lane_i.minor_indices(), a_lane_i.values());
+
+    for (a_col, a_val) in a_minors.iter().zip(a_vals) {
+        // TODO: Use exponential search instead of linear search.
+        // If C has substantially more entries in the row than A, then a line search
+        // will needlessly visit many entries in C.
+        let (c_idx, _) = c_minors
+            .iter()
+            .enumerate()
+            .find(|(_, c_col)| *c_col == a_col)
+            .ok_or_else(spadd_cs_unexpected_entry)?;
+        c_vals[c_idx] += alpha.inlined_clone() * a_val.inlined_clone();
+        c_minors = &c_minors[c_idx..];
+        c_vals = &mut c_vals[c_idx..];
+    }
+
+    Ok(())
+}
+
 /// Helper functionality for implementing CSR/CSC SPMM.
 ///
 /// The implementation essentially assumes that `a` is a CSR matrix. To use it with CSC matrices,
@@ -143,16 +190,14 @@ pub fn spmm_cs_dense<T>(
             for j in 0..c.ncols() {
                 let mut c_col_j = c.column_mut(j);
                 for (c_ij, a_row_i) in c_col_j.iter_mut().zip(a.lane_iter()) {
-                    let mut dot_ij = T::zero();
-                    for (&k, a_ik) in a_row_i.minor_indices().iter().zip(a_row_i.values()) {
-                        let b_contrib = match b {
-                            Op::NoOp(ref b) => b.index((k, j)),
-                            Op::Transpose(ref b) => b.index((j, k)),
-                        };
-                        dot_ij += a_ik.inlined_clone() * b_contrib.inlined_clone();
-                    }
-                    *c_ij = beta.inlined_clone() * c_ij.inlined_clone()
-                        + alpha.inlined_clone() * dot_ij;
+                    spmm_cs_dense_entry(
+                        beta.inlined_clone(),
+                        c_ij,
+                        alpha.inlined_clone(),
+                        &a_row_i,
+                        &b,
+                        j,
+                    );
                 }
             }
         }
@@ -184,3 +229,26 @@ pub fn spmm_cs_dense<T>(
         }
     }
 }
+
+/// Computes the entry `c_ij <- beta * c_ij + alpha * dot(a_i, op(B)_j)`, where `a_i` is the `i`-th
+/// row of a CSR matrix `A` and `op(B)_j` is the `j`-th column of `op(B)`.
+pub fn spmm_cs_dense_entry<T>(
+    beta: T,
+    c_ij: &mut T,
+    alpha: T,
+    a_row_i: &CsLane<T>,
+    b: &Op<DMatrixSlice<T>>,
+    j: usize,
+) where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    let mut dot_ij = T::zero();
+    for (&k, a_ik) in a_row_i.minor_indices().iter().zip(a_row_i.values()) {
+        let b_contrib = match b {
+            Op::NoOp(ref b) => b.index((k, j)),
+            Op::Transpose(ref b) => b.index((j, k)),
+        };
+        dot_ij += a_ik.inlined_clone() * b_contrib.inlined_clone();
+    }
+    *c_ij = beta * c_ij.inlined_clone() + alpha * dot_ij;
+}
@@ -0,0 +1,126 @@
+use super::{symmetric_adjacency, Permutation};
+use crate::pattern::SparsityPattern;
+use std::collections::BTreeSet;
+
+/// Computes an approximate minimum degree ordering of a square sparsity pattern.
+///
+/// The rows of `A + A^T` are eliminated greedily by increasing degree, i.e. by increasing number
+/// of off-diagonal non-zeros in the remaining part of the matrix. The elimination is simulated
+/// on a quotient graph, and the exact degrees are replaced by the upper bounds of Amestoy, Davis
+/// and Duff, which are much cheaper to update. Elements whose variables are all adjacent to the
+/// new pivot are absorbed. Unlike the reference AMD implementation, indistinguishable rows are not
+/// merged into supervariables, and dense rows receive no special treatment.
+///
+/// The resulting permutation is well-suited to sparse Cholesky and LU factorizations.
+///
+/// # Panics
+///
+/// Panics if the sparsity pattern is not square.
+pub fn approximate_minimum_degree(pattern: &SparsityPattern) -> Permutation {
+    let mut variables = symmetric_adjacency(pattern);
+    let n = variables.len();
+
+    // `elements[e]` holds the variables of the element created by eliminating `e`, and
+    // `adjacent_elements[i]` holds the (non-absorbed) elements that contain the variable `i`.
+    let mut elements: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut adjacent_elements: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut eliminated = vec![false; n];
+    let mut absorbed = vec![false; n];
+
+    let mut degrees: Vec<_> = variables.iter().map(Vec::len).collect();
+    let mut queue: BTreeSet<_> = degrees.iter().copied().zip(0..n).collect();
+
+    // Workspaces
+    let mut in_pivot_element = vec![false; n];
+    let mut external_degrees = vec![usize::MAX; n];
+    let mut touched_elements = Vec::new();
+
+    let mut order = Vec::with_capacity(n);
+
+    while let Some(&(degree, p)) = queue.iter().next() {
+        let _ = queue.remove(&(degree, p));
+        order.push(p);
+        eliminated[p] = true;
+
+        // Form the new element `L_p`, the union of the variables adjacent to `p` and of the
+        // elements containing `p`, which are absorbed into it.
+        let mut pivot_element = Vec::new();
+        for &i in &variables[p] {
+            if !eliminated[i] && !in_pivot_element[i] {
+                in_pivot_element[i] = true;
+                pivot_element.push(i);
+            }
+        }
+        for e in std::mem::take(&mut adjacent_elements[p]) {
+            for &i in &elements[e] {
+                if !eliminated[i] && !in_pivot_element[i] {
+                    in_pivot_element[i] = true;
+                    pivot_element.push(i);
+                }
+            }
+            absorbed[e] = true;
+            elements[e] = Vec::new();
+        }
+        variables[p] = Vec::new();
+
+        // Update the quotient graph: the variables of `L_p` are now connected through the
+        // element `p`, so that they can be removed from each others' adjacency lists.
+        for &i in &pivot_element {
+            adjacent_elements[i].retain(|&e| !absorbed[e]);
+            adjacent_elements[i].push(p);
+            variables[i].retain(|&j| !eliminated[j] && !in_pivot_element[j]);
+        }
+
+        // Compute `|L_e \ L_p|` for every element `e` adjacent to a variable of `L_p`.
+        for &i in &pivot_element {
+            for &e in &adjacent_elements[i] {
+                if e != p {
+                    if external_degrees[e] == usize::MAX {
+                        external_degrees[e] = elements[e].len();
+                        touched_elements.push(e);
+                    }
+                    external_degrees[e] -= 1;
+                }
+            }
+        }
+
+        // Elements that are subsets of `L_p` are absorbed into it.
+        for &e in &touched_elements {
+            if external_degrees[e] == 0 {
+                absorbed[e] = true;
+                for &i in &elements[e] {
+                    adjacent_elements[i].retain(|&f| f != e);
+                }
+                elements[e] = Vec::new();
+            }
+        }
+
+        // Update the approximate degrees of the variables of `L_p`.
+        let remaining = n - order.len();
+        for &i in &pivot_element {
+            let external_degree: usize = adjacent_elements[i]
+                .iter()
+                .filter(|&&e| e != p)
+                .map(|&e| external_degrees[e])
+                .sum();
+            let pivot_degree = pivot_element.len() - 1;
+            let degree = (variables[i].len() + pivot_degree + external_degree)
+                .min(degrees[i] + pivot_degree)
+                .min(remaining - 1);
+
+            let _ = queue.remove(&(degrees[i], i));
+            degrees[i] = degree;
+            let _ = queue.insert((degree, i));
+        }
+
+        for e in touched_elements.drain(..) {
+            external_degrees[e] = usize::MAX;
+        }
+        for &i in &pivot_element {
+            in_pivot_element[i] = false;
+        }
+        elements[p] = pivot_element;
+    }
+
+    Permutation::try_from_indices(order).expect("Internal error: ordering must be a permutation")
+}
@@ -0,0 +1,106 @@
+//! Fill-reducing and bandwidth-reducing orderings of sparse matrices.
+//!
+//! The amount of fill-in produced by a sparse factorization strongly depends on the order in
+//! which the rows and columns of the matrix are eliminated. The orderings provided here compute
+//! a [`Permutation`] `P` from the sparsity pattern of a square matrix `A`, such that factoring
+//! the symmetrically permuted matrix `P A P^T` is cheaper than factoring `A` itself:
+//!
+//! - [`approximate_minimum_degree`] greedily eliminates the rows with the smallest (approximate)
+//!   degree, which typically produces sparse factors.
+//! - [`reverse_cuthill_mckee`] reduces the bandwidth and profile of the matrix.
+//!
+//! Both orderings only depend on the pattern of `A + A^T`, so that the symmetry of the pattern
+//! is not required. The [`CscCholesky`](crate::factorization::CscCholesky) factorization can
+//! apply an ordering automatically, see
+//! [`CscCholesky::factor_with_ordering`](crate::factorization::CscCholesky::factor_with_ordering).
+//!
+//! # Example
+//!
+//! ```rust
+//! use nalgebra_sparse::csc::CscMatrix;
+//! use nalgebra_sparse::factorization::CscSymbolicCholesky;
+//! use nalgebra_sparse::ordering::approximate_minimum_degree;
+//! use nalgebra::DMatrix;
+//!
+//! // An "arrow" matrix, whose first row and column are dense.
+//! let dense = DMatrix::from_fn(5, 5, |i, j| if i == j || i == 0 || j == 0 { 1.0 } else { 0.0 });
+//! let matrix = CscMatrix::from(&dense);
+//!
+//! // Eliminating the first row fills the whole Cholesky factor.
+//! let natural = CscSymbolicCholesky::factor(matrix.pattern().clone());
+//! assert_eq!(natural.l_pattern().nnz(), 15);
+//!
+//! // The minimum degree ordering postpones its elimination and does not produce any fill-in.
+//! let permutation = approximate_minimum_degree(matrix.pattern());
+//! let permuted_pattern = permutation.permute_symmetric_pattern(matrix.pattern());
+//! let reordered = CscSymbolicCholesky::factor(permuted_pattern);
+//! assert_eq!(reordered.l_pattern().nnz(), 9);
+//! ```
+mod amd;
+mod permutation;
+mod rcm;
+
+pub use self::amd::approximate_minimum_degree;
+pub use self::permutation::Permutation;
+pub use self::rcm::reverse_cuthill_mckee;
+
+use crate::ops::serial::spadd_pattern;
+use crate::pattern::SparsityPattern;
+
+/// The ordering applied by a factorization before factoring a matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderingMethod {
+    /// The matrix is factored in its given order.
+    Natural,
+    /// The [`approximate_minimum_degree`] ordering.
+    ApproximateMinimumDegree,
+    /// The [`reverse_cuthill_mckee`] ordering.
+    ReverseCuthillMcKee,
+    /// A user-provided permutation.
+    Custom(Permutation),
+}
+
+impl OrderingMethod {
+    /// Computes the permutation associated with this method for the given sparsity pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sparsity pattern is not square, or if the dimension of a custom
+    /// permutation does not match the dimension of the pattern.
+    pub fn compute(&self, pattern: &SparsityPattern) -> Permutation {
+        match self {
+            OrderingMethod::Natural => Permutation::identity(pattern.major_dim()),
+            OrderingMethod::ApproximateMinimumDegree => approximate_minimum_degree(pattern),
+            OrderingMethod::ReverseCuthillMcKee => reverse_cuthill_mckee(pattern),
+            OrderingMethod::Custom(permutation) => {
+                assert_eq!(
+                    permutation.len(),
+                    pattern.major_dim(),
+                    "The dimensions of the permutation and the pattern must be the same."
+                );
+                permutation.clone()
+            }
+        }
+    }
+}
+
+/// The adjacency lists of the graph of `A + A^T`, excluding the diagonal.
+fn symmetric_adjacency(pattern: &SparsityPattern) -> Vec<Vec<usize>> {
+    assert_eq!(
+        pattern.major_dim(),
+        pattern.minor_dim(),
+        "Major and minor dimensions must be the same (square matrix)."
+    );
+    let symmetric = spadd_pattern(pattern, &pattern.transpose());
+
+    (0..symmetric.major_dim())
+        .map(|i| {
+            symmetric
+                .lane(i)
+                .iter()
+                .copied()
+                .filter(|&j| j != i)
+                .collect()
+        })
+        .collect()
+}
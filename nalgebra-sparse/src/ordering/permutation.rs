@@ -0,0 +1,201 @@
+use crate::csc::CscMatrix;
+use crate::pattern::SparsityPattern;
+use crate::{SparseFormatError, SparseFormatErrorKind};
+use nalgebra::{DMatrixSliceMut, Scalar};
+
+/// A permutation `P` of the indices `0..n`.
+///
+/// The permutation is represented by the array `indices`, such that row `k` of `P A` is row
+/// `indices[k]` of `A`. In other words, `indices[k]` is the index that is moved to position `k`.
+/// The inverse permutation `P^T` is stored as well, so that both can be applied efficiently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    indices: Vec<usize>,
+    inverse_indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// The identity permutation of `0..n`.
+    pub fn identity(n: usize) -> Self {
+        Self {
+            indices: (0..n).collect(),
+            inverse_indices: (0..n).collect(),
+        }
+    }
+
+    /// Try to construct a permutation from the array of indices moved to each position.
+    ///
+    /// An error is returned if an index is out of bounds, or if it appears more than once.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::ordering::Permutation;
+    /// let permutation = Permutation::try_from_indices(vec![2, 0, 1]).unwrap();
+    /// assert_eq!(permutation.inverse_indices(), &[1, 2, 0]);
+    /// assert!(Permutation::try_from_indices(vec![2, 0, 2]).is_err());
+    /// ```
+    pub fn try_from_indices(indices: Vec<usize>) -> Result<Self, SparseFormatError> {
+        let n = indices.len();
+        let mut inverse_indices = vec![usize::MAX; n];
+
+        for (k, &i) in indices.iter().enumerate() {
+            if i >= n {
+                return Err(SparseFormatError::from_kind_and_msg(
+                    SparseFormatErrorKind::IndexOutOfBounds,
+                    "Permutation index out of bounds.",
+                ));
+            } else if inverse_indices[i] != usize::MAX {
+                return Err(SparseFormatError::from_kind_and_msg(
+                    SparseFormatErrorKind::DuplicateEntry,
+                    "Permutation indices must be unique.",
+                ));
+            }
+            inverse_indices[i] = k;
+        }
+
+        Ok(Self {
+            indices,
+            inverse_indices,
+        })
+    }
+
+    /// The number of permuted indices.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether the permutation acts on an empty set of indices.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// The indices moved to each position, i.e. row `k` of `P A` is row `indices[k]` of `A`.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// The position of each index, i.e. the indices of the inverse permutation `P^T`.
+    pub fn inverse_indices(&self) -> &[usize] {
+        &self.inverse_indices
+    }
+
+    /// The inverse permutation `P^T`.
+    pub fn inverse(&self) -> Self {
+        Self {
+            indices: self.inverse_indices.clone(),
+            inverse_indices: self.indices.clone(),
+        }
+    }
+
+    /// Computes the sparsity pattern of the symmetrically permuted matrix `P A P^T`, where `A` is
+    /// the CSR or CSC matrix associated with the given pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is not square, or if its dimension differs from the length of the
+    /// permutation.
+    pub fn permute_symmetric_pattern(&self, pattern: &SparsityPattern) -> SparsityPattern {
+        self.symmetric_permutation_map(pattern).0
+    }
+
+    /// Computes the symmetrically permuted matrix `P A P^T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or if its dimension differs from the length of the
+    /// permutation.
+    pub fn permute_symmetric_csc<T: Clone>(&self, matrix: &CscMatrix<T>) -> CscMatrix<T> {
+        let (pattern, value_indices) = self.symmetric_permutation_map(matrix.pattern());
+        let values = value_indices
+            .iter()
+            .map(|&p| matrix.values()[p].clone())
+            .collect();
+        CscMatrix::try_from_pattern_and_values(pattern, values)
+            .expect("Internal error: Permutation should never fail.")
+    }
+
+    /// Computes `P B` in place, where `B` is a dense matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` differs from the length of the permutation.
+    pub fn permute_rows_mut<'a, T: Scalar>(&self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        permute_rows_with(&self.indices, b.into())
+    }
+
+    /// Computes `P^T B` in place, where `B` is a dense matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `b` differs from the length of the permutation.
+    pub fn inverse_permute_rows_mut<'a, T: Scalar>(&self, b: impl Into<DMatrixSliceMut<'a, T>>) {
+        permute_rows_with(&self.inverse_indices, b.into())
+    }
+
+    /// Computes the pattern of `P A P^T`, together with the index in the values of `A` of each
+    /// of its non-zeros.
+    pub(crate) fn symmetric_permutation_map(
+        &self,
+        pattern: &SparsityPattern,
+    ) -> (SparsityPattern, Vec<usize>) {
+        assert_eq!(
+            pattern.major_dim(),
+            pattern.minor_dim(),
+            "Major and minor dimensions must be the same (square matrix)."
+        );
+        assert_eq!(
+            pattern.major_dim(),
+            self.len(),
+            "The dimensions of the permutation and the pattern must be the same."
+        );
+
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        let mut indices = Vec::with_capacity(pattern.nnz());
+        let mut value_indices = Vec::with_capacity(pattern.nnz());
+        let mut lane_entries = Vec::new();
+        offsets.push(0);
+
+        for &old_lane in &self.indices {
+            let offset = pattern.major_offsets()[old_lane];
+            lane_entries.clear();
+            lane_entries.extend(
+                pattern
+                    .lane(old_lane)
+                    .iter()
+                    .enumerate()
+                    .map(|(p, &i)| (self.inverse_indices[i], offset + p)),
+            );
+            lane_entries.sort_unstable();
+
+            indices.extend(lane_entries.iter().map(|&(i, _)| i));
+            value_indices.extend(lane_entries.iter().map(|&(_, p)| p));
+            offsets.push(indices.len());
+        }
+
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(self.len(), self.len(), offsets, indices)
+                .expect("Internal error: Permutation should never fail.");
+        (pattern, value_indices)
+    }
+}
+
+fn permute_rows_with<T: Scalar>(indices: &[usize], mut b: DMatrixSliceMut<T>) {
+    assert_eq!(
+        b.nrows(),
+        indices.len(),
+        "The number of rows must match the length of the permutation."
+    );
+    let mut work = Vec::with_capacity(indices.len());
+
+    for j in 0..b.ncols() {
+        let mut b_j = b.column_mut(j);
+        work.clear();
+        work.extend(indices.iter().map(|&i| b_j[i].inlined_clone()));
+
+        for (k, w) in work.drain(..).enumerate() {
+            b_j[k] = w;
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::{symmetric_adjacency, Permutation};
+use crate::pattern::SparsityPattern;
+
+/// Computes the reverse Cuthill-McKee ordering of a square sparsity pattern.
+///
+/// Each connected component of the graph of `A + A^T` is traversed breadth-first, starting from
+/// a pseudo-peripheral vertex found with the algorithm of Gibbs, Poole and Stockmeyer, and visiting
+/// the neighbors of each vertex by increasing degree. Reversing the resulting order typically
+/// reduces the bandwidth and the profile of `P A P^T`, which is well-suited to banded or
+/// skyline solvers.
+///
+/// # Panics
+///
+/// Panics if the sparsity pattern is not square.
+pub fn reverse_cuthill_mckee(pattern: &SparsityPattern) -> Permutation {
+    let adjacency = symmetric_adjacency(pattern);
+    let n = adjacency.len();
+
+    let mut order = Vec::with_capacity(n);
+    let mut visited = vec![false; n];
+    let mut levels = vec![usize::MAX; n];
+
+    // Visit the components in order of the minimum degree of their vertices.
+    let mut by_degree: Vec<_> = (0..n).collect();
+    by_degree.sort_by_key(|&i| adjacency[i].len());
+
+    for &root in &by_degree {
+        if visited[root] {
+            continue;
+        }
+
+        let start = pseudo_peripheral_vertex(&adjacency, root, &mut levels);
+
+        let component_begin = order.len();
+        visited[start] = true;
+        order.push(start);
+
+        let mut next = component_begin;
+        while next < order.len() {
+            let i = order[next];
+            next += 1;
+
+            let neighbors_begin = order.len();
+            for &j in &adjacency[i] {
+                if !visited[j] {
+                    visited[j] = true;
+                    order.push(j);
+                }
+            }
+            order[neighbors_begin..].sort_by_key(|&j| adjacency[j].len());
+        }
+    }
+
+    order.reverse();
+    Permutation::try_from_indices(order).expect("Internal error: ordering must be a permutation")
+}
+
+/// Finds a vertex of approximately maximal eccentricity in the component of `root`.
+fn pseudo_peripheral_vertex(adjacency: &[Vec<usize>], root: usize, levels: &mut [usize]) -> usize {
+    let mut start = root;
+    let (mut eccentricity, mut last_level) = level_structure(adjacency, start, levels);
+
+    loop {
+        // Try the vertex of minimum degree in the last level of the level structure.
+        let candidate = *last_level
+            .iter()
+            .min_by_key(|&&i| adjacency[i].len())
+            .expect("Internal error: the last level is never empty");
+        let (candidate_eccentricity, candidate_last_level) =
+            level_structure(adjacency, candidate, levels);
+
+        if candidate_eccentricity > eccentricity {
+            start = candidate;
+            eccentricity = candidate_eccentricity;
+            last_level = candidate_last_level;
+        } else {
+            return start;
+        }
+    }
+}
+
+/// Computes the rooted level structure of `root`, returning its depth and its last level.
+///
+/// The `levels` workspace is restored to `usize::MAX` on return.
+fn level_structure(
+    adjacency: &[Vec<usize>],
+    root: usize,
+    levels: &mut [usize],
+) -> (usize, Vec<usize>) {
+    let mut vertices = vec![root];
+    levels[root] = 0;
+    let mut last_level_begin = 0;
+
+    let mut next = 0;
+    while next < vertices.len() {
+        let i = vertices[next];
+        next += 1;
+
+        for &j in &adjacency[i] {
+            if levels[j] == usize::MAX {
+                levels[j] = levels[i] + 1;
+                if levels[j] != levels[vertices[vertices.len() - 1]] {
+                    last_level_begin = vertices.len();
+                }
+                vertices.push(j);
+            }
+        }
+    }
+
+    let depth = levels[vertices[vertices.len() - 1]];
+    for &i in &vertices {
+        levels[i] = usize::MAX;
+    }
+
+    (depth, vertices.split_off(last_level_begin))
+}
@@ -391,3 +391,53 @@ impl<'a> Iterator for SparsityPatternIter<'a> {
         }
     }
 }
+
+#[cfg(feature = "serde-serialize")]
+mod serde_serialize {
+    use super::SparsityPattern;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "SparsityPattern")]
+    struct SparsityPatternRef<'a> {
+        major_offsets: &'a [usize],
+        minor_indices: &'a [usize],
+        minor_dim: usize,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "SparsityPattern")]
+    struct SparsityPatternData {
+        major_offsets: Vec<usize>,
+        minor_indices: Vec<usize>,
+        minor_dim: usize,
+    }
+
+    impl Serialize for SparsityPattern {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            SparsityPatternRef {
+                major_offsets: &self.major_offsets,
+                minor_indices: &self.minor_indices,
+                minor_dim: self.minor_dim,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SparsityPattern {
+        /// Deserializes a sparsity pattern, returning an error if the data does not form a
+        /// valid pattern.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = SparsityPatternData::deserialize(deserializer)?;
+            // An empty offset array is rejected by the validation below
+            let major_dim = data.major_offsets.len().saturating_sub(1);
+            SparsityPattern::try_from_offsets_and_indices(
+                major_dim,
+                data.minor_dim,
+                data.major_offsets,
+                data.minor_indices,
+            )
+            .map_err(de::Error::custom)
+        }
+    }
+}
@@ -75,3 +75,40 @@ pub fn csc_strategy() -> impl Strategy<Value = CscMatrix<i32>> {
         PROPTEST_MAX_NNZ,
     )
 }
+
+/// Generates symmetric positive definite matrices of the form `x^T x + I`.
+pub fn positive_definite() -> impl Strategy<Value = CscMatrix<f64>> {
+    csc(
+        value_strategy::<f64>(),
+        PROPTEST_MATRIX_DIM,
+        PROPTEST_MATRIX_DIM,
+        PROPTEST_MAX_NNZ,
+    )
+    .prop_map(|x| x.transpose() * &x + CscMatrix::identity(x.ncols()))
+}
+
+/// Generates square nonsymmetric matrices made strictly diagonally dominant.
+pub fn diagonally_dominant() -> impl Strategy<Value = CscMatrix<f64>> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csc(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+        .prop_map(|a| {
+            let n = a.nrows();
+            a + CscMatrix::identity(n) * (5.0 * (n as f64 + 1.0))
+        })
+}
+
+/// Generates a list of indices in `0 .. dim`, which may contain repeated indices.
+pub fn index_list_strategy(dim: usize) -> impl Strategy<Value = Vec<usize>> {
+    let max_len = if dim > 0 {
+        PROPTEST_MATRIX_DIM.end() + 2
+    } else {
+        0
+    };
+    proptest::collection::vec(0..dim.max(1), 0..=max_len)
+}
+
+/// Generates a list of distinct indices in `0 .. dim`, in arbitrary order.
+pub fn distinct_index_list_strategy(dim: usize) -> impl Strategy<Value = Vec<usize>> {
+    let indices: Vec<_> = (0..dim).collect();
+    proptest::sample::subsequence(indices, 0..=dim).prop_shuffle()
+}
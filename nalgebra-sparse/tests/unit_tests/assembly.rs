@@ -0,0 +1,201 @@
+use nalgebra::{DMatrix, Matrix4};
+use nalgebra_sparse::assembly::CsrAssembler;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+
+use nalgebra::proptest::matrix;
+use proptest::prelude::*;
+
+use crate::assert_panics;
+use crate::common::PROPTEST_I32_VALUE_STRATEGY;
+
+/// Assembles the element matrices by pushing all their entries to a COO matrix.
+fn assemble_with_coo(
+    num_nodes: usize,
+    dofs_per_node: usize,
+    connectivity: &[Vec<usize>],
+    element_matrices: &[DMatrix<i32>],
+) -> CsrMatrix<i32> {
+    let n = num_nodes * dofs_per_node;
+    let mut coo = CooMatrix::new(n, n);
+    for (nodes, element_matrix) in connectivity.iter().zip(element_matrices) {
+        let dofs: Vec<_> = nodes
+            .iter()
+            .flat_map(|&node| node * dofs_per_node..(node + 1) * dofs_per_node)
+            .collect();
+        for (a, &row) in dofs.iter().enumerate() {
+            for (b, &col) in dofs.iter().enumerate() {
+                coo.push(row, col, element_matrix[(a, b)]);
+            }
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+#[test]
+fn csr_assembler_assembles_static_element_matrices() {
+    // Two line elements with two degrees of freedom per node, sharing node 1
+    let connectivity = vec![[0, 1], [2, 1]];
+    let assembler = CsrAssembler::from_connectivity(3, 2, &connectivity);
+    assert_eq!(assembler.num_elements(), 2);
+    assert_eq!(assembler.element_dim(1), 4);
+    assert_eq!(assembler.pattern().major_dim(), 6);
+    assert_eq!(assembler.pattern().minor_dim(), 6);
+    assert_eq!(assembler.pattern().lane(0), &[0, 1, 2, 3]);
+    assert_eq!(assembler.pattern().lane(3), &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(assembler.pattern().lane(4), &[2, 3, 4, 5]);
+
+    let element_matrix =
+        |element: usize| Matrix4::from_fn(|i, j| (10 * element + 4 * i + j) as i32);
+    let matrix = assembler.assemble(element_matrix);
+
+    let element_matrices: Vec<_> = (0..2)
+        .map(|e| DMatrix::from_iterator(4, 4, element_matrix(e).iter().cloned()))
+        .collect();
+    let connectivity: Vec<_> = connectivity.iter().map(|nodes| nodes.to_vec()).collect();
+    let expected = assemble_with_coo(3, 2, &connectivity, &element_matrices);
+    assert_eq!(matrix, expected);
+
+    // Element matrices are added to the existing values
+    let mut matrix = matrix;
+    assembler.add_element_matrix(&mut matrix, 1, &element_matrix(1));
+    let mut expected = DMatrix::from(&expected);
+    for (a, &row) in [4, 5, 2, 3].iter().enumerate() {
+        for (b, &col) in [4, 5, 2, 3].iter().enumerate() {
+            expected[(row, col)] += element_matrix(1)[(a, b)];
+        }
+    }
+    assert_eq!(DMatrix::from(&matrix), expected);
+    assert_eq!(matrix.pattern(), assembler.pattern());
+}
+
+#[test]
+fn csr_assembler_panics_on_invalid_input() {
+    assert_panics!(CsrAssembler::from_connectivity(2, 1, &[[0, 2]]));
+
+    let assembler = CsrAssembler::from_connectivity(3, 1, &[vec![0, 1], vec![1, 2, 0]]);
+    let mut matrix = assembler.new_matrix::<i32>();
+
+    // Element matrix has the wrong dimensions
+    assert_panics!(assembler.add_element_matrix(
+        &mut matrix.clone(),
+        0,
+        &DMatrix::<i32>::zeros(3, 3)
+    ));
+    assert_panics!(assembler.add_element_matrix(
+        &mut matrix.clone(),
+        1,
+        &DMatrix::<i32>::zeros(3, 2)
+    ));
+    // Element index out of bounds
+    assert_panics!(assembler.add_element_matrix(
+        &mut matrix.clone(),
+        2,
+        &DMatrix::<i32>::zeros(2, 2)
+    ));
+    // Matrix does not have the pattern of the assembler
+    let identity = CsrMatrix::identity(3);
+    assert_panics!(assembler.add_element_matrix(
+        &mut identity.clone(),
+        0,
+        &DMatrix::<i32>::zeros(2, 2)
+    ));
+
+    assembler.add_element_matrix(&mut matrix, 0, &DMatrix::from_element(2, 2, 1));
+    assert_eq!(matrix.values().iter().sum::<i32>(), 4);
+}
+
+/// Generates a connectivity, the number of degrees of freedom per node and the element matrices.
+fn assembly_strategy() -> impl Strategy<Value = (usize, usize, Vec<Vec<usize>>, Vec<DMatrix<i32>>)>
+{
+    (0..=6usize, 1..=2usize)
+        .prop_flat_map(|(num_nodes, dofs_per_node)| {
+            let max_elements = if num_nodes > 0 { 6 } else { 0 };
+            let element = proptest::collection::vec(0..num_nodes.max(1), 1..=3);
+            let connectivity = proptest::collection::vec(element, 0..=max_elements);
+            (Just(num_nodes), Just(dofs_per_node), connectivity)
+        })
+        .prop_flat_map(|(num_nodes, dofs_per_node, connectivity)| {
+            let element_matrices: Vec<_> = connectivity
+                .iter()
+                .map(|nodes| {
+                    let n = nodes.len() * dofs_per_node;
+                    matrix(PROPTEST_I32_VALUE_STRATEGY, n, n)
+                })
+                .collect();
+            (
+                Just(num_nodes),
+                Just(dofs_per_node),
+                Just(connectivity),
+                element_matrices,
+            )
+        })
+}
+
+proptest! {
+    #[test]
+    fn csr_assembler_agrees_with_coo_assembly(
+        (num_nodes, dofs_per_node, connectivity, element_matrices) in assembly_strategy()
+    ) {
+        let assembler = CsrAssembler::from_connectivity(num_nodes, dofs_per_node, &connectivity);
+        let expected = assemble_with_coo(num_nodes, dofs_per_node, &connectivity, &element_matrices);
+
+        let matrix = assembler.assemble(|e| element_matrices[e].clone());
+        prop_assert_eq!(&matrix, &expected);
+
+        // Reassembly into the same matrix
+        let mut matrix = matrix;
+        matrix.values_mut().iter_mut().for_each(|v| *v = 0);
+        assembler.add_element_matrices(&mut matrix, |e| element_matrices[e].clone());
+        prop_assert_eq!(&matrix, &expected);
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn csr_assembler_par_assemble_static_element_matrices() {
+        // A 2D mesh of 3x3 quadrilaterals on a 4x4 grid of nodes
+        let connectivity: Vec<_> = (0..3)
+            .flat_map(|i| (0..3).map(move |j| 4 * i + j))
+            .map(|n| [n, n + 1, n + 5, n + 4])
+            .collect();
+        let assembler = CsrAssembler::from_connectivity(16, 1, &connectivity);
+        assert!(assembler.element_colors().len() >= 4);
+
+        let element_matrix = |e: usize| Matrix4::from_fn(|i, j| (e + i * j) as i32 - 3);
+        assert_eq!(
+            assembler.par_assemble(element_matrix),
+            assembler.assemble(element_matrix)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn csr_assembler_par_assemble_agrees_with_coo_assembly(
+            (num_nodes, dofs_per_node, connectivity, element_matrices) in assembly_strategy()
+        ) {
+            let assembler = CsrAssembler::from_connectivity(num_nodes, dofs_per_node, &connectivity);
+            let expected = assemble_with_coo(num_nodes, dofs_per_node, &connectivity, &element_matrices);
+            let matrix = assembler.par_assemble(|e| element_matrices[e].clone());
+            prop_assert_eq!(&matrix, &expected);
+
+            // Every element has exactly one color, and elements of the same color share no node
+            let colors = assembler.element_colors();
+            let mut elements: Vec<_> = colors.iter().flatten().cloned().collect();
+            elements.sort_unstable();
+            prop_assert_eq!(elements, (0..connectivity.len()).collect::<Vec<_>>());
+            for color in colors {
+                let mut nodes = HashSet::new();
+                for &e in color {
+                    let element_nodes: HashSet<_> = connectivity[e].iter().cloned().collect();
+                    prop_assert!(nodes.is_disjoint(&element_nodes));
+                    nodes.extend(element_nodes);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use nalgebra::{DMatrix, Dynamic, Matrix2x3, U2, U3};
+use nalgebra_sparse::bsr::BsrMatrix;
+use nalgebra_sparse::convert::serial::{convert_coo_bsr, convert_csr_bsr};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::ops::serial::spmm_bsr_dense;
+use nalgebra_sparse::ops::Op;
+use nalgebra_sparse::pattern::SparsityPattern;
+use nalgebra_sparse::proptest::sparsity_pattern;
+use nalgebra_sparse::SparseFormatErrorKind;
+
+use nalgebra::proptest::matrix;
+use proptest::prelude::*;
+
+use crate::assert_panics;
+use crate::common::{csr_strategy, PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM};
+
+/// A 4x9 matrix with 2x3 blocks at the block positions (0, 0), (0, 2) and (1, 1).
+fn example_bsr() -> BsrMatrix<i32, U2, U3> {
+    let pattern =
+        SparsityPattern::try_from_offsets_and_indices(2, 3, vec![0, 2, 3], vec![0, 2, 1]).unwrap();
+    let blocks = vec![
+        Matrix2x3::new(1, 2, 3, 4, 5, 6),
+        Matrix2x3::new(7, 0, 8, 0, 9, 0),
+        Matrix2x3::new(0, 0, 0, 0, 0, 10),
+    ];
+    BsrMatrix::try_from_pattern_and_blocks(pattern, U2, U3, blocks).unwrap()
+}
+
+#[rustfmt::skip]
+fn example_dense() -> DMatrix<i32> {
+    DMatrix::from_row_slice(
+        4,
+        9,
+        &[
+            1, 2, 3, 0, 0, 0, 7, 0, 8,
+            4, 5, 6, 0, 0, 0, 0, 9, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 10, 0, 0, 0,
+        ],
+    )
+}
+
+#[test]
+fn bsr_matrix_valid_data() {
+    let bsr = example_bsr();
+    assert_eq!(bsr.nrows(), 4);
+    assert_eq!(bsr.ncols(), 9);
+    assert_eq!(bsr.block_shape(), (2, 3));
+    assert_eq!(bsr.block_row_count(), 2);
+    assert_eq!(bsr.block_col_count(), 3);
+    assert_eq!(bsr.block_count(), 3);
+    assert_eq!(bsr.nnz(), 18);
+    assert_eq!(&bsr.values()[..6], &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(DMatrix::from(&bsr), example_dense());
+
+    // The same matrix with dynamically sized blocks, constructed from the values
+    let (pattern, values) = bsr.clone().into_pattern_and_values();
+    let dynamic =
+        BsrMatrix::try_from_pattern_and_values(pattern, Dynamic::new(2), Dynamic::new(3), values)
+            .unwrap();
+    assert_eq!(dynamic.block_shape(), (2, 3));
+    assert_eq!(DMatrix::from(&dynamic), example_dense());
+
+    // A matrix without blocks
+    let empty = BsrMatrix::<f64, U3>::try_from_pattern_and_values(
+        SparsityPattern::zeros(2, 4),
+        U3,
+        U3,
+        Vec::new(),
+    )
+    .unwrap();
+    assert_eq!((empty.nrows(), empty.ncols()), (6, 12));
+    assert_eq!(empty.nnz(), 0);
+    assert!(empty.block_iter().next().is_none());
+    assert_eq!(DMatrix::from(&empty), DMatrix::zeros(6, 12));
+}
+
+#[test]
+fn bsr_matrix_invalid_data() {
+    let pattern =
+        SparsityPattern::try_from_offsets_and_indices(2, 3, vec![0, 2, 3], vec![0, 2, 1]).unwrap();
+
+    // Wrong number of values
+    let result = BsrMatrix::try_from_pattern_and_values(pattern.clone(), U2, U3, vec![0; 17]);
+    assert_eq!(
+        result.unwrap_err().kind(),
+        &SparseFormatErrorKind::InvalidStructure
+    );
+
+    // Zero block dimension
+    let result = BsrMatrix::try_from_pattern_and_values(
+        pattern.clone(),
+        Dynamic::new(0),
+        U3,
+        Vec::<i32>::new(),
+    );
+    assert_eq!(
+        result.unwrap_err().kind(),
+        &SparseFormatErrorKind::InvalidStructure
+    );
+
+    // Wrong number of blocks
+    let blocks = vec![DMatrix::<i32>::zeros(2, 3); 2];
+    let result = BsrMatrix::try_from_pattern_and_blocks(
+        pattern.clone(),
+        Dynamic::new(2),
+        Dynamic::new(3),
+        blocks,
+    );
+    assert_eq!(
+        result.unwrap_err().kind(),
+        &SparseFormatErrorKind::InvalidStructure
+    );
+
+    // Block with the wrong dimensions
+    let blocks = vec![
+        DMatrix::zeros(2, 3),
+        DMatrix::zeros(3, 2),
+        DMatrix::zeros(2, 3),
+    ];
+    let result = BsrMatrix::<i32>::try_from_pattern_and_blocks(
+        pattern,
+        Dynamic::new(2),
+        Dynamic::new(3),
+        blocks,
+    );
+    assert_eq!(
+        result.unwrap_err().kind(),
+        &SparseFormatErrorKind::InvalidStructure
+    );
+}
+
+#[test]
+fn bsr_matrix_block_access() {
+    let mut bsr = example_bsr();
+
+    let blocks: Vec<_> = bsr
+        .block_iter()
+        .map(|(i, j, block)| (i, j, block.clone_owned()))
+        .collect();
+    assert_eq!(
+        blocks,
+        vec![
+            (0, 0, Matrix2x3::new(1, 2, 3, 4, 5, 6)),
+            (0, 2, Matrix2x3::new(7, 0, 8, 0, 9, 0)),
+            (1, 1, Matrix2x3::new(0, 0, 0, 0, 0, 10)),
+        ]
+    );
+
+    assert_eq!(
+        bsr.get_block(0, 2).unwrap(),
+        Matrix2x3::new(7, 0, 8, 0, 9, 0)
+    );
+    assert!(bsr.get_block(0, 1).is_none());
+    assert!(bsr.get_block(1, 2).is_none());
+    assert!(bsr.get_block(2, 0).is_none());
+    assert!(bsr.get_block(0, 3).is_none());
+
+    bsr.get_block_mut(1, 1).unwrap()[(0, 0)] = 11;
+    assert!(bsr.get_block_mut(1, 0).is_none());
+    for (_, j, mut block) in bsr.block_iter_mut() {
+        block[(1, 2)] += j as i32;
+    }
+
+    let mut expected = example_dense();
+    expected[(2, 3)] = 11;
+    expected[(1, 8)] += 2;
+    expected[(3, 5)] += 1;
+    assert_eq!(DMatrix::from(&bsr), expected);
+}
+
+#[test]
+fn bsr_matrix_conversions() {
+    let bsr = example_bsr();
+    let dense = example_dense();
+
+    // Zeros within the stored blocks are explicitly stored
+    let csr = CsrMatrix::from(&bsr);
+    assert_eq!(csr.nnz(), 18);
+    assert_eq!(DMatrix::from(&csr), dense);
+    let coo = CooMatrix::from(&bsr);
+    assert_eq!(coo.nnz(), 18);
+    assert_eq!(DMatrix::from(&coo), dense);
+
+    // Only blocks with explicitly stored entries appear in the result
+    let bsr_from_dense = convert_csr_bsr(&CsrMatrix::from(&dense), U2, U3);
+    assert_eq!(bsr_from_dense, bsr);
+    assert_eq!(convert_csr_bsr(&csr, U2, U3), bsr);
+
+    // Duplicates are summed
+    let mut coo = CooMatrix::new(4, 9);
+    coo.push(3, 5, 4);
+    coo.push(0, 0, 1);
+    coo.push(3, 5, 6);
+    let bsr = convert_coo_bsr(&coo, Dynamic::new(2), Dynamic::new(3));
+    assert_eq!(bsr.block_count(), 2);
+    assert_eq!(bsr.get_block(1, 1).unwrap()[(1, 2)], 10);
+    assert_eq!(DMatrix::from(&bsr), DMatrix::from(&coo));
+}
+
+#[test]
+fn bsr_matrix_conversion_panics_on_incompatible_dimensions() {
+    let csr = CsrMatrix::<f64>::identity(4);
+    assert_panics!(convert_csr_bsr(&csr, U3, U3));
+    assert_panics!(convert_csr_bsr(&csr, U2, U3));
+    assert_panics!(convert_csr_bsr(&csr, Dynamic::new(0), Dynamic::new(2)));
+}
+
+#[test]
+fn spmm_bsr_dense_panics_on_dim_mismatch() {
+    let bsr = example_bsr();
+    let b = DMatrix::<i32>::zeros(9, 2);
+    let c = DMatrix::zeros(4, 3);
+    assert_panics!(spmm_bsr_dense(
+        0,
+        &mut c.clone(),
+        1,
+        Op::NoOp(&bsr),
+        Op::NoOp(&b)
+    ));
+    assert_panics!(spmm_bsr_dense(
+        0,
+        &mut c.clone(),
+        1,
+        Op::Transpose(&bsr),
+        Op::NoOp(&b)
+    ));
+}
+
+fn op<T>(transposed: bool, obj: T) -> Op<T> {
+    if transposed {
+        Op::Transpose(obj)
+    } else {
+        Op::NoOp(obj)
+    }
+}
+
+/// A BSR matrix with 2x3 blocks.
+fn bsr_strategy() -> impl Strategy<Value = BsrMatrix<i32, U2, U3>> {
+    sparsity_pattern(0..=4usize, 0..=4usize, 10).prop_flat_map(|pattern| {
+        let values = proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, pattern.nnz() * 6);
+        values.prop_map(move |values| {
+            BsrMatrix::try_from_pattern_and_values(pattern.clone(), U2, U3, values).unwrap()
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn bsr_csr_roundtrip(csr in csr_strategy()) {
+        let dense = DMatrix::from(&csr);
+        let (r, c) = (csr.nrows().max(1), csr.ncols().max(1));
+        // Use the matrix dimensions as block dimensions, and single rows/columns as blocks
+        for &(block_nrows, block_ncols) in &[(1, 1), (r, c), (1, c), (r, 1)] {
+            prop_assume!(csr.nrows() % block_nrows == 0 && csr.ncols() % block_ncols == 0);
+            let bsr = convert_csr_bsr(&csr, Dynamic::new(block_nrows), Dynamic::new(block_ncols));
+            prop_assert_eq!(DMatrix::from(&bsr), dense.clone());
+            prop_assert_eq!(DMatrix::from(&CsrMatrix::from(&bsr)), dense.clone());
+        }
+    }
+
+    #[test]
+    fn spmm_bsr_dense_agrees_with_dense_result(
+        (bsr, trans_a, trans_b, b, c, alpha, beta) in bsr_strategy()
+            .prop_flat_map(|bsr| {
+                (Just(bsr), any::<bool>(), any::<bool>(), PROPTEST_MATRIX_DIM)
+            })
+            .prop_flat_map(|(bsr, trans_a, trans_b, k)| {
+                let (m, n) = if trans_a { (bsr.ncols(), bsr.nrows()) } else { (bsr.nrows(), bsr.ncols()) };
+                let b = if trans_b {
+                    matrix(PROPTEST_I32_VALUE_STRATEGY, k, n)
+                } else {
+                    matrix(PROPTEST_I32_VALUE_STRATEGY, n, k)
+                };
+                let c = matrix(PROPTEST_I32_VALUE_STRATEGY, m, k);
+                (Just(bsr), Just(trans_a), Just(trans_b), b, c,
+                 PROPTEST_I32_VALUE_STRATEGY, PROPTEST_I32_VALUE_STRATEGY)
+            }))
+    {
+        let mut result = c.clone();
+        spmm_bsr_dense(beta, &mut result, alpha, op(trans_a, &bsr), op(trans_b, &b));
+
+        let a_dense = DMatrix::from(&bsr);
+        let op_a = if trans_a { a_dense.transpose() } else { a_dense };
+        let op_b = if trans_b { b.transpose() } else { b.clone() };
+        let expected = c * beta + op_a * op_b * alpha;
+
+        prop_assert_eq!(result, expected);
+    }
+}
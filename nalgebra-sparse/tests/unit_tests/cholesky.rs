@@ -1,26 +1,15 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
-use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use crate::assert_panics;
+use crate::common::{positive_definite, value_strategy, PROPTEST_MATRIX_DIM};
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::factorization::{CscCholesky};
-use nalgebra_sparse::proptest::csc;
+use nalgebra_sparse::ordering::OrderingMethod;
 use nalgebra::{Matrix5, Vector5, Cholesky, DMatrix};
 use nalgebra::proptest::matrix;
 
 use proptest::prelude::*;
 use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
 
-fn positive_definite() -> impl Strategy<Value=CscMatrix<f64>> {
-    let csc_f64 = csc(value_strategy::<f64>(),
-                      PROPTEST_MATRIX_DIM,
-                      PROPTEST_MATRIX_DIM,
-                      PROPTEST_MAX_NNZ);
-    csc_f64
-        .prop_map(|x| {
-            // Add a small multiple of the identity to ensure positive definiteness
-            x.transpose() * &x + CscMatrix::identity(x.ncols())
-        })
-}
-
 proptest! {
     #[test]
     fn cholesky_correct_for_positive_definite_matrices(
@@ -60,6 +49,43 @@ proptest! {
         }
     }
 
+    #[test]
+    fn cholesky_with_ordering(
+        (matrix, rhs) in positive_definite()
+            .prop_flat_map(|csc| {
+                let rhs = matrix(value_strategy::<f64>(), csc.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csc), rhs)
+            }),
+        ordering in prop_oneof![
+            Just(OrderingMethod::Natural),
+            Just(OrderingMethod::ApproximateMinimumDegree),
+            Just(OrderingMethod::ReverseCuthillMcKee),
+        ]
+    ) {
+        let mut cholesky = CscCholesky::factor_with_ordering(&matrix, &ordering).unwrap();
+        let l = cholesky.l();
+
+        let permuted = match cholesky.permutation() {
+            Some(permutation) => permutation.permute_symmetric_csc(&matrix),
+            None => matrix.clone(),
+        };
+        prop_assert_matrix_eq!(l * l.transpose(), permuted, comp = abs, tol = 1e-8);
+
+        let x = cholesky.solve(&rhs);
+        prop_assert_matrix_eq!(&matrix * &x, rhs, comp=abs, tol=1e-12);
+
+        // The values passed to `refactor` are those of the original matrix
+        let doubled: Vec<_> = matrix.values().iter().map(|v| 2.0 * v).collect();
+        cholesky.refactor(&doubled).unwrap();
+        let x = cholesky.solve(&rhs);
+        prop_assert_matrix_eq!(&matrix * &x, rhs / 2.0, comp=abs, tol=1e-12);
+
+        // A value slice with the wrong length is rejected before it is permuted
+        let mut too_many = doubled.clone();
+        too_many.push(1.0);
+        assert_panics!(cholesky.clone().refactor(&too_many));
+    }
+
 }
 
 // This is a test ported from nalgebra's "sparse" module, for the original CsCholesky impl
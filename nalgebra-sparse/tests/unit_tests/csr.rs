@@ -1,12 +1,16 @@
 use nalgebra::DMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::proptest::csr;
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
 use proptest::prelude::*;
 use proptest::sample::subsequence;
 
 use crate::assert_panics;
-use crate::common::csr_strategy;
+use crate::common::{
+    csr_strategy, distinct_index_list_strategy, index_list_strategy, PROPTEST_I32_VALUE_STRATEGY,
+    PROPTEST_MAX_NNZ,
+};
 
 use std::collections::HashSet;
 
@@ -524,6 +528,77 @@ fn csr_matrix_row_iter() {
     }
 }
 
+#[test]
+fn csr_matrix_submatrix_extraction_and_insertion() {
+    #[rustfmt::skip]
+    let dense = DMatrix::from_row_slice(3, 4, &[
+        1, 0, 2, 0,
+        0, 3, 0, 4,
+        5, 0, 0, 6
+    ]);
+    let csr = CsrMatrix::from(&dense);
+
+    let slice = csr.slice(1..3, 1..4);
+    assert_eq!(slice.nnz(), 3);
+    assert_eq!(
+        DMatrix::from(&slice),
+        DMatrix::from_row_slice(2, 3, &[3, 0, 4, 0, 0, 6])
+    );
+    assert_eq!(csr.slice(1..1, 0..4).nnz(), 0);
+    assert_eq!(csr.slice(0..3, 0..4), csr);
+
+    let rows = csr.select_rows(&[2, 0, 2]);
+    assert_eq!(rows.nnz(), 6);
+    #[rustfmt::skip]
+    assert_eq!(DMatrix::from(&rows), DMatrix::from_row_slice(3, 4, &[
+        5, 0, 0, 6,
+        1, 0, 2, 0,
+        5, 0, 0, 6
+    ]));
+
+    let cols = csr.select_columns(&[3, 0]);
+    assert_eq!(cols.nnz(), 4);
+    assert_eq!(
+        DMatrix::from(&cols),
+        DMatrix::from_row_slice(3, 2, &[0, 1, 4, 0, 6, 5])
+    );
+
+    let submatrix = csr.submatrix(&[2, 1], &[3, 1]);
+    assert_eq!(submatrix.nnz(), 3);
+    assert_eq!(
+        DMatrix::from(&submatrix),
+        DMatrix::from_row_slice(2, 2, &[6, 0, 4, 3])
+    );
+    assert_eq!(csr.insert_submatrix(&[2, 1], &[3, 1], &submatrix), csr);
+
+    // Explicit entries within the submatrix are replaced by the entries of the inserted matrix
+    let mut inserted = DMatrix::zeros(2, 2);
+    inserted[(0, 1)] = 9;
+    let result = csr.insert_submatrix(&[2, 1], &[3, 1], &CsrMatrix::from(&inserted));
+    assert_eq!(result.nnz(), 4);
+    #[rustfmt::skip]
+    assert_eq!(DMatrix::from(&result), DMatrix::from_row_slice(3, 4, &[
+        1, 0, 2, 0,
+        0, 0, 0, 0,
+        5, 9, 0, 0
+    ]));
+}
+
+#[test]
+fn csr_matrix_submatrix_panics_on_invalid_indices() {
+    let csr = CsrMatrix::<i32>::identity(3);
+    assert_panics!(csr.slice(0..4, 0..3));
+    assert_panics!(csr.slice(0..3, 1..4));
+    assert_panics!(csr.select_rows(&[0, 3]));
+    assert_panics!(csr.select_columns(&[3]));
+    assert_panics!(csr.submatrix(&[0], &[1, 3]));
+
+    let submatrix = CsrMatrix::<i32>::identity(2);
+    assert_panics!(csr.insert_submatrix(&[0, 0], &[0, 1], &submatrix));
+    assert_panics!(csr.insert_submatrix(&[0, 1], &[1, 3], &submatrix));
+    assert_panics!(csr.insert_submatrix(&[0, 1, 2], &[0, 1], &submatrix));
+}
+
 proptest! {
     #[test]
     fn csr_double_transpose_is_identity(csr in csr_strategy()) {
@@ -598,4 +673,66 @@ proptest! {
         prop_assert_eq!(csr.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csr), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csr_slice_agrees_with_dense(
+        (csr, rows, cols) in csr_strategy()
+            .prop_flat_map(|matrix| {
+                let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+                let rows = (0..=nrows, 0..=nrows).prop_map(|(a, b)| a.min(b)..a.max(b));
+                let cols = (0..=ncols, 0..=ncols).prop_map(|(a, b)| a.min(b)..a.max(b));
+                (Just(matrix), rows, cols)
+            }))
+    {
+        let slice = csr.slice(rows.clone(), cols.clone());
+        let dense = DMatrix::from(&csr);
+        let dense_slice = dense.slice((rows.start, cols.start), (rows.len(), cols.len()));
+        prop_assert_eq!(DMatrix::from(&slice), dense_slice.clone_owned());
+    }
+
+    #[test]
+    fn csr_submatrix_agrees_with_dense(
+        (csr, rows, cols) in csr_strategy()
+            .prop_flat_map(|matrix| {
+                let rows = index_list_strategy(matrix.nrows());
+                let cols = index_list_strategy(matrix.ncols());
+                (Just(matrix), rows, cols)
+            }))
+    {
+        let dense = DMatrix::from(&csr);
+        let selected_rows = csr.select_rows(&rows);
+        let selected_cols = csr.select_columns(&cols);
+        let submatrix = csr.submatrix(&rows, &cols);
+        prop_assert_eq!(DMatrix::from(&selected_rows), dense.select_rows(&rows));
+        prop_assert_eq!(DMatrix::from(&selected_cols), dense.select_columns(&cols));
+        prop_assert_eq!(DMatrix::from(&submatrix), dense.select_rows(&rows).select_columns(&cols));
+        prop_assert_eq!(&submatrix, &selected_rows.select_columns(&cols));
+    }
+
+    #[test]
+    fn csr_insert_submatrix_agrees_with_dense(
+        (csr, rows, cols, submatrix) in csr_strategy()
+            .prop_flat_map(|matrix| {
+                let rows = distinct_index_list_strategy(matrix.nrows());
+                let cols = distinct_index_list_strategy(matrix.ncols());
+                (Just(matrix), rows, cols)
+            })
+            .prop_flat_map(|(matrix, rows, cols)| {
+                let submatrix = csr(PROPTEST_I32_VALUE_STRATEGY, rows.len(), cols.len(), PROPTEST_MAX_NNZ);
+                (Just(matrix), Just(rows), Just(cols), submatrix)
+            }))
+    {
+        let result = csr.insert_submatrix(&rows, &cols, &submatrix);
+
+        let mut expected = DMatrix::from(&csr);
+        let dense_submatrix = DMatrix::from(&submatrix);
+        for (i, &r) in rows.iter().enumerate() {
+            for (j, &c) in cols.iter().enumerate() {
+                expected[(r, c)] = dense_submatrix[(i, j)];
+            }
+        }
+        prop_assert_eq!(DMatrix::from(&result), expected);
+        prop_assert_eq!(&result.submatrix(&rows, &cols), &submatrix);
+        prop_assert_eq!(&csr.insert_submatrix(&rows, &cols, &csr.submatrix(&rows, &cols)), &csr);
+    }
 }
@@ -0,0 +1,183 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{diagonally_dominant, positive_definite, value_strategy};
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::iterative::{
+    bicgstab, conjugate_gradient, gmres, minres, IdentityOperator, LinearOperator, SolverOptions,
+    SolverStatus,
+};
+use nalgebra::{DMatrix, DVector};
+use nalgebra::proptest::vector;
+
+use proptest::prelude::*;
+
+const TOLERANCE: f64 = 1e-10;
+
+fn options() -> SolverOptions<f64> {
+    SolverOptions::new(TOLERANCE, 200)
+}
+
+fn with_rhs(matrices: impl Strategy<Value=CscMatrix<f64>>) -> impl Strategy<Value=(CscMatrix<f64>, DVector<f64>)> {
+    matrices.prop_flat_map(|a| {
+        let n = a.nrows();
+        (Just(a), vector(value_strategy::<f64>(), n))
+    })
+}
+
+fn relative_residual(a: &CscMatrix<f64>, b: &DVector<f64>, x: &DVector<f64>) -> f64 {
+    let r = b - DMatrix::from(a) * x;
+    if b.norm() == 0.0 { r.norm() } else { r.norm() / b.norm() }
+}
+
+// The 1D Laplacian, which is symmetric positive definite
+fn laplacian(n: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| if i == j { 2.0 } else if i + 1 == j || j + 1 == i { -1.0 } else { 0.0 })
+}
+
+#[test]
+fn linear_operator_implementations() {
+    let dense = laplacian(4);
+    let x = DVector::from_column_slice(&[1.0, -2.0, 3.0, 0.5]);
+    let expected = &dense * &x;
+
+    let mut y = DVector::zeros(4);
+    dense.apply(&x, &mut y);
+    assert_eq!(y, expected);
+
+    let mut y = DVector::repeat(4, f64::NAN);
+    CsrMatrix::from(&dense).apply(&x, &mut y);
+    assert_eq!(y, expected);
+
+    let mut y = DVector::repeat(4, f64::NAN);
+    CscMatrix::from(&dense).apply(&x, &mut y);
+    assert_eq!(y, expected);
+
+    let closure = |x: &DVector<f64>, y: &mut DVector<f64>| y.copy_from(&(&dense * x));
+    let mut y = DVector::zeros(4);
+    closure.apply(&x, &mut y);
+    assert_eq!(y, expected);
+
+    let mut y = DVector::zeros(4);
+    IdentityOperator.apply(&x, &mut y);
+    assert_eq!(y, x);
+}
+
+#[test]
+fn preconditioned_solvers_with_closures() {
+    // A badly scaled symmetric positive definite matrix, preconditioned by the inverse of its diagonal.
+    let n = 30;
+    let scaling = DVector::from_fn(n, |i, _| 10.0f64.powi((i % 5) as i32));
+    let dense = DMatrix::from_diagonal(&scaling) * laplacian(n) * DMatrix::from_diagonal(&scaling);
+    let diagonal = dense.diagonal();
+    let jacobi = |x: &DVector<f64>, y: &mut DVector<f64>| y.copy_from(&x.component_div(&diagonal));
+    let b = DVector::from_fn(n, |i, _| (i as f64).sin());
+
+    let options = SolverOptions::new(1e-10, 1000);
+    let check = |x: &DVector<f64>| (&b - &dense * x).norm() <= 1e-8 * b.norm();
+
+    let mut x = DVector::zeros(n);
+    let unpreconditioned = conjugate_gradient(&dense, &IdentityOperator, &b, &mut x, &options);
+    let mut x = DVector::zeros(n);
+    let report = conjugate_gradient(&dense, &jacobi, &b, &mut x, &options);
+    assert!(report.converged() && check(&x));
+    assert!(report.iterations < unpreconditioned.iterations);
+
+    let mut x = DVector::zeros(n);
+    assert!(minres(&dense, &jacobi, &b, &mut x, &options).converged() && check(&x));
+    let mut x = DVector::zeros(n);
+    assert!(gmres(&dense, &jacobi, &b, &mut x, n, &options).converged() && check(&x));
+    let mut x = DVector::zeros(n);
+    assert!(bicgstab(&dense, &jacobi, &b, &mut x, &options).converged() && check(&x));
+}
+
+#[test]
+fn minres_symmetric_indefinite() {
+    let n = 20;
+    let dense = laplacian(n) - DMatrix::identity(n, n) * 1.5;
+    let a = CsrMatrix::from(&dense);
+    let b = DVector::from_fn(n, |i, _| 1.0 + i as f64);
+
+    let mut x = DVector::zeros(n);
+    let report = minres(&a, &IdentityOperator, &b, &mut x, &options());
+    assert_eq!(report.status, SolverStatus::Converged);
+    assert!((&b - &dense * &x).norm() <= 1e-8 * b.norm());
+
+    // The MINRES residual norms are non-increasing.
+    assert!(report.residual_history.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+
+    // Conjugate gradient detects the indefiniteness of the matrix.
+    let mut x = DVector::zeros(n);
+    let report = conjugate_gradient(&a, &IdentityOperator, &b, &mut x, &options());
+    assert_eq!(report.status, SolverStatus::Breakdown);
+}
+
+#[test]
+fn max_iterations_reached() {
+    let n = 50;
+    let a = CscMatrix::from(&laplacian(n));
+    let b = DVector::repeat(n, 1.0);
+    let options = SolverOptions::new(1e-12, 5);
+
+    let mut x = DVector::zeros(n);
+    let report = conjugate_gradient(&a, &IdentityOperator, &b, &mut x, &options);
+    assert_eq!(report.status, SolverStatus::MaxIterationsReached);
+    assert_eq!(report.iterations, 5);
+    assert_eq!(report.residual_history.len(), 6);
+    assert_eq!(report.residual_history[0], 1.0);
+    assert_eq!(report.residual_norm(), report.residual_history.last().copied());
+
+    let mut x = DVector::zeros(n);
+    let report = gmres(&a, &IdentityOperator, &b, &mut x, 2, &options);
+    assert_eq!(report.status, SolverStatus::MaxIterationsReached);
+    assert_eq!(report.iterations, 5);
+    assert_eq!(report.residual_history.len(), 6);
+}
+
+#[test]
+fn solvers_with_exact_initial_guess() {
+    let a = CscMatrix::from(&laplacian(5));
+    let x_exact = DVector::from_column_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    let b = DMatrix::from(&a) * &x_exact;
+
+    let mut x = x_exact.clone();
+    let report = bicgstab(&a, &IdentityOperator, &b, &mut x, &options());
+    assert_eq!(report.status, SolverStatus::Converged);
+    assert_eq!(report.iterations, 0);
+    assert_eq!(report.residual_history, vec![0.0]);
+    assert_eq!(x, x_exact);
+}
+
+proptest! {
+    #[test]
+    fn conjugate_gradient_positive_definite((a, b) in with_rhs(positive_definite())) {
+        let mut x = DVector::zeros(b.len());
+        let report = conjugate_gradient(&a, &IdentityOperator, &b, &mut x, &options());
+        prop_assert!(report.converged());
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+        prop_assert_eq!(report.residual_history.len(), report.iterations + 1);
+    }
+
+    #[test]
+    fn minres_positive_definite((a, b) in with_rhs(positive_definite())) {
+        let mut x = DVector::zeros(b.len());
+        let report = minres(&a, &IdentityOperator, &b, &mut x, &options());
+        prop_assert!(report.converged());
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+
+    #[test]
+    fn gmres_nonsymmetric((a, b) in with_rhs(diagonally_dominant()), restart in 1..=8usize) {
+        let mut x = DVector::zeros(b.len());
+        let report = gmres(&a, &IdentityOperator, &b, &mut x, restart, &options());
+        prop_assert!(report.converged());
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+
+    #[test]
+    fn bicgstab_nonsymmetric((a, b) in with_rhs(diagonally_dominant())) {
+        let mut x = DVector::zeros(b.len());
+        let report = bicgstab(&a, &IdentityOperator, &b, &mut x, &options());
+        prop_assert!(report.converged());
+        prop_assert!(relative_residual(&a, &b, &x) <= 1e-8);
+    }
+}
@@ -0,0 +1,145 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::factorization::{CscLu, CscSymbolicLu, LuError};
+use nalgebra_sparse::proptest::csc;
+use nalgebra::{DMatrix, Matrix4};
+use nalgebra::proptest::matrix;
+
+use proptest::prelude::*;
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
+
+fn square_matrix() -> impl Strategy<Value=CscMatrix<f64>> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csc(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+}
+
+// Matrices with integer entries, which are singular if and only if their determinant is below 1.
+fn nonsingular_matrix() -> impl Strategy<Value=CscMatrix<f64>> {
+    square_matrix()
+        .prop_filter("matrix must be nonsingular", |m| DMatrix::from(m).determinant().abs() > 0.5)
+}
+
+fn permuted_rows(matrix: &DMatrix<f64>, perm: &[usize]) -> DMatrix<f64> {
+    DMatrix::from_fn(matrix.nrows(), matrix.ncols(), |i, j| matrix[(perm[i], j)])
+}
+
+fn check_factors(lu: &CscLu<f64>, matrix: &CscMatrix<f64>) -> Result<(), TestCaseError> {
+    let l = DMatrix::from(lu.l());
+    let u = DMatrix::from(lu.u());
+    let pa = permuted_rows(&DMatrix::from(matrix), lu.row_permutation());
+
+    prop_assert_matrix_eq!(&l * &u, pa, comp = abs, tol = 1e-10);
+    prop_assert!(lu.l().triplet_iter().all(|(i, j, v)| j < i || (i == j && *v == 1.0)));
+    prop_assert!(lu.u().triplet_iter().all(|(i, j, _)| i <= j));
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn lu_correct_for_square_matrices(matrix in square_matrix()) {
+        let determinant = DMatrix::from(&matrix).determinant();
+
+        match CscLu::factor(&matrix) {
+            Ok(lu) => {
+                check_factors(&lu, &matrix)?;
+                prop_assert!(lu.l().values().iter().all(|v| v.abs() <= 1.0));
+            },
+            Err(_) => prop_assert!(determinant.abs() < 0.5),
+        }
+    }
+
+    #[test]
+    fn lu_threshold_pivoting(matrix in nonsingular_matrix(), threshold in 0.0..=1.0) {
+        let lu = CscLu::factor_with_threshold(&matrix, threshold).unwrap();
+        check_factors(&lu, &matrix)?;
+        prop_assert!(lu.l().values().iter().all(|v| v.abs() * threshold <= 1.0 + 1e-12));
+    }
+
+    #[test]
+    fn lu_solve(
+        (matrix, rhs) in nonsingular_matrix()
+            .prop_flat_map(|csc| {
+                let rhs = matrix(value_strategy::<f64>(), csc.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(csc), rhs)
+            })
+    ) {
+        let lu = CscLu::factor(&matrix).unwrap();
+        let dense = DMatrix::from(&matrix);
+
+        // solve_mut
+        {
+            let mut x = rhs.clone();
+            lu.solve_mut(&mut x);
+            prop_assert_matrix_eq!(&dense * &x, rhs, comp=abs, tol=1e-9);
+        }
+
+        // solve
+        {
+            let x = lu.solve(&rhs);
+            prop_assert_matrix_eq!(&dense * &x, rhs, comp=abs, tol=1e-9);
+        }
+
+        // solve_transpose_mut
+        {
+            let mut x = rhs.clone();
+            lu.solve_transpose_mut(&mut x);
+            prop_assert_matrix_eq!(dense.transpose() * &x, rhs, comp=abs, tol=1e-9);
+        }
+
+        // solve_transpose
+        {
+            let x = lu.solve_transpose(&rhs);
+            prop_assert_matrix_eq!(dense.transpose() * &x, rhs, comp=abs, tol=1e-9);
+        }
+    }
+
+    #[test]
+    fn lu_refactor(matrix in nonsingular_matrix(), scale in 0.5..2.0) {
+        let symbolic = CscSymbolicLu::factor(matrix.pattern().clone());
+        let mut lu = CscLu::factor_numerical(symbolic, matrix.values(), 1.0).unwrap();
+        let perm = lu.row_permutation().to_vec();
+
+        let mut scaled = matrix.clone();
+        scaled.values_mut().iter_mut().for_each(|v| *v *= scale);
+        lu.refactor(scaled.values()).unwrap();
+
+        prop_assert_eq!(lu.row_permutation(), &perm[..]);
+        check_factors(&lu, &scaled)?;
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_pivoting_and_refactor() {
+    let a = Matrix4::new(
+        0.0, 2.0, 0.0, 1.0,
+        3.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 4.0, 0.0,
+        1.0, 0.0, 2.0, 5.0);
+    let csc_a = CscMatrix::from(&a);
+
+    let mut lu = CscLu::factor(&csc_a).unwrap();
+    assert_eq!(lu.row_permutation(), &[1, 0, 2, 3]);
+
+    let b = DMatrix::from_fn(4, 2, |i, j| (i + 2 * j) as f64);
+    let x = lu.solve(&b);
+    let expected = DMatrix::from_fn(4, 4, |i, j| a[(i, j)]).lu().solve(&b).unwrap();
+    assert_matrix_eq!(x, expected, comp = abs, tol = 1e-12);
+
+    // Keeping the pivot order, the first pivot of the new values vanishes.
+    let mut values = csc_a.values().to_vec();
+    values[0] = 0.0;
+    assert_eq!(lu.refactor(&values), Err(LuError::Singular));
+
+    lu.refactor(csc_a.values()).unwrap();
+    assert_matrix_eq!(lu.solve(&b), expected, comp = abs, tol = 1e-12);
+}
+
+#[test]
+fn lu_singular() {
+    let a = Matrix4::from_fn(|i, j| if j == 2 { 0.0 } else { (i * j + 1) as f64 });
+    let csc_a = CscMatrix::from(&a);
+
+    assert_eq!(CscLu::factor(&csc_a).unwrap_err(), LuError::Singular);
+}
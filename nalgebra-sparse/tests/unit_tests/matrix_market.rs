@@ -0,0 +1,276 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::io::{
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    load_dense_from_matrix_market_file, load_dense_from_matrix_market_str,
+    save_to_matrix_market_file, save_to_matrix_market_str, MatrixMarketErrorKind,
+};
+use nalgebra_sparse::proptest::{coo_with_duplicates, csc, csr};
+use nalgebra::{Complex, DMatrix};
+use nalgebra::proptest::matrix;
+
+use proptest::prelude::*;
+
+fn error_of<T: std::fmt::Debug>(result: Result<T, nalgebra_sparse::io::MatrixMarketError>) -> (MatrixMarketErrorKind, Option<usize>) {
+    let error = result.unwrap_err();
+    (error.kind().clone(), error.line())
+}
+
+#[test]
+fn load_coordinate_general() {
+    let data = "\
+%%MatrixMarket matrix coordinate real general
+% A comment
+
+%  and another one after a blank line
+3 4 5
+1 1 1.5
+3 2 -2e-3
+1 4 +4
+3 2 1.0
+
+2 3 .25
+";
+    let coo = load_coo_from_matrix_market_str::<f64>(data).unwrap();
+    assert_eq!(coo.nrows(), 3);
+    assert_eq!(coo.ncols(), 4);
+    assert_eq!(coo.row_indices(), &[0, 2, 0, 2, 1]);
+    assert_eq!(coo.col_indices(), &[0, 1, 3, 1, 2]);
+    assert_eq!(coo.values(), &[1.5, -2e-3, 4.0, 1.0, 0.25]);
+
+    // Duplicates are summed in the dense matrix
+    let dense = load_dense_from_matrix_market_str::<f64>(data).unwrap();
+    assert_eq!(dense, DMatrix::from_row_slice(3, 4, &[
+        1.5, 0.0, 0.0, 4.0,
+        0.0, 0.0, 0.25, 0.0,
+        0.0, 0.998, 0.0, 0.0]));
+}
+
+#[test]
+fn load_coordinate_with_symmetry() {
+    let symmetric = "\
+%%MatrixMarket matrix coordinate integer symmetric
+3 3 3
+1 1 4
+3 1 -1
+3 2 2
+";
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        4, 0, -1,
+        0, 0, 2,
+        -1, 2, 0]);
+    assert_eq!(load_dense_from_matrix_market_str::<i32>(symmetric).unwrap(), expected);
+    assert_eq!(load_coo_from_matrix_market_str::<i64>(symmetric).unwrap().nnz(), 5);
+    // Integer data can be read into real types
+    assert_eq!(load_dense_from_matrix_market_str::<f64>(symmetric).unwrap(), expected.map(|x| x as f64));
+
+    let skew_symmetric = "\
+%%MatrixMarket matrix coordinate real skew-symmetric
+3 3 2
+2 1 1.5
+3 2 -2
+";
+    assert_eq!(load_dense_from_matrix_market_str::<f32>(skew_symmetric).unwrap(), DMatrix::from_row_slice(3, 3, &[
+        0.0, -1.5, 0.0,
+        1.5, 0.0, 2.0,
+        0.0, -2.0, 0.0]));
+
+    let hermitian = "\
+%%MatrixMarket matrix coordinate complex hermitian
+2 2 2
+1 1 2.0 0.0
+2 1 1.0 -3.0
+";
+    assert_eq!(load_dense_from_matrix_market_str::<Complex<f64>>(hermitian).unwrap(), DMatrix::from_row_slice(2, 2, &[
+        Complex::new(2.0, 0.0), Complex::new(1.0, 3.0),
+        Complex::new(1.0, -3.0), Complex::new(0.0, 0.0)]));
+}
+
+#[test]
+fn load_coordinate_pattern() {
+    let data = "\
+%%MatrixMarket matrix coordinate pattern symmetric
+3 3 2
+2 1
+3 3
+";
+    let coo = load_coo_from_matrix_market_str::<()>(data).unwrap();
+    let mut triplets: Vec<_> = coo.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+    triplets.sort();
+    assert_eq!(triplets, vec![(0, 1), (1, 0), (2, 2)]);
+
+    let (kind, line) = error_of(load_coo_from_matrix_market_str::<f64>(data));
+    assert_eq!(kind, MatrixMarketErrorKind::TypeMismatch);
+    assert_eq!(line, Some(1));
+}
+
+#[test]
+fn load_array() {
+    let general = "\
+%%MATRIXMARKET Matrix Array Real General
+2 3
+1
+2
+3
+4
+5
+6
+";
+    assert_eq!(load_dense_from_matrix_market_str::<f64>(general).unwrap(),
+               DMatrix::from_column_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    assert_eq!(load_coo_from_matrix_market_str::<f64>(general).unwrap().nnz(), 6);
+
+    let symmetric = "\
+%%MatrixMarket matrix array real symmetric
+3 3
+1
+2
+3
+4
+5
+6
+";
+    assert_eq!(load_dense_from_matrix_market_str::<f64>(symmetric).unwrap(), DMatrix::from_row_slice(3, 3, &[
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 5.0,
+        3.0, 5.0, 6.0]));
+
+    let skew_symmetric = "\
+%%MatrixMarket matrix array integer skew-symmetric
+3 3
+1
+2
+3
+";
+    assert_eq!(load_dense_from_matrix_market_str::<i8>(skew_symmetric).unwrap(), DMatrix::from_row_slice(3, 3, &[
+        0, -1, -2,
+        1, 0, -3,
+        2, 3, 0]));
+
+    let complex = "\
+%%MatrixMarket matrix array complex general
+1 2
+1 -1
+0.5 2
+";
+    assert_eq!(load_dense_from_matrix_market_str::<Complex<f32>>(complex).unwrap(),
+               DMatrix::from_row_slice(1, 2, &[Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)]));
+}
+
+#[test]
+fn load_errors_report_kind_and_line() {
+    let cases: Vec<(&str, MatrixMarketErrorKind, Option<usize>)> = vec![
+        ("", MatrixMarketErrorKind::InvalidHeader, None),
+        ("%%MatrixMarket matrix coordinate real\n1 1 0\n", MatrixMarketErrorKind::InvalidHeader, Some(1)),
+        ("\n%%MatrixMarket tensor coordinate real general\n", MatrixMarketErrorKind::InvalidHeader, Some(2)),
+        ("%%MatrixMarket matrix coordinate quaternion general\n", MatrixMarketErrorKind::InvalidHeader, Some(1)),
+        ("%%MatrixMarket matrix array pattern general\n", MatrixMarketErrorKind::InvalidHeader, Some(1)),
+        ("%%MatrixMarket matrix coordinate real hermitian\n", MatrixMarketErrorKind::InvalidHeader, Some(1)),
+        ("%%MatrixMarket matrix coordinate real general\n% comment\n", MatrixMarketErrorKind::ParsingError, None),
+        ("%%MatrixMarket matrix coordinate real general\n2 2\n", MatrixMarketErrorKind::ParsingError, Some(2)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 1\n% comment\n1 1 x\n", MatrixMarketErrorKind::ParsingError, Some(4)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1.0 2.0\n", MatrixMarketErrorKind::ParsingError, Some(3)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 1\n-1 1 1.0\n", MatrixMarketErrorKind::ParsingError, Some(3)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n0 1 1.0\n", MatrixMarketErrorKind::IndexOutOfBounds, Some(4)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 3 1.0\n", MatrixMarketErrorKind::IndexOutOfBounds, Some(3)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 1.0\n2 2 1.0\n", MatrixMarketErrorKind::EntryCountMismatch, Some(4)),
+        ("%%MatrixMarket matrix coordinate real general\n2 2 3\n1 1 1.0\n2 2 1.0\n\n", MatrixMarketErrorKind::EntryCountMismatch, Some(4)),
+        ("%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n", MatrixMarketErrorKind::EntryCountMismatch, Some(5)),
+        ("%%MatrixMarket matrix coordinate real general\n3 3 18446744073709551615\n1 1 1.0\n", MatrixMarketErrorKind::EntryCountMismatch, Some(3)),
+        ("%%MatrixMarket matrix array real general\n4294967296 4294967296\n", MatrixMarketErrorKind::ParsingError, Some(2)),
+        ("%%MatrixMarket matrix array real symmetric\n18446744073709551615 18446744073709551615\n", MatrixMarketErrorKind::ParsingError, Some(2)),
+        ("%%MatrixMarket matrix coordinate real symmetric\n2 3 0\n", MatrixMarketErrorKind::NonSquare, Some(2)),
+        ("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1.0\n", MatrixMarketErrorKind::NotLowerTriangle, Some(3)),
+        ("%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n2 2 1.0\n", MatrixMarketErrorKind::DiagonalError, Some(3)),
+    ];
+
+    for (data, kind, line) in cases {
+        let result = load_coo_from_matrix_market_str::<f64>(data);
+        let error = result.unwrap_err();
+        assert_eq!((error.kind(), error.line()), (&kind, line), "{}", data);
+        if let Some(line) = line {
+            assert!(error.to_string().starts_with(&format!("Matrix Market error on line {}: ", line)));
+        }
+    }
+
+    // Like skew-symmetric matrices, hermitian matrices restrict their diagonal entries
+    let hermitian = "%%MatrixMarket matrix coordinate complex hermitian\n2 2 2\n1 1 1.0 0.0\n2 2 1.0 0.5\n";
+    assert_eq!(error_of(load_coo_from_matrix_market_str::<Complex<f64>>(hermitian)), (MatrixMarketErrorKind::DiagonalError, Some(4)));
+    let hermitian = "%%MatrixMarket matrix array complex hermitian\n2 2\n1.0 0.0\n2.0 1.0\n3.0 -1.0\n";
+    assert_eq!(error_of(load_coo_from_matrix_market_str::<Complex<f64>>(hermitian)), (MatrixMarketErrorKind::DiagonalError, Some(5)));
+
+    let real = "%%MatrixMarket matrix coordinate real general\n1 1 1\n1 1 1.0\n";
+    assert_eq!(error_of(load_coo_from_matrix_market_str::<i8>(real)), (MatrixMarketErrorKind::TypeMismatch, Some(1)));
+    let overflow = "%%MatrixMarket matrix coordinate integer general\n1 1 1\n1 1 300\n";
+    assert_eq!(error_of(load_coo_from_matrix_market_str::<i8>(overflow)), (MatrixMarketErrorKind::ParsingError, Some(3)));
+
+    // Negation overflow in skew-symmetric integer matrices
+    let data = "%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n2 1 -128\n";
+    assert_eq!(error_of(load_coo_from_matrix_market_str::<i8>(data)), (MatrixMarketErrorKind::ParsingError, Some(3)));
+    assert!(load_coo_from_matrix_market_str::<i16>(data).is_ok());
+}
+
+#[test]
+fn save_formats() {
+    let coo = CooMatrix::try_from_triplets(2, 3, vec![1, 0], vec![2, 0], vec![1.5, -1e-20]).unwrap();
+    assert_eq!(save_to_matrix_market_str(&coo),
+               "%%MatrixMarket matrix coordinate real general\n2 3 2\n2 3 1.5\n1 1 -1e-20\n");
+
+    let pattern = CscMatrix::from(&coo).pattern().clone();
+    let csc = CscMatrix::try_from_pattern_and_values(pattern, vec![(); 2]).unwrap();
+    assert_eq!(save_to_matrix_market_str(&csc),
+               "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 1\n2 3\n");
+
+    let dense = DMatrix::from_row_slice(2, 2, &[Complex::new(1.0, 2.0), Complex::new(0.0, 0.0),
+                                                Complex::new(-3.0, 0.5), Complex::new(4.0, -1.0)]);
+    assert_eq!(save_to_matrix_market_str(&dense),
+               "%%MatrixMarket matrix array complex general\n2 2\n 1.0 2.0\n -3.0 0.5\n 0.0 0.0\n 4.0 -1.0\n");
+}
+
+#[test]
+fn file_round_trip() {
+    let directory = std::env::temp_dir();
+    let path = directory.join(format!("nalgebra_sparse_matrix_market_{}.mtx", std::process::id()));
+
+    let csr = CsrMatrix::try_from_csr_data(2, 2, vec![0, 1, 2], vec![1, 0], vec![3, -4]).unwrap();
+    save_to_matrix_market_file(&csr, &path).unwrap();
+    let coo = load_coo_from_matrix_market_file::<i32, _>(&path).unwrap();
+    assert_eq!(CsrMatrix::from(&coo), csr);
+    let dense = load_dense_from_matrix_market_file::<i64, _>(&path).unwrap();
+    assert_eq!(dense, DMatrix::from_row_slice(2, 2, &[0, 3, -4, 0]));
+    std::fs::remove_file(&path).unwrap();
+
+    let (kind, line) = error_of(load_coo_from_matrix_market_file::<f64, _>(&path));
+    assert_eq!(kind, MatrixMarketErrorKind::IoError);
+    assert_eq!(line, None);
+}
+
+proptest! {
+    #[test]
+    fn csr_round_trip(csr in csr(value_strategy::<i32>(), PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)) {
+        let coo = load_coo_from_matrix_market_str::<i32>(&save_to_matrix_market_str(&csr)).unwrap();
+        prop_assert_eq!(CsrMatrix::from(&coo), csr);
+    }
+
+    #[test]
+    fn csc_round_trip(csc in csc(-1.0e10..1.0e10f64, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)) {
+        // The written values round-trip exactly.
+        let coo = load_coo_from_matrix_market_str::<f64>(&save_to_matrix_market_str(&csc)).unwrap();
+        prop_assert_eq!(CscMatrix::from(&coo), csc);
+    }
+
+    #[test]
+    fn coo_round_trip_keeps_duplicates(coo in coo_with_duplicates(value_strategy::<i32>(), PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ, 2)) {
+        let loaded = load_coo_from_matrix_market_str::<i32>(&save_to_matrix_market_str(&coo)).unwrap();
+        prop_assert_eq!(loaded, coo);
+    }
+
+    #[test]
+    fn dense_round_trip(dense in matrix(-1.0e10..1.0e10f32, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM)) {
+        let loaded = load_dense_from_matrix_market_str::<f32>(&save_to_matrix_market_str(&dense)).unwrap();
+        prop_assert_eq!(loaded, dense);
+    }
+}
@@ -1,8 +1,17 @@
+mod assembly;
+mod bsr;
 mod cholesky;
 mod convert_serial;
 mod coo;
 mod csc;
 mod csr;
+mod iterative;
+mod lu;
+mod matrix_market;
 mod ops;
+mod ordering;
 mod pattern;
+mod preconditioners;
 mod proptest;
+#[cfg(feature = "serde-serialize")]
+mod serde;
@@ -1233,3 +1233,228 @@ proptest! {
     }
 
 }
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::*;
+    use nalgebra_sparse::coo::CooMatrix;
+    use nalgebra_sparse::ops::parallel;
+    use nalgebra_sparse::ops::serial::OperationErrorKind;
+
+    /// Runs the closure in a dedicated thread pool with the given number of threads.
+    fn with_threads<R: Send>(num_threads: usize, f: impl FnOnce() -> R + Send) -> R {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap()
+            .install(f)
+    }
+
+    /// A deterministic pseudo-random sparse matrix with floating-point values.
+    fn pseudo_random_csr(
+        nrows: usize,
+        ncols: usize,
+        nnz_per_row: usize,
+        seed: u64,
+    ) -> CsrMatrix<f64> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 33
+        };
+        let mut coo = CooMatrix::new(nrows, ncols);
+        for i in 0..nrows {
+            for _ in 0..nnz_per_row {
+                let j = next() as usize % ncols;
+                let value = next() as f64 / (1u64 << 31) as f64 - 0.5;
+                coo.push(i, j, value);
+            }
+        }
+        CsrMatrix::from(&coo)
+    }
+
+    #[test]
+    fn parallel_results_are_identical_to_serial_results_for_any_number_of_threads() {
+        let a = pseudo_random_csr(300, 200, 7, 1);
+        let b = pseudo_random_csr(200, 250, 5, 2);
+        let a_dense = DMatrix::from(&a);
+        let b_dense = DMatrix::from(&b);
+        let c_dense = DMatrix::from(&pseudo_random_csr(300, 250, 3, 3));
+        let (alpha, beta) = (0.3, 1.7);
+
+        let mut expected_dense = c_dense.clone();
+        spmm_csr_dense(
+            beta,
+            &mut expected_dense,
+            alpha,
+            Op::NoOp(&a),
+            Op::NoOp(&b_dense),
+        );
+        let mut expected_dense_t = c_dense.transpose();
+        spmm_csr_dense(
+            beta,
+            &mut expected_dense_t,
+            alpha,
+            Op::Transpose(&b),
+            Op::Transpose(&a_dense),
+        );
+
+        let c_pattern = spmm_csr_pattern(a.pattern(), b.pattern());
+        let mut expected_sparse =
+            CsrMatrix::try_from_pattern_and_values(c_pattern.clone(), vec![1.0; c_pattern.nnz()])
+                .unwrap();
+        spmm_csr_prealloc(
+            beta,
+            &mut expected_sparse,
+            alpha,
+            Op::NoOp(&a),
+            Op::NoOp(&b),
+        )
+        .unwrap();
+        let mut expected_sum = expected_sparse.clone();
+        spadd_csr_prealloc(
+            beta,
+            &mut expected_sum,
+            alpha,
+            Op::Transpose(&expected_sparse.transpose()),
+        )
+        .unwrap();
+
+        for &num_threads in &[1, 2, 3, 8] {
+            with_threads(num_threads, || {
+                let mut c = c_dense.clone();
+                parallel::spmm_csr_dense(beta, &mut c, alpha, Op::NoOp(&a), Op::NoOp(&b_dense));
+                assert_eq!(c, expected_dense);
+
+                let mut c_t = c_dense.transpose();
+                parallel::spmm_csr_dense(
+                    beta,
+                    &mut c_t,
+                    alpha,
+                    Op::Transpose(&b),
+                    Op::Transpose(&a_dense),
+                );
+                assert_eq!(c_t, expected_dense_t);
+
+                let mut c_sparse = CsrMatrix::try_from_pattern_and_values(
+                    c_pattern.clone(),
+                    vec![1.0; c_pattern.nnz()],
+                )
+                .unwrap();
+                parallel::spmm_csr_prealloc(beta, &mut c_sparse, alpha, Op::NoOp(&a), Op::NoOp(&b))
+                    .unwrap();
+                assert_eq!(c_sparse, expected_sparse);
+
+                parallel::spadd_csr_prealloc(
+                    beta,
+                    &mut c_sparse,
+                    alpha,
+                    Op::Transpose(&expected_sparse.transpose()),
+                )
+                .unwrap();
+                assert_eq!(c_sparse, expected_sum);
+
+                assert_eq!(parallel::transpose_csr(&a), a.transpose());
+                let a_csc = CscMatrix::from(&a);
+                assert_eq!(parallel::transpose_csc(&a_csc), a_csc.transpose());
+            });
+        }
+    }
+
+    #[test]
+    fn parallel_errors_agree_with_serial_errors() {
+        let a = CsrMatrix::<f64>::identity(50);
+        let mut c = CsrMatrix::zeros(50, 50);
+
+        let error =
+            parallel::spmm_csr_prealloc(1.0, &mut c, 1.0, Op::NoOp(&a), Op::NoOp(&a)).unwrap_err();
+        let serial_error =
+            spmm_csr_prealloc(1.0, &mut c, 1.0, Op::NoOp(&a), Op::NoOp(&a)).unwrap_err();
+        assert!(matches!(error.kind(), OperationErrorKind::InvalidPattern));
+        assert_eq!(error.message(), serial_error.message());
+
+        for &a in &[Op::NoOp(&a), Op::Transpose(&a)] {
+            let error = parallel::spadd_csr_prealloc(1.0, &mut c, 1.0, a).unwrap_err();
+            let serial_error = spadd_csr_prealloc(1.0, &mut c, 1.0, a).unwrap_err();
+            assert!(matches!(error.kind(), OperationErrorKind::InvalidPattern));
+            assert_eq!(error.message(), serial_error.message());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parallel_spmm_csr_dense_agrees_with_serial(
+            SpmmCsrDenseArgs { c, beta, alpha, a, b } in spmm_csr_dense_args_strategy()
+        ) {
+            let mut expected = c.clone();
+            spmm_csr_dense(beta, &mut expected, alpha, a.as_ref(), b.as_ref());
+            let mut result = c.clone();
+            parallel::spmm_csr_dense(beta, &mut result, alpha, a.as_ref(), b.as_ref());
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn parallel_spmm_csc_dense_agrees_with_serial(
+            SpmmCscDenseArgs { c, beta, alpha, a, b } in spmm_csc_dense_args_strategy()
+        ) {
+            let mut expected = c.clone();
+            spmm_csc_dense(beta, &mut expected, alpha, a.as_ref(), b.as_ref());
+            let mut result = c.clone();
+            parallel::spmm_csc_dense(beta, &mut result, alpha, a.as_ref(), b.as_ref());
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn parallel_spadd_csr_prealloc_agrees_with_serial(
+            SpaddCsrArgs { c, beta, alpha, a } in spadd_csr_prealloc_args_strategy()
+        ) {
+            let mut expected = c.clone();
+            spadd_csr_prealloc(beta, &mut expected, alpha, a.as_ref()).unwrap();
+            let mut result = c.clone();
+            parallel::spadd_csr_prealloc(beta, &mut result, alpha, a.as_ref()).unwrap();
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn parallel_spadd_csc_prealloc_agrees_with_serial(
+            SpaddCscArgs { c, beta, alpha, a } in spadd_csc_prealloc_args_strategy()
+        ) {
+            let mut expected = c.clone();
+            spadd_csc_prealloc(beta, &mut expected, alpha, a.as_ref()).unwrap();
+            let mut result = c.clone();
+            parallel::spadd_csc_prealloc(beta, &mut result, alpha, a.as_ref()).unwrap();
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn parallel_spmm_csr_prealloc_agrees_with_serial(
+            SpmmCsrArgs { c, beta, alpha, a, b } in spmm_csr_prealloc_args_strategy()
+        ) {
+            let mut expected = c.clone();
+            spmm_csr_prealloc(beta, &mut expected, alpha, a.as_ref(), b.as_ref()).unwrap();
+            let mut result = c.clone();
+            parallel::spmm_csr_prealloc(beta, &mut result, alpha, a.as_ref(), b.as_ref()).unwrap();
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn parallel_spmm_csc_prealloc_agrees_with_serial(
+            SpmmCscArgs { c, beta, alpha, a, b } in spmm_csc_prealloc_args_strategy()
+        ) {
+            let mut expected = c.clone();
+            spmm_csc_prealloc(beta, &mut expected, alpha, a.as_ref(), b.as_ref()).unwrap();
+            let mut result = c.clone();
+            parallel::spmm_csc_prealloc(beta, &mut result, alpha, a.as_ref(), b.as_ref()).unwrap();
+            prop_assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn parallel_transpose_agrees_with_serial(csr in csr_strategy()) {
+            prop_assert_eq!(parallel::transpose_csr(&csr), csr.transpose());
+            let csc = CscMatrix::from(&csr);
+            prop_assert_eq!(parallel::transpose_csc(&csc), csc.transpose());
+        }
+    }
+}
@@ -0,0 +1,149 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::factorization::CscSymbolicCholesky;
+use nalgebra_sparse::ordering::{approximate_minimum_degree, reverse_cuthill_mckee, OrderingMethod, Permutation};
+use nalgebra_sparse::pattern::SparsityPattern;
+use nalgebra_sparse::proptest::{csc, sparsity_pattern};
+use nalgebra_sparse::SparseFormatErrorKind;
+use nalgebra::DMatrix;
+use nalgebra::proptest::matrix;
+
+use proptest::prelude::*;
+use matrixcompare::prop_assert_matrix_eq;
+
+fn square_pattern() -> impl Strategy<Value=SparsityPattern> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| sparsity_pattern(n..=n, n..=n, PROPTEST_MAX_NNZ))
+}
+
+fn permutation() -> impl Strategy<Value=Permutation> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| Just((0..n).collect::<Vec<_>>()).prop_shuffle())
+        .prop_map(|indices| Permutation::try_from_indices(indices).unwrap())
+}
+
+// The pattern of the 5-point Laplacian on a `nx x ny` grid.
+fn grid_laplacian_pattern(nx: usize, ny: usize) -> SparsityPattern {
+    let n = nx * ny;
+    let mut offsets = vec![0];
+    let mut indices = Vec::new();
+
+    for j in 0..n {
+        let (x, y) = (j % nx, j / nx);
+        if y > 0 { indices.push(j - nx); }
+        if x > 0 { indices.push(j - 1); }
+        indices.push(j);
+        if x + 1 < nx { indices.push(j + 1); }
+        if y + 1 < ny { indices.push(j + nx); }
+        offsets.push(indices.len());
+    }
+
+    SparsityPattern::try_from_offsets_and_indices(n, n, offsets, indices).unwrap()
+}
+
+fn bandwidth(pattern: &SparsityPattern) -> usize {
+    pattern.entries().map(|(i, j)| i.abs_diff(j)).max().unwrap_or(0)
+}
+
+fn is_permutation(permutation: &Permutation, n: usize) -> bool {
+    let mut indices = permutation.indices().to_vec();
+    indices.sort_unstable();
+    indices == (0..n).collect::<Vec<_>>()
+        && (0..n).all(|k| permutation.inverse_indices()[permutation.indices()[k]] == k)
+}
+
+#[test]
+fn permutation_try_from_indices() {
+    let permutation = Permutation::try_from_indices(vec![1, 3, 0, 2]).unwrap();
+    assert_eq!(permutation.len(), 4);
+    assert_eq!(permutation.indices(), &[1, 3, 0, 2]);
+    assert_eq!(permutation.inverse_indices(), &[2, 0, 3, 1]);
+    assert_eq!(permutation.inverse().inverse(), permutation);
+    assert_eq!(Permutation::identity(3).indices(), &[0, 1, 2]);
+    assert!(Permutation::identity(0).is_empty());
+
+    let out_of_bounds = Permutation::try_from_indices(vec![1, 3, 0]).unwrap_err();
+    assert_eq!(out_of_bounds.kind(), &SparseFormatErrorKind::IndexOutOfBounds);
+    let duplicate = Permutation::try_from_indices(vec![1, 0, 1]).unwrap_err();
+    assert_eq!(duplicate.kind(), &SparseFormatErrorKind::DuplicateEntry);
+}
+
+#[test]
+fn rcm_reduces_bandwidth_of_shuffled_grid() {
+    let (nx, ny) = (8, 6);
+    let pattern = grid_laplacian_pattern(nx, ny);
+    // A deterministic scrambling of the grid vertices.
+    let shuffle = Permutation::try_from_indices((0..nx * ny).map(|i| (i * 7) % (nx * ny)).collect()).unwrap();
+    let shuffled = shuffle.permute_symmetric_pattern(&pattern);
+    assert!(bandwidth(&shuffled) > 2 * nx);
+
+    let permutation = reverse_cuthill_mckee(&shuffled);
+    assert!(bandwidth(&permutation.permute_symmetric_pattern(&shuffled)) <= nx);
+}
+
+#[test]
+fn amd_reduces_fill_in_of_grid() {
+    let pattern = grid_laplacian_pattern(10, 10);
+    let natural = CscSymbolicCholesky::factor(pattern.clone());
+    let amd = CscSymbolicCholesky::factor_with_ordering(pattern.clone(), &OrderingMethod::ApproximateMinimumDegree);
+
+    assert!(natural.permutation().is_none());
+    assert!(amd.permutation().is_some());
+    assert!(amd.l_pattern().nnz() < natural.l_pattern().nnz() * 3 / 4);
+}
+
+#[test]
+fn orderings_of_disconnected_pattern() {
+    // Two disconnected edges and two isolated vertices.
+    let pattern = SparsityPattern::try_from_offsets_and_indices(
+        6, 6, vec![0, 2, 4, 5, 7, 9, 10], vec![0, 3, 1, 4, 2, 0, 3, 1, 4, 5]).unwrap();
+
+    assert!(is_permutation(&approximate_minimum_degree(&pattern), 6));
+    let rcm = reverse_cuthill_mckee(&pattern);
+    assert!(is_permutation(&rcm, 6));
+    assert_eq!(bandwidth(&rcm.permute_symmetric_pattern(&pattern)), 1);
+}
+
+proptest! {
+    #[test]
+    fn orderings_are_permutations(pattern in square_pattern()) {
+        let n = pattern.major_dim();
+        prop_assert!(is_permutation(&approximate_minimum_degree(&pattern), n));
+        prop_assert!(is_permutation(&reverse_cuthill_mckee(&pattern), n));
+        prop_assert!(is_permutation(&OrderingMethod::Natural.compute(&pattern), n));
+    }
+
+    #[test]
+    fn permute_symmetric_csc_matches_dense(
+        (matrix, permutation) in permutation().prop_flat_map(|p| {
+            let n = p.len();
+            (csc(value_strategy::<i32>(), n..=n, n..=n, PROPTEST_MAX_NNZ), Just(p))
+        })
+    ) {
+        let dense = DMatrix::from(&matrix);
+        let p = permutation.indices();
+        let expected = DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| dense[(p[i], p[j])]);
+
+        let permuted = permutation.permute_symmetric_csc(&matrix);
+        prop_assert_matrix_eq!(permuted, expected);
+        prop_assert_eq!(permutation.permute_symmetric_pattern(matrix.pattern()), permuted.pattern().clone());
+    }
+
+    #[test]
+    fn permute_rows_roundtrip(
+        (b, permutation) in permutation().prop_flat_map(|p| {
+            let n = p.len();
+            (matrix(value_strategy::<i32>(), n, PROPTEST_MATRIX_DIM), Just(p))
+        })
+    ) {
+        let p = permutation.indices();
+        let expected = DMatrix::from_fn(b.nrows(), b.ncols(), |i, j| b[(p[i], j)]);
+
+        let mut permuted = b.clone();
+        permutation.permute_rows_mut(&mut permuted);
+        prop_assert_eq!(&permuted, &expected);
+
+        permutation.inverse_permute_rows_mut(&mut permuted);
+        prop_assert_eq!(permuted, b);
+    }
+}
@@ -0,0 +1,249 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+use crate::common::{diagonally_dominant, value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::factorization::CscCholesky;
+use nalgebra_sparse::iterative::{
+    bicgstab, conjugate_gradient, gmres, BlockJacobi, IdentityOperator, IncompleteCholesky,
+    IncompleteLu, Jacobi, LinearOperator, PreconditionerError, SolverOptions, SolverReport, Ssor,
+};
+use nalgebra_sparse::proptest::csc;
+use nalgebra::{DMatrix, DVector};
+
+use proptest::prelude::*;
+use matrixcompare::{assert_matrix_eq, prop_assert_matrix_eq};
+
+// Symmetric matrices made strictly diagonally dominant with a positive diagonal
+fn symmetric_diagonally_dominant() -> impl Strategy<Value=CscMatrix<f64>> {
+    PROPTEST_MATRIX_DIM
+        .prop_flat_map(|n| csc(value_strategy::<f64>(), n..=n, n..=n, PROPTEST_MAX_NNZ))
+        .prop_map(|a| {
+            let n = a.nrows();
+            a.transpose() + &a + CscMatrix::identity(n) * (10.0 * (n as f64 + 1.0))
+        })
+}
+
+/// The dense matrix of an operator, assembled column by column.
+fn operator_matrix(operator: &impl LinearOperator<f64>, n: usize) -> DMatrix<f64> {
+    let mut matrix = DMatrix::zeros(n, n);
+    let mut y = DVector::zeros(n);
+    for j in 0..n {
+        let mut e = DVector::zeros(n);
+        e[j] = 1.0;
+        operator.apply(&e, &mut y);
+        matrix.column_mut(j).copy_from(&y);
+    }
+    matrix
+}
+
+fn tridiagonal(n: usize, lower: f64, diagonal: f64, upper: f64) -> CscMatrix<f64> {
+    let dense = DMatrix::from_fn(n, n, |i, j| {
+        if i == j { diagonal } else if i == j + 1 { lower } else if j == i + 1 { upper } else { 0.0 }
+    });
+    CscMatrix::from(&dense)
+}
+
+// The five-point discretization of `-Δu + c ∂u/∂x` on a `k x k` grid, with upwinding of the
+// convection term, which is an M-matrix
+fn convection_diffusion_2d(k: usize, c: f64) -> CscMatrix<f64> {
+    let mut coo = CooMatrix::new(k * k, k * k);
+    for i in 0..k {
+        for j in 0..k {
+            let row = i * k + j;
+            coo.push(row, row, 4.0 + c);
+            if j > 0 { coo.push(row, row - 1, -1.0 - c); }
+            if j + 1 < k { coo.push(row, row + 1, -1.0); }
+            if i > 0 { coo.push(row, row - k, -1.0); }
+            if i + 1 < k { coo.push(row, row + k, -1.0); }
+        }
+    }
+    CscMatrix::from(&coo)
+}
+
+#[test]
+fn jacobi_and_block_jacobi() {
+    let dense = DMatrix::from_row_slice(5, 5, &[
+        4.0, 1.0, 0.0, 2.0, 0.0,
+        2.0, 5.0, 0.0, 0.0, 1.0,
+        0.0, 1.0, 3.0, 1.0, 0.0,
+        1.0, 0.0, 2.0, 6.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 2.0]);
+    let a = CscMatrix::from(&dense);
+    let x = DVector::from_column_slice(&[1.0, -2.0, 3.0, 0.5, 4.0]);
+    let mut y = DVector::zeros(5);
+
+    let jacobi = Jacobi::new(&a).unwrap();
+    jacobi.apply(&x, &mut y);
+    assert_matrix_eq!(y, x.component_div(&dense.diagonal()), comp = float);
+
+    // Blocks of size 2, with a trailing block of size 1
+    let block_jacobi = BlockJacobi::new(&a, 2).unwrap();
+    assert_eq!(block_jacobi.inverse_blocks().len(), 3);
+    block_jacobi.apply(&x, &mut y);
+    for &(start, len) in &[(0, 2), (2, 2), (4, 1)] {
+        let block = dense.slice((start, start), (len, len));
+        assert_matrix_eq!(block * y.rows(start, len), x.rows(start, len), comp = abs, tol = 1e-12);
+    }
+
+    // A single block gives the exact inverse
+    BlockJacobi::new(&a, 5).unwrap().apply(&x, &mut y);
+    assert_matrix_eq!(&dense * &y, x, comp = abs, tol = 1e-12);
+
+    let mut singular = dense.clone();
+    singular[(2, 2)] = 0.0;
+    assert_eq!(Jacobi::new(&CscMatrix::from(&singular)), Err(PreconditionerError::Singular));
+    singular[(4, 4)] = 0.0;
+    assert_eq!(BlockJacobi::new(&CscMatrix::from(&singular), 4), Err(PreconditionerError::Singular));
+}
+
+#[test]
+fn incomplete_factorizations_of_tridiagonal_matrices_are_exact() {
+    let n = 8;
+    let a = tridiagonal(n, -1.0, 2.0, -1.0);
+    let ic = IncompleteCholesky::factor(&a).unwrap();
+    let cholesky = CscCholesky::factor(&a).unwrap();
+    assert_matrix_eq!(ic.l(), cholesky.l(), comp = abs, tol = 1e-12);
+
+    let b = DVector::from_fn(n, |i, _| 1.0 + i as f64);
+    let mut x = DVector::zeros(n);
+    ic.apply(&b, &mut x);
+    assert_matrix_eq!(DMatrix::from(&a) * &x, b, comp = abs, tol = 1e-12);
+
+    let a = tridiagonal(n, -2.0, 4.0, 1.0);
+    for ilu in [IncompleteLu::factor(&a).unwrap(), IncompleteLu::factor_with_threshold(&a, 0.0, 1).unwrap()] {
+        let lu = DMatrix::from(ilu.l()) * DMatrix::from(&ilu.u());
+        assert_matrix_eq!(lu, DMatrix::from(&a), comp = abs, tol = 1e-12);
+        assert_eq!(ilu.l().nnz(), 2 * n - 1);
+        assert_eq!(ilu.u().nnz(), 2 * n - 1);
+        ilu.apply(&b, &mut x);
+        assert_matrix_eq!(DMatrix::from(&a) * &x, b, comp = abs, tol = 1e-12);
+    }
+}
+
+#[test]
+fn ssor_with_unit_relaxation_is_symmetric_gauss_seidel() {
+    let a = convection_diffusion_2d(3, 0.5);
+    let dense = DMatrix::from(&a);
+    let ssor = Ssor::new(&a, 1.0).unwrap();
+    assert_eq!(ssor.omega(), 1.0);
+
+    // M = (D + L) D^-1 (D + U)
+    let lower = dense.lower_triangle();
+    let upper = dense.upper_triangle();
+    let diagonal = DMatrix::from_diagonal(&dense.diagonal());
+    let m = lower * diagonal.try_inverse().unwrap() * upper;
+    assert_matrix_eq!(operator_matrix(&ssor, 9) * m, DMatrix::identity(9, 9), comp = abs, tol = 1e-12);
+}
+
+#[test]
+fn preconditioner_construction_errors() {
+    // Missing diagonal entry
+    let a = CscMatrix::from(&DMatrix::from_row_slice(2, 2, &[0.0, 1.0, 1.0, 2.0]));
+    assert_eq!(Ssor::new(&a, 1.0), Err(PreconditionerError::Singular));
+    assert_eq!(IncompleteLu::factor(&a), Err(PreconditionerError::Singular));
+    assert_eq!(IncompleteLu::factor_with_threshold(&a, 0.0, 2), Err(PreconditionerError::Singular));
+    assert_eq!(IncompleteCholesky::factor(&a), Err(PreconditionerError::NotPositiveDefinite));
+
+    // Zero pivot after elimination
+    let a = CscMatrix::from(&DMatrix::from_row_slice(2, 2, &[1.0, 1.0, 1.0, 1.0]));
+    assert_eq!(IncompleteLu::factor(&a), Err(PreconditionerError::Singular));
+    assert_eq!(IncompleteCholesky::factor(&a), Err(PreconditionerError::NotPositiveDefinite));
+
+    let indefinite = tridiagonal(4, 1.0, 1.0, 1.0);
+    assert_eq!(IncompleteCholesky::factor(&indefinite), Err(PreconditionerError::NotPositiveDefinite));
+}
+
+/// Runs a solver from a zero initial guess, checks that it converged, and returns the number of
+/// iterations.
+fn iterations(a: &CscMatrix<f64>, b: &DVector<f64>, solver: impl FnOnce(&mut DVector<f64>) -> SolverReport<f64>) -> usize {
+    let mut x = DVector::zeros(b.len());
+    let report = solver(&mut x);
+    assert!(report.converged());
+    assert!((b - DMatrix::from(a) * &x).norm() <= 1e-8 * b.norm());
+    report.iterations
+}
+
+#[test]
+fn preconditioners_accelerate_krylov_solvers() {
+    let options = SolverOptions::new(1e-10, 1000);
+
+    // Symmetric positive definite diffusion problem with conjugate gradient
+    let a = convection_diffusion_2d(12, 0.0);
+    let b = DVector::from_fn(a.nrows(), |i, _| ((i + 1) as f64).sin());
+    let ssor = Ssor::new(&a, 1.5).unwrap();
+    let ic = IncompleteCholesky::factor(&a).unwrap();
+    let none = iterations(&a, &b, |x| conjugate_gradient(&a, &IdentityOperator, &b, x, &options));
+    let with_ssor = iterations(&a, &b, |x| conjugate_gradient(&a, &ssor, &b, x, &options));
+    let with_ic = iterations(&a, &b, |x| conjugate_gradient(&a, &ic, &b, x, &options));
+    assert!(with_ssor < none);
+    assert!(with_ic < none);
+
+    // Nonsymmetric convection-diffusion problem with GMRES and BiCGSTAB
+    let a = convection_diffusion_2d(12, 5.0);
+    let b = DVector::from_fn(a.nrows(), |i, _| ((i + 1) as f64).sin());
+    let ilu0 = IncompleteLu::factor(&a).unwrap();
+    let ilut = IncompleteLu::factor_with_threshold(&a, 1e-3, 10).unwrap();
+    let none = iterations(&a, &b, |x| gmres(&a, &IdentityOperator, &b, x, 30, &options));
+    let with_ilu0 = iterations(&a, &b, |x| gmres(&a, &ilu0, &b, x, 30, &options));
+    let with_ilut = iterations(&a, &b, |x| gmres(&a, &ilut, &b, x, 30, &options));
+    assert!(with_ilu0 < none);
+    assert!(with_ilut < with_ilu0);
+
+    let none = iterations(&a, &b, |x| bicgstab(&a, &IdentityOperator, &b, x, &options));
+    let with_ilu0 = iterations(&a, &b, |x| bicgstab(&a, &ilu0, &b, x, &options));
+    assert!(with_ilu0 < none);
+}
+
+proptest! {
+    #[test]
+    fn incomplete_cholesky_agrees_with_matrix_on_pattern(a in symmetric_diagonally_dominant()) {
+        let ic = IncompleteCholesky::factor(&a).unwrap();
+        let l = DMatrix::from(ic.l());
+        let llt = &l * l.transpose();
+        for (i, j, &a_ij) in a.triplet_iter() {
+            prop_assert!((llt[(i, j)] - a_ij).abs() <= 1e-10);
+        }
+        prop_assert!(ic.l().triplet_iter().all(|(i, j, _)| i >= j && a.get_entry(i, j).is_some()));
+    }
+
+    #[test]
+    fn ilu0_agrees_with_matrix_on_pattern(a in diagonally_dominant()) {
+        let ilu = IncompleteLu::factor(&a).unwrap();
+        let lu = DMatrix::from(ilu.l()) * DMatrix::from(&ilu.u());
+        for (i, j, &a_ij) in a.triplet_iter() {
+            prop_assert!((lu[(i, j)] - a_ij).abs() <= 1e-10);
+        }
+        prop_assert!(ilu.l().triplet_iter().all(|(i, j, &v)| i > j && a.get_entry(i, j).is_some() || i == j && v == 1.0));
+        prop_assert!(ilu.u().triplet_iter().all(|(i, j, _)| i <= j && a.get_entry(i, j).is_some()));
+    }
+
+    #[test]
+    fn ilut_without_dropping_is_exact(a in diagonally_dominant()) {
+        let n = a.nrows();
+        let ilu = IncompleteLu::factor_with_threshold(&a, 0.0, n).unwrap();
+        prop_assert_matrix_eq!(operator_matrix(&ilu, n) * DMatrix::from(&a), DMatrix::identity(n, n), comp = abs, tol = 1e-10);
+    }
+
+    #[test]
+    fn ilut_respects_fill_limit(a in diagonally_dominant(), max_fill in 0..3usize) {
+        let ilu = IncompleteLu::factor_with_threshold(&a, 1e-2, max_fill).unwrap();
+        for i in 0..a.nrows() {
+            prop_assert!(ilu.l().transpose().col(i).nnz() <= max_fill + 1);
+            prop_assert!(ilu.u().transpose().col(i).nnz() <= max_fill + 1);
+        }
+    }
+
+    #[test]
+    fn ssor_matches_dense_formula(a in diagonally_dominant(), omega in prop::sample::select(vec![0.5, 1.0, 1.2, 1.8])) {
+        let n = a.nrows();
+        let dense = DMatrix::from(&a);
+        let ssor = Ssor::new(&a, omega).unwrap();
+
+        // M = ω / (2 - ω) (D / ω + L) (D / ω)^-1 (D / ω + U)
+        let d = DMatrix::from_diagonal(&dense.diagonal()) / omega;
+        let lower = dense.lower_triangle() - DMatrix::from_diagonal(&dense.diagonal()) + &d;
+        let upper = dense.upper_triangle() - DMatrix::from_diagonal(&dense.diagonal()) + &d;
+        let m = lower * d.try_inverse().unwrap() * upper * (omega / (2.0 - omega));
+        prop_assert_matrix_eq!(operator_matrix(&ssor, n) * m, DMatrix::identity(n, n), comp = abs, tol = 1e-10);
+    }
+}
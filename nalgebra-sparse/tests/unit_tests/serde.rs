@@ -0,0 +1,157 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::pattern::SparsityPattern;
+
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+/// Tokens of a sequence of `usize` values.
+fn index_tokens(indices: &[usize]) -> Vec<Token> {
+    let mut tokens = vec![Token::Seq { len: Some(indices.len()) }];
+    tokens.extend(indices.iter().map(|&i| Token::U64(i as u64)));
+    tokens.push(Token::SeqEnd);
+    tokens
+}
+
+/// Tokens of a sequence of `f64` values.
+fn value_tokens(values: &[f64]) -> Vec<Token> {
+    let mut tokens = vec![Token::Seq { len: Some(values.len()) }];
+    tokens.extend(values.iter().map(|&v| Token::F64(v)));
+    tokens.push(Token::SeqEnd);
+    tokens
+}
+
+fn pattern_tokens(major_offsets: &[usize], minor_indices: &[usize], minor_dim: usize) -> Vec<Token> {
+    let mut tokens = vec![Token::Struct { name: "SparsityPattern", len: 3 }];
+    tokens.push(Token::Str("major_offsets"));
+    tokens.extend(index_tokens(major_offsets));
+    tokens.push(Token::Str("minor_indices"));
+    tokens.extend(index_tokens(minor_indices));
+    tokens.push(Token::Str("minor_dim"));
+    tokens.push(Token::U64(minor_dim as u64));
+    tokens.push(Token::StructEnd);
+    tokens
+}
+
+/// Tokens of a matrix serialized as its dimensions followed by two index arrays and the values.
+fn matrix_tokens(
+    name: &'static str,
+    (nrows, ncols): (usize, usize),
+    (first_name, first): (&'static str, &[usize]),
+    (second_name, second): (&'static str, &[usize]),
+    values: &[f64],
+) -> Vec<Token> {
+    let mut tokens = vec![Token::Struct { name, len: 5 }];
+    tokens.push(Token::Str("nrows"));
+    tokens.push(Token::U64(nrows as u64));
+    tokens.push(Token::Str("ncols"));
+    tokens.push(Token::U64(ncols as u64));
+    tokens.push(Token::Str(first_name));
+    tokens.extend(index_tokens(first));
+    tokens.push(Token::Str(second_name));
+    tokens.extend(index_tokens(second));
+    tokens.push(Token::Str("values"));
+    tokens.extend(value_tokens(values));
+    tokens.push(Token::StructEnd);
+    tokens
+}
+
+fn coo_tokens(nrows: usize, ncols: usize, row_indices: &[usize], col_indices: &[usize], values: &[f64]) -> Vec<Token> {
+    matrix_tokens("CooMatrix", (nrows, ncols), ("row_indices", row_indices), ("col_indices", col_indices), values)
+}
+
+fn csr_tokens(nrows: usize, ncols: usize, row_offsets: &[usize], col_indices: &[usize], values: &[f64]) -> Vec<Token> {
+    matrix_tokens("CsrMatrix", (nrows, ncols), ("row_offsets", row_offsets), ("col_indices", col_indices), values)
+}
+
+fn csc_tokens(nrows: usize, ncols: usize, col_offsets: &[usize], row_indices: &[usize], values: &[f64]) -> Vec<Token> {
+    matrix_tokens("CscMatrix", (nrows, ncols), ("col_offsets", col_offsets), ("row_indices", row_indices), values)
+}
+
+#[test]
+fn sparsity_pattern_roundtrip() {
+    assert_tokens(&SparsityPattern::zeros(0, 0), &pattern_tokens(&[0], &[], 0));
+    assert_tokens(&SparsityPattern::zeros(3, 2), &pattern_tokens(&[0, 0, 0, 0], &[], 2));
+
+    let pattern = SparsityPattern::try_from_offsets_and_indices(3, 4, vec![0, 2, 2, 3], vec![0, 3, 1]).unwrap();
+    assert_tokens(&pattern, &pattern_tokens(&[0, 2, 2, 3], &[0, 3, 1], 4));
+}
+
+#[test]
+fn sparsity_pattern_deserialize_invalid() {
+    let offsets_msg = "Length of offset array is not equal to (major_dim + 1).";
+    let offset_msg = "First or last offset is incompatible with format.";
+    let bounds_msg = "A minor index is out of bounds.";
+    let duplicate_msg = "Input data contains duplicate entries.";
+    let sorted_msg = "Minor indices are not monotonically increasing within each lane.";
+
+    assert_de_tokens_error::<SparsityPattern>(&pattern_tokens(&[], &[], 4), offsets_msg);
+    assert_de_tokens_error::<SparsityPattern>(&pattern_tokens(&[1, 2, 3], &[0, 3, 1], 4), offset_msg);
+    assert_de_tokens_error::<SparsityPattern>(&pattern_tokens(&[0, 2, 2, 2], &[0, 3, 1], 4), offset_msg);
+    assert_de_tokens_error::<SparsityPattern>(&pattern_tokens(&[0, 2, 2, 3], &[0, 4, 1], 4), bounds_msg);
+    assert_de_tokens_error::<SparsityPattern>(&pattern_tokens(&[0, 2, 2, 3], &[3, 3, 1], 4), duplicate_msg);
+    assert_de_tokens_error::<SparsityPattern>(&pattern_tokens(&[0, 2, 2, 3], &[3, 0, 1], 4), sorted_msg);
+}
+
+#[test]
+fn coo_roundtrip() {
+    assert_tokens(&CooMatrix::<f64>::new(0, 0), &coo_tokens(0, 0, &[], &[], &[]));
+    assert_tokens(&CooMatrix::<f64>::new(3, 4), &coo_tokens(3, 4, &[], &[], &[]));
+
+    let coo = CooMatrix::try_from_triplets(3, 4, vec![0, 2, 0, 1], vec![1, 3, 1, 0], vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_tokens(&coo, &coo_tokens(3, 4, &[0, 2, 0, 1], &[1, 3, 1, 0], &[1.0, 2.0, 3.0, 4.0]));
+}
+
+#[test]
+fn coo_deserialize_invalid() {
+    assert_de_tokens_error::<CooMatrix<f64>>(&coo_tokens(3, 4, &[0, 3], &[1, 3], &[1.0, 2.0]), "Row index out of bounds.");
+    assert_de_tokens_error::<CooMatrix<f64>>(&coo_tokens(3, 4, &[0, 2], &[1, 4], &[1.0, 2.0]), "Col index out of bounds.");
+    assert_de_tokens_error::<CooMatrix<f64>>(&coo_tokens(3, 4, &[0, 2], &[1], &[1.0, 2.0]), "Number of row and col indices must be the same.");
+    assert_de_tokens_error::<CooMatrix<f64>>(&coo_tokens(3, 4, &[0, 2], &[1, 3], &[1.0]), "Number of col indices and values must be the same.");
+}
+
+#[test]
+fn csr_roundtrip() {
+    assert_tokens(&CsrMatrix::<f64>::zeros(0, 0), &csr_tokens(0, 0, &[0], &[], &[]));
+    assert_tokens(&CsrMatrix::<f64>::zeros(3, 4), &csr_tokens(3, 4, &[0, 0, 0, 0], &[], &[]));
+
+    let csr = CsrMatrix::try_from_csr_data(3, 4, vec![0, 2, 2, 3], vec![0, 3, 1], vec![1.0, 2.0, 3.0]).unwrap();
+    assert_tokens(&csr, &csr_tokens(3, 4, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn csr_deserialize_invalid() {
+    let offsets_msg = "Length of row offset array is not equal to nrows + 1.";
+    let bounds_msg = "Column indices are out of bounds.";
+    let sorted_msg = "Column indices are not monotonically increasing (sorted) within each row.";
+    let values_msg = "Number of values and column indices must be the same";
+
+    assert_de_tokens_error::<CsrMatrix<f64>>(&csr_tokens(2, 4, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0]), offsets_msg);
+    assert_de_tokens_error::<CsrMatrix<f64>>(&csr_tokens(3, 3, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0]), bounds_msg);
+    assert_de_tokens_error::<CsrMatrix<f64>>(&csr_tokens(3, 4, &[0, 2, 2, 3], &[3, 0, 1], &[1.0, 2.0, 3.0]), sorted_msg);
+    assert_de_tokens_error::<CsrMatrix<f64>>(&csr_tokens(3, 4, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0]), values_msg);
+}
+
+#[test]
+fn csc_roundtrip() {
+    assert_tokens(&CscMatrix::<f64>::zeros(0, 0), &csc_tokens(0, 0, &[0], &[], &[]));
+    assert_tokens(&CscMatrix::<f64>::zeros(3, 4), &csc_tokens(3, 4, &[0, 0, 0, 0, 0], &[], &[]));
+
+    let csc = CscMatrix::try_from_csc_data(4, 3, vec![0, 2, 2, 3], vec![0, 3, 1], vec![1.0, 2.0, 3.0]).unwrap();
+    assert_tokens(&csc, &csc_tokens(4, 3, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn csc_deserialize_invalid() {
+    let offsets_msg = "Length of col offset array is not equal to ncols + 1.";
+    let bounds_msg = "Row indices are out of bounds.";
+    let duplicate_msg = "Matrix data contains duplicate entries.";
+    let values_msg = "Number of values and row indices must be the same";
+
+    assert_de_tokens_error::<CscMatrix<f64>>(&csc_tokens(4, 2, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0]), offsets_msg);
+    assert_de_tokens_error::<CscMatrix<f64>>(&csc_tokens(3, 3, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0]), bounds_msg);
+    assert_de_tokens_error::<CscMatrix<f64>>(&csc_tokens(4, 3, &[0, 2, 2, 3], &[3, 3, 1], &[1.0, 2.0, 3.0]), duplicate_msg);
+    assert_de_tokens_error::<CscMatrix<f64>>(&csc_tokens(4, 3, &[0, 2, 2, 3], &[0, 3, 1], &[1.0, 2.0, 3.0, 4.0]), values_msg);
+}
@@ -8,7 +8,7 @@ use crate::allocator::Allocator;
 use crate::base::{DefaultAllocator, Dim, DimName, Matrix, MatrixMN, Normed, VectorN};
 use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::storage::{Storage, StorageMut};
-use crate::{ComplexField, Scalar, SimdComplexField, Unit};
+use crate::{ComplexField, RealField, Scalar, SimdComplexField, Unit};
 use simba::scalar::ClosedNeg;
 use simba::simd::{SimdOption, SimdPartialOrd, SimdValue};
 
@@ -444,6 +444,53 @@ impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
     }
 }
 
+/// # Induced matrix norms
+impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// The 1-norm of this matrix, i.e., its maximum absolute column sum.
+    ///
+    /// This is the matrix norm induced by the vector 1-norm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        -4.0, 5.0, 6.0);
+    /// assert_eq!(m.one_norm(), 9.0);
+    /// ```
+    #[inline]
+    pub fn one_norm(&self) -> N::RealField {
+        self.column_iter()
+            .map(|col| {
+                col.iter()
+                    .fold(N::RealField::zero(), |a, e| a + e.modulus())
+            })
+            .fold(N::RealField::zero(), |a, b| a.max(b))
+    }
+
+    /// The ∞-norm of this matrix, i.e., its maximum absolute row sum.
+    ///
+    /// This is the matrix norm induced by the vector ∞-norm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        -4.0, 5.0, 6.0);
+    /// assert_eq!(m.infinity_norm(), 15.0);
+    /// ```
+    #[inline]
+    pub fn infinity_norm(&self) -> N::RealField {
+        self.row_iter()
+            .map(|row| {
+                row.iter()
+                    .fold(N::RealField::zero(), |a, e| a + e.modulus())
+            })
+            .fold(N::RealField::zero(), |a, b| a.max(b))
+    }
+}
+
 impl<N: SimdComplexField, R: Dim, C: Dim> Normed for MatrixMN<N, R, C>
 where
     DefaultAllocator: Allocator<N, R, C>,
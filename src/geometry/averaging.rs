@@ -0,0 +1,393 @@
+use simba::scalar::RealField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimDiff, DimMin, DimName, DimSub, U1};
+use crate::base::{DefaultAllocator, Matrix4, MatrixN, Vector3, Vector6};
+use crate::linalg::SymmetricEigen;
+
+use crate::geometry::{Isometry3, Quaternion, Rotation, Rotation3, Translation3, UnitQuaternion};
+
+/*
+ *
+ * In the following, we provide weighted means of rotations and poses:
+ * =========================
+ *
+ * Chordal L2 mean: `Rotation::mean`, `UnitQuaternion::mean`, `Isometry3::mean`
+ * Geodesic (Karcher) mean: `Rotation3::geodesic_mean`, `UnitQuaternion::geodesic_mean`,
+ *                          `Isometry3::geodesic_mean`
+ *
+ * The chordal mean minimizes the weighted sum of the squared Frobenius distances between the
+ * rotation matrices and is computed in closed form. The geodesic mean minimizes the weighted sum
+ * of the squared rotation angles, and is computed iteratively starting from the chordal mean.
+ *
+ */
+
+/// The sum of the weights of `len` elements, or `None` if it is not positive.
+fn total_weight<N: RealField>(len: usize, weights: Option<&[N]>) -> Option<N> {
+    let total = match weights {
+        Some(weights) => {
+            assert_eq!(
+                weights.len(),
+                len,
+                "Averaging: there must be exactly one weight per element."
+            );
+            weights.iter().fold(N::zero(), |acc, w| acc + *w)
+        }
+        None => crate::convert(len as f64),
+    };
+
+    if total > N::zero() {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// Whether the iterations of a geodesic mean have converged after an update of norm `step`,
+/// following an update of norm `last_step`.
+///
+/// The updates can't be computed more accurately than the data, so the iterations also stop once
+/// the updates stop decreasing, provided they are already below the square root of `tol`.
+fn has_converged<N: RealField>(step: N, last_step: Option<N>, tol: N) -> bool {
+    step <= tol || (matches!(last_step, Some(last) if step >= last) && step * step <= tol)
+}
+
+/// # Averaging
+impl<N: RealField, D> Rotation<N, D>
+where
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    /// The weighted chordal L2 mean of the given rotations.
+    ///
+    /// This is the rotation closest, in the Frobenius norm, to the weighted arithmetic mean of the
+    /// rotation matrices. It is computed by projecting this arithmetic mean onto the rotations
+    /// with an SVD. If `weights` is `None`, all the rotations have the same weight. Otherwise the
+    /// weights must be non-negative.
+    ///
+    /// Returns `None` if the total weight is zero, in particular if there is no rotation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `rotations` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let axis = Vector3::z_axis();
+    /// let rotations = [
+    ///     Rotation3::from_axis_angle(&axis, 0.1),
+    ///     Rotation3::from_axis_angle(&axis, 0.5),
+    /// ];
+    ///
+    /// let mean = Rotation3::mean(&rotations, None).unwrap();
+    /// assert_relative_eq!(mean, Rotation3::from_axis_angle(&axis, 0.3), epsilon = 1.0e-10);
+    ///
+    /// let mean = Rotation3::mean(&rotations, Some(&[1.0, 0.0])).unwrap();
+    /// assert_relative_eq!(mean, rotations[0], epsilon = 1.0e-10);
+    /// ```
+    pub fn mean(rotations: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        let _ = total_weight(rotations.len(), weights)?;
+
+        let mut sum = MatrixN::<N, D>::zeros();
+        for (i, rotation) in rotations.iter().enumerate() {
+            let w = weights.map_or(N::one(), |w| w[i]);
+            sum += rotation.matrix() * w;
+        }
+
+        let svd = sum.svd(true, true);
+        let mut u = svd.u?;
+        let v_t = svd.v_t?;
+
+        // Flip the direction associated to the smallest singular value if the closest orthogonal
+        // matrix is a reflection.
+        if u.determinant() * v_t.determinant() < N::zero() {
+            let imin = svd.singular_values.imin();
+            u.column_mut(imin).neg_mut();
+        }
+
+        Some(Self::from_matrix_unchecked(u * v_t))
+    }
+}
+
+/// # Averaging
+impl<N: RealField> Rotation3<N> {
+    /// The weighted geodesic mean, also known as the Karcher mean, of the given rotations.
+    ///
+    /// This is an iterative method. See `Self::geodesic_mean_eps` to provide more convergence
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let rotations = [
+    ///     Rotation3::new(Vector3::new(0.1, 0.0, 0.0)),
+    ///     Rotation3::new(Vector3::new(0.0, 0.2, 0.0)),
+    ///     Rotation3::new(Vector3::new(0.0, 0.0, 0.3)),
+    /// ];
+    /// let mean = Rotation3::geodesic_mean(&rotations, None).unwrap();
+    ///
+    /// // The mean of the tangent vectors around the geodesic mean vanishes.
+    /// let sum = rotations.iter().fold(Vector3::zeros(), |acc, r| acc + (mean.inverse() * r).log());
+    /// assert_relative_eq!(sum, Vector3::zeros(), epsilon = 1.0e-10);
+    /// ```
+    pub fn geodesic_mean(rotations: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        Self::geodesic_mean_eps(rotations, weights, N::default_epsilon(), 100)
+    }
+
+    /// The weighted geodesic mean, also known as the Karcher mean, of the given rotations.
+    ///
+    /// This converts the rotations to unit quaternions and calls
+    /// `UnitQuaternion::geodesic_mean_eps`, which documents the parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `rotations` don't have the same length.
+    pub fn geodesic_mean_eps(
+        rotations: &[Self],
+        weights: Option<&[N]>,
+        eps: N,
+        max_iter: usize,
+    ) -> Option<Self> {
+        let quaternions: Vec<_> = rotations
+            .iter()
+            .map(UnitQuaternion::from_rotation_matrix)
+            .collect();
+        UnitQuaternion::geodesic_mean_eps(&quaternions, weights, eps, max_iter)
+            .map(|mean| mean.to_rotation_matrix())
+    }
+}
+
+/// # Averaging
+impl<N: RealField> UnitQuaternion<N> {
+    /// The weighted chordal L2 mean of the given rotations.
+    ///
+    /// This implements "Averaging Quaternions" by Markley et al., 2007: the mean is the
+    /// eigenvector of the largest eigenvalue of the weighted sum of the matrices `q * qᵀ`. It
+    /// doesn't depend on the signs of the quaternions, and it represents the same rotation as
+    /// `Rotation3::mean`. If `weights` is `None`, all the rotations have the same weight.
+    /// Otherwise the weights must be non-negative.
+    ///
+    /// Returns `None` if the total weight is zero, in particular if there is no rotation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `rotations` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let axis = Vector3::y_axis();
+    /// let q1 = UnitQuaternion::from_axis_angle(&axis, 0.2);
+    /// let q2 = UnitQuaternion::from_axis_angle(&axis, 0.6);
+    /// // `-q2` represents the same rotation as `q2`.
+    /// let q2 = UnitQuaternion::new_unchecked(-q2.into_inner());
+    ///
+    /// let mean = UnitQuaternion::mean(&[q1, q2], None).unwrap();
+    /// assert_relative_eq!(mean.angle_to(&UnitQuaternion::from_axis_angle(&axis, 0.4)), 0.0, epsilon = 1.0e-7);
+    /// ```
+    pub fn mean(rotations: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        let _ = total_weight(rotations.len(), weights)?;
+
+        let mut sum = Matrix4::zeros();
+        for (i, rotation) in rotations.iter().enumerate() {
+            let w = weights.map_or(N::one(), |w| w[i]);
+            sum.ger(w, &rotation.coords, &rotation.coords, N::one());
+        }
+
+        let eigen = SymmetricEigen::new(sum);
+        let imax = eigen.eigenvalues.imax();
+        let coords = eigen.eigenvectors.column(imax).into_owned();
+
+        Some(Self::new_normalize(Quaternion::from(coords)))
+    }
+
+    /// The weighted geodesic mean, also known as the Karcher mean, of the given rotations.
+    ///
+    /// This is an iterative method. See `Self::geodesic_mean_eps` to provide more convergence
+    /// parameters.
+    pub fn geodesic_mean(rotations: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        Self::geodesic_mean_eps(rotations, weights, N::default_epsilon(), 100)
+    }
+
+    /// The weighted geodesic mean, also known as the Karcher mean, of the given rotations.
+    ///
+    /// The mean is computed by Riemannian gradient descent starting from the chordal mean. If
+    /// `weights` is `None`, all the rotations have the same weight. Otherwise the weights must be
+    /// non-negative. Returns `None` if the total weight is zero or if the iterations don't converge.
+    ///
+    /// # Parameters
+    ///
+    /// * `rotations`: the rotations to average.
+    /// * `weights`: the weight of each rotation.
+    /// * `eps`: the angle of the update under which the iterations stop. They also stop once the
+    ///   rounding errors prevent the updates from decreasing below `eps`, if they are already
+    ///   smaller than `eps.sqrt()`.
+    /// * `max_iter`: the maximum number of iterations. Loops indefinitely until convergence if set to `0`.
+    ///   If this number of iterations is exceeded, `None` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `rotations` don't have the same length.
+    pub fn geodesic_mean_eps(
+        rotations: &[Self],
+        weights: Option<&[N]>,
+        eps: N,
+        mut max_iter: usize,
+    ) -> Option<Self> {
+        let total = total_weight(rotations.len(), weights)?;
+        let mut mean = Self::mean(rotations, weights)?;
+
+        if max_iter == 0 {
+            max_iter = usize::MAX;
+        }
+
+        let mut last_step = None;
+
+        for _ in 0..max_iter {
+            let mut step = Vector3::zeros();
+            for (i, rotation) in rotations.iter().enumerate() {
+                let w = weights.map_or(N::one(), |w| w[i]);
+                step.axpy(w, &(mean.inverse() * rotation).log(), N::one());
+            }
+            step /= total;
+
+            mean *= Self::from_scaled_axis(step);
+            let _ = mean.renormalize();
+
+            let step = step.norm();
+            if has_converged(step, last_step, eps) {
+                return Some(mean);
+            }
+            last_step = Some(step);
+        }
+
+        None
+    }
+}
+
+/// # Averaging
+impl<N: RealField> Isometry3<N> {
+    /// The weighted chordal L2 mean of the given poses.
+    ///
+    /// The rotational part is the mean computed by `UnitQuaternion::mean`, and the translational
+    /// part is the weighted arithmetic mean of the translations. If `weights` is `None`, all the
+    /// poses have the same weight. Otherwise the weights must be non-negative.
+    ///
+    /// Returns `None` if the total weight is zero, in particular if there is no pose.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `poses` don't have the same length.
+    pub fn mean(poses: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        let total = total_weight(poses.len(), weights)?;
+
+        let mut translation = Vector3::zeros();
+        for (i, pose) in poses.iter().enumerate() {
+            let w = weights.map_or(N::one(), |w| w[i]);
+            translation.axpy(w, &pose.translation.vector, N::one());
+        }
+        translation /= total;
+
+        let rotations: Vec<_> = poses.iter().map(|pose| pose.rotation).collect();
+        let rotation = UnitQuaternion::mean(&rotations, weights)?;
+
+        Some(Self::from_parts(Translation3::from(translation), rotation))
+    }
+
+    /// The weighted geodesic mean of the given poses in SE(3).
+    ///
+    /// This is an iterative method. See `Self::geodesic_mean_eps` to provide more convergence
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector3};
+    /// let poses = [
+    ///     Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.5)),
+    ///     Isometry3::new(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, -0.5)),
+    /// ];
+    /// let mean = Isometry3::geodesic_mean(&poses, None).unwrap();
+    ///
+    /// // The mean of the twists around the geodesic mean vanishes.
+    /// let sum = (mean.inverse() * poses[0]).log() + (mean.inverse() * poses[1]).log();
+    /// assert_relative_eq!(sum, nalgebra::Vector6::zeros(), epsilon = 1.0e-10);
+    /// assert_relative_eq!(mean.rotation.angle(), 0.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn geodesic_mean(poses: &[Self], weights: Option<&[N]>) -> Option<Self> {
+        Self::geodesic_mean_eps(poses, weights, N::default_epsilon(), 100)
+    }
+
+    /// The weighted geodesic mean of the given poses in SE(3).
+    ///
+    /// This is the pose around which the weighted mean of the twists `(mean⁻¹ * pose).log()`
+    /// vanishes. It is computed by Gauss-Newton iterations starting from the chordal mean. If
+    /// `weights` is `None`, all the poses have the same weight. Otherwise the weights must be
+    /// non-negative. Returns `None` if the total weight is zero or if the iterations don't converge.
+    ///
+    /// # Parameters
+    ///
+    /// * `poses`: the poses to average.
+    /// * `weights`: the weight of each pose.
+    /// * `eps`: the relative tolerance of the iterations. They stop when the norm of the twist of
+    ///   the update is below `eps` times the norm of the largest translation, or `eps` if all the
+    ///   translations are shorter than one. Like for rotations, they also stop once the rounding
+    ///   errors prevent the updates from decreasing below this tolerance, if they are already
+    ///   smaller than its square root.
+    /// * `max_iter`: the maximum number of iterations. Loops indefinitely until convergence if set to `0`.
+    ///   If this number of iterations is exceeded, `None` is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `poses` don't have the same length.
+    pub fn geodesic_mean_eps(
+        poses: &[Self],
+        weights: Option<&[N]>,
+        eps: N,
+        mut max_iter: usize,
+    ) -> Option<Self> {
+        let total = total_weight(poses.len(), weights)?;
+        let mut mean = Self::mean(poses, weights)?;
+
+        // The twists can't be computed more accurately than the translations are represented.
+        let tol = poses.iter().fold(N::one(), |acc, pose| {
+            acc.max(pose.translation.vector.norm())
+        }) * eps;
+        let mut last_step = None;
+
+        if max_iter == 0 {
+            max_iter = usize::MAX;
+        }
+
+        for _ in 0..max_iter {
+            let mut step = Vector6::zeros();
+            for (i, pose) in poses.iter().enumerate() {
+                let w = weights.map_or(N::one(), |w| w[i]);
+                step.axpy(w, &mean.inv_mul(pose).log(), N::one());
+            }
+            step /= total;
+
+            mean *= Self::exp(step);
+            let _ = mean.rotation.renormalize();
+
+            let step = step.norm();
+            if has_converged(step, last_step, tol) {
+                return Some(mean);
+            }
+            last_step = Some(step);
+        }
+
+        None
+    }
+}
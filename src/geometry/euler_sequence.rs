@@ -0,0 +1,262 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::RealField;
+
+use crate::geometry::Quaternion;
+
+/// The three axes, in order, about which the elementary rotations of an Euler sequence are
+/// applied.
+///
+/// The sequences that use three distinct axes are the Tait–Bryan sequences. The sequences that
+/// start and end with the same axis are the proper Euler sequences.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerAxes {
+    /// Rotations about X, then Y, then Z (Tait–Bryan).
+    XYZ,
+    /// Rotations about X, then Z, then Y (Tait–Bryan).
+    XZY,
+    /// Rotations about Y, then X, then Z (Tait–Bryan).
+    YXZ,
+    /// Rotations about Y, then Z, then X (Tait–Bryan).
+    YZX,
+    /// Rotations about Z, then X, then Y (Tait–Bryan).
+    ZXY,
+    /// Rotations about Z, then Y, then X (Tait–Bryan).
+    ZYX,
+    /// Rotations about X, then Y, then X (proper Euler).
+    XYX,
+    /// Rotations about X, then Z, then X (proper Euler).
+    XZX,
+    /// Rotations about Y, then X, then Y (proper Euler).
+    YXY,
+    /// Rotations about Y, then Z, then Y (proper Euler).
+    YZY,
+    /// Rotations about Z, then X, then Z (proper Euler).
+    ZXZ,
+    /// Rotations about Z, then Y, then Z (proper Euler).
+    ZYZ,
+}
+
+impl EulerAxes {
+    /// The indices (0 for X, 1 for Y, 2 for Z) of the three axes of this sequence.
+    #[inline]
+    pub fn indices(self) -> [usize; 3] {
+        match self {
+            EulerAxes::XYZ => [0, 1, 2],
+            EulerAxes::XZY => [0, 2, 1],
+            EulerAxes::YXZ => [1, 0, 2],
+            EulerAxes::YZX => [1, 2, 0],
+            EulerAxes::ZXY => [2, 0, 1],
+            EulerAxes::ZYX => [2, 1, 0],
+            EulerAxes::XYX => [0, 1, 0],
+            EulerAxes::XZX => [0, 2, 0],
+            EulerAxes::YXY => [1, 0, 1],
+            EulerAxes::YZY => [1, 2, 1],
+            EulerAxes::ZXZ => [2, 0, 2],
+            EulerAxes::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// The same axes, in reverse order.
+    #[inline]
+    pub fn reversed(self) -> Self {
+        match self {
+            EulerAxes::XYZ => EulerAxes::ZYX,
+            EulerAxes::XZY => EulerAxes::YZX,
+            EulerAxes::YXZ => EulerAxes::ZXY,
+            EulerAxes::YZX => EulerAxes::XZY,
+            EulerAxes::ZXY => EulerAxes::YXZ,
+            EulerAxes::ZYX => EulerAxes::XYZ,
+            proper => proper,
+        }
+    }
+
+    /// Is this a proper Euler sequence, i.e., are the first and last axes the same?
+    #[inline]
+    pub fn is_proper(self) -> bool {
+        let [first, _, last] = self.indices();
+        first == last
+    }
+}
+
+/// A convention for Euler angles: the axes of the three elementary rotations, and whether they
+/// are applied about the axes of the rotating frame (intrinsic) or of the fixed frame (extrinsic).
+///
+/// With the angles `(a, b, c)` and the axes `[i, j, k]`, the intrinsic sequence corresponds to
+/// the rotation `R_i(a) * R_j(b) * R_k(c)` while the extrinsic sequence corresponds to
+/// `R_k(c) * R_j(b) * R_i(a)`. Thus, an intrinsic sequence is the same as the extrinsic sequence
+/// with the reversed axes and the reversed angles.
+///
+/// The angles retrieved from a rotation are such that the first and third ones lie in `[-π, π]`.
+/// The second one lies in `[0, π]` for proper Euler sequences and in `[-π/2, π/2]` for
+/// Tait–Bryan sequences. At the singularities (gimbal lock), where only the sum or difference of
+/// the first and third angles is defined, the third angle is set to zero.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{EulerAxes, EulerSequence, UnitQuaternion, Vector3};
+/// // Yaw, then pitch, then roll about the axes of the rotating body.
+/// let zyx = EulerSequence::intrinsic(EulerAxes::ZYX);
+/// let rot = UnitQuaternion::from_euler_sequence(zyx, 0.3, 0.2, 0.1);
+/// let expected = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.3)
+///     * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 0.2)
+///     * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.1);
+/// assert_relative_eq!(rot, expected, epsilon = 1.0e-7);
+///
+/// let (yaw, pitch, roll) = rot.euler_sequence_angles(zyx);
+/// assert_relative_eq!(yaw, 0.3, epsilon = 1.0e-7);
+/// assert_relative_eq!(pitch, 0.2, epsilon = 1.0e-7);
+/// assert_relative_eq!(roll, 0.1, epsilon = 1.0e-7);
+///
+/// // This is the same as roll-pitch-yaw about the fixed axes.
+/// assert_eq!(zyx.reversed(), EulerSequence::ROLL_PITCH_YAW);
+/// assert_relative_eq!(rot, UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3), epsilon = 1.0e-7);
+/// ```
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EulerSequence {
+    axes: EulerAxes,
+    intrinsic: bool,
+}
+
+impl EulerSequence {
+    /// The extrinsic XYZ sequence, i.e., roll, then pitch, then yaw about the fixed axes.
+    ///
+    /// This is the convention of `Rotation3::from_euler_angles` and `UnitQuaternion::from_euler_angles`.
+    pub const ROLL_PITCH_YAW: Self = Self::extrinsic(EulerAxes::XYZ);
+
+    /// The sequence of rotations about the given axes of the rotating frame.
+    #[inline]
+    pub const fn intrinsic(axes: EulerAxes) -> Self {
+        Self {
+            axes,
+            intrinsic: true,
+        }
+    }
+
+    /// The sequence of rotations about the given axes of the fixed frame.
+    #[inline]
+    pub const fn extrinsic(axes: EulerAxes) -> Self {
+        Self {
+            axes,
+            intrinsic: false,
+        }
+    }
+
+    /// The axes of this sequence.
+    #[inline]
+    pub fn axes(self) -> EulerAxes {
+        self.axes
+    }
+
+    /// Are the rotations applied about the axes of the rotating frame?
+    #[inline]
+    pub fn is_intrinsic(self) -> bool {
+        self.intrinsic
+    }
+
+    /// Are the rotations applied about the axes of the fixed frame?
+    #[inline]
+    pub fn is_extrinsic(self) -> bool {
+        !self.intrinsic
+    }
+
+    /// Is this a proper Euler sequence, i.e., are the first and last axes the same?
+    #[inline]
+    pub fn is_proper(self) -> bool {
+        self.axes.is_proper()
+    }
+
+    /// The equivalent sequence of the other kind, with the reversed axes.
+    ///
+    /// The angles of a rotation in the returned sequence are the angles in `self`, in reverse
+    /// order.
+    #[inline]
+    pub fn reversed(self) -> Self {
+        Self {
+            axes: self.axes.reversed(),
+            intrinsic: !self.intrinsic,
+        }
+    }
+
+    /// The Euler angles of the rotation represented by the unit quaternion `q`.
+    ///
+    /// Implementation based on "Quaternion to Euler angles conversion: A direct, general and
+    /// computationally efficient method", by Evandro Bernardes and Stéphane Viollet, 2022.
+    pub(crate) fn angles_from_quaternion<N: RealField>(self, q: &Quaternion<N>) -> (N, N, N) {
+        if self.intrinsic {
+            Self::intrinsic_angles_from_quaternion(self.axes, q, false)
+        } else {
+            let (c, b, a) = Self::intrinsic_angles_from_quaternion(self.axes.reversed(), q, true);
+            (a, b, c)
+        }
+    }
+
+    /// The intrinsic angles `(a, b, c)` of `q`. At the singularities, `a` is set to zero if
+    /// `zero_first` is `true`, and `c` otherwise.
+    fn intrinsic_angles_from_quaternion<N: RealField>(
+        axes: EulerAxes,
+        q: &Quaternion<N>,
+        zero_first: bool,
+    ) -> (N, N, N) {
+        let [i, j, k] = axes.indices();
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        let parity =
+            (i as isize - j as isize) * (j as isize - k as isize) * (k as isize - i as isize) / 2;
+        let sign: N = crate::convert(parity as f64);
+
+        let (w, qi, qj, qk) = (q.w, q.coords[i], q.coords[j], q.coords[k] * sign);
+
+        // `sum` and `diff` are the half sum and half difference of the first and third angles
+        // (with the third angle multiplied by `sign` for Tait–Bryan sequences). They are the
+        // arguments of two pairs of components, whose norms are proportional to the cosine and
+        // sine of half the middle angle (shifted by π/2 for Tait–Bryan sequences).
+        let (sum_norm, diff_norm, mut sum, mut diff);
+        if proper {
+            sum_norm = w.hypot(qi);
+            diff_norm = qj.hypot(qk);
+            sum = qi.atan2(w);
+            diff = qk.atan2(qj);
+        } else {
+            sum_norm = (qi + qk).hypot(w + qj);
+            diff_norm = (qi - qk).hypot(w - qj);
+            sum = (qi + qk).atan2(w + qj);
+            diff = (qi - qk).atan2(w - qj);
+        }
+
+        let middle = if proper {
+            diff_norm.atan2(sum_norm) * crate::convert(2.0)
+        } else {
+            sum_norm.atan2(diff_norm) * crate::convert(2.0) - N::frac_pi_2()
+        };
+
+        // Gimbal lock: only one of `sum` and `diff` is defined.
+        let eps = N::default_epsilon().sqrt();
+        let flip = if zero_first { -N::one() } else { N::one() };
+        if diff_norm <= eps {
+            diff = sum * flip;
+        } else if sum_norm <= eps {
+            sum = diff * flip;
+        }
+
+        let wrap = |angle: N| {
+            if angle > N::pi() {
+                angle - N::two_pi()
+            } else if angle < -N::pi() {
+                angle + N::two_pi()
+            } else {
+                angle
+            }
+        };
+
+        if proper {
+            (wrap(sum + diff), middle, wrap(sum - diff))
+        } else {
+            (wrap(sum + diff), middle, wrap(sum - diff) * sign)
+        }
+    }
+}
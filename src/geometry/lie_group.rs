@@ -0,0 +1,672 @@
+use simba::scalar::RealField;
+
+use crate::base::dimension::U3;
+use crate::base::{Matrix3, Matrix6, Vector2, Vector3, Vector6};
+
+use crate::geometry::{
+    Isometry2, Isometry3, Rotation2, Rotation3, Translation3, UnitComplex, UnitDualQuaternion,
+    UnitQuaternion,
+};
+
+/*
+ *
+ * In the following, we provide the exponential and logarithm maps, the adjoint matrix, and the
+ * left and right Jacobians (with their inverses) of the groups:
+ * =========================
+ *
+ * SO(2): Rotation2, UnitComplex   (tangent: the rotation angle)
+ * SO(3): Rotation3, UnitQuaternion (tangent: the scaled axis `ω`)
+ * SE(2): Isometry2                 (tangent: the twist `(ρ, θ)` as a `Vector3`)
+ * SE(3): Isometry3, UnitDualQuaternion (tangent: the twist `(ρ, ω)` as a `Vector6`)
+ *
+ * The left Jacobian `Jl(ξ)` and the right Jacobian `Jr(ξ) = Jl(-ξ)` are such that, for a small
+ * tangent `δ`, we have `exp(ξ + δ) ≈ exp(Jl(ξ) * δ) * exp(ξ) ≈ exp(ξ) * exp(Jr(ξ) * δ)`.
+ *
+ */
+
+/// Under this squared angle, the coefficients of the Jacobians are computed from their Taylor
+/// expansions to avoid catastrophic cancellations.
+fn is_small_angle<N: RealField>(theta_sq: N) -> bool {
+    theta_sq < N::default_epsilon().sqrt()
+}
+
+/// The left Jacobian of SO(3), which is also the matrix `V` such that the translational part of
+/// the exponential of a twist `(ρ, ω)` of SE(3) is `V * ρ`.
+fn so3_left_jacobian<N: RealField>(omega: &Vector3<N>) -> Matrix3<N> {
+    let theta_sq = omega.norm_squared();
+    let (a, b) = if is_small_angle(theta_sq) {
+        (
+            crate::convert::<_, N>(0.5) - theta_sq / crate::convert(24.0),
+            N::one() / crate::convert(6.0) - theta_sq / crate::convert(120.0),
+        )
+    } else {
+        let theta = theta_sq.sqrt();
+        let (sin, cos) = theta.sin_cos();
+        (
+            (N::one() - cos) / theta_sq,
+            (theta - sin) / (theta_sq * theta),
+        )
+    };
+
+    let w = omega.cross_matrix();
+    Matrix3::identity() + w * a + w * w * b
+}
+
+fn so3_left_jacobian_inverse<N: RealField>(omega: &Vector3<N>) -> Matrix3<N> {
+    let theta_sq = omega.norm_squared();
+    let c = if is_small_angle(theta_sq) {
+        N::one() / crate::convert(12.0) + theta_sq / crate::convert(720.0)
+    } else {
+        let half_theta = theta_sq.sqrt() * crate::convert(0.5);
+        let (sin, cos) = half_theta.sin_cos();
+        (N::one() - half_theta * cos / sin) / theta_sq
+    };
+
+    let w = omega.cross_matrix();
+    Matrix3::identity() - w * crate::convert::<_, N>(0.5) + w * w * c
+}
+
+/// The coefficients `(θ - sin θ) / θ³`, `(θ² + 2 cos θ - 2) / (2 θ⁴)` and
+/// `(2 θ - 3 sin θ + θ cos θ) / (2 θ⁵)` of the matrix `Q` of SE(3).
+///
+/// The closed forms lose about `eps / θ⁴` in relative precision, so they are only used for angles
+/// larger than one radian. Below it, the Taylor series are accurate after eight terms.
+fn se3_q_coefficients<N: RealField>(theta_sq: N) -> (N, N, N) {
+    let two: N = crate::convert(2.0);
+    let three: N = crate::convert(3.0);
+
+    if theta_sq < N::one() {
+        // The `j`-th terms are `(-1)ʲ θ²ʲ` divided by `(2j + 3)!`, `(2j + 4)!` and
+        // `(2j + 5)! / (j + 1)`.
+        let (mut c1, mut c2, mut c3) = (N::zero(), N::zero(), N::zero());
+        let mut term = N::one() / crate::convert(6.0);
+
+        for j in 0..8 {
+            let (k4, k5): (N, N) = (
+                crate::convert(2.0 * j as f64 + 4.0),
+                crate::convert(2.0 * j as f64 + 5.0),
+            );
+            c1 += term;
+            c2 += term / k4;
+            c3 += term * crate::convert(j as f64 + 1.0) / (k4 * k5);
+            term *= -theta_sq / (k4 * k5);
+        }
+
+        (c1, c2, c3)
+    } else {
+        let theta = theta_sq.sqrt();
+        let (sin, cos) = theta.sin_cos();
+        (
+            (theta - sin) / (theta_sq * theta),
+            (theta_sq + two * cos - two) / (two * theta_sq * theta_sq),
+            (two * theta - three * sin + theta * cos) / (two * theta_sq * theta_sq * theta),
+        )
+    }
+}
+
+/// The top-right block `Q` of the left Jacobian of SE(3).
+///
+/// See "State Estimation for Robotics", by Timothy D. Barfoot, Section 7.1.5.
+fn se3_left_jacobian_q<N: RealField>(rho: &Vector3<N>, omega: &Vector3<N>) -> Matrix3<N> {
+    let two: N = crate::convert(2.0);
+    let three: N = crate::convert(3.0);
+    let (c1, c2, c3) = se3_q_coefficients(omega.norm_squared());
+
+    let r = rho.cross_matrix();
+    let w = omega.cross_matrix();
+    let wr = w * r;
+    let rw = r * w;
+    let wrw = wr * w;
+
+    r / two + (wr + rw + wrw) * c1 + (w * wr + rw * w - wrw * three) * c2 + (wrw * w + w * wrw) * c3
+}
+
+fn se3_left_jacobian<N: RealField>(twist: &Vector6<N>) -> Matrix6<N> {
+    let rho = twist.fixed_rows::<U3>(0).into_owned();
+    let omega = twist.fixed_rows::<U3>(3).into_owned();
+    let jacobian = so3_left_jacobian(&omega);
+
+    let mut res = Matrix6::zeros();
+    res.fixed_slice_mut::<U3, U3>(0, 0).copy_from(&jacobian);
+    res.fixed_slice_mut::<U3, U3>(0, 3)
+        .copy_from(&se3_left_jacobian_q(&rho, &omega));
+    res.fixed_slice_mut::<U3, U3>(3, 3).copy_from(&jacobian);
+    res
+}
+
+fn se3_left_jacobian_inverse<N: RealField>(twist: &Vector6<N>) -> Matrix6<N> {
+    let rho = twist.fixed_rows::<U3>(0).into_owned();
+    let omega = twist.fixed_rows::<U3>(3).into_owned();
+    let inverse = so3_left_jacobian_inverse(&omega);
+    let q = se3_left_jacobian_q(&rho, &omega);
+
+    let mut res = Matrix6::zeros();
+    res.fixed_slice_mut::<U3, U3>(0, 0).copy_from(&inverse);
+    res.fixed_slice_mut::<U3, U3>(0, 3)
+        .copy_from(&(-(inverse * q * inverse)));
+    res.fixed_slice_mut::<U3, U3>(3, 3).copy_from(&inverse);
+    res
+}
+
+/// The coefficients `(sin θ / θ, (1 - cos θ) / θ², (θ - sin θ) / θ²)` used by the Jacobians
+/// of SE(2).
+fn se2_coefficients<N: RealField>(theta: N) -> (N, N, N) {
+    let theta_sq = theta * theta;
+    if is_small_angle(theta_sq) {
+        (
+            N::one() - theta_sq / crate::convert(6.0),
+            crate::convert::<_, N>(0.5) - theta_sq / crate::convert(24.0),
+            theta / crate::convert(6.0) - theta_sq * theta / crate::convert(120.0),
+        )
+    } else {
+        let (sin, cos) = theta.sin_cos();
+        (
+            sin / theta,
+            (N::one() - cos) / theta_sq,
+            (theta - sin) / theta_sq,
+        )
+    }
+}
+
+fn se2_left_jacobian<N: RealField>(twist: &Vector3<N>) -> Matrix3<N> {
+    let (rho1, rho2, theta) = (twist.x, twist.y, twist.z);
+    let (a, d, c) = se2_coefficients(theta);
+    let b = d * theta;
+
+    Matrix3::new(
+        a,
+        -b,
+        rho1 * c + rho2 * d,
+        b,
+        a,
+        rho2 * c - rho1 * d,
+        N::zero(),
+        N::zero(),
+        N::one(),
+    )
+}
+
+/// The inverse of the matrix `V` such that the translational part of the exponential of a twist
+/// `(ρ, θ)` of SE(2) is `V * ρ`.
+fn se2_v_inverse<N: RealField>(theta: N) -> (N, N) {
+    let half_theta = theta * crate::convert(0.5);
+    let a = if is_small_angle(theta * theta) {
+        N::one() - theta * theta / crate::convert(12.0)
+    } else {
+        let (sin, cos) = half_theta.sin_cos();
+        half_theta * cos / sin
+    };
+
+    (a, half_theta)
+}
+
+fn se2_left_jacobian_inverse<N: RealField>(twist: &Vector3<N>) -> Matrix3<N> {
+    let (a, b) = se2_v_inverse(twist.z);
+    let jacobian = se2_left_jacobian(twist);
+    let (w1, w2) = (jacobian[(0, 2)], jacobian[(1, 2)]);
+
+    Matrix3::new(
+        a,
+        b,
+        -(a * w1 + b * w2),
+        -b,
+        a,
+        b * w1 - a * w2,
+        N::zero(),
+        N::zero(),
+        N::one(),
+    )
+}
+
+/// # Lie group
+impl<N: RealField> Rotation2<N> {
+    /// The exponential map of SO(2), i.e., the rotation with the given angle.
+    ///
+    /// This is the same as `Rotation2::new(angle)`.
+    #[inline]
+    pub fn exp(angle: N) -> Self {
+        Self::new(angle)
+    }
+
+    /// The logarithm map of SO(2), i.e., the rotation angle in `(-π, π]`.
+    #[inline]
+    pub fn log(&self) -> N {
+        self.angle()
+    }
+
+    /// The adjoint of this rotation, which is always one since SO(2) is commutative.
+    #[inline]
+    pub fn adjoint(&self) -> N {
+        N::one()
+    }
+
+    /// The left Jacobian of SO(2), which is always one since SO(2) is commutative.
+    #[inline]
+    pub fn left_jacobian(_angle: N) -> N {
+        N::one()
+    }
+
+    /// The right Jacobian of SO(2), which is always one since SO(2) is commutative.
+    #[inline]
+    pub fn right_jacobian(_angle: N) -> N {
+        N::one()
+    }
+
+    /// The inverse of the left Jacobian of SO(2), which is always one.
+    #[inline]
+    pub fn left_jacobian_inverse(_angle: N) -> N {
+        N::one()
+    }
+
+    /// The inverse of the right Jacobian of SO(2), which is always one.
+    #[inline]
+    pub fn right_jacobian_inverse(_angle: N) -> N {
+        N::one()
+    }
+}
+
+/// # Lie group
+impl<N: RealField> UnitComplex<N> {
+    /// The exponential map of SO(2), i.e., the rotation with the given angle.
+    ///
+    /// This is the same as `UnitComplex::new(angle)`.
+    #[inline]
+    pub fn exp(angle: N) -> Self {
+        Self::new(angle)
+    }
+
+    /// The logarithm map of SO(2), i.e., the rotation angle in `(-π, π]`.
+    #[inline]
+    pub fn log(&self) -> N {
+        self.angle()
+    }
+
+    /// The adjoint of this rotation, which is always one since SO(2) is commutative.
+    #[inline]
+    pub fn adjoint(&self) -> N {
+        N::one()
+    }
+
+    /// The left Jacobian of SO(2), which is always one since SO(2) is commutative.
+    #[inline]
+    pub fn left_jacobian(_angle: N) -> N {
+        N::one()
+    }
+
+    /// The right Jacobian of SO(2), which is always one since SO(2) is commutative.
+    #[inline]
+    pub fn right_jacobian(_angle: N) -> N {
+        N::one()
+    }
+
+    /// The inverse of the left Jacobian of SO(2), which is always one.
+    #[inline]
+    pub fn left_jacobian_inverse(_angle: N) -> N {
+        N::one()
+    }
+
+    /// The inverse of the right Jacobian of SO(2), which is always one.
+    #[inline]
+    pub fn right_jacobian_inverse(_angle: N) -> N {
+        N::one()
+    }
+}
+
+/// # Lie group
+impl<N: RealField> Rotation3<N> {
+    /// The exponential map of SO(3).
+    ///
+    /// This is the same as `Rotation3::from_scaled_axis(omega)`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let omega = Vector3::new(0.1, 0.2, 0.3);
+    /// let rot = Rotation3::exp(omega);
+    /// assert_relative_eq!(rot.log(), omega, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn exp(omega: Vector3<N>) -> Self {
+        Self::from_scaled_axis(omega)
+    }
+
+    /// The logarithm map of SO(3), i.e., the rotation axis multiplied by the rotation angle in
+    /// `[0, π]`.
+    ///
+    /// This is computed from the unit quaternion of this rotation. Unlike `self.scaled_axis()`,
+    /// which relies on the trace of the matrix, it remains accurate for angles close to zero.
+    #[inline]
+    pub fn log(&self) -> Vector3<N> {
+        UnitQuaternion::from_rotation_matrix(self).log()
+    }
+
+    /// The adjoint matrix of this rotation, which is its rotation matrix.
+    ///
+    /// It is such that `self * Rotation3::exp(omega) * self.inverse()` is equal to
+    /// `Rotation3::exp(self.adjoint() * omega)`.
+    #[inline]
+    pub fn adjoint(&self) -> Matrix3<N> {
+        *self.matrix()
+    }
+
+    /// The left Jacobian of SO(3) at the tangent `omega`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let omega = Vector3::new(0.1, 0.2, 0.3);
+    /// let delta = Vector3::new(1.0, -2.0, 3.0) * 1.0e-6;
+    /// let perturbed = Rotation3::exp(Rotation3::left_jacobian(omega) * delta) * Rotation3::exp(omega);
+    /// assert_relative_eq!(Rotation3::exp(omega + delta), perturbed, epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn left_jacobian(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian(&omega)
+    }
+
+    /// The right Jacobian of SO(3) at the tangent `omega`.
+    #[inline]
+    pub fn right_jacobian(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian(&-omega)
+    }
+
+    /// The inverse of the left Jacobian of SO(3) at the tangent `omega`.
+    ///
+    /// It is singular if the norm of `omega` is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn left_jacobian_inverse(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian_inverse(&omega)
+    }
+
+    /// The inverse of the right Jacobian of SO(3) at the tangent `omega`.
+    ///
+    /// It is singular if the norm of `omega` is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn right_jacobian_inverse(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian_inverse(&-omega)
+    }
+}
+
+/// # Lie group
+impl<N: RealField> UnitQuaternion<N> {
+    /// The logarithm map of SO(3), i.e., the rotation axis multiplied by the rotation angle in
+    /// `[0, π]`.
+    ///
+    /// This is the same as `self.scaled_axis()`. The corresponding exponential map is
+    /// `UnitQuaternion::from_scaled_axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let omega = Vector3::new(0.1, 0.2, 0.3);
+    /// let rot = UnitQuaternion::from_scaled_axis(omega);
+    /// assert_relative_eq!(rot.log(), omega, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Vector3<N> {
+        self.scaled_axis()
+    }
+
+    /// The adjoint matrix of this rotation, which is its rotation matrix.
+    #[inline]
+    pub fn adjoint(&self) -> Matrix3<N> {
+        self.to_rotation_matrix().into_inner()
+    }
+
+    /// The left Jacobian of SO(3) at the tangent `omega`.
+    #[inline]
+    pub fn left_jacobian(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian(&omega)
+    }
+
+    /// The right Jacobian of SO(3) at the tangent `omega`.
+    #[inline]
+    pub fn right_jacobian(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian(&-omega)
+    }
+
+    /// The inverse of the left Jacobian of SO(3) at the tangent `omega`.
+    ///
+    /// It is singular if the norm of `omega` is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn left_jacobian_inverse(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian_inverse(&omega)
+    }
+
+    /// The inverse of the right Jacobian of SO(3) at the tangent `omega`.
+    ///
+    /// It is singular if the norm of `omega` is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn right_jacobian_inverse(omega: Vector3<N>) -> Matrix3<N> {
+        so3_left_jacobian_inverse(&-omega)
+    }
+}
+
+/// # Lie group
+impl<N: RealField> Isometry2<N> {
+    /// The exponential map of SE(2).
+    ///
+    /// The twist is `(ρ, θ)` where `ρ` is the translational part and `θ` the rotation angle.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry2, Vector2, Vector3};
+    /// // A quarter turn along an arc of radius one.
+    /// let twist = Vector3::new(std::f64::consts::FRAC_PI_2, 0.0, std::f64::consts::FRAC_PI_2);
+    /// let iso = Isometry2::exp(twist);
+    /// assert_relative_eq!(iso.translation.vector, Vector2::new(1.0, 1.0), epsilon = 1.0e-7);
+    /// assert_relative_eq!(iso.log(), twist, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn exp(twist: Vector3<N>) -> Self {
+        let (a, d, _) = se2_coefficients(twist.z);
+        let b = d * twist.z;
+        let translation = Vector2::new(a * twist.x - b * twist.y, b * twist.x + a * twist.y);
+        Self::new(translation, twist.z)
+    }
+
+    /// The logarithm map of SE(2), i.e., the twist `(ρ, θ)` with `θ` in `(-π, π]`.
+    #[inline]
+    pub fn log(&self) -> Vector3<N> {
+        let theta = self.rotation.angle();
+        let (a, b) = se2_v_inverse(theta);
+        let t = &self.translation.vector;
+        Vector3::new(a * t.x + b * t.y, a * t.y - b * t.x, theta)
+    }
+
+    /// The adjoint matrix of this isometry.
+    ///
+    /// It is such that `self * Isometry2::exp(twist) * self.inverse()` is equal to
+    /// `Isometry2::exp(self.adjoint() * twist)`.
+    #[inline]
+    pub fn adjoint(&self) -> Matrix3<N> {
+        let r = self.rotation.to_rotation_matrix();
+        let t = &self.translation.vector;
+
+        Matrix3::new(
+            r[(0, 0)],
+            r[(0, 1)],
+            t.y,
+            r[(1, 0)],
+            r[(1, 1)],
+            -t.x,
+            N::zero(),
+            N::zero(),
+            N::one(),
+        )
+    }
+
+    /// The left Jacobian of SE(2) at the given twist.
+    #[inline]
+    pub fn left_jacobian(twist: Vector3<N>) -> Matrix3<N> {
+        se2_left_jacobian(&twist)
+    }
+
+    /// The right Jacobian of SE(2) at the given twist.
+    #[inline]
+    pub fn right_jacobian(twist: Vector3<N>) -> Matrix3<N> {
+        se2_left_jacobian(&-twist)
+    }
+
+    /// The inverse of the left Jacobian of SE(2) at the given twist.
+    ///
+    /// It is singular if the rotation angle is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn left_jacobian_inverse(twist: Vector3<N>) -> Matrix3<N> {
+        se2_left_jacobian_inverse(&twist)
+    }
+
+    /// The inverse of the right Jacobian of SE(2) at the given twist.
+    ///
+    /// It is singular if the rotation angle is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn right_jacobian_inverse(twist: Vector3<N>) -> Matrix3<N> {
+        se2_left_jacobian_inverse(&-twist)
+    }
+}
+
+/// # Lie group
+impl<N: RealField> Isometry3<N> {
+    /// The exponential map of SE(3).
+    ///
+    /// The twist is `(ρ, ω)` where `ρ` is the translational part (the first three components)
+    /// and `ω` the rotational part (the last three components).
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector6};
+    /// let twist = Vector6::new(1.0, 2.0, 3.0, 0.1, 0.2, 0.3);
+    /// let iso = Isometry3::exp(twist);
+    /// assert_relative_eq!(iso.log(), twist, epsilon = 1.0e-7);
+    ///
+    /// // The exponential of a small perturbation `delta` of `twist`.
+    /// let delta = Vector6::new(1.0, -2.0, 3.0, -4.0, 5.0, -6.0) * 1.0e-6;
+    /// let perturbed = iso * Isometry3::exp(Isometry3::right_jacobian(twist) * delta);
+    /// assert_relative_eq!(Isometry3::exp(twist + delta), perturbed, epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn exp(twist: Vector6<N>) -> Self {
+        let rho = twist.fixed_rows::<U3>(0).into_owned();
+        let omega = twist.fixed_rows::<U3>(3).into_owned();
+        let translation = Translation3::from(so3_left_jacobian(&omega) * rho);
+        Self::from_parts(translation, UnitQuaternion::from_scaled_axis(omega))
+    }
+
+    /// The logarithm map of SE(3), i.e., the twist `(ρ, ω)` where the norm of `ω` is in `[0, π]`.
+    #[inline]
+    pub fn log(&self) -> Vector6<N> {
+        let omega = self.rotation.scaled_axis();
+        let rho = so3_left_jacobian_inverse(&omega) * self.translation.vector;
+        Vector6::new(rho.x, rho.y, rho.z, omega.x, omega.y, omega.z)
+    }
+
+    /// The adjoint matrix of this isometry.
+    ///
+    /// It is such that `self * Isometry3::exp(twist) * self.inverse()` is equal to
+    /// `Isometry3::exp(self.adjoint() * twist)`.
+    #[inline]
+    pub fn adjoint(&self) -> Matrix6<N> {
+        let r = self.rotation.to_rotation_matrix().into_inner();
+        let tr = self.translation.vector.cross_matrix() * r;
+
+        let mut res = Matrix6::zeros();
+        res.fixed_slice_mut::<U3, U3>(0, 0).copy_from(&r);
+        res.fixed_slice_mut::<U3, U3>(0, 3).copy_from(&tr);
+        res.fixed_slice_mut::<U3, U3>(3, 3).copy_from(&r);
+        res
+    }
+
+    /// The left Jacobian of SE(3) at the given twist.
+    #[inline]
+    pub fn left_jacobian(twist: Vector6<N>) -> Matrix6<N> {
+        se3_left_jacobian(&twist)
+    }
+
+    /// The right Jacobian of SE(3) at the given twist.
+    #[inline]
+    pub fn right_jacobian(twist: Vector6<N>) -> Matrix6<N> {
+        se3_left_jacobian(&-twist)
+    }
+
+    /// The inverse of the left Jacobian of SE(3) at the given twist.
+    ///
+    /// It is singular if the rotation angle is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn left_jacobian_inverse(twist: Vector6<N>) -> Matrix6<N> {
+        se3_left_jacobian_inverse(&twist)
+    }
+
+    /// The inverse of the right Jacobian of SE(3) at the given twist.
+    ///
+    /// It is singular if the rotation angle is a non-zero multiple of `2π`.
+    #[inline]
+    pub fn right_jacobian_inverse(twist: Vector6<N>) -> Matrix6<N> {
+        se3_left_jacobian_inverse(&-twist)
+    }
+}
+
+/// # Lie group
+impl<N: RealField> UnitDualQuaternion<N> {
+    /// The exponential map of SE(3).
+    ///
+    /// See `Isometry3::exp` for the layout of the twist.
+    #[inline]
+    pub fn exp(twist: Vector6<N>) -> Self {
+        Self::from_isometry(&Isometry3::exp(twist))
+    }
+
+    /// The logarithm map of SE(3).
+    ///
+    /// See `Isometry3::log` for the layout of the twist.
+    #[inline]
+    pub fn log(&self) -> Vector6<N> {
+        self.to_isometry().log()
+    }
+
+    /// The adjoint matrix of this unit dual quaternion interpreted as an isometry.
+    #[inline]
+    pub fn adjoint(&self) -> Matrix6<N> {
+        self.to_isometry().adjoint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn se3_q_coefficients_small_angles() {
+        // The closed forms are inaccurate here, and the series are truncated after three terms.
+        for i in 0..=30 {
+            let theta = 1.0e-5 * 1.0e3f64.powf(i as f64 / 30.0);
+            let t2 = theta * theta;
+            let (c1, c2, c3) = super::se3_q_coefficients(t2);
+
+            assert_relative_eq!(
+                c1,
+                1.0 / 6.0 - t2 / 120.0 + t2 * t2 / 5040.0,
+                max_relative = 1.0e-15
+            );
+            assert_relative_eq!(
+                c2,
+                1.0 / 24.0 - t2 / 720.0 + t2 * t2 / 40320.0,
+                max_relative = 1.0e-15
+            );
+            assert_relative_eq!(
+                c3,
+                1.0 / 120.0 - t2 / 2520.0 + t2 * t2 / 120960.0,
+                max_relative = 1.0e-15
+            );
+        }
+
+        // The series and the closed forms agree around the threshold.
+        let below = super::se3_q_coefficients(1.0 - 1.0e-12);
+        let above = super::se3_q_coefficients(1.0f64);
+        assert_relative_eq!(below.0, above.0, max_relative = 1.0e-12);
+        assert_relative_eq!(below.1, above.1, max_relative = 1.0e-12);
+        assert_relative_eq!(below.2, above.2, max_relative = 1.0e-12);
+    }
+}
@@ -22,6 +22,8 @@ mod rotation_ops;
 mod rotation_simba; // TODO: implement Rotation methods.
 mod rotation_specialization;
 
+mod euler_sequence;
+
 mod quaternion;
 mod quaternion_construction;
 mod quaternion_conversion;
@@ -48,6 +50,14 @@ mod translation_coordinates;
 mod translation_ops;
 mod translation_simba;
 
+mod scale;
+mod scale_alias;
+mod scale_construction;
+mod scale_conversion;
+mod scale_coordinates;
+mod scale_ops;
+mod scale_simba;
+
 mod isometry;
 mod isometry_alias;
 mod isometry_construction;
@@ -74,6 +84,12 @@ mod transform_simba;
 
 mod reflection;
 
+mod lie_group;
+
+mod point_registration;
+
+mod averaging;
+
 mod orthographic;
 mod perspective;
 
@@ -85,6 +101,8 @@ pub use self::point_alias::*;
 pub use self::rotation::*;
 pub use self::rotation_alias::*;
 
+pub use self::euler_sequence::{EulerAxes, EulerSequence};
+
 pub use self::quaternion::*;
 
 pub use self::dual_quaternion::*;
@@ -94,6 +112,9 @@ pub use self::unit_complex::*;
 pub use self::translation::*;
 pub use self::translation_alias::*;
 
+pub use self::scale::*;
+pub use self::scale_alias::*;
+
 pub use self::isometry::*;
 pub use self::isometry_alias::*;
 
@@ -105,5 +126,7 @@ pub use self::transform_alias::*;
 
 pub use self::reflection::*;
 
+pub use self::point_registration::RegistrationResiduals;
+
 pub use self::orthographic::Orthographic3;
 pub use self::perspective::Perspective3;
@@ -0,0 +1,350 @@
+use simba::scalar::RealField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimDiff, DimMin, DimName, DimSub, U1, U2, U3};
+use crate::base::{DefaultAllocator, MatrixN, VectorN};
+
+use crate::geometry::{
+    Isometry, Isometry2, Isometry3, Point, Rotation, Similarity, Similarity2, Similarity3,
+    Translation, UnitComplex, UnitQuaternion,
+};
+
+/// Statistics about the distances between the points of a point set registration, after the
+/// estimated transformation has been applied to the source points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RegistrationResiduals<N> {
+    /// The weighted root mean square of the distances.
+    pub rmsd: N,
+    /// The largest distance.
+    pub max: N,
+}
+
+impl<N: RealField> RegistrationResiduals<N> {
+    fn new<D: DimName>(
+        from: &[Point<N, D>],
+        to: &[Point<N, D>],
+        weights: Option<&[N]>,
+        transform: impl Fn(&Point<N, D>) -> Point<N, D>,
+    ) -> Self
+    where
+        DefaultAllocator: Allocator<N, D>,
+    {
+        let mut total_weight = N::zero();
+        let mut sum_squared = N::zero();
+        let mut max = N::zero();
+
+        for (i, (p, q)) in from.iter().zip(to.iter()).enumerate() {
+            let weight = weights.map_or(N::one(), |w| w[i]);
+            let distance_squared = (transform(p) - q).norm_squared();
+            total_weight += weight;
+            sum_squared += distance_squared * weight;
+            max = max.max(distance_squared);
+        }
+
+        RegistrationResiduals {
+            rmsd: (sum_squared / total_weight).sqrt(),
+            max: max.sqrt(),
+        }
+    }
+}
+
+/// Computes the similarity that maps the points `from` to the points `to` with the least weighted
+/// squared error.
+///
+/// See "Least-squares estimation of transformation parameters between two point patterns", by
+/// Shinji Umeyama, 1991. If `with_scaling` is `false`, this is the Kabsch algorithm and the
+/// scaling factor of the returned similarity is one.
+fn umeyama_fit<N: RealField, D>(
+    from: &[Point<N, D>],
+    to: &[Point<N, D>],
+    weights: Option<&[N]>,
+    with_scaling: bool,
+) -> Option<Similarity<N, D, Rotation<N, D>>>
+where
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    assert_eq!(
+        from.len(),
+        to.len(),
+        "Point registration: the two point sets must have the same number of points."
+    );
+
+    if let Some(weights) = weights {
+        assert_eq!(
+            weights.len(),
+            from.len(),
+            "Point registration: there must be exactly one weight per point."
+        );
+    }
+
+    let weight = |i: usize| weights.map_or(N::one(), |w| w[i]);
+
+    let mut total_weight = N::zero();
+    let mut from_centroid = VectorN::<N, D>::zeros();
+    let mut to_centroid = VectorN::<N, D>::zeros();
+
+    for (i, (p, q)) in from.iter().zip(to.iter()).enumerate() {
+        let w = weight(i);
+        total_weight += w;
+        from_centroid.axpy(w, &p.coords, N::one());
+        to_centroid.axpy(w, &q.coords, N::one());
+    }
+
+    if total_weight <= N::zero() {
+        return None;
+    }
+
+    from_centroid /= total_weight;
+    to_centroid /= total_weight;
+
+    let mut covariance = MatrixN::<N, D>::zeros();
+    let mut from_variance = N::zero();
+
+    for (i, (p, q)) in from.iter().zip(to.iter()).enumerate() {
+        let w = weight(i);
+        let dp = &p.coords - &from_centroid;
+        let dq = &q.coords - &to_centroid;
+        covariance.ger(w, &dq, &dp, N::one());
+        from_variance += dp.norm_squared() * w;
+    }
+
+    covariance /= total_weight;
+    from_variance /= total_weight;
+
+    let svd = covariance.svd(true, true);
+    let mut u = svd.u?;
+    let v_t = svd.v_t?;
+    let mut singular_values = svd.singular_values;
+
+    // Flip the direction associated to the smallest singular value if the optimal orthogonal
+    // matrix is a reflection.
+    if u.determinant() * v_t.determinant() < N::zero() {
+        let imin = singular_values.imin();
+        u.column_mut(imin).neg_mut();
+        singular_values[imin] = -singular_values[imin];
+    }
+
+    let rotation = u * v_t;
+
+    let scaling = if with_scaling {
+        if from_variance <= N::zero() {
+            return None;
+        }
+
+        let scaling = singular_values.sum() / from_variance;
+
+        if scaling <= N::zero() {
+            return None;
+        }
+
+        scaling
+    } else {
+        N::one()
+    };
+
+    let translation = to_centroid - &rotation * from_centroid * scaling;
+    Some(Similarity::from_parts(
+        Translation::from(translation),
+        Rotation::from_matrix_unchecked(rotation),
+        scaling,
+    ))
+}
+
+/// # Point set registration
+impl<N: RealField, D> Isometry<N, D, Rotation<N, D>>
+where
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    /// The rigid transformation that best maps the points `from` to the points `to`, with the
+    /// least weighted squared error, together with the residuals of the fit.
+    ///
+    /// This uses the Kabsch algorithm based on the SVD of the cross-covariance of the points. The
+    /// result is always a proper rotation, never a reflection. If `weights` is `None`, all the
+    /// points have the same weight. Otherwise the weights must be non-negative.
+    ///
+    /// Returns `None` if the total weight is zero, in particular if there is no point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from`, `to` and `weights` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{IsometryMatrix2, Point2, Vector2};
+    /// let iso = IsometryMatrix2::new(Vector2::new(1.0, 2.0), 0.5);
+    /// let from = [Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 3.0)];
+    /// let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+    ///
+    /// let (fit, residuals) = IsometryMatrix2::from_point_correspondences(&from, &to, None).unwrap();
+    /// assert_relative_eq!(fit, iso, epsilon = 1.0e-10);
+    /// assert_relative_eq!(residuals.rmsd, 0.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn from_point_correspondences(
+        from: &[Point<N, D>],
+        to: &[Point<N, D>],
+        weights: Option<&[N]>,
+    ) -> Option<(Self, RegistrationResiduals<N>)> {
+        let iso = umeyama_fit(from, to, weights, false)?.isometry;
+        let residuals = RegistrationResiduals::new(from, to, weights, |p| &iso * p);
+        Some((iso, residuals))
+    }
+}
+
+/// # Point set registration
+impl<N: RealField, D> Similarity<N, D, Rotation<N, D>>
+where
+    D: DimName + DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<(usize, usize), D>,
+{
+    /// The similarity that best maps the points `from` to the points `to`, with the least
+    /// weighted squared error, together with the residuals of the fit.
+    ///
+    /// This uses the Umeyama algorithm, i.e., the Kabsch algorithm complemented with the optimal
+    /// uniform scaling factor. The result never contains a reflection. If `weights` is `None`,
+    /// all the points have the same weight. Otherwise the weights must be non-negative.
+    ///
+    /// Returns `None` if the total weight is zero, or if all the weighted points of `from` or of
+    /// `to` coincide, in which case the scaling factor is not defined or is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from`, `to` and `weights` don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Point2, SimilarityMatrix2, Vector2};
+    /// let sim = SimilarityMatrix2::new(Vector2::new(1.0, 2.0), 0.5, 3.0);
+    /// let from = [Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 3.0)];
+    /// let to: Vec<_> = from.iter().map(|p| sim * p).collect();
+    ///
+    /// let (fit, residuals) = SimilarityMatrix2::umeyama(&from, &to, None).unwrap();
+    /// assert_relative_eq!(fit, sim, epsilon = 1.0e-10);
+    /// assert_relative_eq!(residuals.max, 0.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn umeyama(
+        from: &[Point<N, D>],
+        to: &[Point<N, D>],
+        weights: Option<&[N]>,
+    ) -> Option<(Self, RegistrationResiduals<N>)> {
+        let sim = umeyama_fit(from, to, weights, true)?;
+        let residuals = RegistrationResiduals::new(from, to, weights, |p| &sim * p);
+        Some((sim, residuals))
+    }
+}
+
+/// # Point set registration
+impl<N: RealField> Isometry2<N> {
+    /// The rigid transformation that best maps the points `from` to the points `to`, with the
+    /// least weighted squared error, together with the residuals of the fit.
+    ///
+    /// See `IsometryMatrix2::from_point_correspondences` for details.
+    pub fn from_point_correspondences(
+        from: &[Point<N, U2>],
+        to: &[Point<N, U2>],
+        weights: Option<&[N]>,
+    ) -> Option<(Self, RegistrationResiduals<N>)> {
+        let fit = umeyama_fit(from, to, weights, false)?.isometry;
+        let rotation = UnitComplex::from_rotation_matrix(&fit.rotation);
+        let iso = Self::from_parts(fit.translation, rotation);
+        let residuals = RegistrationResiduals::new(from, to, weights, |p| iso * p);
+        Some((iso, residuals))
+    }
+}
+
+/// # Point set registration
+impl<N: RealField> Isometry3<N> {
+    /// The rigid transformation that best maps the points `from` to the points `to`, with the
+    /// least weighted squared error, together with the residuals of the fit.
+    ///
+    /// See `IsometryMatrix3::from_point_correspondences` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Point3, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let from = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 2.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 3.0),
+    /// ];
+    /// let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+    ///
+    /// // The last point is ignored.
+    /// let weights = [1.0, 2.0, 1.0, 0.0];
+    /// let mut noisy = to.clone();
+    /// noisy[3].x += 10.0;
+    ///
+    /// let (fit, residuals) =
+    ///     Isometry3::from_point_correspondences(&from, &noisy, Some(&weights)).unwrap();
+    /// assert_relative_eq!(fit, iso, epsilon = 1.0e-10);
+    /// assert_relative_eq!(residuals.rmsd, 0.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(residuals.max, 10.0, epsilon = 1.0e-10);
+    /// ```
+    pub fn from_point_correspondences(
+        from: &[Point<N, U3>],
+        to: &[Point<N, U3>],
+        weights: Option<&[N]>,
+    ) -> Option<(Self, RegistrationResiduals<N>)> {
+        let fit = umeyama_fit(from, to, weights, false)?.isometry;
+        let rotation = UnitQuaternion::from_rotation_matrix(&fit.rotation);
+        let iso = Self::from_parts(fit.translation, rotation);
+        let residuals = RegistrationResiduals::new(from, to, weights, |p| iso * p);
+        Some((iso, residuals))
+    }
+}
+
+/// # Point set registration
+impl<N: RealField> Similarity2<N> {
+    /// The similarity that best maps the points `from` to the points `to`, with the least
+    /// weighted squared error, together with the residuals of the fit.
+    ///
+    /// See `SimilarityMatrix2::umeyama` for details.
+    pub fn umeyama(
+        from: &[Point<N, U2>],
+        to: &[Point<N, U2>],
+        weights: Option<&[N]>,
+    ) -> Option<(Self, RegistrationResiduals<N>)> {
+        let fit = umeyama_fit(from, to, weights, true)?;
+        let rotation = UnitComplex::from_rotation_matrix(&fit.isometry.rotation);
+        let sim = Self::from_parts(fit.isometry.translation, rotation, fit.scaling());
+        let residuals = RegistrationResiduals::new(from, to, weights, |p| sim * p);
+        Some((sim, residuals))
+    }
+}
+
+/// # Point set registration
+impl<N: RealField> Similarity3<N> {
+    /// The similarity that best maps the points `from` to the points `to`, with the least
+    /// weighted squared error, together with the residuals of the fit.
+    ///
+    /// See `SimilarityMatrix3::umeyama` for details.
+    pub fn umeyama(
+        from: &[Point<N, U3>],
+        to: &[Point<N, U3>],
+        weights: Option<&[N]>,
+    ) -> Option<(Self, RegistrationResiduals<N>)> {
+        let fit = umeyama_fit(from, to, weights, true)?;
+        let rotation = UnitQuaternion::from_rotation_matrix(&fit.isometry.rotation);
+        let sim = Self::from_parts(fit.isometry.translation, rotation, fit.scaling());
+        let residuals = RegistrationResiduals::new(from, to, weights, |p| sim * p);
+        Some((sim, residuals))
+    }
+}
@@ -21,7 +21,7 @@ use crate::base::{
     Matrix3, Matrix4, MatrixSlice, MatrixSliceMut, Normed, Scalar, Unit, Vector3, Vector4,
 };
 
-use crate::geometry::{Point3, Rotation};
+use crate::geometry::{EulerSequence, Point3, Rotation};
 
 /// A quaternion. See the type alias `UnitQuaternion = Unit<Quaternion>` for a quaternion
 /// that may be used as a rotation.
@@ -1431,6 +1431,37 @@ where
         self.to_rotation_matrix().euler_angles()
     }
 
+    /// Retrieves the Euler angles of this unit quaternion in the given sequence.
+    ///
+    /// The angles are produced in the order of the axes of `sequence`. See [`EulerSequence`] for
+    /// their ranges and the handling of gimbal lock.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerAxes, EulerSequence, UnitQuaternion};
+    /// let zyz = EulerSequence::intrinsic(EulerAxes::ZYZ);
+    /// let rot = UnitQuaternion::from_euler_sequence(zyz, 0.1, 0.2, 0.3);
+    /// let euler = rot.euler_sequence_angles(zyz);
+    /// assert_relative_eq!(euler.0, 0.1, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.1, 0.2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.2, 0.3, epsilon = 1.0e-6);
+    ///
+    /// // At gimbal lock, the third angle is set to zero.
+    /// let rot = UnitQuaternion::from_euler_sequence(zyz, 0.1, 0.0, 0.3);
+    /// let euler = rot.euler_sequence_angles(zyz);
+    /// assert_relative_eq!(euler.0, 0.4, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.1, 0.0, epsilon = 1.0e-6);
+    /// assert_eq!(euler.2, 0.0);
+    /// ```
+    #[inline]
+    pub fn euler_sequence_angles(&self, sequence: EulerSequence) -> (N, N, N)
+    where
+        N: RealField,
+    {
+        sequence.angles_from_quaternion(self.quaternion())
+    }
+
     /// Converts this unit quaternion into its equivalent homogeneous transformation matrix.
     ///
     /// # Example
@@ -21,7 +21,7 @@ use crate::base::storage::Storage;
 use crate::base::{Matrix3, Matrix4, Unit, Vector, Vector3, Vector4};
 use crate::{Scalar, SimdRealField};
 
-use crate::geometry::{Quaternion, Rotation3, UnitQuaternion};
+use crate::geometry::{EulerSequence, Quaternion, Rotation3, UnitQuaternion};
 
 impl<N: Scalar> Quaternion<N> {
     /// Creates a quaternion from a 4D vector. The quaternion scalar part corresponds to the `w`
@@ -274,136 +274,80 @@ where
         Self::new_normalize(q)
     }
 
-    /// Define the order that the Euler angles are applied. Note that these are the
-    /// combination of Proper and Tait-Bryan
-    pub enum EulerOrder {
-        /// Rotation about Z, then new X, then new Z. A classic Euler format.
-        ZXZ,
-        /// Rotation about X, then new Y, then new X. A classic Euler format.
-        XYX,
-        /// Rotation about Y, then new Z, then new Y. A classic Euler format.
-        YZY,
-        /// Rotation about Z, then new Y, then new Z. A classic Euler format.
-        ZYZ,
-        /// Rotation about X, then new Z, then new X. A classic Euler format.
-        XZX,
-        /// Rotation about Y, then new X, then new Y. A classic Euler format.
-        YXY,
-        /// Rotation about X, then new Y, then new Z. A Tait-Bryan format.
-        XYZ,
-        /// Rotation about Y, then new Z, then new X. A Tait-Bryan format.
-        YZX,
-        /// Rotation about Z, then new X, then new Y. A Tait-Bryan format.
-        ZXY,
-        /// Rotation about X, then new Z, then new Y. A Tait-Bryan format.
-        XZY,
-        /// Rotation about Z, then new Y, then new X. A Tait-Bryan format.
-        ZYX,
-        /// Rotation about Y, then new X, then new Z. A Tait-Bryan format.
-        YXZ,
-    }
-
-    /// Creates a new unit quaternion from Euler angles in the specified order.
-    ///
-    /// The order is one of the twelve classic or Tait-Bryan variations.
+    /// Creates a new unit quaternion from Euler angles.
+    ///
+    /// The primitive rotations are applied in order: 1 roll − 2 pitch − 3 yaw.
     ///
     /// # Example
     /// ```
     /// # #[macro_use] extern crate approx;
-    /// # use nalgebra::{UnitQuaternion, Vector3, EulerOrder};
-    /// let rot = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3, EulerOrder::ZYX);
-    /// let about_x = UnitQuaternion::new(0.1 * Vector3::x());
-    /// let about_y = UnitQuaternion::new(0.2 * Vector3::y());
-    /// let about_z = UnitQuaternion::new(0.3 * Vector3::z());
-    /// let rot_test = about_x * about_y * about_z;
-    /// assert_relative_eq!(rot, rot_test);
+    /// # use nalgebra::UnitQuaternion;
+    /// let rot = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
     /// let euler = rot.euler_angles();
     /// assert_relative_eq!(euler.0, 0.1, epsilon = 1.0e-6);
     /// assert_relative_eq!(euler.1, 0.2, epsilon = 1.0e-6);
     /// assert_relative_eq!(euler.2, 0.3, epsilon = 1.0e-6);
     /// ```
     #[inline]
-    pub fn from_euler_angles(first: N, second: N, third: N, order: EulerOrder) -> Self {
-        let (s1, c1) = (first * crate::convert(0.5f64)).simd_sin_cos();
-        let (s2, c2) = (second * crate::convert(0.5f64)).simd_sin_cos();
-        let (s3, c3) = (third * crate::convert(0.5f64)).simd_sin_cos();
+    pub fn from_euler_angles(roll: N, pitch: N, yaw: N) -> Self {
+        let (sr, cr) = (roll * crate::convert(0.5f64)).simd_sin_cos();
+        let (sp, cp) = (pitch * crate::convert(0.5f64)).simd_sin_cos();
+        let (sy, cy) = (yaw * crate::convert(0.5f64)).simd_sin_cos();
+
+        let q = Quaternion::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        );
 
-        let q = match order {
-            EulerOrder::XYX => Quaternion::new(
-                c1 * c2 * c3 - s1 * c2 * s3,
-                c1 * c2 * s3 + s1 * c2 * c3,
-                c1 * s2 * c3 + s1 * s2 * c3,
-                s1 * s2 * c3 - c1 * s2 * c3,
-            ),
-            EulerOrder::XYZ => Quaternion::new(
-                c1 * c2 * c3 - s1 * s2 * s3,
-                s1 * c2 * c3 + c1 * s2 * s3,
-                c1 * s2 * c3 - s1 * c2 * s3,
-                c1 * c2 * s3 + s1 * s2 * c3,
-            ),
-            EulerOrder::XZX => Quaternion::new(
-                c1 * c2 * c3 - s1 * c2 * s3,
-                c1 * c2 * s3 + s1 * c2 * c3,
-                c1 * s2 * s3 - s1 * s2 * c3,
-                s1 * s2 * s3 + c1 * s2 * c3,
-            ),
-            EulerOrder::XZY => Quaternion::new(
-                c1 * c2 * c3 - s1 * s2 * s3,
-                s1 * c2 * c3 - c1 * s2 * s3,
-                c1 * c2 * s3 + s1 * s2 * c3,
-                s1 * c2 * s3 + c1 * s2 * c3,
-            ),
-            EulerOrder::YXY => Quaternion::new(
-                c1 * c2 * c3 - s1 * c2 * s3,
-                c1 * s2 * c3 - s1 * s2 * s3,
-                c1 * c2 * s3 + s1 * c2 * c3,
-                c1 * s2 * s3 + s1 * s2 * c3,
-            ),
-            EulerOrder::YXZ => Quaternion::new(
-                c1 * c2 * c3 - s1 * s2 * s3,
-                c1 * s2 * c3 + s1 * c2 * s3,
-                s1 * c2 * c3 - c1 * s2 * s3,
-                c1 * c2 * s3 + s1 * s2 * c3,
-            ),
-            EulerOrder::YZX => Quaternion::new(
-                c1 * c2 * c3 - s1 * s2 * s3,
-                c1 * c2 * s3 + s1 * s2 * c3,
-                s1 * c2 * c3 + c1 * s2 * c3,
-                c1 * s2 * c3 - s1 * c2 * c3,
-            ),
-            EulerOrder::YZY => Quaternion::new(
-                c1 * c2 * c3 - s1 * c2 * s3,
-                s1 * s2 * c3 - c1 * s2 * s3,
-                c1 * c2 * s3 + s1 * c2 * c3,
-                s1 * s2 * s3 + c1 * s2 * c3,
-            ),
-            EulerOrder::ZXY => Quaternion::new(
-                c1 * c2 * c3 - s1 * s2 * s3,
-                c1 * s2 * c3 - s1 * c2 * s3,
-                c1 * c2 * s3 + s1 * s2 * c3,
-                c1 * s2 * s3 + s1 * c2 * c3,
-            ),
-            EulerOrder::ZXZ => Quaternion::new(
-                c1 * c2 * c3 - s1 * c2 * s3,
-                c1 * s2 * c3 + s1 * s2 * s3,
-                s1 * s2 * c3 - c1 * s2 * s3,
-                c1 * c2 * s3 + s1 * c2 * c3,
-            ),
-            EulerOrder::ZYX => Quaternion::new(
-                c1 * c2 * c3 + s1 * s2 * s3,
-                c1 * c2 * s3 - s1 * s2 * c3,
-                c1 * s2 * c3 + s1 * c2 * s3,
-                s1 * c2 * c3 - c1 * s2 * s3,
-            ),
-            EulerOrder::ZYZ => Quaternion::new(
-                c1 * c2 * c3 - s1 * c2 * s3,
-                c1 * s2 * s3 - s1 * s2 * c3,
-                s1 * s2 * s3 + c1 * s2 * c3,
-                c1 * c2 * s3 + s1 * c2 * c3,
-            ),
+        Self::new_unchecked(q)
+    }
+
+    /// Creates a new unit quaternion from Euler angles in the given sequence.
+    ///
+    /// The angles are given in the order of the axes of `sequence`. See [`EulerSequence`] for
+    /// the details of the conventions.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerAxes, EulerSequence, UnitQuaternion, Vector3};
+    /// let zxz = EulerSequence::extrinsic(EulerAxes::ZXZ);
+    /// let rot = UnitQuaternion::from_euler_sequence(zxz, 0.1, 0.2, 0.3);
+    /// let about_z = |angle| UnitQuaternion::from_axis_angle(&Vector3::z_axis(), angle);
+    /// let about_x = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.2);
+    /// assert_relative_eq!(rot, about_z(0.3) * about_x * about_z(0.1), epsilon = 1.0e-7);
+    ///
+    /// // Roll-pitch-yaw angles are an extrinsic XYZ sequence.
+    /// assert_relative_eq!(
+    ///     UnitQuaternion::from_euler_sequence(EulerSequence::ROLL_PITCH_YAW, 0.1, 0.2, 0.3),
+    ///     UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3),
+    ///     epsilon = 1.0e-7
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_euler_sequence(sequence: EulerSequence, first: N, second: N, third: N) -> Self {
+        let elementary = |axis: usize, angle: N| {
+            let (s, c) = (angle * crate::convert(0.5f64)).simd_sin_cos();
+            let mut coords = Vector4::zeros();
+            coords[axis] = s;
+            coords[3] = c;
+            Self::new_unchecked(Quaternion::from(coords))
         };
 
-        Self::new_unchecked(q)
+        let [i, j, k] = sequence.axes().indices();
+        let (q1, q2, q3) = (
+            elementary(i, first),
+            elementary(j, second),
+            elementary(k, third),
+        );
+
+        if sequence.is_intrinsic() {
+            q1 * q2 * q3
+        } else {
+            q3 * q2 * q1
+        }
     }
 
     /// Builds an unit quaternion from a basis assumed to be orthonormal.
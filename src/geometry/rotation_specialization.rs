@@ -19,7 +19,7 @@ use crate::base::dimension::{U1, U2, U3};
 use crate::base::storage::Storage;
 use crate::base::{Matrix2, Matrix3, MatrixN, Unit, Vector, Vector1, Vector2, Vector3, VectorN};
 
-use crate::geometry::{Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::geometry::{EulerSequence, Rotation2, Rotation3, UnitComplex, UnitQuaternion};
 
 /*
  *
@@ -435,6 +435,26 @@ where
             cp * cr,
         ))
     }
+
+    /// Creates a new rotation from Euler angles in the given sequence.
+    ///
+    /// The angles are given in the order of the axes of `sequence`. See [`EulerSequence`] for
+    /// the details of the conventions.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerAxes, EulerSequence, Rotation3, Vector3};
+    /// let yxz = EulerSequence::intrinsic(EulerAxes::YXZ);
+    /// let rot = Rotation3::from_euler_sequence(yxz, 0.1, 0.2, 0.3);
+    /// let expected = Rotation3::from_axis_angle(&Vector3::y_axis(), 0.1)
+    ///     * Rotation3::from_axis_angle(&Vector3::x_axis(), 0.2)
+    ///     * Rotation3::from_axis_angle(&Vector3::z_axis(), 0.3);
+    /// assert_relative_eq!(rot, expected, epsilon = 1.0e-7);
+    /// ```
+    pub fn from_euler_sequence(sequence: EulerSequence, first: N, second: N, third: N) -> Self {
+        UnitQuaternion::from_euler_sequence(sequence, first, second, third).to_rotation_matrix()
+    }
 }
 
 /// # Construction from a 3D eye position and target point
@@ -738,6 +758,38 @@ where
         Self::from_matrix_unchecked(rot)
     }
 
+    /// Builds the rotation matrix closest to `m` in the Frobenius norm.
+    ///
+    /// This uses the polar decomposition `m = U * H` of `m`. If `U` is a reflection, i.e., if
+    /// `m` has a negative determinant, the direction of `H` with the smallest stretch is flipped
+    /// so that a proper rotation is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// let rot = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.5);
+    /// let stretch = Matrix3::from_diagonal(&Vector3::new(1.0, 2.0, 3.0));
+    /// let deformation = rot.matrix() * stretch;
+    ///
+    /// assert_relative_eq!(Rotation3::from_matrix_polar(&deformation), rot, epsilon = 1.0e-7);
+    /// ```
+    pub fn from_matrix_polar(m: &Matrix3<N>) -> Self
+    where
+        N: RealField,
+    {
+        let (mut u, h) = m.polar().unpack();
+
+        if u.determinant() < N::zero() {
+            let eigen = h.symmetric_eigen();
+            let v = eigen.eigenvectors.column(eigen.eigenvalues.imin());
+            let uv = u * v;
+            u.ger(-crate::convert::<_, N>(2.0), &uv, &v, N::one());
+        }
+
+        Self::from_matrix_unchecked(u)
+    }
+
     /// Ensure this rotation is an orthonormal rotation matrix. This is useful when repeated
     /// computations might cause the matrix from progressively not being orthonormal anymore.
     #[inline]
@@ -917,6 +969,29 @@ impl<N: SimdRealField> Rotation3<N> {
             )
         }
     }
+
+    /// Euler angles of this rotation in the given sequence.
+    ///
+    /// The angles are produced in the order of the axes of `sequence`. See [`EulerSequence`] for
+    /// their ranges and the handling of gimbal lock.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerAxes, EulerSequence, Rotation3};
+    /// let xzy = EulerSequence::extrinsic(EulerAxes::XZY);
+    /// let rot = Rotation3::from_euler_sequence(xzy, 0.1, 0.2, 0.3);
+    /// let euler = rot.euler_sequence_angles(xzy);
+    /// assert_relative_eq!(euler.0, 0.1, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.1, 0.2, epsilon = 1.0e-6);
+    /// assert_relative_eq!(euler.2, 0.3, epsilon = 1.0e-6);
+    /// ```
+    pub fn euler_sequence_angles(&self, sequence: EulerSequence) -> (N, N, N)
+    where
+        N: RealField,
+    {
+        UnitQuaternion::from_rotation_matrix(self).euler_sequence_angles(sequence)
+    }
 }
 
 #[cfg(feature = "rand-no-std")]
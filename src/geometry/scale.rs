@@ -0,0 +1,367 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+use std::fmt;
+use std::hash;
+#[cfg(feature = "abomonation-serialize")]
+use std::io::{Result as IOResult, Write};
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "abomonation-serialize")]
+use abomonation::Abomonation;
+
+use simba::scalar::{ClosedDiv, ClosedMul};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimName, DimNameAdd, DimNameSum, U1};
+use crate::base::storage::Owned;
+use crate::base::{DefaultAllocator, MatrixN, Scalar, VectorN};
+
+use crate::geometry::Point;
+
+/// A scale which supports non-uniform scaling.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Scale<N: Scalar, D: DimName>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// The scale coordinates, i.e., how much is multiplied to a point's coordinates when it is
+    /// scaled.
+    pub vector: VectorN<N, D>,
+}
+
+impl<N: Scalar + hash::Hash, D: DimName + hash::Hash> hash::Hash for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    Owned<N, D>: hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.vector.hash(state)
+    }
+}
+
+impl<N: Scalar + Copy, D: DimName> Copy for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    Owned<N, D>: Copy,
+{
+}
+
+impl<N: Scalar, D: DimName> Clone for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    Owned<N, D>: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Scale::from(self.vector.clone())
+    }
+}
+
+#[cfg(feature = "abomonation-serialize")]
+impl<N, D> Abomonation for Scale<N, D>
+where
+    N: Scalar,
+    D: DimName,
+    VectorN<N, D>: Abomonation,
+    DefaultAllocator: Allocator<N, D>,
+{
+    unsafe fn entomb<W: Write>(&self, writer: &mut W) -> IOResult<()> {
+        self.vector.entomb(writer)
+    }
+
+    fn extent(&self) -> usize {
+        self.vector.extent()
+    }
+
+    unsafe fn exhume<'a, 'b>(&'a mut self, bytes: &'b mut [u8]) -> Option<&'b mut [u8]> {
+        self.vector.exhume(bytes)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<N: Scalar, D: DimName> Serialize for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    Owned<N, D>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.vector.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'a, N: Scalar, D: DimName> Deserialize<'a> for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    Owned<N, D>: Deserialize<'a>,
+{
+    fn deserialize<Des>(deserializer: Des) -> Result<Self, Des::Error>
+    where
+        Des: Deserializer<'a>,
+    {
+        let matrix = VectorN::<N, D>::deserialize(deserializer)?;
+
+        Ok(Scale::from(matrix))
+    }
+}
+
+impl<N: Scalar, D: DimName> Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// Inverts `self`.
+    ///
+    /// Returns `None` if any of the scale factors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Scale2, Scale3};
+    /// let s = Scale3::new(1.0, 2.0, 4.0);
+    /// assert_eq!(s * s.try_inverse().unwrap(), Scale3::identity());
+    /// assert_eq!(s.try_inverse().unwrap() * s, Scale3::identity());
+    ///
+    /// // Work in all dimensions.
+    /// let s = Scale2::new(1.0, 2.0);
+    /// assert_eq!(s * s.try_inverse().unwrap(), Scale2::identity());
+    ///
+    /// // Returns None if a scale factor is zero.
+    /// let s = Scale2::new(0.0, 2.0);
+    /// assert_eq!(s.try_inverse(), None);
+    /// ```
+    #[inline]
+    #[must_use = "Did you mean to use try_inverse_mut()?"]
+    pub fn try_inverse(&self) -> Option<Scale<N, D>>
+    where
+        N: Zero + One + ClosedDiv,
+    {
+        if self.vector.iter().any(|e| e.is_zero()) {
+            None
+        } else {
+            Some(self.vector.map(|e| N::one() / e).into())
+        }
+    }
+
+    /// Inverts `self`, leaving the zero scale factors unchanged.
+    ///
+    /// This is the Moore-Penrose pseudo-inverse of the scale, seen as a diagonal matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Scale3;
+    /// let s = Scale3::new(2.0, 0.0, 4.0);
+    /// assert_eq!(s.pseudo_inverse(), Scale3::new(0.5, 0.0, 0.25));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pseudo_inverse(&self) -> Scale<N, D>
+    where
+        N: Zero + One + ClosedDiv,
+    {
+        self.vector
+            .map(|e| if e.is_zero() { N::zero() } else { N::one() / e })
+            .into()
+    }
+
+    /// Converts this scale into its equivalent homogeneous transformation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Scale2, Scale3, Matrix3, Matrix4};
+    /// let s = Scale3::new(10.0, 20.0, 30.0);
+    /// let expected = Matrix4::new(10.0, 0.0, 0.0, 0.0,
+    ///                             0.0, 20.0, 0.0, 0.0,
+    ///                             0.0, 0.0, 30.0, 0.0,
+    ///                             0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(s.to_homogeneous(), expected);
+    ///
+    /// let s = Scale2::new(10.0, 20.0);
+    /// let expected = Matrix3::new(10.0, 0.0, 0.0,
+    ///                             0.0, 20.0, 0.0,
+    ///                             0.0, 0.0, 1.0);
+    /// assert_eq!(s.to_homogeneous(), expected);
+    /// ```
+    #[inline]
+    pub fn to_homogeneous(&self) -> MatrixN<N, DimNameSum<D, U1>>
+    where
+        N: Zero + One,
+        D: DimNameAdd<U1>,
+        DefaultAllocator: Allocator<N, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+    {
+        let mut res = MatrixN::<N, DimNameSum<D, U1>>::identity();
+        for i in 0..D::dim() {
+            res[(i, i)] = self.vector[i].inlined_clone();
+        }
+
+        res
+    }
+
+    /// Inverts `self` in-place.
+    ///
+    /// Returns `false` and leaves `self` unchanged if any of the scale factors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Scale3;
+    /// let s = Scale3::new(1.0, 2.0, 4.0);
+    /// let mut inv_s = s;
+    /// assert!(inv_s.try_inverse_mut());
+    /// assert_eq!(s * inv_s, Scale3::identity());
+    ///
+    /// let mut s = Scale3::new(1.0, 0.0, 4.0);
+    /// assert!(!s.try_inverse_mut());
+    /// assert_eq!(s, Scale3::new(1.0, 0.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn try_inverse_mut(&mut self) -> bool
+    where
+        N: Zero + One + ClosedDiv,
+    {
+        if let Some(inv) = self.try_inverse() {
+            *self = inv;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<N: Scalar + ClosedMul, D: DimName> Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// Scale the given point.
+    ///
+    /// This is the same as the multiplication `self * pt`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Scale3, Point3};
+    /// let s = Scale3::new(1.0, 2.0, 3.0);
+    /// let transformed_point = s.transform_point(&Point3::new(4.0, 5.0, 6.0));
+    /// assert_eq!(transformed_point, Point3::new(4.0, 10.0, 18.0));
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, pt: &Point<N, D>) -> Point<N, D> {
+        pt.coords.component_mul(&self.vector).into()
+    }
+}
+
+impl<N: Scalar + ClosedDiv + Zero, D: DimName> Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// Scale the given point by the inverse of this scale.
+    ///
+    /// Returns `None` if any of the scale factors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Scale3, Point3};
+    /// let s = Scale3::new(1.0, 2.0, 3.0);
+    /// let transformed_point = s.try_inverse_transform_point(&Point3::new(4.0, 6.0, 6.0));
+    /// assert_eq!(transformed_point, Some(Point3::new(4.0, 3.0, 2.0)));
+    ///
+    /// let s = Scale3::new(1.0, 0.0, 3.0);
+    /// assert_eq!(s.try_inverse_transform_point(&Point3::new(4.0, 6.0, 6.0)), None);
+    /// ```
+    #[inline]
+    pub fn try_inverse_transform_point(&self, pt: &Point<N, D>) -> Option<Point<N, D>> {
+        if self.vector.iter().any(|e| e.is_zero()) {
+            None
+        } else {
+            Some(pt.coords.component_div(&self.vector).into())
+        }
+    }
+}
+
+impl<N: Scalar + Eq, D: DimName> Eq for Scale<N, D> where DefaultAllocator: Allocator<N, D> {}
+
+impl<N: Scalar + PartialEq, D: DimName> PartialEq for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    #[inline]
+    fn eq(&self, right: &Scale<N, D>) -> bool {
+        self.vector == right.vector
+    }
+}
+
+impl<N: Scalar + AbsDiffEq, D: DimName> AbsDiffEq for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    N::Epsilon: Copy,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.vector.abs_diff_eq(&other.vector, epsilon)
+    }
+}
+
+impl<N: Scalar + RelativeEq, D: DimName> RelativeEq for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    N::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.vector
+            .relative_eq(&other.vector, epsilon, max_relative)
+    }
+}
+
+impl<N: Scalar + UlpsEq, D: DimName> UlpsEq for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    N::Epsilon: Copy,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.vector.ulps_eq(&other.vector, epsilon, max_ulps)
+    }
+}
+
+/*
+ *
+ * Display
+ *
+ */
+impl<N: Scalar + fmt::Display, D: DimName> fmt::Display for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D> + Allocator<usize, D>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+
+        writeln!(f, "Scale {{")?;
+        write!(f, "{:.*}", precision, self.vector)?;
+        writeln!(f, "}}")
+    }
+}
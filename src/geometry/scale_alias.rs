@@ -0,0 +1,21 @@
+use crate::base::dimension::{U1, U2, U3, U4, U5, U6};
+
+use crate::geometry::Scale;
+
+/// A 1-dimensional nonuniform scaling.
+pub type Scale1<N> = Scale<N, U1>;
+
+/// A 2-dimensional nonuniform scaling.
+pub type Scale2<N> = Scale<N, U2>;
+
+/// A 3-dimensional nonuniform scaling.
+pub type Scale3<N> = Scale<N, U3>;
+
+/// A 4-dimensional nonuniform scaling.
+pub type Scale4<N> = Scale<N, U4>;
+
+/// A 5-dimensional nonuniform scaling.
+pub type Scale5<N> = Scale<N, U5>;
+
+/// A 6-dimensional nonuniform scaling.
+pub type Scale6<N> = Scale<N, U6>;
@@ -0,0 +1,148 @@
+#[cfg(feature = "arbitrary")]
+use crate::base::storage::Owned;
+#[cfg(feature = "arbitrary")]
+use quickcheck::{Arbitrary, Gen};
+
+use num::{One, Zero};
+#[cfg(feature = "rand-no-std")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+use simba::scalar::{ClosedAdd, ClosedMul, SupersetOf};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimName, U1, U2, U3, U4, U5, U6};
+use crate::base::{DefaultAllocator, Scalar, VectorN};
+
+use crate::geometry::Scale;
+
+impl<N: Scalar, D: DimName> Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// Creates a new identity scale.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Point2, Point3, Scale2, Scale3};
+    /// let s = Scale2::identity();
+    /// let p = Point2::new(1.0, 2.0);
+    /// assert_eq!(s * p, p);
+    ///
+    /// // Works in all dimensions.
+    /// let s = Scale3::identity();
+    /// let p = Point3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(s * p, p);
+    /// ```
+    #[inline]
+    pub fn identity() -> Scale<N, D>
+    where
+        N: One,
+    {
+        Self::from(VectorN::<N, D>::from_element(N::one()))
+    }
+
+    /// Creates a new scale with the same scale factor along every axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Scale3;
+    /// let s = Scale3::from_uniform(2.0);
+    /// assert_eq!(s, Scale3::new(2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn from_uniform(scaling: N) -> Scale<N, D> {
+        Self::from(VectorN::<N, D>::from_element(scaling))
+    }
+
+    /// Cast the components of `self` to another type.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Scale2;
+    /// let s = Scale2::new(1.0f64, 2.0);
+    /// let s2 = s.cast::<f32>();
+    /// assert_eq!(s2, Scale2::new(1.0f32, 2.0));
+    /// ```
+    pub fn cast<To: Scalar>(self) -> Scale<To, D>
+    where
+        Scale<To, D>: SupersetOf<Self>,
+        DefaultAllocator: Allocator<To, D>,
+    {
+        crate::convert(self)
+    }
+}
+
+impl<N: Scalar + Zero + One + ClosedAdd + ClosedMul, D: DimName> One for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(feature = "rand-no-std")]
+impl<N: Scalar, D: DimName> Distribution<Scale<N, D>> for Standard
+where
+    DefaultAllocator: Allocator<N, D>,
+    Standard: Distribution<N>,
+{
+    /// Generate an arbitrary random variate for testing purposes.
+    #[inline]
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> Scale<N, D> {
+        Scale::from(rng.gen::<VectorN<N, D>>())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<N: Scalar + Arbitrary + Send, D: DimName> Arbitrary for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    Owned<N, D>: Send,
+{
+    #[inline]
+    fn arbitrary(rng: &mut Gen) -> Self {
+        let v: VectorN<N, D> = Arbitrary::arbitrary(rng);
+        Self::from(v)
+    }
+}
+
+/*
+ *
+ * Small scale construction from components.
+ *
+ */
+macro_rules! componentwise_constructors_impl(
+    ($($doc: expr; $D: ty, $($args: ident:$irow: expr),*);* $(;)*) => {$(
+        impl<N: Scalar> Scale<N, $D>
+            where DefaultAllocator: Allocator<N, $D> {
+            #[doc = "Initializes this scale from its components."]
+            #[doc = "# Example\n```"]
+            #[doc = $doc]
+            #[doc = "```"]
+            #[inline]
+            pub fn new($($args: N),*) -> Self {
+                Self::from(VectorN::<N, $D>::new($($args),*))
+            }
+        }
+    )*}
+);
+
+componentwise_constructors_impl!(
+    "# use nalgebra::Scale1;\nlet s = Scale1::new(1.0);\nassert!(s.vector.x == 1.0);";
+    U1, x:0;
+    "# use nalgebra::Scale2;\nlet s = Scale2::new(1.0, 2.0);\nassert!(s.vector.x == 1.0 && s.vector.y == 2.0);";
+    U2, x:0, y:1;
+    "# use nalgebra::Scale3;\nlet s = Scale3::new(1.0, 2.0, 3.0);\nassert!(s.vector.x == 1.0 && s.vector.y == 2.0 && s.vector.z == 3.0);";
+    U3, x:0, y:1, z:2;
+    "# use nalgebra::Scale4;\nlet s = Scale4::new(1.0, 2.0, 3.0, 4.0);\nassert!(s.vector.x == 1.0 && s.vector.y == 2.0 && s.vector.z == 3.0 && s.vector.w == 4.0);";
+    U4, x:0, y:1, z:2, w:3;
+    "# use nalgebra::Scale5;\nlet s = Scale5::new(1.0, 2.0, 3.0, 4.0, 5.0);\nassert!(s.vector.x == 1.0 && s.vector.y == 2.0 && s.vector.z == 3.0 && s.vector.w == 4.0 && s.vector.a == 5.0);";
+    U5, x:0, y:1, z:2, w:3, a:4;
+    "# use nalgebra::Scale6;\nlet s = Scale6::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);\nassert!(s.vector.x == 1.0 && s.vector.y == 2.0 && s.vector.z == 3.0 && s.vector.w == 4.0 && s.vector.a == 5.0 && s.vector.b == 6.0);";
+    U6, x:0, y:1, z:2, w:3, a:4, b:5;
+);
@@ -0,0 +1,210 @@
+use num::{One, Zero};
+
+use simba::scalar::{RealField, SubsetOf, SupersetOf};
+use simba::simd::PrimitiveSimdValue;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimName, DimNameAdd, DimNameSum, U1};
+use crate::base::{DefaultAllocator, MatrixN, Scalar, VectorN};
+
+use crate::geometry::{Scale, SuperTCategoryOf, TAffine, Transform};
+
+/*
+ * This file provides the following conversions:
+ * =============================================
+ *
+ * Scale -> Scale
+ * Scale -> Transform
+ * Scale -> Matrix (homogeneous)
+ */
+
+impl<N1, N2, D: DimName> SubsetOf<Scale<N2, D>> for Scale<N1, D>
+where
+    N1: Scalar,
+    N2: Scalar + SupersetOf<N1>,
+    DefaultAllocator: Allocator<N1, D> + Allocator<N2, D>,
+{
+    #[inline]
+    fn to_superset(&self) -> Scale<N2, D> {
+        Scale::from(self.vector.to_superset())
+    }
+
+    #[inline]
+    fn is_in_subset(scale: &Scale<N2, D>) -> bool {
+        crate::is_convertible::<_, VectorN<N1, D>>(&scale.vector)
+    }
+
+    #[inline]
+    fn from_superset_unchecked(scale: &Scale<N2, D>) -> Self {
+        Scale {
+            vector: scale.vector.to_subset_unchecked(),
+        }
+    }
+}
+
+impl<N1, N2, D, C> SubsetOf<Transform<N2, D, C>> for Scale<N1, D>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+    C: SuperTCategoryOf<TAffine>,
+    D: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<N1, D>
+        + Allocator<N2, D>
+        + Allocator<N1, DimNameSum<D, U1>, DimNameSum<D, U1>>
+        + Allocator<N2, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+{
+    #[inline]
+    fn to_superset(&self) -> Transform<N2, D, C> {
+        Transform::from_matrix_unchecked(self.to_homogeneous().to_superset())
+    }
+
+    #[inline]
+    fn is_in_subset(t: &Transform<N2, D, C>) -> bool {
+        <Self as SubsetOf<_>>::is_in_subset(t.matrix())
+    }
+
+    #[inline]
+    fn from_superset_unchecked(t: &Transform<N2, D, C>) -> Self {
+        Self::from_superset_unchecked(t.matrix())
+    }
+}
+
+impl<N1, N2, D> SubsetOf<MatrixN<N2, DimNameSum<D, U1>>> for Scale<N1, D>
+where
+    N1: RealField,
+    N2: RealField + SupersetOf<N1>,
+    D: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<N1, D>
+        + Allocator<N2, D>
+        + Allocator<N1, DimNameSum<D, U1>, DimNameSum<D, U1>>
+        + Allocator<N2, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+{
+    #[inline]
+    fn to_superset(&self) -> MatrixN<N2, DimNameSum<D, U1>> {
+        self.to_homogeneous().to_superset()
+    }
+
+    #[inline]
+    fn is_in_subset(m: &MatrixN<N2, DimNameSum<D, U1>>) -> bool {
+        // Scalar types agree.
+        m.iter().all(|e| SupersetOf::<N1>::is_in_subset(e)) &&
+        // The matrix is diagonal, i.e., there is no rotation, shear or translation.
+        m.iter().enumerate().all(|(k, e)| {
+            let (i, j) = (k % m.nrows(), k / m.nrows());
+            i == j || e.is_zero()
+        }) &&
+        // The normalization factor is one.
+        m[(D::dim(), D::dim())] == N2::one()
+    }
+
+    #[inline]
+    fn from_superset_unchecked(m: &MatrixN<N2, DimNameSum<D, U1>>) -> Self {
+        let v = m.fixed_slice::<D, D>(0, 0).diagonal();
+        Self {
+            vector: crate::convert_unchecked(v),
+        }
+    }
+}
+
+impl<N: Scalar + Zero + One, D: DimName> From<Scale<N, D>> for MatrixN<N, DimNameSum<D, U1>>
+where
+    D: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+{
+    #[inline]
+    fn from(s: Scale<N, D>) -> Self {
+        s.to_homogeneous()
+    }
+}
+
+impl<N: Scalar, D: DimName> From<VectorN<N, D>> for Scale<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    #[inline]
+    fn from(vector: VectorN<N, D>) -> Self {
+        Scale { vector }
+    }
+}
+
+impl<N: Scalar + PrimitiveSimdValue, D: DimName> From<[Scale<N::Element, D>; 2]> for Scale<N, D>
+where
+    N: From<[<N as simba::simd::SimdValue>::Element; 2]>,
+    N::Element: Scalar,
+    DefaultAllocator: Allocator<N, D> + Allocator<N::Element, D>,
+{
+    #[inline]
+    fn from(arr: [Scale<N::Element, D>; 2]) -> Self {
+        Self::from(VectorN::from([
+            arr[0].vector.clone(),
+            arr[1].vector.clone(),
+        ]))
+    }
+}
+
+impl<N: Scalar + PrimitiveSimdValue, D: DimName> From<[Scale<N::Element, D>; 4]> for Scale<N, D>
+where
+    N: From<[<N as simba::simd::SimdValue>::Element; 4]>,
+    N::Element: Scalar,
+    DefaultAllocator: Allocator<N, D> + Allocator<N::Element, D>,
+{
+    #[inline]
+    fn from(arr: [Scale<N::Element, D>; 4]) -> Self {
+        Self::from(VectorN::from([
+            arr[0].vector.clone(),
+            arr[1].vector.clone(),
+            arr[2].vector.clone(),
+            arr[3].vector.clone(),
+        ]))
+    }
+}
+
+impl<N: Scalar + PrimitiveSimdValue, D: DimName> From<[Scale<N::Element, D>; 8]> for Scale<N, D>
+where
+    N: From<[<N as simba::simd::SimdValue>::Element; 8]>,
+    N::Element: Scalar,
+    DefaultAllocator: Allocator<N, D> + Allocator<N::Element, D>,
+{
+    #[inline]
+    fn from(arr: [Scale<N::Element, D>; 8]) -> Self {
+        Self::from(VectorN::from([
+            arr[0].vector.clone(),
+            arr[1].vector.clone(),
+            arr[2].vector.clone(),
+            arr[3].vector.clone(),
+            arr[4].vector.clone(),
+            arr[5].vector.clone(),
+            arr[6].vector.clone(),
+            arr[7].vector.clone(),
+        ]))
+    }
+}
+
+impl<N: Scalar + PrimitiveSimdValue, D: DimName> From<[Scale<N::Element, D>; 16]> for Scale<N, D>
+where
+    N: From<[<N as simba::simd::SimdValue>::Element; 16]>,
+    N::Element: Scalar,
+    DefaultAllocator: Allocator<N, D> + Allocator<N::Element, D>,
+{
+    #[inline]
+    fn from(arr: [Scale<N::Element, D>; 16]) -> Self {
+        Self::from(VectorN::from([
+            arr[0].vector.clone(),
+            arr[1].vector.clone(),
+            arr[2].vector.clone(),
+            arr[3].vector.clone(),
+            arr[4].vector.clone(),
+            arr[5].vector.clone(),
+            arr[6].vector.clone(),
+            arr[7].vector.clone(),
+            arr[8].vector.clone(),
+            arr[9].vector.clone(),
+            arr[10].vector.clone(),
+            arr[11].vector.clone(),
+            arr[12].vector.clone(),
+            arr[13].vector.clone(),
+            arr[14].vector.clone(),
+            arr[15].vector.clone(),
+        ]))
+    }
+}
@@ -0,0 +1,206 @@
+use num::{One, Zero};
+use std::ops::{Mul, MulAssign};
+
+use simba::scalar::{ClosedAdd, ClosedMul, RealField, SubsetOf};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimName, DimNameAdd, DimNameSum, U1, U3, U4};
+use crate::base::{DefaultAllocator, MatrixN, Scalar, VectorN};
+
+use crate::geometry::{
+    Isometry, Point, Rotation, Scale, TAffine, Transform, Translation, UnitQuaternion,
+};
+
+/*
+ *
+ * In the following, we provide:
+ * =========================
+ *
+ * Scale × Scale
+ * Scale × Point
+ * Scale × Vector
+ *
+ * Scale × Translation
+ * Scale × Rotation
+ * Scale × UnitQuaternion
+ * Scale × Isometry
+ *
+ * Translation    × Scale
+ * Rotation       × Scale
+ * UnitQuaternion × Scale
+ * Isometry       × Scale
+ *
+ * Scale ×= Scale
+ *
+ *
+ * The compositions of a scale with another kind of transformation are not scales anymore.
+ * They are therefore returned as affine transformations.
+ */
+
+// Scale × Scale
+md_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1) for D: DimName;
+    self: Scale<N, D>, right: Scale<N, D>, Output = Scale<N, D>;
+    [val val] => Scale::from(self.vector.component_mul(&right.vector));
+    [ref val] => Scale::from(self.vector.component_mul(&right.vector));
+    [val ref] => Scale::from(self.vector.component_mul(&right.vector));
+    [ref ref] => Scale::from(self.vector.component_mul(&right.vector));
+);
+
+// Scale × Point
+md_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1) for D: DimName;
+    self: Scale<N, D>, right: Point<N, D>, Output = Point<N, D>;
+    [val val] => Point::from(self.vector.component_mul(&right.coords));
+    [ref val] => Point::from(self.vector.component_mul(&right.coords));
+    [val ref] => Point::from(self.vector.component_mul(&right.coords));
+    [ref ref] => Point::from(self.vector.component_mul(&right.coords));
+);
+
+// Scale × Vector
+md_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1) for D: DimName;
+    self: Scale<N, D>, right: VectorN<N, D>, Output = VectorN<N, D>;
+    [val val] => self.vector.component_mul(&right);
+    [ref val] => self.vector.component_mul(&right);
+    [val ref] => self.vector.component_mul(right);
+    [ref ref] => self.vector.component_mul(right);
+);
+
+// Scale ×= Scale
+md_assign_impl_all!(
+    MulAssign, mul_assign;
+    (D, U1), (D, U1) for D: DimName;
+    self: Scale<N, D>, right: Scale<N, D>;
+    [val] => self.vector.component_mul_assign(&right.vector);
+    [ref] => self.vector.component_mul_assign(&right.vector);
+);
+
+macro_rules! scale_affine_composition_impl(
+    ($Op: ident, $op: ident;
+     ($R1: ty, $C1: ty),($R2: ty, $C2: ty),($R3: ty, $C3: ty)
+     $(for $($Dims: ident: $DimsBound: ident $(<$($BoundParam: ty),*>)*),+)*;
+     $lhs: ident: $Lhs: ty, $rhs: ident: $Rhs: ty, Output = $Output: ty;
+     $action: expr; $($lives: tt),*) => {
+        impl<$($lives ,)* N: RealField $($(, $Dims: $DimsBound $(<$($BoundParam),*>)*)+)*> $Op<$Rhs> for $Lhs
+            where DefaultAllocator: Allocator<N, $R1, $C1> +
+                                    Allocator<N, $R2, $C2> +
+                                    Allocator<N, $R3, $C3> {
+            type Output = $Output;
+
+            #[inline]
+            fn $op($lhs, $rhs: $Rhs) -> Self::Output {
+                $action
+            }
+        }
+    }
+);
+
+macro_rules! scale_affine_composition_impl_all(
+    ($Op: ident, $op: ident;
+     ($R1: ty, $C1: ty),($R2: ty, $C2: ty),($R3: ty, $C3: ty)
+     $(for $($Dims: ident: $DimsBound: ident $(<$($BoundParam: ty),*>)*),+)*;
+     $lhs: ident: $Lhs: ty, $rhs: ident: $Rhs: ty, Output = $Output: ty;
+     $action: expr;) => {
+
+        scale_affine_composition_impl!(
+            $Op, $op;
+            ($R1, $C1),($R2, $C2),($R3, $C3) $(for $($Dims: $DimsBound $(<$($BoundParam),*>)*),+)*;
+            $lhs: $Lhs, $rhs: $Rhs, Output = $Output;
+            $action; );
+
+        scale_affine_composition_impl!(
+            $Op, $op;
+            ($R1, $C1),($R2, $C2),($R3, $C3) $(for $($Dims: $DimsBound $(<$($BoundParam),*>)*),+)*;
+            $lhs: &'a $Lhs, $rhs: $Rhs, Output = $Output;
+            $action; 'a);
+
+        scale_affine_composition_impl!(
+            $Op, $op;
+            ($R1, $C1),($R2, $C2),($R3, $C3) $(for $($Dims: $DimsBound $(<$($BoundParam),*>)*),+)*;
+            $lhs: $Lhs, $rhs: &'b $Rhs, Output = $Output;
+            $action; 'b);
+
+        scale_affine_composition_impl!(
+            $Op, $op;
+            ($R1, $C1),($R2, $C2),($R3, $C3) $(for $($Dims: $DimsBound $(<$($BoundParam),*>)*),+)*;
+            $lhs: &'a $Lhs, $rhs: &'b $Rhs, Output = $Output;
+            $action; 'a, 'b);
+    }
+);
+
+/*
+ *
+ * TODO: don't explicitly build the homogeneous matrices.
+ * The scale could be applied directly to the rows or columns of the other transformation's
+ * homogeneous matrix.
+ *
+ */
+// Scale × Translation
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1), (DimNameSum<D, U1>, DimNameSum<D, U1>) for D: DimNameAdd<U1>;
+    self: Scale<N, D>, right: Translation<N, D>, Output = Transform<N, D, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// Translation × Scale
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1), (DimNameSum<D, U1>, DimNameSum<D, U1>) for D: DimNameAdd<U1>;
+    self: Translation<N, D>, right: Scale<N, D>, Output = Transform<N, D, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// Scale × Rotation
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (D, U1), (D, D), (DimNameSum<D, U1>, DimNameSum<D, U1>) for D: DimNameAdd<U1>;
+    self: Scale<N, D>, right: Rotation<N, D>, Output = Transform<N, D, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// Rotation × Scale
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (D, D), (D, U1), (DimNameSum<D, U1>, DimNameSum<D, U1>) for D: DimNameAdd<U1>;
+    self: Rotation<N, D>, right: Scale<N, D>, Output = Transform<N, D, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// Scale × UnitQuaternion
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (U3, U1), (U4, U1), (U4, U4);
+    self: Scale<N, U3>, right: UnitQuaternion<N>, Output = Transform<N, U3, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// UnitQuaternion × Scale
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (U4, U1), (U3, U1), (U4, U4);
+    self: UnitQuaternion<N>, right: Scale<N, U3>, Output = Transform<N, U3, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// Scale × Isometry
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1), (DimNameSum<D, U1>, DimNameSum<D, U1>)
+    for D: DimNameAdd<U1>, R: SubsetOf<MatrixN<N, DimNameSum<D, U1>>>;
+    self: Scale<N, D>, right: Isometry<N, D, R>, Output = Transform<N, D, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
+
+// Isometry × Scale
+scale_affine_composition_impl_all!(
+    Mul, mul;
+    (D, U1), (D, U1), (DimNameSum<D, U1>, DimNameSum<D, U1>)
+    for D: DimNameAdd<U1>, R: SubsetOf<MatrixN<N, DimNameSum<D, U1>>>;
+    self: Isometry<N, D, R>, right: Scale<N, D>, Output = Transform<N, D, TAffine>;
+    Transform::from_matrix_unchecked(self.to_homogeneous() * right.to_homogeneous());
+);
@@ -1,4 +1,7 @@
 //! Parsers for various matrix formats.
+//!
+//! The Matrix Market parser only supports real matrices in coordinate format. The `io` module of
+//! `nalgebra-sparse` provides a complete reader and writer for the Matrix Market format.
 
 pub use self::matrix_market::{cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str};
 
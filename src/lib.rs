@@ -67,7 +67,9 @@ an optimized set of tools for computer graphics and physics. Those features incl
 * General transformations that does not have to be invertible, stored as a homogeneous matrix:
   `Transform2`, `Transform3`.
 * 3D projections for computer graphics: `Perspective3`, `Orthographic3`.
-* Matrix factorizations: `Cholesky`, `QR`, `LU`, `FullPivLU`, `SVD`, `Schur`, `Hessenberg`, `SymmetricEigen`.
+* Matrix factorizations: `Cholesky`, `LDLT`, `QR`, `LQ`, `RQ`, `CompleteOrthogonalDecomposition`, `LU`, `FullPivLU`,
+  `SVD`, `Polar`, `Schur`, `Hessenberg`, `SymmetricEigen`, `Eigen`, `GeneralizedSchur`, `GeneralizedEigen`,
+  `SymmetricGeneralizedEigen`.
 * Insertion and removal of rows of columns of a matrix.
 */
 
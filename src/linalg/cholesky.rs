@@ -1,16 +1,18 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
-use num::One;
-use simba::scalar::ComplexField;
+use num::{One, Zero};
+use simba::scalar::{ComplexField, SupersetOf};
 use simba::simd::SimdComplexField;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Vector};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Vector, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 
+use crate::linalg::{norm_estimate, refinement, RefinedSolution};
+
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -195,6 +197,64 @@ where
         Some(Cholesky { chol: matrix })
     }
 
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// The 1-norm `anorm` of the decomposed matrix must be provided, e.g., computed with
+    /// `matrix.one_norm()` before the decomposition.
+    pub fn rcond(&self, anorm: N::RealField) -> N::RealField
+    where
+        DefaultAllocator: Allocator<N, D>,
+    {
+        let dim = self.chol.data.shape().0;
+
+        if dim.value() == 0 {
+            return N::RealField::one();
+        }
+
+        if anorm.is_zero() {
+            return N::RealField::zero();
+        }
+
+        // The decomposed matrix is hermitian so both solves are the same.
+        let solve = |x: &mut VectorN<N, D>| {
+            self.solve_mut(x);
+            true
+        };
+        let inv_norm = norm_estimate::estimate_inverse_one_norm(dim, solve, solve);
+
+        match inv_norm {
+            Some(inv_norm) if !inv_norm.is_zero() => inv_norm.recip() / anorm,
+            _ => N::RealField::zero(),
+        }
+    }
+
+    /// Solves the linear system `a * x = b` with iterative refinement, where `self` is a
+    /// decomposition of `a` and `x` is the unknown to be determined.
+    ///
+    /// See `RefinedSolution` for the precision of the residuals and the error bounds.
+    pub fn solve_refined<N2, S, C2: Dim, S2>(
+        &self,
+        a: &Matrix<N2, D, D, S>,
+        b: &Matrix<N2, D, C2, S2>,
+    ) -> Option<RefinedSolution<N2, D, C2>>
+    where
+        N2: ComplexField + SupersetOf<N>,
+        S: Storage<N2, D, D>,
+        S2: Storage<N2, D, C2>,
+        DefaultAllocator: Allocator<N, D>
+            + Allocator<N2, D>
+            + Allocator<N2, D, C2>
+            + Allocator<N2::RealField, D>
+            + Allocator<N2::RealField, C2>,
+    {
+        // The decomposed matrix is hermitian so both solves are the same.
+        let solve = |x: &mut VectorN<N, D>| {
+            self.solve_mut(x);
+            true
+        };
+        refinement::solve_refined(a, b, solve, solve)
+    }
+
     /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `v`,
     /// performs a rank one update such that we end up with the decomposition of `M + sigma * (v * v.adjoint())`.
     #[inline]
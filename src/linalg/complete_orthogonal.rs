@@ -0,0 +1,182 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::storage::Storage;
+
+use crate::linalg::{ColPivQR, PermutationSequence, LQ};
+
+/// The complete orthogonal decomposition of a general matrix.
+///
+/// The matrix `m` is decomposed as `m * P = Q * T * Z`, where `P` is a permutation matrix, `Q`
+/// has orthonormal columns, `Z` has orthonormal rows, and `T` is a square lower-triangular matrix
+/// whose only non-zero elements are on its top-left `rank x rank` block. It is computed from the
+/// QR decomposition with column pivoting of `m` followed by the LQ decomposition of the `rank`
+/// first rows of its `R` factor.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>, C> +
+                           Allocator<N, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         ColPivQR<N, R, C>: Serialize,
+         LQ<N, DimMinimum<R, C>, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>, C> +
+                           Allocator<N, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         ColPivQR<N, R, C>: Deserialize<'de>,
+         LQ<N, DimMinimum<R, C>, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct CompleteOrthogonalDecomposition<N: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DimMinimum<R, C>: DimMin<C, Output = DimMinimum<R, C>>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    col_piv_qr: ColPivQR<N, R, C>,
+    lq: LQ<N, DimMinimum<R, C>, C>,
+    rank: usize,
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Copy for CompleteOrthogonalDecomposition<N, R, C>
+where
+    DimMinimum<R, C>: DimMin<C, Output = DimMinimum<R, C>>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+    ColPivQR<N, R, C>: Copy,
+    LQ<N, DimMinimum<R, C>, C>: Copy,
+{
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> CompleteOrthogonalDecomposition<N, R, C>
+where
+    DimMinimum<R, C>: DimMin<C, Output = DimMinimum<R, C>>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, C>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes the complete orthogonal decomposition of `matrix`.
+    ///
+    /// The rank of `matrix` is determined as the number of leading diagonal elements of the `R`
+    /// factor of its QR decomposition with column pivoting that are greater than `eps` times the
+    /// largest one (in absolute value).
+    pub fn new(matrix: MatrixMN<N, R, C>, eps: N::RealField) -> Self {
+        let col_piv_qr = ColPivQR::new(matrix);
+        let mut r = col_piv_qr.r();
+        let min_nrows_ncols = r.nrows();
+
+        let mut max_diag = N::RealField::zero();
+        for i in 0..min_nrows_ncols {
+            max_diag = max_diag.max(r[(i, i)].modulus());
+        }
+
+        let threshold = eps * max_diag;
+        let rank = (0..min_nrows_ncols)
+            .position(|i| r[(i, i)].modulus() <= threshold)
+            .unwrap_or(min_nrows_ncols);
+
+        r.rows_range_mut(rank..).fill(N::zero());
+
+        CompleteOrthogonalDecomposition {
+            col_piv_qr,
+            lq: LQ::new(r),
+            rank,
+        }
+    }
+
+    /// The numerical rank of the decomposed matrix.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Computes the matrix `Q` with orthonormal columns of this decomposition.
+    pub fn q(&self) -> MatrixMN<N, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>>,
+    {
+        self.col_piv_qr.q()
+    }
+
+    /// Retrieves the lower-triangular matrix `T` of this decomposition.
+    ///
+    /// Only its top-left `rank x rank` block is non-zero.
+    pub fn t(&self) -> MatrixMN<N, DimMinimum<R, C>, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<N, DimMinimum<R, C>, DimMinimum<R, C>>,
+    {
+        self.lq.l()
+    }
+
+    /// Computes the matrix `Z` with orthonormal rows of this decomposition.
+    pub fn z(&self) -> MatrixMN<N, DimMinimum<R, C>, C> {
+        self.lq.q()
+    }
+
+    /// Retrieves the column permutation `P` of this decomposition.
+    #[inline]
+    pub fn p(&self) -> &PermutationSequence<DimMinimum<R, C>> {
+        self.col_piv_qr.p()
+    }
+
+    /// Computes the minimum-norm solution `x` of the least squares problem of minimizing
+    /// `(self * x - b).norm()`.
+    ///
+    /// Among all the minimizers, the returned solution is the one with the smallest norm. This
+    /// is the solution that would be computed with the pseudo-inverse of the decomposed matrix.
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> MatrixMN<N, C, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<N, R2, C2>
+            + Allocator<N, C, C2>
+            + Allocator<N, DimMinimum<R, C>, DimMinimum<R, C>>,
+    {
+        let qr = self.col_piv_qr.col_piv_qr_internal();
+        assert_eq!(
+            qr.nrows(),
+            b.nrows(),
+            "Complete orthogonal decomposition: the right-hand side has an inconsistent number of rows."
+        );
+
+        let mut qtb = b.clone_owned();
+        self.col_piv_qr.q_tr_mul(&mut qtb);
+
+        let ncols = qr.data.shape().1;
+        let mut res = MatrixMN::zeros_generic(ncols, b.data.shape().1);
+        res.rows_range_mut(..self.rank)
+            .copy_from(&qtb.rows_range(..self.rank));
+
+        let t = self.t();
+        let _ = t
+            .slice_range(..self.rank, ..self.rank)
+            .solve_lower_triangular_mut(&mut res.rows_range_mut(..self.rank));
+
+        self.lq.q_ad_mul(&mut res);
+        self.col_piv_qr.p().inv_permute_rows(&mut res);
+
+        res
+    }
+}
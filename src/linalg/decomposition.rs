@@ -1,9 +1,12 @@
 use crate::storage::Storage;
 use crate::{
-    Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, RealField, Schur, SymmetricEigen,
-    SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    Allocator, Bidiagonal, Cholesky, ColPivQR, CompleteOrthogonalDecomposition, ComplexEigen,
+    ComplexField, ComplexGeneralizedEigen, DefaultAllocator, Dim, DimDiff, DimMin, DimMinimum,
+    DimSub, Eigen, FullPivLU, GeneralizedEigen, GeneralizedSchur, Hessenberg, Matrix, Polar,
+    RealField, Schur, SymmetricEigen, SymmetricGeneralizedEigen, SymmetricTridiagonal, UpdatableLU,
+    UpdatableQR, LDLT, LQ, LU, QR, RQ, SVD, U1, UDU,
 };
+use num_complex::Complex;
 
 /// # Rectangular matrix decomposition
 ///
@@ -13,10 +16,15 @@ use crate::{
 /// | Decomposition            | Factors             | Details |
 /// | -------------------------|---------------------|--------------|
 /// | QR                       | `Q * R`             | `Q` is an unitary matrix, and `R` is upper-triangular. |
+/// | Updatable QR             | `Q * R`             | Same as QR, with a full unitary `Q` stored explicitly so that the factors can be updated with Givens rotations. |
 /// | QR with column pivoting  | `Q * R * P⁻¹`       | `Q` is an unitary matrix, and `R` is upper-triangular. `P` is a permutation matrix. |
+/// | LQ                       | `L * Q`             | `L` is lower-triangular, and `Q` has orthonormal rows. |
+/// | RQ                       | `R * Q`             | `R` is upper-triangular, and `Q` has orthonormal rows. |
+/// | Complete orthogonal      | `Q * T * Z * P⁻¹`   | `Q` has orthonormal columns, `Z` has orthonormal rows, and `T` is lower-triangular with a non-zero `rank x rank` top-left block. `P` is a permutation matrix. |
 /// | LU with partial pivoting | `P⁻¹ * L * U`       | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` is a permutation matrix. |
 /// | LU with full pivoting    | `P⁻¹ * L * U * Q⁻¹` | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` and `Q` are permutation matrices. |
 /// | SVD                      | `U * Σ * Vᵀ`        | `U` and `V` are two orthogonal matrices and `Σ` is a diagonal matrix containing the singular values. |
+/// | Polar                    | `U * H`             | `U` has orthonormal columns (or rows), and `H` is hermitian positive semi-definite. |
 impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     /// Computes the bidiagonalization using householder reflections.
     pub fn bidiagonalize(self) -> Bidiagonal<N, R, C>
@@ -61,6 +69,20 @@ impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
         QR::new(self.into_owned())
     }
 
+    /// Computes the QR decomposition of this matrix with explicit factors that can be updated
+    /// efficiently.
+    pub fn updatable_qr(self) -> UpdatableQR<N, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<N, R, C>
+            + Allocator<N, R, R>
+            + Allocator<N, R>
+            + Allocator<N, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>, C>,
+    {
+        UpdatableQR::new(self.into_owned())
+    }
+
     /// Computes the QR decomposition (with column pivoting) of this matrix.
     pub fn col_piv_qr(self) -> ColPivQR<N, R, C>
     where
@@ -73,6 +95,47 @@ impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
         ColPivQR::new(self.into_owned())
     }
 
+    /// Computes the LQ decomposition of this matrix.
+    pub fn lq(self) -> LQ<N, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator:
+            Allocator<N, R, C> + Allocator<N, R> + Allocator<N, C> + Allocator<N, DimMinimum<R, C>>,
+    {
+        LQ::new(self.into_owned())
+    }
+
+    /// Computes the RQ decomposition of this matrix.
+    pub fn rq(self) -> RQ<N, R, C>
+    where
+        R: DimMin<C>,
+        DefaultAllocator:
+            Allocator<N, R, C> + Allocator<N, R> + Allocator<N, C> + Allocator<N, DimMinimum<R, C>>,
+    {
+        RQ::new(self.into_owned())
+    }
+
+    /// Computes the complete orthogonal decomposition of this matrix.
+    ///
+    /// Diagonal elements of the `R` factor of the QR decomposition with column pivoting that
+    /// are smaller than `eps` times the largest one are treated as zero to determine the rank.
+    pub fn complete_orthogonal_decomposition(
+        self,
+        eps: N::RealField,
+    ) -> CompleteOrthogonalDecomposition<N, R, C>
+    where
+        R: DimMin<C>,
+        DimMinimum<R, C>: DimMin<C, Output = DimMinimum<R, C>>,
+        DefaultAllocator: Allocator<N, R, C>
+            + Allocator<N, R>
+            + Allocator<N, C>
+            + Allocator<N, DimMinimum<R, C>, C>
+            + Allocator<N, DimMinimum<R, C>>
+            + Allocator<(usize, usize), DimMinimum<R, C>>,
+    {
+        CompleteOrthogonalDecomposition::new(self.into_owned(), eps)
+    }
+
     /// Computes the Singular Value Decomposition using implicit shift.
     pub fn svd(self, compute_u: bool, compute_v: bool) -> SVD<N, R, C>
     where
@@ -123,6 +186,55 @@ impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     {
         SVD::try_new(self.into_owned(), compute_u, compute_v, eps, max_niter)
     }
+
+    /// Computes the polar decomposition of this matrix.
+    ///
+    /// This uses the scaled Newton iteration, and falls back to the Singular Value Decomposition
+    /// if the iteration fails.
+    pub fn polar(self) -> Polar<N, R, C>
+    where
+        R: DimMin<C>,
+        DimMinimum<R, C>: DimSub<U1>, // for SVD.
+        DefaultAllocator: Allocator<N, R, C>
+            + Allocator<N, C, C>
+            + Allocator<N, C>
+            + Allocator<N, R>
+            + Allocator<N, R, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>, C>
+            + Allocator<N, C, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>>
+            + Allocator<N, DimDiff<DimMinimum<R, C>, U1>>
+            + Allocator<N::RealField, DimMinimum<R, C>>
+            + Allocator<N::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+    {
+        Polar::new(self.into_owned())
+    }
+
+    /// Attempts to compute the polar decomposition of this matrix using the scaled Newton
+    /// iteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance on the relative difference between two consecutive iterates.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_polar(self, eps: N::RealField, max_niter: usize) -> Option<Polar<N, R, C>>
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<N, R, C>
+            + Allocator<N, C, C>
+            + Allocator<N, C>
+            + Allocator<N, R>
+            + Allocator<N, R, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>, C>
+            + Allocator<N, C, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>, DimMinimum<R, C>>
+            + Allocator<N, DimMinimum<R, C>>,
+    {
+        Polar::try_new(self.into_owned(), eps, max_niter)
+    }
 }
 
 /// # Square matrix decomposition
@@ -135,9 +247,15 @@ impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
 /// | Hessenberg               | `Q * H * Qᵀ`             | `Q` is a unitary matrix and `H` an upper-Hessenberg matrix. |
 /// | Cholesky                 | `L * Lᵀ`                 | `L` is a lower-triangular matrix. |
 /// | UDU                      | `U * D * Uᵀ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
+/// | Updatable LU             | `P⁻¹ * L * E⁻¹ * U * Q⁻¹` | `P` and `L` are the factors of an LU decomposition, `E` accumulates the row operations of the updates, `U` is upper-triangular, and `Q` is a permutation matrix. |
+/// | LDLT                     | `Pᵀ * L * D * Lᵀ * P`    | `L` is a lower-triangular matrix, `D` a block-diagonal matrix, and `P` a permutation matrix. |
 /// | Schur decomposition      | `Q * T * Qᵀ`             | `Q` is an unitary matrix and `T` a quasi-upper-triangular matrix. |
 /// | Symmetric eigendecomposition | `Q ~ Λ ~ Qᵀ`   | `Q` is an unitary matrix, and `Λ` is a real diagonal matrix. |
+/// | Eigendecomposition       | `V * Λ * V⁻¹`            | `V` contains the eigenvectors, and `Λ` is a diagonal matrix. |
 /// | Symmetric tridiagonalization | `Q ~ T ~ Qᵀ`   | `Q` is an unitary matrix, and `T` is a tridiagonal matrix. |
+/// | Generalized Schur (QZ)   | `Q * S * Zᵀ`, `Q * T * Zᵀ` | `Q` and `Z` are unitary matrices, `S` is quasi-upper-triangular and `T` upper-triangular. |
+/// | Generalized eigendecomposition | `A * V * Β = B * V * Α` | `V` contains the eigenvectors, `Α` and `Β` are diagonal matrices. |
+/// | Symmetric generalized eigendecomposition | `A * V = B * V * Λ` | `V` is `B`-orthonormal, and `Λ` is a real diagonal matrix. |
 impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> Matrix<N, D, D, S> {
     /// Attempts to compute the Cholesky decomposition of this matrix.
     ///
@@ -162,6 +280,30 @@ impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> Matrix<N, D, D, S> {
         UDU::new(self.into_owned())
     }
 
+    /// Computes the LDLT decomposition with Bunch-Kaufman pivoting of this matrix.
+    ///
+    /// The input matrix `self` is assumed to be symmetric (or hermitian), possibly indefinite,
+    /// and only its lower-triangular part is read.
+    pub fn ldlt(self) -> LDLT<N, D>
+    where
+        DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+    {
+        LDLT::new(self.into_owned())
+    }
+
+    /// Computes the LU decomposition with partial (row) pivoting of this matrix, with factors
+    /// that can be updated efficiently.
+    pub fn updatable_lu(self) -> UpdatableLU<N, D>
+    where
+        D: DimMin<D, Output = D>,
+        DefaultAllocator: Allocator<N, D, D>
+            + Allocator<N, D>
+            + Allocator<(usize, usize), D>
+            + Allocator<usize, D>,
+    {
+        UpdatableLU::new(self.into_owned())
+    }
+
     /// Computes the Hessenberg decomposition of this matrix using householder reflections.
     pub fn hessenberg(self) -> Hessenberg<N, D>
     where
@@ -205,6 +347,57 @@ impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> Matrix<N, D, D, S> {
         Schur::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Computes the eigendecomposition of this square matrix.
+    ///
+    /// Returns `None` if some eigenvalues cannot be represented with the scalar type `N`, i.e.,
+    /// if this matrix is real and has complex eigenvalues. Use `.complex_eigen()` instead in
+    /// this case.
+    pub fn eigen(self) -> Option<Eigen<N, D>>
+    where
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+            + Allocator<N, DimDiff<D, U1>>
+            + Allocator<N, D, D>
+            + Allocator<N, D>,
+    {
+        Eigen::new(self.into_owned())
+    }
+
+    /// Attempts to compute the eigendecomposition of this square matrix with user-specified
+    /// convergence parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_eigen(self, eps: N::RealField, max_niter: usize) -> Option<Eigen<N, D>>
+    where
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+            + Allocator<N, DimDiff<D, U1>>
+            + Allocator<N, D, D>
+            + Allocator<N, D>,
+    {
+        Eigen::try_new(self.into_owned(), eps, max_niter)
+    }
+
+    /// Computes the eigendecomposition of this real square matrix, with complex eigenvalues
+    /// and eigenvectors.
+    pub fn complex_eigen(self) -> ComplexEigen<N, D>
+    where
+        N: RealField,
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<Complex<N>, D, DimDiff<D, U1>>
+            + Allocator<Complex<N>, DimDiff<D, U1>>
+            + Allocator<Complex<N>, D, D>
+            + Allocator<Complex<N>, D>,
+    {
+        let m = self.map(|e| Complex::new(e, N::zero()));
+        Eigen::new(m).expect("The complex Schur form is always upper-triangular.")
+    }
+
     /// Computes the eigendecomposition of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
@@ -245,6 +438,110 @@ impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> Matrix<N, D, D, S> {
         SymmetricEigen::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Computes the generalized Schur (QZ) decomposition of the matrix pair `(self, b)`.
+    pub fn generalized_schur<S2>(self, b: Matrix<N, D, D, S2>) -> GeneralizedSchur<N, D>
+    where
+        S2: Storage<N, D, D>,
+        DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+    {
+        GeneralizedSchur::new(self.into_owned(), b.into_owned())
+    }
+
+    /// Attempts to compute the generalized Schur (QZ) decomposition of the matrix pair
+    /// `(self, b)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_generalized_schur<S2>(
+        self,
+        b: Matrix<N, D, D, S2>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Option<GeneralizedSchur<N, D>>
+    where
+        S2: Storage<N, D, D>,
+        DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+    {
+        GeneralizedSchur::try_new(self.into_owned(), b.into_owned(), eps, max_niter)
+    }
+
+    /// Computes the eigendecomposition of the matrix pair `(self, b)`, i.e., the solutions of
+    /// `self * x = λ * b * x`.
+    ///
+    /// Returns `None` if some eigenvalues cannot be represented with the scalar type `N`, i.e.,
+    /// if the matrices are real and have complex generalized eigenvalues. Use
+    /// `.complex_generalized_eigen(b)` instead in this case.
+    pub fn generalized_eigen<S2>(self, b: Matrix<N, D, D, S2>) -> Option<GeneralizedEigen<N, D>>
+    where
+        S2: Storage<N, D, D>,
+        DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+    {
+        GeneralizedEigen::new(self.into_owned(), b.into_owned())
+    }
+
+    /// Attempts to compute the eigendecomposition of the matrix pair `(self, b)` with
+    /// user-specified convergence parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_generalized_eigen<S2>(
+        self,
+        b: Matrix<N, D, D, S2>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Option<GeneralizedEigen<N, D>>
+    where
+        S2: Storage<N, D, D>,
+        DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+    {
+        GeneralizedEigen::try_new(self.into_owned(), b.into_owned(), eps, max_niter)
+    }
+
+    /// Computes the eigendecomposition of the real matrix pair `(self, b)`, with complex
+    /// eigenvalues and eigenvectors.
+    pub fn complex_generalized_eigen<S2>(
+        self,
+        b: Matrix<N, D, D, S2>,
+    ) -> ComplexGeneralizedEigen<N, D>
+    where
+        N: RealField,
+        S2: Storage<N, D, D>,
+        DefaultAllocator: Allocator<Complex<N>, D, D> + Allocator<Complex<N>, D>,
+    {
+        let a = self.map(|e| Complex::new(e, N::zero()));
+        let b = b.map(|e| Complex::new(e, N::zero()));
+        GeneralizedEigen::new(a, b)
+            .expect("The complex generalized Schur form is always upper-triangular.")
+    }
+
+    /// Computes the eigendecomposition of the symmetric-definite matrix pair `(self, b)`, where
+    /// `self` is symmetric and `b` is symmetric definite-positive.
+    ///
+    /// Returns `None` if `b` is not definite-positive. Only the lower-triangular parts
+    /// (including the diagonals) of `self` and `b` are read.
+    pub fn symmetric_generalized_eigen<S2>(
+        self,
+        b: Matrix<N, D, D, S2>,
+    ) -> Option<SymmetricGeneralizedEigen<N, D>>
+    where
+        D: DimSub<U1>,
+        S2: Storage<N, D, D>,
+        DefaultAllocator: Allocator<N, D, D>
+            + Allocator<N, DimDiff<D, U1>>
+            + Allocator<N::RealField, D>
+            + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        SymmetricGeneralizedEigen::new(self.into_owned(), b.into_owned())
+    }
+
     /// Computes the tridiagonalization of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
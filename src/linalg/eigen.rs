@@ -1,46 +1,62 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
-use num_complex::Complex;
-use simba::scalar::ComplexField;
-use std::cmp;
-use std::fmt::Display;
-use std::ops::MulAssign;
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
 
 use crate::allocator::Allocator;
-use crate::base::dimension::{Dim, DimDiff, DimSub, Dynamic, U1, U2, U3};
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
 use crate::base::storage::Storage;
-use crate::base::{
-    DefaultAllocator, Hessenberg, MatrixN, SquareMatrix, Unit, Vector2, Vector3, VectorN,
-};
-use crate::constraint::{DimEq, ShapeConstraint};
+use crate::base::{DefaultAllocator, MatrixN, VectorN};
 
-use crate::geometry::{Reflection, UnitComplex};
-use crate::linalg::householder;
 use crate::linalg::Schur;
 
-/// Eigendecomposition of a real matrix with real eigenvalues (or complex eigen values for complex matrices).
+/// Eigendecomposition of a general (non-symmetric) square matrix.
+///
+/// The eigenvalues and eigenvectors are expressed with the scalar type `N` of the decomposed
+/// matrix. Therefore, a real matrix with complex eigenvalues cannot be decomposed this way:
+/// use `ComplexEigen` (or the `.complex_eigen()` matrix method) instead.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize",
-    serde(bound(serialize = "DefaultAllocator: Allocator<N, D>,
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D>,
          VectorN<N, D>: Serialize,
          MatrixN<N, D>: Serialize"))
 )]
 #[cfg_attr(
     feature = "serde-serialize",
-    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D>,
-         VectorN<N, D>: Serialize,
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D>,
+         VectorN<N, D>: Deserialize<'de>,
          MatrixN<N, D>: Deserialize<'de>"))
 )]
 #[derive(Clone, Debug)]
 pub struct Eigen<N: ComplexField, D: Dim>
-where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
 {
-    pub eigenvectors: MatrixN<N, D>,
+    /// The unsorted eigenvalues of the decomposed matrix.
     pub eigenvalues: VectorN<N, D>,
+
+    /// The right eigenvectors of the decomposed matrix.
+    ///
+    /// The i-th column `v` is normalized and satisfies `m * v = λ * v` where `λ` is the
+    /// i-th eigenvalue.
+    pub eigenvectors: MatrixN<N, D>,
+
+    /// The left eigenvectors of the decomposed matrix.
+    ///
+    /// The i-th column `u` is normalized and satisfies `u.adjoint() * m = λ * u.adjoint()`
+    /// where `λ` is the i-th eigenvalue.
+    pub left_eigenvectors: MatrixN<N, D>,
 }
 
+/// Eigendecomposition of a real matrix with complex eigenvalues and eigenvectors.
+pub type ComplexEigen<N, D> = Eigen<NumComplex<N>, D>;
+
 impl<N: ComplexField, D: Dim> Copy for Eigen<N, D>
 where
     DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
@@ -51,63 +67,160 @@ where
 
 impl<N: ComplexField, D: Dim> Eigen<N, D>
 where
-    D: DimSub<U1>,                                   // For Hessenberg.
-    ShapeConstraint: DimEq<Dynamic, DimDiff<D, U1>>, // For Hessenberg.
+    D: DimSub<U1>, // For Hessenberg.
     DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
         + Allocator<N, DimDiff<D, U1>>
         + Allocator<N, D, D>
         + Allocator<N, D>,
-    // XXX: for debug
-    DefaultAllocator: Allocator<usize, D, D>,
-    MatrixN<N, D>: Display,
 {
-    /// Computes the eigendecomposition of a diagonalizable matrix with Complex eigenvalues.
-    pub fn new(m: MatrixN<N, D>) -> Option<Eigen<N, D>> {
+    /// Computes the eigendecomposition of a square matrix.
+    ///
+    /// Returns `None` if some eigenvalues cannot be represented with the scalar type `N`, i.e.,
+    /// if `N` is real and the matrix has complex eigenvalues.
+    pub fn new(m: MatrixN<N, D>) -> Option<Self> {
+        Self::try_new(m, N::RealField::default_epsilon(), 0)
+    }
+
+    /// Attempts to compute the eigendecomposition of a square matrix.
+    ///
+    /// Returns `None` if some eigenvalues cannot be represented with the scalar type `N`, or if
+    /// the underlying Schur decomposition did not converge.
+    ///
+    /// Repeated eigenvalues are supported. If the matrix is defective (i.e. not diagonalizable)
+    /// the eigenvectors associated to the same eigenvalue will be nearly collinear.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self> {
         assert!(
             m.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
 
         let dim = m.nrows();
-        let (mut eigenvectors, mut eigenvalues) = Schur::new(m, 0).unwrap().unpack();
+        let (q, t) = Schur::try_new(m, eps, max_niter)?.unpack();
 
-        println!("Schur eigenvalues: {}", eigenvalues);
-
-        // Check that the eigenvalues are all Complex.
-        for i in 0..dim - 1 {
-            if !eigenvalues[(i + 1, i)].is_zero() {
+        // A real Schur form with 2x2 diagonal blocks means some eigenvalues are complex.
+        for i in 1..dim {
+            if !t[(i, i - 1)].is_zero() {
                 return None;
             }
         }
 
-        for j in 1..dim {
-            for i in 0..j {
-                let diff = eigenvalues[(i, i)] - eigenvalues[(j, j)];
+        let mut eigenvectors = &q * Self::triangular_right_eigenvectors(&t);
+        let mut left_eigenvectors = q * Self::triangular_left_eigenvectors(&t);
 
-                if diff.is_zero() && !eigenvalues[(i, j)].is_zero() {
-                    return None;
-                }
+        for i in 0..dim {
+            let _ = eigenvectors.column_mut(i).normalize_mut();
+            let _ = left_eigenvectors.column_mut(i).normalize_mut();
+        }
+
+        Some(Self {
+            eigenvalues: t.diagonal(),
+            eigenvectors,
+            left_eigenvectors,
+        })
+    }
 
-                let z = -eigenvalues[(i, j)] / diff;
+    /// The smallest absolute value allowed for the pivots of the triangular eigenvector solves.
+    ///
+    /// Pivots smaller than this (which occur for repeated eigenvalues) are replaced by this
+    /// threshold, following the same strategy as LAPACK's `xTREVC`.
+    fn small_pivot_threshold(t_amax: N::RealField, lambda: N) -> N::RealField {
+        let ulp = N::RealField::default_epsilon();
+        let smin = (lambda.norm1() * ulp).max(t_amax * ulp);
+
+        if smin.is_zero() {
+            ulp
+        } else {
+            smin
+        }
+    }
 
-                for k in j + 1..dim {
-                    eigenvalues[(i, k)] -= z * eigenvalues[(j, k)];
+    /// Computes the right eigenvectors of the upper-triangular matrix `t`.
+    fn triangular_right_eigenvectors(t: &MatrixN<N, D>) -> MatrixN<N, D> {
+        let dim = t.data.shape().0;
+        let n = dim.value();
+        let t_amax = t.camax();
+        let mut x = MatrixN::zeros_generic(dim, dim);
+
+        for k in 0..n {
+            let lambda = t[(k, k)];
+            let smin = Self::small_pivot_threshold(t_amax, lambda);
+            x[(k, k)] = N::one();
+
+            // Solve (T[..k, ..k] - λ I) x = -T[..k, k] by back-substitution.
+            for i in (0..k).rev() {
+                let mut rhs = N::zero();
+                for j in i + 1..=k {
+                    rhs -= t[(i, j)] * x[(j, k)];
                 }
 
-                for k in 0..dim {
-                    eigenvectors[(k, j)] += z * eigenvectors[(k, i)];
+                let mut pivot = t[(i, i)] - lambda;
+                if pivot.norm1() < smin {
+                    pivot = N::from_real(smin);
                 }
+
+                x[(i, k)] = rhs / pivot;
+                rescale_if_large(&mut x, k, i..=k);
             }
         }
 
-        // Normalize the eigenvector basis.
-        for i in 0..dim {
-            let _ = eigenvectors.column_mut(i).normalize_mut();
+        x
+    }
+
+    /// Computes the left eigenvectors of the upper-triangular matrix `t`.
+    fn triangular_left_eigenvectors(t: &MatrixN<N, D>) -> MatrixN<N, D> {
+        let dim = t.data.shape().0;
+        let n = dim.value();
+        let t_amax = t.camax();
+        let mut y = MatrixN::zeros_generic(dim, dim);
+
+        for k in 0..n {
+            let lambda = t[(k, k)];
+            let smin = Self::small_pivot_threshold(t_amax, lambda);
+            y[(k, k)] = N::one();
+
+            // Solve (T[k.., k..] - λ I)ᴴ y = 0 by forward substitution.
+            for i in k + 1..n {
+                let mut rhs = N::zero();
+                for j in k..i {
+                    rhs -= t[(j, i)].conjugate() * y[(j, k)];
+                }
+
+                let mut pivot = (t[(i, i)] - lambda).conjugate();
+                if pivot.norm1() < smin {
+                    pivot = N::from_real(smin);
+                }
+
+                y[(i, k)] = rhs / pivot;
+                rescale_if_large(&mut y, k, k..=i);
+            }
         }
 
-        Some(Eigen {
-            eigenvectors,
-            eigenvalues: eigenvalues.diagonal(),
-        })
+        y
+    }
+}
+
+/// Rescales the given elements of the column `k` of `x` if they are so large that they
+/// may overflow during the next substitution steps.
+pub(crate) fn rescale_if_large<N: ComplexField, D: Dim>(
+    x: &mut MatrixN<N, D>,
+    k: usize,
+    rows: std::ops::RangeInclusive<usize>,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let bignum = N::RealField::one() / N::RealField::default_epsilon();
+    let (start, end) = (*rows.start(), *rows.end());
+    let mut col = x.slice_mut((start, k), (end - start + 1, 1));
+    let amax = col.camax();
+
+    if amax > bignum {
+        col.unscale_mut(amax);
     }
 }
@@ -0,0 +1,362 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use num::Zero;
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, MatrixN, VectorN};
+
+use crate::linalg::eigen::rescale_if_large;
+use crate::linalg::{Cholesky, GeneralizedSchur, SymmetricEigen};
+
+/// Eigendecomposition of a square matrix pair `(a, b)`, i.e., solutions of the generalized
+/// eigenvalue problem `a * x = λ * b * x`.
+///
+/// The eigenvalues are represented as pairs `(alpha, beta)` such that `λ = alpha / beta`. This
+/// allows the representation of infinite eigenvalues (when `beta` is zero), which occur when `b`
+/// is singular.
+///
+/// Just like `Eigen`, a real matrix pair with complex eigenvalues cannot be decomposed this way:
+/// use `ComplexGeneralizedEigen` (or the `.complex_generalized_eigen()` matrix method) instead.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D>,
+         VectorN<N, D>: Serialize,
+         MatrixN<N, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D>,
+         VectorN<N, D>: Deserialize<'de>,
+         MatrixN<N, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedEigen<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    /// The numerators of the unsorted generalized eigenvalues.
+    pub alphas: VectorN<N, D>,
+
+    /// The denominators of the unsorted generalized eigenvalues.
+    pub betas: VectorN<N, D>,
+
+    /// The right generalized eigenvectors.
+    ///
+    /// The i-th column `v` is normalized and satisfies `beta * a * v = alpha * b * v`, where
+    /// `alpha` and `beta` are the i-th components of `self.alphas` and `self.betas`.
+    pub eigenvectors: MatrixN<N, D>,
+
+    /// The left generalized eigenvectors.
+    ///
+    /// The i-th column `u` is normalized and satisfies
+    /// `beta * u.adjoint() * a = alpha * u.adjoint() * b`.
+    pub left_eigenvectors: MatrixN<N, D>,
+}
+
+/// Eigendecomposition of a real matrix pair with complex eigenvalues and eigenvectors.
+pub type ComplexGeneralizedEigen<N, D> = GeneralizedEigen<NumComplex<N>, D>;
+
+impl<N: ComplexField, D: Dim> Copy for GeneralizedEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: Dim> GeneralizedEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    /// Computes the eigendecomposition of the square matrix pair `(a, b)`.
+    ///
+    /// Returns `None` if some eigenvalues cannot be represented with the scalar type `N`, i.e.,
+    /// if `N` is real and the matrix pair has complex eigenvalues.
+    pub fn new(a: MatrixN<N, D>, b: MatrixN<N, D>) -> Option<Self> {
+        Self::try_new(a, b, N::RealField::default_epsilon(), 0)
+    }
+
+    /// Attempts to compute the eigendecomposition of the square matrix pair `(a, b)`.
+    ///
+    /// Returns `None` if some eigenvalues cannot be represented with the scalar type `N`, or if
+    /// the underlying generalized Schur decomposition did not converge.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(
+        a: MatrixN<N, D>,
+        b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let schur = GeneralizedSchur::try_new(a, b, eps, max_niter)?;
+        let (alphas, betas) = schur.eigenvalues()?;
+        let (q, s, t, z) = schur.unpack();
+        let dim = s.nrows();
+
+        let mut eigenvectors = z * Self::triangular_right_eigenvectors(&s, &t, &alphas, &betas);
+        let mut left_eigenvectors = q * Self::triangular_left_eigenvectors(&s, &t, &alphas, &betas);
+
+        for i in 0..dim {
+            let _ = eigenvectors.column_mut(i).normalize_mut();
+            let _ = left_eigenvectors.column_mut(i).normalize_mut();
+        }
+
+        Some(Self {
+            alphas,
+            betas,
+            eigenvectors,
+            left_eigenvectors,
+        })
+    }
+
+    /// The generalized eigenvalues `alpha / beta`.
+    ///
+    /// Infinite eigenvalues (i.e. with a zero `beta`) will result in non-finite components.
+    pub fn eigenvalues(&self) -> VectorN<N, D> {
+        self.alphas.zip_map(&self.betas, |alpha, beta| alpha / beta)
+    }
+
+    /// The `(alpha, beta)` pair of the k-th eigenvalue, scaled so that its largest component
+    /// has a unit norm.
+    fn normalized_pair(alphas: &VectorN<N, D>, betas: &VectorN<N, D>, k: usize) -> (N, N) {
+        let scale = alphas[k].norm1().max(betas[k].norm1());
+
+        if scale.is_zero() {
+            (alphas[k], betas[k])
+        } else {
+            (alphas[k].unscale(scale), betas[k].unscale(scale))
+        }
+    }
+
+    /// The smallest absolute value allowed for the pivots of the triangular eigenvector solves.
+    ///
+    /// Pivots smaller than this (which occur for repeated eigenvalues) are replaced by this
+    /// threshold, following the same strategy as LAPACK's `xTGEVC`.
+    fn small_pivot_threshold(
+        s_amax: N::RealField,
+        t_amax: N::RealField,
+        alpha: N,
+        beta: N,
+    ) -> N::RealField {
+        let ulp = N::RealField::default_epsilon();
+        let smin = (beta.norm1() * s_amax * ulp).max(alpha.norm1() * t_amax * ulp);
+
+        if smin.is_zero() {
+            ulp
+        } else {
+            smin
+        }
+    }
+
+    /// Computes the right eigenvectors of the upper-triangular matrix pair `(s, t)`.
+    fn triangular_right_eigenvectors(
+        s: &MatrixN<N, D>,
+        t: &MatrixN<N, D>,
+        alphas: &VectorN<N, D>,
+        betas: &VectorN<N, D>,
+    ) -> MatrixN<N, D> {
+        let dim = s.data.shape().0;
+        let n = dim.value();
+        let (s_amax, t_amax) = (s.camax(), t.camax());
+        let mut x = MatrixN::zeros_generic(dim, dim);
+
+        for k in 0..n {
+            let (alpha, beta) = Self::normalized_pair(alphas, betas, k);
+            let smin = Self::small_pivot_threshold(s_amax, t_amax, alpha, beta);
+            x[(k, k)] = N::one();
+
+            // Solve (β S - α T)[..k, ..k] x = -(β S - α T)[..k, k] by back-substitution.
+            for i in (0..k).rev() {
+                let mut rhs = N::zero();
+                for j in i + 1..=k {
+                    rhs -= (beta * s[(i, j)] - alpha * t[(i, j)]) * x[(j, k)];
+                }
+
+                let mut pivot = beta * s[(i, i)] - alpha * t[(i, i)];
+                if pivot.norm1() < smin {
+                    pivot = N::from_real(smin);
+                }
+
+                x[(i, k)] = rhs / pivot;
+                rescale_if_large(&mut x, k, i..=k);
+            }
+        }
+
+        x
+    }
+
+    /// Computes the left eigenvectors of the upper-triangular matrix pair `(s, t)`.
+    fn triangular_left_eigenvectors(
+        s: &MatrixN<N, D>,
+        t: &MatrixN<N, D>,
+        alphas: &VectorN<N, D>,
+        betas: &VectorN<N, D>,
+    ) -> MatrixN<N, D> {
+        let dim = s.data.shape().0;
+        let n = dim.value();
+        let (s_amax, t_amax) = (s.camax(), t.camax());
+        let mut y = MatrixN::zeros_generic(dim, dim);
+
+        for k in 0..n {
+            let (alpha, beta) = Self::normalized_pair(alphas, betas, k);
+            let smin = Self::small_pivot_threshold(s_amax, t_amax, alpha, beta);
+            y[(k, k)] = N::one();
+
+            // Solve (β S - α T)[k.., k..]ᴴ y = 0 by forward substitution.
+            for i in k + 1..n {
+                let mut rhs = N::zero();
+                for j in k..i {
+                    rhs -= (beta * s[(j, i)] - alpha * t[(j, i)]).conjugate() * y[(j, k)];
+                }
+
+                let mut pivot = (beta * s[(i, i)] - alpha * t[(i, i)]).conjugate();
+                if pivot.norm1() < smin {
+                    pivot = N::from_real(smin);
+                }
+
+                y[(i, k)] = rhs / pivot;
+                rescale_if_large(&mut y, k, k..=i);
+            }
+        }
+
+        y
+    }
+}
+
+/// Eigendecomposition of a symmetric-definite matrix pair `(a, b)`, i.e., solutions of the
+/// generalized eigenvalue problem `a * x = λ * b * x` where `a` is symmetric (or hermitian) and `b`
+/// is symmetric (or hermitian) definite-positive.
+///
+/// The problem is reduced to a standard symmetric eigenvalue problem using the Cholesky
+/// decomposition of `b`, so all the eigenvalues are real.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<N::RealField, D>: Serialize,
+         MatrixN<N, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<N::RealField, D>: Deserialize<'de>,
+         MatrixN<N, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct SymmetricGeneralizedEigen<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+{
+    /// The generalized eigenvectors.
+    ///
+    /// They are normalized with respect to `b`, i.e., `eigenvectors.adjoint() * b * eigenvectors`
+    /// is the identity matrix.
+    pub eigenvectors: MatrixN<N, D>,
+
+    /// The unsorted generalized eigenvalues.
+    pub eigenvalues: VectorN<N::RealField, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for SymmetricGeneralizedEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N::RealField, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: Dim> SymmetricGeneralizedEigen<N, D>
+where
+    D: DimSub<U1>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the eigendecomposition of the symmetric-definite matrix pair `(a, b)`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive.
+    pub fn new(a: MatrixN<N, D>, b: MatrixN<N, D>) -> Option<Self> {
+        Self::try_new(a, b, N::RealField::default_epsilon(), 0)
+    }
+
+    /// Attempts to compute the eigendecomposition of the symmetric-definite matrix pair `(a, b)`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive or if the symmetric eigendecomposition did
+    /// not converge.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(
+        mut a: MatrixN<N, D>,
+        b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            a.is_square() && b.is_square(),
+            "Unable to compute the generalized eigendecomposition of non-square matrices."
+        );
+        assert_eq!(
+            a.shape(),
+            b.shape(),
+            "Generalized eigendecomposition: the two matrices must have the same dimensions."
+        );
+
+        let l = Cholesky::new(b)?.unpack();
+
+        // Reduce to the standard problem L⁻¹ * a * L⁻ᴴ * y = λ * y, with x = L⁻ᴴ * y.
+        let n = a.nrows();
+        for j in 0..n {
+            for i in 0..j {
+                a[(i, j)] = a[(j, i)].conjugate();
+            }
+        }
+
+        let mut c = a;
+
+        if !l.solve_lower_triangular_mut(&mut c) {
+            return None;
+        }
+
+        c.adjoint_mut();
+
+        if !l.solve_lower_triangular_mut(&mut c) {
+            return None;
+        }
+
+        let eig = SymmetricEigen::try_new(c, eps, max_niter)?;
+        let mut eigenvectors = eig.eigenvectors;
+
+        if !l.ad_solve_lower_triangular_mut(&mut eigenvectors) {
+            return None;
+        }
+
+        Some(Self {
+            eigenvectors,
+            eigenvalues: eig.eigenvalues,
+        })
+    }
+}
@@ -0,0 +1,696 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, Dynamic, U1, U2};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix2, MatrixN, Vector2, VectorN};
+
+use crate::linalg::givens::GivensRotation;
+
+/// Generalized Schur decomposition (also called QZ decomposition) of a pair of square matrices.
+///
+/// The matrices `a` and `b` are decomposed as `a = Q * S * Z.adjoint()` and
+/// `b = Q * T * Z.adjoint()` where `Q` and `Z` are unitary, `T` is upper-triangular, and `S` is
+/// upper-triangular if `N` is a complex field. If `N` is real, `S` is upper-quasitriangular: it
+/// may contain 2x2 diagonal blocks corresponding to pairs of complex conjugate generalized
+/// eigenvalues.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D>,
+         MatrixN<N, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D>,
+         MatrixN<N, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedSchur<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    q: MatrixN<N, D>,
+    s: MatrixN<N, D>,
+    t: MatrixN<N, D>,
+    z: MatrixN<N, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for GeneralizedSchur<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+    MatrixN<N, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: Dim> GeneralizedSchur<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    /// Computes the generalized Schur decomposition of the matrix pair `(a, b)`.
+    pub fn new(a: MatrixN<N, D>, b: MatrixN<N, D>) -> Self {
+        Self::try_new(a, b, N::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the generalized Schur decomposition of the matrix pair `(a, b)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(
+        mut a: MatrixN<N, D>,
+        mut b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            a.is_square() && b.is_square(),
+            "Unable to compute the generalized Schur decomposition of non-square matrices."
+        );
+        assert_eq!(
+            a.shape(),
+            b.shape(),
+            "Generalized Schur decomposition: the two matrices must have the same dimensions."
+        );
+
+        let dim = a.data.shape().0;
+        let n = dim.value();
+
+        let mut q = MatrixN::identity_generic(dim, dim);
+        let mut z = MatrixN::identity_generic(dim, dim);
+
+        if n == 0 {
+            return Some(Self { q, s: a, t: b, z });
+        }
+
+        let amax_a = a.camax();
+        let amax_b = b.camax();
+
+        if !amax_a.is_zero() {
+            a.unscale_mut(amax_a);
+        }
+
+        if !amax_b.is_zero() {
+            b.unscale_mut(amax_b);
+        }
+
+        let (mut s, mut t) = (a, b);
+        hessenberg_triangular(&mut s, &mut t, &mut q, &mut z);
+
+        // Implicit double-shift QZ method (Moler & Stewart).
+        let norm_t = t.norm();
+        let atol = eps * s.norm();
+        let mut niter = 0;
+        let mut niter_since_deflation = 0;
+        let (mut start, mut end) = delimit_subproblem(&mut s, eps, atol, n - 1);
+
+        while end != start {
+            let subdim = end - start + 1;
+            let infinite = (start..=end).find(|&k| t[(k, k)].norm1() <= eps * norm_t);
+
+            if let Some(k) = infinite {
+                t[(k, k)] = N::zero();
+                deflate_infinite_eigenvalue(&mut s, &mut t, &mut q, &mut z, k, start, end);
+            } else if subdim > 2 {
+                niter_since_deflation += 1;
+                let exceptional = niter_since_deflation % 10 == 0;
+                qz_step(&mut s, &mut t, &mut q, &mut z, start, end, exceptional);
+            } else {
+                // Decouple the 2x2 block if its generalized eigenvalues can be represented by `N`.
+                decompose_2x2_block(&mut s, &mut t, &mut q, &mut z, start);
+
+                // Check if we reached the beginning of the matrix.
+                if end > 2 {
+                    end -= 2;
+                } else {
+                    break;
+                }
+            }
+
+            let sub = delimit_subproblem(&mut s, eps, atol, end);
+
+            if sub.1 != end {
+                niter_since_deflation = 0;
+            }
+
+            start = sub.0;
+            end = sub.1;
+
+            niter += 1;
+            if niter == max_niter {
+                return None;
+            }
+        }
+
+        if !amax_a.is_zero() {
+            s.scale_mut(amax_a);
+        }
+
+        if !amax_b.is_zero() {
+            t.scale_mut(amax_b);
+        }
+
+        Some(Self { q, s, t, z })
+    }
+
+    /// Retrieves the unitary matrices `Q` and `Z`, the upper-quasitriangular matrix `S` and the
+    /// upper-triangular matrix `T` such that the decomposed matrices are equal to
+    /// `Q * S * Z.adjoint()` and `Q * T * Z.adjoint()`.
+    ///
+    /// The returned tuple is `(Q, S, T, Z)`.
+    pub fn unpack(self) -> (MatrixN<N, D>, MatrixN<N, D>, MatrixN<N, D>, MatrixN<N, D>) {
+        (self.q, self.s, self.t, self.z)
+    }
+
+    /// Reorders this decomposition so that the generalized eigenvalues `(alpha, beta)` for which
+    /// `select(alpha, beta)` returns `true` appear first on the diagonals of `S` and `T`.
+    ///
+    /// The relative order of the selected eigenvalues is preserved. Returns the number of selected
+    /// eigenvalues, or `None` if `S` is not upper-triangular, i.e., if `N` is real and some
+    /// generalized eigenvalues are complex.
+    pub fn reorder<F: FnMut(N, N) -> bool>(&mut self, mut select: F) -> Option<usize> {
+        let n = self.s.nrows();
+
+        for i in 1..n {
+            if !self.s[(i, i - 1)].is_zero() {
+                return None;
+            }
+        }
+
+        let mut nselected = 0;
+
+        for i in 0..n {
+            if select(self.s[(i, i)], self.t[(i, i)]) {
+                // Bubble the eigenvalue up by successive swaps of adjacent diagonal entries.
+                for j in (nselected..i).rev() {
+                    let (alpha, beta) = (self.s[(j + 1, j + 1)], self.t[(j + 1, j + 1)]);
+                    move_eigenvalue_first(
+                        &mut self.s,
+                        &mut self.t,
+                        &mut self.q,
+                        &mut self.z,
+                        j,
+                        alpha,
+                        beta,
+                    );
+                }
+
+                nselected += 1;
+            }
+        }
+
+        Some(nselected)
+    }
+
+    /// Computes the generalized eigenvalues of the decomposed matrix pair.
+    ///
+    /// The generalized eigenvalues are returned as pairs `(alpha, beta)`, each stored into one
+    /// vector, such that the i-th eigenvalue is `alpha[i] / beta[i]`. An eigenvalue is infinite
+    /// if `beta[i]` is zero. Returns `None` if some eigenvalues are complex.
+    pub fn eigenvalues(&self) -> Option<(VectorN<N, D>, VectorN<N, D>)> {
+        let n = self.s.nrows();
+
+        for i in 1..n {
+            if !self.s[(i, i - 1)].is_zero() {
+                return None;
+            }
+        }
+
+        Some((self.s.diagonal(), self.t.diagonal()))
+    }
+
+    /// Computes the complex generalized eigenvalues of the decomposed matrix pair.
+    ///
+    /// The generalized eigenvalues are returned as pairs `(alpha, beta)` such that the i-th
+    /// eigenvalue is `alpha[i] / beta[i]`.
+    pub fn complex_eigenvalues(&self) -> (VectorN<NumComplex<N>, D>, VectorN<NumComplex<N>, D>)
+    where
+        N: RealField,
+        DefaultAllocator: Allocator<NumComplex<N>, D>,
+    {
+        let dim = self.s.data.shape().0;
+        let n = dim.value();
+        let mut alphas = VectorN::zeros_generic(dim, U1);
+        let mut betas = VectorN::zeros_generic(dim, U1);
+        let mut m = 0;
+
+        while m < n {
+            if m + 1 == n || self.s[(m + 1, m)].is_zero() {
+                alphas[m] = NumComplex::new(self.s[(m, m)], N::zero());
+                betas[m] = NumComplex::new(self.t[(m, m)], N::zero());
+                m += 1;
+            } else {
+                // Solve the 2x2 generalized eigenvalue subproblem. The block has non-zero
+                // diagonal `T` entries because infinite eigenvalues are always deflated first.
+                let s = self
+                    .s
+                    .fixed_slice::<U2, U2>(m, m)
+                    .map(|e| NumComplex::new(e, N::zero()));
+                let t = self
+                    .t
+                    .fixed_slice::<U2, U2>(m, m)
+                    .map(|e| NumComplex::new(e, N::zero()));
+                let (lambda1, lambda2) = compute_2x2_eigvals(&s, &t)
+                    .expect("Complex generalized eigenvalues always exist.");
+
+                alphas[m] = lambda1;
+                alphas[m + 1] = lambda2;
+                betas[m] = NumComplex::one();
+                betas[m + 1] = NumComplex::one();
+                m += 2;
+            }
+        }
+
+        (alphas, betas)
+    }
+}
+
+/// Reduces `b` to upper-triangular form and then `a` to upper-Hessenberg form, while keeping `b`
+/// upper-triangular, accumulating the rotations into `q` and `z`.
+fn hessenberg_triangular<N: ComplexField, D: Dim>(
+    a: &mut MatrixN<N, D>,
+    b: &mut MatrixN<N, D>,
+    q: &mut MatrixN<N, D>,
+    z: &mut MatrixN<N, D>,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let n = a.nrows();
+
+    for j in 0..n {
+        for i in (j + 1..n).rev() {
+            if let Some((rot, r)) =
+                GivensRotation::cancel_y(&Vector2::new(b[(i - 1, j)], b[(i, j)]))
+            {
+                rotate_pencil_rows(a, b, q, &rot, i - 1, 0);
+                b[(i - 1, j)] = r;
+                b[(i, j)] = N::zero();
+            }
+        }
+    }
+
+    for j in 0..n.saturating_sub(2) {
+        for i in (j + 2..n).rev() {
+            if let Some((rot, r)) =
+                GivensRotation::cancel_y(&Vector2::new(a[(i - 1, j)], a[(i, j)]))
+            {
+                rotate_pencil_rows(a, b, q, &rot, i - 1, j);
+                a[(i - 1, j)] = r;
+                a[(i, j)] = N::zero();
+            }
+
+            if let Some(rot) = cancel_first_column(b[(i, i - 1)], b[(i, i)]) {
+                rotate_pencil_columns(a, b, z, &rot, i - 1, n);
+                b[(i, i - 1)] = N::zero();
+            }
+        }
+    }
+}
+
+/// Performs one implicit double-shift QZ step on the unreduced block `start..=end`.
+fn qz_step<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    t: &mut MatrixN<N, D>,
+    q: &mut MatrixN<N, D>,
+    z: &mut MatrixN<N, D>,
+    start: usize,
+    end: usize,
+    exceptional: bool,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    // The shifts are the eigenvalues of the trailing 2x2 block of `M = S * T⁻¹`. Since `S` is
+    // upper-Hessenberg and `T` upper-triangular, only a few entries of `T⁻¹` are needed.
+    let (a, b, c) = (end - 2, end - 1, end);
+    let tinv_ab = -t[(a, b)] / (t[(a, a)] * t[(b, b)]);
+    let tinv_ac =
+        (t[(a, b)] * t[(b, c)] - t[(a, c)] * t[(b, b)]) / (t[(a, a)] * t[(b, b)] * t[(c, c)]);
+    let tinv_bb = N::one() / t[(b, b)];
+    let tinv_bc = -t[(b, c)] / (t[(b, b)] * t[(c, c)]);
+    let tinv_cc = N::one() / t[(c, c)];
+
+    let mbb = s[(b, a)] * tinv_ab + s[(b, b)] * tinv_bb;
+    let mbc = s[(b, a)] * tinv_ac + s[(b, b)] * tinv_bc + s[(b, c)] * tinv_cc;
+    let mcb = s[(c, b)] * tinv_bb;
+    let mcc = s[(c, b)] * tinv_bc + s[(c, c)] * tinv_cc;
+
+    let (tra, det) = if exceptional {
+        // Ad-hoc shift used to break cycles.
+        let shift = mcc + N::from_real(mcb.modulus() * crate::convert(1.5));
+        (shift + shift, shift * shift)
+    } else {
+        (mbb + mcc, mbb * mcc - mbc * mcb)
+    };
+
+    // First two columns of the first three rows of `M`.
+    let (i, j) = (start, start + 1);
+    let tinv_ii = N::one() / t[(i, i)];
+    let tinv_ij = -t[(i, j)] / (t[(i, i)] * t[(j, j)]);
+    let tinv_jj = N::one() / t[(j, j)];
+
+    let m11 = s[(i, i)] * tinv_ii;
+    let m12 = s[(i, i)] * tinv_ij + s[(i, j)] * tinv_jj;
+    let m21 = s[(j, i)] * tinv_ii;
+    let m22 = s[(j, i)] * tinv_ij + s[(j, j)] * tinv_jj;
+    let m32 = s[(j + 1, j)] * tinv_jj;
+
+    let mut x = m11 * m11 + m12 * m21 - tra * m11 + det;
+    let mut y = m21 * (m11 + m22 - tra);
+    let mut w = m21 * m32;
+
+    for k in start..end - 1 {
+        let col = if k > start { k - 1 } else { start };
+
+        // Annihilate the last two components of the bulge column.
+        if let Some((rot, r)) = GivensRotation::cancel_y(&Vector2::new(y, w)) {
+            rotate_pencil_rows(s, t, q, &rot, k + 1, col);
+            y = r;
+        }
+
+        if let Some((rot, r)) = GivensRotation::cancel_y(&Vector2::new(x, y)) {
+            rotate_pencil_rows(s, t, q, &rot, k, col);
+            x = r;
+        }
+
+        if k > start {
+            s[(k, k - 1)] = x;
+            s[(k + 1, k - 1)] = N::zero();
+            s[(k + 2, k - 1)] = N::zero();
+        }
+
+        // Restore the triangular structure of `t`.
+        let nrows = (k + 4).min(end + 1);
+
+        if let Some(rot) = cancel_first_column(t[(k + 2, k)], t[(k + 2, k + 1)]) {
+            rotate_pencil_columns(s, t, z, &rot, k, nrows);
+            t[(k + 2, k)] = N::zero();
+        }
+
+        if let Some(rot) = cancel_first_column(t[(k + 2, k + 1)], t[(k + 2, k + 2)]) {
+            rotate_pencil_columns(s, t, z, &rot, k + 1, nrows);
+            t[(k + 2, k + 1)] = N::zero();
+        }
+
+        if let Some(rot) = cancel_first_column(t[(k + 1, k)], t[(k + 1, k + 1)]) {
+            rotate_pencil_columns(s, t, z, &rot, k, nrows);
+            t[(k + 1, k)] = N::zero();
+        }
+
+        x = s[(k + 1, k)];
+        y = s[(k + 2, k)];
+
+        if k + 3 <= end {
+            w = s[(k + 3, k)];
+        }
+    }
+
+    let m = end - 1;
+
+    if let Some((rot, r)) = GivensRotation::cancel_y(&Vector2::new(x, y)) {
+        rotate_pencil_rows(s, t, q, &rot, m, m - 1);
+        s[(m, m - 1)] = r;
+        s[(end, m - 1)] = N::zero();
+    }
+
+    if let Some(rot) = cancel_first_column(t[(end, m)], t[(end, end)]) {
+        rotate_pencil_columns(s, t, z, &rot, m, end + 1);
+        t[(end, m)] = N::zero();
+    }
+}
+
+/// Deflates the infinite eigenvalue corresponding to the zero diagonal entry `t[(k, k)]` of the
+/// unreduced block `start..=end`.
+///
+/// The zero is chased down to the bottom of the block where it can be deflated.
+fn deflate_infinite_eigenvalue<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    t: &mut MatrixN<N, D>,
+    q: &mut MatrixN<N, D>,
+    z: &mut MatrixN<N, D>,
+    k: usize,
+    start: usize,
+    end: usize,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    if k == start {
+        if let Some((rot, r)) =
+            GivensRotation::cancel_y(&Vector2::new(s[(start, start)], s[(start + 1, start)]))
+        {
+            rotate_pencil_rows(s, t, q, &rot, start, start);
+            s[(start, start)] = r;
+        }
+
+        s[(start + 1, start)] = N::zero();
+        t[(start + 1, start)] = N::zero();
+        return;
+    }
+
+    for j in k..end {
+        if let Some((rot, r)) =
+            GivensRotation::cancel_y(&Vector2::new(t[(j, j + 1)], t[(j + 1, j + 1)]))
+        {
+            rotate_pencil_rows(s, t, q, &rot, j, j - 1);
+            t[(j, j + 1)] = r;
+            t[(j + 1, j + 1)] = N::zero();
+        }
+
+        if let Some(rot) = cancel_first_column(s[(j + 1, j - 1)], s[(j + 1, j)]) {
+            rotate_pencil_columns(s, t, z, &rot, j - 1, j + 2);
+            s[(j + 1, j - 1)] = N::zero();
+        }
+    }
+
+    if let Some(rot) = cancel_first_column(s[(end, end - 1)], s[(end, end)]) {
+        rotate_pencil_columns(s, t, z, &rot, end - 1, end + 1);
+    }
+
+    s[(end, end - 1)] = N::zero();
+    t[(end, end - 1)] = N::zero();
+}
+
+/// Triangularizes the 2x2 diagonal block starting at `start` if its generalized eigenvalues can
+/// be represented with the scalar type `N`.
+fn decompose_2x2_block<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    t: &mut MatrixN<N, D>,
+    q: &mut MatrixN<N, D>,
+    z: &mut MatrixN<N, D>,
+    start: usize,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let s2 = s.fixed_slice::<U2, U2>(start, start).into_owned();
+    let t2 = t.fixed_slice::<U2, U2>(start, start).into_owned();
+
+    if let Some((lambda, _)) = compute_2x2_eigvals(&s2, &t2) {
+        move_eigenvalue_first(s, t, q, z, start, lambda, N::one());
+    }
+}
+
+/// Applies rotations to the 2x2 diagonal blocks starting at `start` so that their first columns
+/// become upper-triangular with the generalized eigenvalue `(alpha, beta)` on their diagonals.
+///
+/// If the blocks are already upper-triangular, this swaps their diagonal entries when
+/// `(alpha, beta)` is the generalized eigenvalue in second position.
+fn move_eigenvalue_first<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    t: &mut MatrixN<N, D>,
+    q: &mut MatrixN<N, D>,
+    z: &mut MatrixN<N, D>,
+    start: usize,
+    alpha: N,
+    beta: N,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let s2 = s.fixed_slice::<U2, U2>(start, start);
+    let t2 = t.fixed_slice::<U2, U2>(start, start);
+
+    // Find a vector of the kernel of `β S - α T`.
+    let m = s2 * beta - t2 * alpha;
+    let row0 = m[(0, 0)].norm1() + m[(0, 1)].norm1();
+    let row1 = m[(1, 0)].norm1() + m[(1, 1)].norm1();
+    let kernel = if row0.is_zero() && row1.is_zero() {
+        Vector2::x()
+    } else if row0 >= row1 {
+        Vector2::new(m[(0, 1)], -m[(0, 0)])
+    } else {
+        Vector2::new(m[(1, 1)], -m[(1, 0)])
+    };
+
+    // Rotate the columns so that the first one is aligned with the kernel vector.
+    if let Some((rot, _)) = GivensRotation::cancel_y(&kernel) {
+        rotate_pencil_columns(s, t, z, &rot.inverse(), start, start + 2);
+    }
+
+    // Rotate the rows so that the first columns of both blocks become upper-triangular.
+    let s_col = Vector2::new(s[(start, start)], s[(start + 1, start)]);
+    let t_col = Vector2::new(t[(start, start)], t[(start + 1, start)]);
+    let col = if s_col.camax() > t_col.camax() {
+        s_col
+    } else {
+        t_col
+    };
+
+    if let Some((rot, _)) = GivensRotation::cancel_y(&col) {
+        rotate_pencil_rows(s, t, q, &rot, start, start);
+    }
+
+    s[(start + 1, start)] = N::zero();
+    t[(start + 1, start)] = N::zero();
+}
+
+/// Computes the generalized eigenvalues of the 2x2 pencil `(s, t)` where `t` is
+/// upper-triangular with a non-zero diagonal.
+///
+/// Returns `None` if the eigenvalues cannot be represented with the scalar type `N`.
+fn compute_2x2_eigvals<N: ComplexField>(s: &Matrix2<N>, t: &Matrix2<N>) -> Option<(N, N)> {
+    // det(S - λ T) = a λ² + b λ + c
+    let a = t[(0, 0)] * t[(1, 1)];
+    let b = t[(0, 1)] * s[(1, 0)] - s[(0, 0)] * t[(1, 1)] - s[(1, 1)] * t[(0, 0)];
+    let c = s[(0, 0)] * s[(1, 1)] - s[(0, 1)] * s[(1, 0)];
+
+    let four: N = crate::convert(4.0);
+    let mut sqrt_discr = (b * b - four * a * c).try_sqrt()?;
+
+    // Avoid catastrophic cancellation.
+    if (b.conjugate() * sqrt_discr).real() < N::RealField::zero() {
+        sqrt_discr = -sqrt_discr;
+    }
+
+    let half_q = (b + sqrt_discr) * crate::convert(-0.5);
+
+    if half_q.is_zero() {
+        Some((N::zero(), N::zero()))
+    } else {
+        Some((half_q / a, c / half_q))
+    }
+}
+
+/// Computes the rotation `R` such that the first component of `[x, y] * R` is zero.
+fn cancel_first_column<N: ComplexField>(x: N, y: N) -> Option<GivensRotation<N>> {
+    if x.is_zero() {
+        return None;
+    }
+
+    let (mod_y, sign_y) = y.to_exp();
+
+    if mod_y.is_zero() {
+        let (_, sign_x) = x.to_exp();
+        Some(GivensRotation::new_unchecked(N::RealField::zero(), -sign_x))
+    } else {
+        let denom = (x.modulus_squared() + mod_y * mod_y).sqrt();
+        let s = -(x * sign_y.conjugate()).unscale(denom);
+        Some(GivensRotation::new_unchecked(mod_y / denom, s))
+    }
+}
+
+/// Applies `rot` to the rows `i` and `i + 1` of both `s` and `t`, starting at the column `j`,
+/// and accumulates its inverse into `q`.
+fn rotate_pencil_rows<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    t: &mut MatrixN<N, D>,
+    q: &mut MatrixN<N, D>,
+    rot: &GivensRotation<N>,
+    i: usize,
+    j: usize,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = s.data.shape().0;
+    let ncols = Dynamic::new(dim.value() - j);
+
+    rot.rotate(&mut s.generic_slice_mut((i, j), (U2, ncols)));
+    rot.rotate(&mut t.generic_slice_mut((i, j), (U2, ncols)));
+    rot.inverse()
+        .rotate_rows(&mut q.generic_slice_mut((0, i), (dim, U2)));
+}
+
+/// Applies `rot` to the first `nrows` rows of the columns `j` and `j + 1` of both `s` and `t`,
+/// and accumulates it into `z`.
+fn rotate_pencil_columns<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    t: &mut MatrixN<N, D>,
+    z: &mut MatrixN<N, D>,
+    rot: &GivensRotation<N>,
+    j: usize,
+    nrows: usize,
+) where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = s.data.shape().0;
+    let nrows = Dynamic::new(nrows);
+
+    rot.rotate_rows(&mut s.generic_slice_mut((0, j), (nrows, U2)));
+    rot.rotate_rows(&mut t.generic_slice_mut((0, j), (nrows, U2)));
+    rot.rotate_rows(&mut z.generic_slice_mut((0, j), (dim, U2)));
+}
+
+fn delimit_subproblem<N: ComplexField, D: Dim>(
+    s: &mut MatrixN<N, D>,
+    eps: N::RealField,
+    atol: N::RealField,
+    end: usize,
+) -> (usize, usize)
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let mut n = end;
+
+    while n > 0 {
+        let m = n - 1;
+
+        if negligible_subdiagonal(s, n, eps, atol) {
+            s[(n, m)] = N::zero();
+        } else {
+            break;
+        }
+
+        n -= 1;
+    }
+
+    if n == 0 {
+        return (0, 0);
+    }
+
+    let mut new_start = n - 1;
+    while new_start > 0 {
+        let m = new_start - 1;
+
+        if negligible_subdiagonal(s, new_start, eps, atol) {
+            s[(new_start, m)] = N::zero();
+            break;
+        }
+
+        new_start -= 1;
+    }
+
+    (new_start, n)
+}
+
+/// Tests if the subdiagonal element `s[(i, i - 1)]` can be set to zero.
+///
+/// The absolute tolerance `atol` ensures deflation also happens next to zero diagonal elements,
+/// e.g., when `a` is rank-deficient.
+fn negligible_subdiagonal<N: ComplexField, D: Dim>(
+    s: &MatrixN<N, D>,
+    i: usize,
+    eps: N::RealField,
+    atol: N::RealField,
+) -> bool
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let off_diag = s[(i, i - 1)].norm1();
+    off_diag <= atol || off_diag <= eps * (s[(i, i)].norm1() + s[(i - 1, i - 1)].norm1())
+}
@@ -0,0 +1,417 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, U1};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::PermutationSequence;
+
+/// The LDLT decomposition with Bunch-Kaufman pivoting of a symmetric (or hermitian), possibly
+/// indefinite, matrix.
+///
+/// The matrix `m` is decomposed as `P * m * P.transpose() = L * D * L.adjoint()` where `P` is a
+/// permutation matrix, `L` is lower-triangular with a unit diagonal, and `D` is block-diagonal with
+/// hermitian blocks of size 1x1 or 2x2.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D> +
+                           Allocator<(usize, usize), D>,
+         MatrixN<N, D>: Serialize,
+         VectorN<N, D>: Serialize,
+         PermutationSequence<D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D> +
+                           Allocator<(usize, usize), D>,
+         MatrixN<N, D>: Deserialize<'de>,
+         VectorN<N, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct LDLT<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    // The strictly lower-triangular part contains `L` and the diagonal contains the
+    // diagonal of `D`.
+    ldlt: MatrixN<N, D>,
+    // The i-th element is the subdiagonal element of `D` at `(i + 1, i)`. It is non-zero only
+    // for the 2x2 blocks.
+    subdiag: VectorN<N, D>,
+    p: PermutationSequence<D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for LDLT<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N, D>: Copy,
+    PermutationSequence<D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: Dim> LDLT<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the LDLT decomposition with Bunch-Kaufman pivoting of `matrix`.
+    ///
+    /// The input matrix is assumed to be symmetric (or hermitian) and only its lower-triangular
+    /// part is read. The decomposition always succeeds, even if `matrix` is singular.
+    pub fn new(mut matrix: MatrixN<N, D>) -> Self {
+        assert!(matrix.is_square(), "The input matrix must be square.");
+
+        let dim = matrix.data.shape().0;
+        let n = dim.value();
+
+        // Make the matrix explicitly hermitian so that symmetric permutations can be applied by
+        // swapping whole rows and columns.
+        for j in 0..n {
+            matrix[(j, j)] = N::from_real(matrix[(j, j)].real());
+
+            for i in j + 1..n {
+                matrix[(j, i)] = matrix[(i, j)].conjugate();
+            }
+        }
+
+        let mut subdiag = VectorN::zeros_generic(dim, U1);
+        let mut p = PermutationSequence::identity_generic(dim);
+
+        // Bunch-Kaufman pivoting parameter (1 + sqrt(17)) / 8, minimizing the element growth bound.
+        let alpha: N::RealField = (N::RealField::one()
+            + crate::convert::<_, N::RealField>(17.0).sqrt())
+            / crate::convert(8.0);
+        let mut k = 0;
+
+        while k < n {
+            let absakk = matrix[(k, k)].norm1();
+            let (imax, colmax) = if k + 1 < n {
+                let imax = matrix.slice_range(k + 1.., k).icamax() + k + 1;
+                (imax, matrix[(imax, k)].norm1())
+            } else {
+                (k, N::RealField::zero())
+            };
+
+            let (kp, kstep) = if absakk.max(colmax).is_zero() || absakk >= alpha * colmax {
+                (k, 1)
+            } else {
+                let mut rowmax = N::RealField::zero();
+                for j in k..n {
+                    if j != imax {
+                        rowmax = rowmax.max(matrix[(imax, j)].norm1());
+                    }
+                }
+
+                if absakk * rowmax >= alpha * colmax * colmax {
+                    (k, 1)
+                } else if matrix[(imax, imax)].norm1() >= alpha * rowmax {
+                    (imax, 1)
+                } else {
+                    (imax, 2)
+                }
+            };
+
+            let kk = k + kstep - 1;
+
+            if kp != kk {
+                matrix.swap_rows(kk, kp);
+                matrix.swap_columns(kk, kp);
+                p.append_permutation(kk, kp);
+            }
+
+            if kstep == 1 {
+                let d = matrix[(k, k)];
+
+                if !d.is_zero() {
+                    for j in k + 1..n {
+                        let coeff = matrix[(j, k)].conjugate() / d;
+
+                        for i in k + 1..n {
+                            let update = matrix[(i, k)] * coeff;
+                            matrix[(i, j)] -= update;
+                        }
+                    }
+
+                    let mut col = matrix.slice_range_mut(k + 1.., k);
+                    col /= d;
+                }
+            } else {
+                let d11 = matrix[(k, k)];
+                let d21 = matrix[(k + 1, k)];
+                let d22 = matrix[(k + 1, k + 1)];
+                let det = d11 * d22 - d21 * d21.conjugate();
+
+                // Compute `W * D⁻¹` where `W` is the 2-columns block below the pivot.
+                for i in k + 2..n {
+                    let w1 = matrix[(i, k)];
+                    let w2 = matrix[(i, k + 1)];
+                    let l1 = (w1 * d22 - w2 * d21) / det;
+                    let l2 = (w2 * d11 - w1 * d21.conjugate()) / det;
+
+                    for j in k + 2..n {
+                        let update =
+                            l1 * matrix[(j, k)].conjugate() + l2 * matrix[(j, k + 1)].conjugate();
+                        matrix[(i, j)] -= update;
+                    }
+
+                    // The columns `k` and `k + 1` are still needed by the update of the other
+                    // rows, so store the multipliers in the upper-triangular part temporarily.
+                    matrix[(k, i)] = l1;
+                    matrix[(k + 1, i)] = l2;
+                }
+
+                for i in k + 2..n {
+                    matrix[(i, k)] = matrix[(k, i)];
+                    matrix[(i, k + 1)] = matrix[(k + 1, i)];
+                }
+
+                subdiag[k] = d21;
+                matrix[(k + 1, k)] = N::zero();
+            }
+
+            k += kstep;
+        }
+
+        Self {
+            ldlt: matrix,
+            subdiag,
+            p,
+        }
+    }
+
+    /// The lower-triangular matrix `L` of this decomposition, with a unit diagonal.
+    #[inline]
+    pub fn l(&self) -> MatrixN<N, D> {
+        let mut res = self.ldlt.clone();
+        res.fill_upper_triangle(N::zero(), 1);
+        res.fill_diagonal(N::one());
+        res
+    }
+
+    /// The block-diagonal matrix `D` of this decomposition.
+    #[inline]
+    pub fn d(&self) -> MatrixN<N, D> {
+        let dim = self.ldlt.data.shape().0;
+        let mut res = MatrixN::from_diagonal(&self.ldlt.diagonal());
+
+        for i in 0..dim.value().saturating_sub(1) {
+            res[(i + 1, i)] = self.subdiag[i];
+            res[(i, i + 1)] = self.subdiag[i].conjugate();
+        }
+
+        res
+    }
+
+    /// The permutation matrix `P` of this decomposition.
+    #[inline]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// The permutation `P`, lower-triangular matrix `L`, and block-diagonal matrix `D` of this
+    /// decomposition.
+    #[inline]
+    pub fn unpack(self) -> (PermutationSequence<D>, MatrixN<N, D>, MatrixN<N, D>) {
+        let l = self.l();
+        let d = self.d();
+
+        (self.p, l, d)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.ldlt.nrows(),
+            b.nrows(),
+            "LDLT solve matrix dimension mismatch."
+        );
+
+        let n = self.ldlt.nrows();
+
+        if n == 0 {
+            return true;
+        }
+
+        self.p.permute_rows(b);
+        let _ = self.ldlt.solve_lower_triangular_with_diag_mut(b, N::one());
+
+        // Solve the block-diagonal system.
+        let mut i = 0;
+        while i < n {
+            if self.subdiag[i].is_zero() {
+                let d = self.ldlt[(i, i)];
+
+                if d.is_zero() {
+                    return false;
+                }
+
+                let mut row = b.row_mut(i);
+                row /= d;
+                i += 1;
+            } else {
+                let d11 = self.ldlt[(i, i)];
+                let d21 = self.subdiag[i];
+                let d22 = self.ldlt[(i + 1, i + 1)];
+                let det = d11 * d22 - d21 * d21.conjugate();
+
+                if det.is_zero() {
+                    return false;
+                }
+
+                for j in 0..b.ncols() {
+                    let b1 = b[(i, j)];
+                    let b2 = b[(i + 1, j)];
+                    b[(i, j)] = (b1 * d22 - b2 * d21.conjugate()) / det;
+                    b[(i + 1, j)] = (b2 * d11 - b1 * d21) / det;
+                }
+
+                i += 2;
+            }
+        }
+
+        // Solve `L.adjoint() * x = b` by backward substitution.
+        for j in 0..b.ncols() {
+            let mut bcol = b.column_mut(j);
+
+            for i in (0..n - 1).rev() {
+                let dot = self
+                    .ldlt
+                    .slice_range(i + 1.., i)
+                    .dotc(&bcol.rows_range(i + 1..));
+                bcol[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the matrix is not invertible.
+    pub fn inverse(&self) -> Option<MatrixN<N, D>> {
+        let (nrows, ncols) = self.ldlt.data.shape();
+        let mut res = MatrixN::identity_generic(nrows, ncols);
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N {
+        let n = self.ldlt.nrows();
+        let mut res = N::one();
+        let mut i = 0;
+
+        while i < n {
+            if self.subdiag[i].is_zero() {
+                res *= self.ldlt[(i, i)];
+                i += 1;
+            } else {
+                let d21 = self.subdiag[i];
+                res *= self.ldlt[(i, i)] * self.ldlt[(i + 1, i + 1)] - d21 * d21.conjugate();
+                i += 2;
+            }
+        }
+
+        res
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    pub fn is_invertible(&self) -> bool {
+        let (_, _, nzeros) = self.inertia();
+        nzeros == 0
+    }
+
+    /// The inertia of the decomposed matrix, i.e., its number of positive, negative, and zero
+    /// eigenvalues, returned in that order.
+    ///
+    /// By Sylvester's law of inertia, this is the inertia of the block-diagonal matrix `D`.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let n = self.ldlt.nrows();
+        let (mut npos, mut nneg, mut nzeros) = (0, 0, 0);
+        let mut i = 0;
+
+        while i < n {
+            if self.subdiag[i].is_zero() {
+                let d = self.ldlt[(i, i)].real();
+
+                if d > N::RealField::zero() {
+                    npos += 1;
+                } else if d < N::RealField::zero() {
+                    nneg += 1;
+                } else {
+                    nzeros += 1;
+                }
+
+                i += 1;
+            } else {
+                // The eigenvalues of the 2x2 block have the signs of its determinant and trace.
+                let d11 = self.ldlt[(i, i)].real();
+                let d22 = self.ldlt[(i + 1, i + 1)].real();
+                let det = d11 * d22 - self.subdiag[i].modulus_squared();
+
+                if det < N::RealField::zero() {
+                    npos += 1;
+                    nneg += 1;
+                } else if det.is_zero() {
+                    nzeros += 1;
+
+                    if d11 + d22 > N::RealField::zero() {
+                        npos += 1;
+                    } else {
+                        nneg += 1;
+                    }
+                } else if d11 + d22 > N::RealField::zero() {
+                    npos += 2;
+                } else {
+                    nneg += 2;
+                }
+
+                i += 2;
+            }
+        }
+
+        (npos, nneg, nzeros)
+    }
+}
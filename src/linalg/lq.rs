@@ -0,0 +1,183 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, Unit, VectorN};
+use crate::dimension::{Dim, DimMin, DimMinimum, U1};
+use crate::storage::{Storage, StorageMut};
+use simba::scalar::ComplexField;
+
+use crate::geometry::Reflection;
+use crate::linalg::householder;
+
+/// The LQ decomposition of a general matrix.
+///
+/// The matrix `m` is decomposed as `m = L * Q` where `L` is lower trapezoidal and `Q` has
+/// orthonormal rows.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>>,
+         MatrixMN<N, R, C>: Serialize,
+         VectorN<N, DimMinimum<R, C>>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>>,
+         MatrixMN<N, R, C>: Deserialize<'de>,
+         VectorN<N, DimMinimum<R, C>>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct LQ<N: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, DimMinimum<R, C>>,
+{
+    lq: MatrixMN<N, R, C>,
+    diag: VectorN<N, DimMinimum<R, C>>,
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Copy for LQ<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, DimMinimum<R, C>>,
+    MatrixMN<N, R, C>: Copy,
+    VectorN<N, DimMinimum<R, C>>: Copy,
+{
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> LQ<N, R, C>
+where
+    DefaultAllocator:
+        Allocator<N, R, C> + Allocator<N, R> + Allocator<N, C> + Allocator<N, DimMinimum<R, C>>,
+{
+    /// Computes the LQ decomposition using householder reflections.
+    pub fn new(mut matrix: MatrixMN<N, R, C>) -> Self {
+        let (nrows, ncols) = matrix.data.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let mut diag =
+            unsafe { crate::unimplemented_or_uninitialized_generic!(min_nrows_ncols, U1) };
+
+        if min_nrows_ncols.value() == 0 {
+            return LQ { lq: matrix, diag };
+        }
+
+        let mut work: VectorN<N, R> =
+            unsafe { crate::unimplemented_or_uninitialized_generic!(nrows, U1) };
+        let mut axis_packed: VectorN<N, C> =
+            unsafe { crate::unimplemented_or_uninitialized_generic!(ncols, U1) };
+
+        for i in 0..min_nrows_ncols.value() {
+            householder::clear_row_unchecked(
+                &mut matrix,
+                &mut diag[i],
+                &mut axis_packed,
+                &mut work,
+                i,
+                0,
+            );
+        }
+
+        LQ { lq: matrix, diag }
+    }
+
+    /// Retrieves the lower trapezoidal submatrix `L` of this decomposition.
+    #[inline]
+    pub fn l(&self) -> MatrixMN<N, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>>,
+    {
+        let (nrows, ncols) = self.lq.data.shape();
+        let mut res = self
+            .lq
+            .columns_generic(0, nrows.min(ncols))
+            .lower_triangle();
+        res.set_partial_diagonal(self.diag.iter().map(|e| N::from_real(e.modulus())));
+        res
+    }
+
+    /// Computes the matrix `Q` with orthonormal rows of this decomposition.
+    pub fn q(&self) -> MatrixMN<N, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<N, DimMinimum<R, C>, C>,
+    {
+        let (nrows, ncols) = self.lq.data.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let mut res = Matrix::identity_generic(min_nrows_ncols, ncols);
+        let mut work: VectorN<N, DimMinimum<R, C>> =
+            unsafe { crate::unimplemented_or_uninitialized_generic!(min_nrows_ncols, U1) };
+        let mut axis_packed: VectorN<N, C> =
+            unsafe { crate::unimplemented_or_uninitialized_generic!(ncols, U1) };
+
+        for i in (0..min_nrows_ncols.value()).rev() {
+            let axis = self.lq.slice_range(i, i..);
+            let mut axis_packed = axis_packed.rows_range_mut(i..);
+            axis_packed.tr_copy_from(&axis);
+            // TODO: sometimes, the axis might have a zero magnitude.
+            let refl = Reflection::new(Unit::new_unchecked(axis_packed), N::zero());
+
+            let mut res_rows = res.slice_range_mut(i.., i..);
+            refl.reflect_rows_with_sign(
+                &mut res_rows,
+                &mut work.rows_range_mut(i..),
+                self.diag[i].signum(),
+            );
+        }
+
+        res
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(L, Q)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        MatrixMN<N, R, DimMinimum<R, C>>,
+        MatrixMN<N, DimMinimum<R, C>, C>,
+    )
+    where
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>> + Allocator<N, DimMinimum<R, C>, C>,
+    {
+        (self.l(), self.q())
+    }
+
+    #[doc(hidden)]
+    pub fn lq_internal(&self) -> &MatrixMN<N, R, C> {
+        &self.lq
+    }
+
+    /// Multiplies the provided matrix by the adjoint of the `Q` matrix of this decomposition,
+    /// i.e., computes `Q.adjoint() * rhs` where the first `min(nrows, ncols)` rows of `rhs` are
+    /// multiplied by `Q.adjoint()` and its other rows are assumed to be zero.
+    ///
+    /// The matrix `rhs` must have as many rows as the decomposed matrix has columns.
+    pub fn q_ad_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        let (nrows, ncols) = self.lq.data.shape();
+        let min_nrows_ncols = nrows.min(ncols).value();
+
+        assert_eq!(
+            rhs.nrows(),
+            ncols.value(),
+            "LQ: the right-hand side has an inconsistent number of rows."
+        );
+
+        rhs.rows_range_mut(min_nrows_ncols..).fill(N::zero());
+
+        let mut axis_packed: VectorN<N, C> =
+            unsafe { crate::unimplemented_or_uninitialized_generic!(ncols, U1) };
+
+        for i in (0..min_nrows_ncols).rev() {
+            let axis = self.lq.slice_range(i, i..);
+            let mut axis_packed = axis_packed.rows_range_mut(i..);
+            axis_packed.tr_copy_from(&axis);
+            let refl = Reflection::new(Unit::new_unchecked(axis_packed), N::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].signum().conjugate());
+        }
+    }
+}
@@ -6,10 +6,11 @@ use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Scalar};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::{ComplexField, Field};
+use num::{One, Zero};
+use simba::scalar::{ComplexField, Field, SupersetOf};
 use std::mem;
 
-use crate::linalg::PermutationSequence;
+use crate::linalg::{norm_estimate, refinement, PermutationSequence, RefinedSolution};
 
 /// LU decomposition with partial (row) pivoting.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -254,6 +255,99 @@ where
         self.lu.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self.adjoint() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn ad_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "LU solve matrix dimension mismatch."
+        );
+        assert!(
+            self.lu.is_square(),
+            "LU solve: unable to solve a non-square system."
+        );
+
+        if !self.lu.ad_solve_upper_triangular_mut(b) {
+            return false;
+        }
+
+        // Solve with the adjoint of the unit lower-triangular factor.
+        let dim = self.lu.nrows();
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+            for i in (0..dim).rev() {
+                let dot = self.lu.slice_range(i + 1.., i).dotc(&b.rows_range(i + 1..));
+                b[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// The 1-norm `anorm` of the decomposed matrix must be provided, e.g., computed with
+    /// `matrix.one_norm()` before the decomposition. The norm of the inverse is estimated from a
+    /// few solves with this decomposition, which is much cheaper than computing the inverse or
+    /// the singular values. Returns zero if the decomposed matrix is not invertible.
+    pub fn rcond(&self, anorm: N::RealField) -> N::RealField
+    where
+        DefaultAllocator: Allocator<N, D>,
+    {
+        let dim = self.lu.data.shape().0;
+
+        if dim.value() == 0 {
+            return N::RealField::one();
+        }
+
+        if anorm.is_zero() {
+            return N::RealField::zero();
+        }
+
+        let inv_norm = norm_estimate::estimate_inverse_one_norm(
+            dim,
+            |x| self.solve_mut(x),
+            |x| self.ad_solve_mut(x),
+        );
+
+        match inv_norm {
+            Some(inv_norm) if !inv_norm.is_zero() => inv_norm.recip() / anorm,
+            _ => N::RealField::zero(),
+        }
+    }
+
+    /// Solves the linear system `a * x = b` with iterative refinement, where `self` is a
+    /// decomposition of `a` and `x` is the unknown to be determined.
+    ///
+    /// See `RefinedSolution` for the precision of the residuals and the error bounds. Returns
+    /// `None` if the decomposed matrix is not invertible.
+    pub fn solve_refined<N2, S, C2: Dim, S2>(
+        &self,
+        a: &Matrix<N2, D, D, S>,
+        b: &Matrix<N2, D, C2, S2>,
+    ) -> Option<RefinedSolution<N2, D, C2>>
+    where
+        N2: ComplexField + SupersetOf<N>,
+        S: Storage<N2, D, D>,
+        S2: Storage<N2, D, C2>,
+        DefaultAllocator: Allocator<N, D>
+            + Allocator<N2, D>
+            + Allocator<N2, D, C2>
+            + Allocator<N2::RealField, D>
+            + Allocator<N2::RealField, C2>,
+    {
+        refinement::solve_refined(a, b, |x| self.solve_mut(x), |x| self.ad_solve_mut(x))
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the matrix is not invertible.
@@ -0,0 +1,416 @@
+//! This module provides the matrix square root, logarithm, trigonometric functions and
+//! general functions to square matrices.
+//!
+//! Except for the trigonometric functions, they are all computed from the Schur decomposition
+//! of the matrix.
+
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use num_complex::Complex;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, MatrixN};
+use crate::{convert, ComplexField, Schur};
+
+#[cfg(feature = "std")]
+use crate::base::dimension::{DimMin, DimMinimum};
+
+/// Threshold on `‖T - I‖` below which the logarithm of `T` is approximated by the Padé
+/// approximant of degree 8 of `log(I + X)`.
+///
+/// This value is taken from Table 2.1 of "Improved inverse scaling and squaring algorithms
+/// for the matrix logarithm", Higham, N. J. & Al-Mohy, A. H., 2012.
+const LN_PADE8_THETA: f64 = 0.34;
+
+/// Positive nodes and weights of the 8-points Gauss-Legendre quadrature on `[-1, 1]`. The
+/// negative nodes are their opposites, with the same weights.
+///
+/// The partial-fraction form of the Padé approximant of `log(I + X)` coincides with this
+/// quadrature applied to `log(I + X) = ∫₀¹ X (I + tX)⁻¹ dt`.
+const GAUSS_LEGENDRE_8: [(f64, f64); 4] = [
+    (0.183_434_642_495_649_8, 0.362_683_783_378_362),
+    (0.525_532_409_916_329, 0.313_706_645_877_887_3),
+    (0.796_666_477_413_626_7, 0.222_381_034_453_374_5),
+    (0.960_289_856_497_536_3, 0.101_228_536_290_376_3),
+];
+
+/// The maximum number of square roots taken by the inverse scaling and squaring method before
+/// giving up.
+const LN_MAX_SQRTS: usize = 64;
+
+/// Computes the principal square root of the upper-triangular matrix `t`.
+///
+/// This uses the recurrence from "A Schur method for the square root of a matrix",
+/// Björck, Å. & Hammarling, S., 1983.
+fn sqrt_upper_triangular<N: ComplexField, D: Dim>(t: &MatrixN<N, D>) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = t.data.shape().0;
+    let n = dim.value();
+    let mut u = MatrixN::zeros_generic(dim, dim);
+
+    for j in 0..n {
+        u[(j, j)] = t[(j, j)].try_sqrt()?;
+
+        for i in (0..j).rev() {
+            let mut num = t[(i, j)];
+            for k in i + 1..j {
+                num -= u[(i, k)] * u[(k, j)];
+            }
+
+            let denom = u[(i, i)] + u[(j, j)];
+
+            if !denom.is_zero() {
+                u[(i, j)] = num / denom;
+            } else if !num.is_zero() {
+                // The matrix is singular and has no square root.
+                return None;
+            }
+        }
+    }
+
+    Some(u)
+}
+
+/// Computes the principal logarithm of the upper-triangular matrix `t`.
+///
+/// This uses an inverse scaling and squaring method: square roots of `t` are taken until it is
+/// close enough to the identity for the Padé approximant of `log(I + X)` to be accurate.
+fn ln_upper_triangular<N: ComplexField, D: Dim>(t: &MatrixN<N, D>) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = t.data.shape().0;
+    let n = dim.value();
+    let ident = MatrixN::identity_generic(dim, dim);
+
+    for i in 0..n {
+        if !t[(i, i)].ln().is_finite() {
+            return None;
+        }
+    }
+
+    let theta: N::RealField = convert(LN_PADE8_THETA);
+    let mut x = t.clone_owned();
+    let mut nsqrts = 0;
+
+    while (&x - &ident).norm() > theta {
+        if nsqrts == LN_MAX_SQRTS {
+            return None;
+        }
+
+        x = sqrt_upper_triangular(&x)?;
+        nsqrts += 1;
+    }
+
+    x -= &ident;
+
+    let mut res = MatrixN::zeros_generic(dim, dim);
+
+    for &(node, weight) in GAUSS_LEGENDRE_8.iter() {
+        for &node in &[-node, node] {
+            // Change of variable from [-1, 1] to [0, 1].
+            let lhs = &ident + &x * convert::<f64, N>((1.0 + node) * 0.5);
+            let sol = lhs.solve_upper_triangular(&x)?;
+            res += sol * convert::<f64, N>(weight * 0.5);
+        }
+    }
+
+    // Undo the square roots: log(T) = 2ˢ log(T^(1/2ˢ)).
+    for _ in 0..nsqrts {
+        res *= convert::<f64, N>(2.0);
+    }
+
+    // The diagonal can be computed exactly.
+    for i in 0..n {
+        res[(i, i)] = t[(i, i)].ln();
+    }
+
+    Some(res)
+}
+
+/// Computes `f(t)` for the upper-triangular matrix `t` using the Parlett recurrence.
+///
+/// Returns `None` if two diagonal elements of `t` are too close for the recurrence to be
+/// numerically reliable.
+fn parlett_upper_triangular<N: ComplexField, D: Dim>(
+    t: &MatrixN<N, D>,
+    mut f: impl FnMut(N) -> N,
+) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = t.data.shape().0;
+    let n = dim.value();
+    let eps = N::RealField::default_epsilon();
+    let t_amax = t.camax();
+    let mut res = MatrixN::zeros_generic(dim, dim);
+
+    for j in 0..n {
+        res[(j, j)] = f(t[(j, j)]);
+
+        for i in (0..j).rev() {
+            let mut num = t[(i, j)] * (res[(j, j)] - res[(i, i)]);
+            for k in i + 1..j {
+                num += t[(i, k)] * res[(k, j)] - res[(i, k)] * t[(k, j)];
+            }
+
+            let denom = t[(j, j)] - t[(i, i)];
+
+            if denom.norm1() > eps * t_amax {
+                res[(i, j)] = num / denom;
+            } else if num.norm1() > eps * t_amax * res.camax() {
+                return None;
+            }
+        }
+    }
+
+    Some(res)
+}
+
+impl<N: ComplexField, D> MatrixN<N, D>
+where
+    D: DimSub<U1>, // For Hessenberg.
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<Complex<N::RealField>, D, DimDiff<D, U1>>
+        + Allocator<Complex<N::RealField>, DimDiff<D, U1>>
+        + Allocator<Complex<N::RealField>, D, D>
+        + Allocator<Complex<N::RealField>, D>,
+{
+    /// Applies a function, given for both the scalar type of this matrix and its complex
+    /// counterpart, to the upper-triangular factor of the Schur decomposition of `self`.
+    ///
+    /// The complex version is used only if `self` is a real matrix with complex eigenvalues.
+    /// In that case, `None` is returned if `self` also has real negative eigenvalues because
+    /// the principal square root and logarithm would not be real.
+    fn schur_function(
+        &self,
+        f: fn(&MatrixN<N, D>) -> Option<MatrixN<N, D>>,
+        complex_f: fn(
+            &MatrixN<Complex<N::RealField>, D>,
+        ) -> Option<MatrixN<Complex<N::RealField>, D>>,
+    ) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "Unable to compute a function of a non-square matrix."
+        );
+
+        let n = self.nrows();
+        let (q, t) = Schur::new(self.clone_owned()).unpack();
+        let is_triangular = (1..n).all(|i| t[(i, i - 1)].is_zero());
+
+        if is_triangular {
+            let ft = f(&t)?;
+            return Some(&q * ft * q.adjoint());
+        }
+
+        // This is a real matrix with complex eigenvalues.
+        for i in 0..n {
+            let isolated =
+                (i == 0 || t[(i, i - 1)].is_zero()) && (i == n - 1 || t[(i + 1, i)].is_zero());
+
+            if isolated && t[(i, i)].real() < N::RealField::zero() {
+                return None;
+            }
+        }
+
+        let m = self.map(|e| Complex::new(e.real(), e.imaginary()));
+        let (q, t) = Schur::new(m).unpack();
+        let ft = complex_f(&t)?;
+        let res = &q * ft * q.adjoint();
+
+        Some(res.map(|e| N::from_real(e.re)))
+    }
+
+    /// Computes the principal square root of this matrix.
+    ///
+    /// The result `s` is the unique square root of `self` (i.e. `s * s == self`) with all its
+    /// eigenvalues in the right half-plane.
+    ///
+    /// Returns `None` if the principal square root does not exist or, for real matrices, if it
+    /// is not real (i.e. if `self` has negative real eigenvalues).
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(33.0, 24.0, 48.0, 57.0);
+    /// let s = m.sqrt().unwrap();
+    /// assert_relative_eq!(s, Matrix2::new(5.0, 2.0, 4.0, 7.0), epsilon = 1.0e-10);
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        self.schur_function(sqrt_upper_triangular, sqrt_upper_triangular)
+    }
+
+    /// Computes the principal logarithm of this matrix.
+    ///
+    /// The result `l` is the unique logarithm of `self` (i.e. `l.exp() == self`) with all its
+    /// eigenvalues having an imaginary part in `]-π, π[`.
+    ///
+    /// Returns `None` if `self` is singular or, for real matrices, if the principal logarithm
+    /// is not real (i.e. if `self` has negative real eigenvalues).
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0, 2.0, 0.0, 1.0);
+    /// let l = m.ln().unwrap();
+    /// assert_relative_eq!(l, Matrix2::new(0.0, 2.0, 0.0, 0.0), epsilon = 1.0e-10);
+    /// ```
+    pub fn ln(&self) -> Option<Self> {
+        self.schur_function(ln_upper_triangular, ln_upper_triangular)
+    }
+
+    /// Computes `f(self)` for an arbitrary scalar function `f` using the Schur-Parlett method.
+    ///
+    /// The function `f` is evaluated at each eigenvalue of `self`, so it must be defined on all
+    /// of them. This returns `None` if some eigenvalues of `self` cannot be represented by the
+    /// scalar type `N` (to handle real matrices with complex eigenvalues, convert them to a
+    /// complex matrix first), or if some eigenvalues are too close to each other for the
+    /// Parlett recurrence to be reliable.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0, 2.0, 0.0, 3.0);
+    /// let f = m.apply_function(|x| x * x).unwrap();
+    /// assert_relative_eq!(f, m * m, epsilon = 1.0e-10);
+    /// ```
+    pub fn apply_function<F: FnMut(N) -> N>(&self, f: F) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "Unable to compute a function of a non-square matrix."
+        );
+
+        let n = self.nrows();
+        let (q, t) = Schur::new(self.clone_owned()).unpack();
+
+        if (1..n).any(|i| !t[(i, i - 1)].is_zero()) {
+            return None;
+        }
+
+        let ft = parlett_upper_triangular(&t, f)?;
+        Some(&q * ft * q.adjoint())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N: ComplexField, D> MatrixN<N, D>
+where
+    D: DimSub<U1> + DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, D, D>
+        + Allocator<(usize, usize), DimMinimum<D, D>>
+        + Allocator<Complex<N::RealField>, D, DimDiff<D, U1>>
+        + Allocator<Complex<N::RealField>, DimDiff<D, U1>>
+        + Allocator<Complex<N::RealField>, D, D>
+        + Allocator<Complex<N::RealField>, D>,
+{
+    /// Raises this matrix to a real power `p`, computed as `exp(p * ln(self))`.
+    ///
+    /// Returns `None` if the principal logarithm of `self` does not exist (see `.ln()`).
+    pub fn powf(&self, p: N::RealField) -> Option<Self> {
+        let l = self.ln()?;
+        Some((l * N::from_real(p)).exp())
+    }
+}
+
+impl<N: ComplexField, D: Dim> MatrixN<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    /// Computes simultaneously the sine and the cosine of this matrix.
+    ///
+    /// This uses truncated Taylor series on the matrix scaled by a power of two, followed by
+    /// the double-angle formulas `sin(2X) = 2 sin(X) cos(X)` and `cos(2X) = 2 cos(X)² - I`.
+    /// If `self` has a NaN or infinite entry, both matrices are filled with NaN.
+    pub fn sin_cos(&self) -> (Self, Self) {
+        assert!(
+            self.is_square(),
+            "Unable to compute the sine and cosine of a non-square matrix."
+        );
+
+        let dim = self.data.shape().0;
+        let ident = Self::identity_generic(dim, dim);
+        let eps = N::RealField::default_epsilon();
+
+        // Scale the matrix so that its norm is at most 1/2.
+        let norm = self.norm();
+
+        if !norm.is_finite() {
+            let nan = Self::from_element_generic(dim, dim, convert(f64::NAN));
+            return (nan.clone(), nan);
+        }
+
+        let mut nsquares = 0;
+        let mut scale = N::RealField::one();
+        let half: N::RealField = convert(0.5);
+
+        while norm * scale > half {
+            scale *= half;
+            nsquares += 1;
+        }
+
+        let x = self * N::from_real(scale);
+        let x2 = &x * &x;
+
+        // Taylor series of sin and cos.
+        let mut sin = x.clone_owned();
+        let mut cos = ident.clone_owned();
+        let mut sin_term = x;
+        let mut cos_term = ident.clone_owned();
+        let mut k = 1;
+
+        // With a norm of at most 1/2, the terms decrease factorially and the series converges
+        // in a few iterations. The bound only guards against a tolerance that cannot be met.
+        while k < 100 {
+            let (kf, kf1, kf2): (N, N, N) = (
+                convert(k as f64),
+                convert((k + 1) as f64),
+                convert((k + 2) as f64),
+            );
+
+            cos_term = &cos_term * &x2 * (-(kf * kf1).recip());
+            sin_term = &sin_term * &x2 * (-(kf1 * kf2).recip());
+            cos += &cos_term;
+            sin += &sin_term;
+            k += 2;
+
+            if cos_term.norm() <= eps * cos.norm() && sin_term.norm() <= eps * sin.norm() {
+                break;
+            }
+        }
+
+        let two: N = convert(2.0);
+
+        for _ in 0..nsquares {
+            let new_sin = &sin * &cos * two;
+            cos = &cos * &cos * two - &ident;
+            sin = new_sin;
+        }
+
+        (sin, cos)
+    }
+
+    /// Computes the sine of this matrix.
+    pub fn sin(&self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Computes the cosine of this matrix.
+    pub fn cos(&self) -> Self {
+        self.sin_cos().1
+    }
+}
@@ -5,47 +5,70 @@ mod bidiagonal;
 mod cholesky;
 mod convolution;
 mod determinant;
+mod eigen;
 // TODO: this should not be needed. However, the exp uses
 // explicit float operations on `f32` and `f64`. We need to
 // get rid of these to allow exp to be used on a no-std context.
 mod col_piv_qr;
+mod complete_orthogonal;
 mod decomposition;
 #[cfg(feature = "std")]
 mod exp;
 mod full_piv_lu;
+mod generalized_eigen;
+mod generalized_schur;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+mod ldlt;
+mod lq;
 mod lu;
+mod matrix_functions;
+mod norm_estimate;
 mod permutation_sequence;
+mod polar;
 mod pow;
 mod qr;
+mod refinement;
+mod riccati;
+mod rq;
 mod schur;
 mod solve;
 mod svd;
+mod sylvester;
 mod symmetric_eigen;
 mod symmetric_tridiagonal;
 mod udu;
-
-//// TODO: Not complete enough for publishing.
-//// This handles only cases where each eigenvalue has multiplicity one.
-// mod eigen;
+mod updatable_lu;
+mod updatable_qr;
 
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
 pub use self::col_piv_qr::*;
+pub use self::complete_orthogonal::*;
 pub use self::convolution::*;
+pub use self::eigen::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
 pub use self::full_piv_lu::*;
+pub use self::generalized_eigen::*;
+pub use self::generalized_schur::*;
 pub use self::hessenberg::*;
+pub use self::ldlt::*;
+pub use self::lq::*;
 pub use self::lu::*;
+pub use self::matrix_functions::*;
 pub use self::permutation_sequence::*;
+pub use self::polar::*;
 pub use self::pow::*;
 pub use self::qr::*;
+pub use self::refinement::*;
+pub use self::rq::*;
 pub use self::schur::*;
 pub use self::svd::*;
 pub use self::symmetric_eigen::*;
 pub use self::symmetric_tridiagonal::*;
 pub use self::udu::*;
+pub use self::updatable_lu::*;
+pub use self::updatable_qr::*;
@@ -0,0 +1,142 @@
+//! Estimation of the 1-norm of the inverse of a matrix from its decomposition.
+
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, VectorN};
+use crate::dimension::{Dim, U1};
+
+/// Estimates the 1-norm of the inverse of the square matrix `A` of dimension `dim` with the
+/// iterative algorithm of Hager and Higham, without computing `A⁻¹` explicitly.
+///
+/// The closures `solve` and `ad_solve` must replace their argument `x` by `A⁻¹ * x` and
+/// `A⁻ᴴ * x` respectively, and return `false` if `A` is not invertible. The estimate is a lower
+/// bound of the exact norm, which it usually matches or underestimates by less than a factor 3.
+pub(crate) fn estimate_inverse_one_norm<N, D, F, G>(
+    dim: D,
+    mut solve: F,
+    mut ad_solve: G,
+) -> Option<N::RealField>
+where
+    N: ComplexField,
+    D: Dim,
+    F: FnMut(&mut VectorN<N, D>) -> bool,
+    G: FnMut(&mut VectorN<N, D>) -> bool,
+    DefaultAllocator: Allocator<N, D>,
+{
+    let n = dim.value();
+
+    if n == 0 {
+        return Some(N::RealField::zero());
+    }
+
+    let n_real: N::RealField = crate::convert(n as f64);
+    let mut x = VectorN::repeat_generic(dim, U1, N::from_real(n_real.recip()));
+
+    if !solve(&mut x) {
+        return None;
+    }
+
+    if n == 1 {
+        return Some(x[0].modulus());
+    }
+
+    let mut estimate = sum_of_moduli(&x);
+    let mut z = x.map(sign);
+
+    if !ad_solve(&mut z) {
+        return None;
+    }
+
+    let mut j = imax_modulus(&z);
+
+    // Hager's method: at most five steps of a gradient ascent of `‖A⁻¹ * x‖₁` over the unit
+    // ball, which converges after two or three steps in practice.
+    for _ in 0..5 {
+        x.fill(N::zero());
+        x[j] = N::one();
+
+        if !solve(&mut x) {
+            return None;
+        }
+
+        let prev_estimate = estimate;
+        estimate = sum_of_moduli(&x);
+
+        if estimate <= prev_estimate {
+            estimate = prev_estimate;
+            break;
+        }
+
+        z = x.map(sign);
+
+        if !ad_solve(&mut z) {
+            return None;
+        }
+
+        let prev_j = j;
+        j = imax_modulus(&z);
+
+        if z[prev_j].modulus() >= z[j].modulus() {
+            break;
+        }
+    }
+
+    // Higham's alternative estimate, guarding against the matrices for which the gradient ascent
+    // gets stuck on a poor local maximum.
+    let n_minus_one: N::RealField = crate::convert((n - 1) as f64);
+    let mut alternating_sign = N::RealField::one();
+
+    for i in 0..n {
+        let i_real: N::RealField = crate::convert(i as f64);
+        x[i] = N::from_real(alternating_sign * (N::RealField::one() + i_real / n_minus_one));
+        alternating_sign = -alternating_sign;
+    }
+
+    if !solve(&mut x) {
+        return None;
+    }
+
+    let three: N::RealField = crate::convert(3.0);
+    let alternative = sum_of_moduli(&x) * crate::convert(2.0) / (three * n_real);
+
+    Some(estimate.max(alternative))
+}
+
+fn sum_of_moduli<N: ComplexField, D: Dim>(x: &VectorN<N, D>) -> N::RealField
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    x.iter()
+        .fold(N::RealField::zero(), |acc, e| acc + e.modulus())
+}
+
+fn imax_modulus<N: ComplexField, D: Dim>(x: &VectorN<N, D>) -> usize
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    let mut imax = 0;
+    let mut max = x[0].modulus();
+
+    for (i, e) in x.iter().enumerate().skip(1) {
+        let modulus = e.modulus();
+
+        if modulus > max {
+            imax = i;
+            max = modulus;
+        }
+    }
+
+    imax
+}
+
+fn sign<N: ComplexField>(e: N) -> N {
+    let modulus = e.modulus();
+
+    if modulus.is_zero() {
+        N::one()
+    } else {
+        e.unscale(modulus)
+    }
+}
@@ -0,0 +1,227 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixMN, MatrixN};
+use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::storage::Storage;
+
+use crate::linalg::{LQ, QR, SVD};
+
+/// The polar decomposition of a general matrix.
+///
+/// The matrix `m` is decomposed as `m = U * H` where `H` is an hermitian positive semi-definite
+/// matrix and `U` has orthonormal columns (or orthonormal rows if `m` has more columns than rows).
+/// If `m` is square, `U` is the unitary matrix closest to `m` in the Frobenius norm.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, C, C>,
+         MatrixMN<N, R, C>: Serialize,
+         MatrixN<N, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, C, C>,
+         MatrixMN<N, R, C>: Deserialize<'de>,
+         MatrixN<N, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct Polar<N: ComplexField, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, C, C>,
+{
+    /// The unitary factor `U` of this decomposition.
+    pub u: MatrixMN<N, R, C>,
+    /// The hermitian positive semi-definite factor `H` of this decomposition.
+    pub h: MatrixN<N, C>,
+}
+
+impl<N: ComplexField, R: Dim, C: Dim> Copy for Polar<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, C, C>,
+    MatrixMN<N, R, C>: Copy,
+    MatrixN<N, C>: Copy,
+{
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Polar<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, C, C>
+        + Allocator<N, R>
+        + Allocator<N, C>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, C, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>>,
+{
+    /// Computes the polar decomposition of `matrix`.
+    ///
+    /// The scaled Newton iteration is used first. If it fails, e.g., because `matrix` is rank
+    /// deficient, the decomposition is computed from the singular value decomposition of `matrix`
+    /// instead.
+    pub fn new(matrix: MatrixMN<N, R, C>) -> Self
+    where
+        DimMinimum<R, C>: DimSub<U1>, // for SVD.
+        DefaultAllocator: Allocator<N, DimDiff<DimMinimum<R, C>, U1>>
+            + Allocator<N::RealField, DimMinimum<R, C>>
+            + Allocator<N::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+    {
+        let eps = N::RealField::default_epsilon().sqrt();
+
+        match Self::try_new(matrix.clone(), eps, 100) {
+            Some(polar) => polar,
+            None => Self::from_svd(SVD::new(matrix, true, true)),
+        }
+    }
+
+    /// Attempts to compute the polar decomposition of `matrix` using the scaled Newton iteration.
+    ///
+    /// Returns `None` if `matrix` is rank deficient or if the iteration did not converge.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance on the relative difference between two consecutive iterates. Once
+    /// it is reached, one last iteration is performed which, thanks to the quadratic convergence
+    /// of the method, reduces the error to about `eps²`.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned, unless `eps` was reached on the last
+    /// iteration. If `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_new(matrix: MatrixMN<N, R, C>, eps: N::RealField, max_niter: usize) -> Option<Self> {
+        let (nrows, ncols) = matrix.data.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        if nrows.value() >= ncols.value() {
+            // With `matrix = Q * R` and `R = U_r * H`, we have `matrix = (Q * U_r) * H`.
+            let (q, r) = if nrows.value() == ncols.value() {
+                (
+                    None,
+                    copy_generic(&matrix, min_nrows_ncols, min_nrows_ncols),
+                )
+            } else {
+                let qr = QR::new(matrix);
+                (
+                    Some(qr.q()),
+                    copy_generic(&qr.r(), min_nrows_ncols, min_nrows_ncols),
+                )
+            };
+
+            let u_r = newton_iteration(r.clone(), eps, max_niter)?;
+            let h = (u_r.ad_mul(&r)).hermitian_part();
+            let u = match q {
+                Some(q) => copy_generic(&(q * u_r), nrows, ncols),
+                None => copy_generic(&u_r, nrows, ncols),
+            };
+
+            Some(Polar {
+                u,
+                h: copy_generic(&h, ncols, ncols),
+            })
+        } else {
+            // With `matrix = L * Q` and `L = U_l * H_l`, we have
+            // `matrix = (U_l * Q) * (Q.adjoint() * H_l * Q)`.
+            let (l, q) = LQ::new(matrix).unpack();
+            let l = copy_generic(&l, min_nrows_ncols, min_nrows_ncols);
+
+            let u_l = newton_iteration(l.clone(), eps, max_niter)?;
+            let h_l = (u_l.ad_mul(&l)).hermitian_part();
+            let u = copy_generic(&(u_l * &q), nrows, ncols);
+            let h = (q.ad_mul(&(h_l * &q))).hermitian_part();
+
+            Some(Polar { u, h })
+        }
+    }
+
+    fn from_svd(svd: SVD<N, R, C>) -> Self
+    where
+        DefaultAllocator: Allocator<N::RealField, DimMinimum<R, C>>,
+    {
+        let u = svd.u.unwrap();
+        let v_t = svd.v_t.unwrap();
+        let mut s_v_t = v_t.clone();
+
+        for (i, s) in svd.singular_values.iter().enumerate() {
+            s_v_t.row_mut(i).scale_mut(*s);
+        }
+
+        Polar {
+            u: u * &v_t,
+            h: v_t.ad_mul(&s_v_t).hermitian_part(),
+        }
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(U, H)`.
+    pub fn unpack(self) -> (MatrixMN<N, R, C>, MatrixN<N, C>) {
+        (self.u, self.h)
+    }
+}
+
+/// Computes the unitary factor of the square invertible matrix `x` with the scaled Newton
+/// iteration `x ← (γ * x + x⁻ᴴ / γ) / 2`.
+fn newton_iteration<N: ComplexField, D: Dim>(
+    mut x: MatrixN<N, D>,
+    eps: N::RealField,
+    max_niter: usize,
+) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let half: N::RealField = crate::convert(0.5);
+    // The scaling is only useful to speed up the first iterations.
+    let scaling_threshold: N::RealField = crate::convert(1.0e-2);
+    let mut scaled = true;
+    let mut converged = false;
+    let mut niter = 0;
+
+    loop {
+        let x_inv = x.clone().try_inverse()?;
+        let gamma = if scaled {
+            (x_inv.norm() / x.norm()).sqrt()
+        } else {
+            N::RealField::one()
+        };
+
+        let mut next = x_inv.adjoint().unscale(gamma) + x.scale(gamma);
+        next.scale_mut(half);
+
+        let diff = (&next - &x).norm();
+        let norm = next.norm();
+        x = next;
+
+        if converged || diff.is_zero() {
+            return Some(x);
+        }
+
+        if !diff.is_finite() {
+            return None;
+        }
+
+        converged = diff <= eps * norm;
+        scaled = scaled && diff > scaling_threshold * norm;
+
+        niter += 1;
+        if niter == max_niter && !converged {
+            return None;
+        }
+    }
+}
+
+fn copy_generic<N: ComplexField, R1: Dim, C1: Dim, S1, R2: Dim, C2: Dim>(
+    m: &crate::base::Matrix<N, R1, C1, S1>,
+    nrows: R2,
+    ncols: C2,
+) -> MatrixMN<N, R2, C2>
+where
+    S1: Storage<N, R1, C1>,
+    DefaultAllocator: Allocator<N, R2, C2>,
+{
+    MatrixMN::from_fn_generic(nrows, ncols, |i, j| m[(i, j)])
+}
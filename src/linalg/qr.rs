@@ -1,4 +1,4 @@
-use num::Zero;
+use num::{One, Zero};
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -7,10 +7,10 @@ use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Unit, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum, U1};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::ComplexField;
+use simba::scalar::{ComplexField, SupersetOf};
 
 use crate::geometry::Reflection;
-use crate::linalg::householder;
+use crate::linalg::{householder, norm_estimate, refinement, RefinedSolution};
 
 /// The QR decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -155,6 +155,22 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].signum().conjugate());
         }
     }
+
+    /// Multiplies the provided matrix by the `Q` matrix of this decomposition.
+    pub fn q_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        let dim = self.diag.len();
+
+        for i in (0..dim).rev() {
+            let axis = self.qr.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].signum());
+        }
+    }
 }
 
 impl<N: ComplexField, D: DimMin<D, Output = D>> QR<N, D, D>
@@ -205,6 +221,47 @@ where
         self.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self.adjoint() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn ad_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.qr.nrows(),
+            b.nrows(),
+            "QR solve matrix dimension mismatch."
+        );
+        assert!(
+            self.qr.is_square(),
+            "QR solve: unable to solve a non-square system."
+        );
+
+        let dim = self.qr.nrows();
+
+        // Solve with the adjoint of `R`, which has the real diagonal `|diag|`.
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+            for i in 0..dim {
+                let diag = self.diag[i].modulus();
+
+                if diag.is_zero() {
+                    return false;
+                }
+
+                let dot = self.qr.slice_range(..i, i).dotc(&b.rows_range(..i));
+                b[i] = (b[i] - dot).unscale(diag);
+            }
+        }
+
+        self.q_mul(b);
+        true
+    }
+
     // TODO: duplicate code from the `solve` module.
     fn solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
         &self,
@@ -260,6 +317,57 @@ where
         }
     }
 
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in the 1-norm.
+    ///
+    /// The 1-norm `anorm` of the decomposed matrix must be provided, e.g., computed with
+    /// `matrix.one_norm()` before the decomposition. Returns zero if the decomposed matrix is not
+    /// invertible.
+    pub fn rcond(&self, anorm: N::RealField) -> N::RealField {
+        let dim = self.qr.data.shape().0;
+
+        if dim.value() == 0 {
+            return N::RealField::one();
+        }
+
+        if anorm.is_zero() {
+            return N::RealField::zero();
+        }
+
+        let inv_norm = norm_estimate::estimate_inverse_one_norm(
+            dim,
+            |x| self.solve_mut(x),
+            |x| self.ad_solve_mut(x),
+        );
+
+        match inv_norm {
+            Some(inv_norm) if !inv_norm.is_zero() => inv_norm.recip() / anorm,
+            _ => N::RealField::zero(),
+        }
+    }
+
+    /// Solves the linear system `a * x = b` with iterative refinement, where `self` is a
+    /// decomposition of `a` and `x` is the unknown to be determined.
+    ///
+    /// See `RefinedSolution` for the precision of the residuals and the error bounds. Returns
+    /// `None` if the decomposed matrix is not invertible.
+    pub fn solve_refined<N2, S, C2: Dim, S2>(
+        &self,
+        a: &Matrix<N2, D, D, S>,
+        b: &Matrix<N2, D, C2, S2>,
+    ) -> Option<RefinedSolution<N2, D, C2>>
+    where
+        N2: ComplexField + SupersetOf<N>,
+        S: Storage<N2, D, D>,
+        S2: Storage<N2, D, C2>,
+        DefaultAllocator: Allocator<N, D>
+            + Allocator<N2, D>
+            + Allocator<N2, D, C2>
+            + Allocator<N2::RealField, D>
+            + Allocator<N2::RealField, C2>,
+    {
+        refinement::solve_refined(a, b, |x| self.solve_mut(x), |x| self.ad_solve_mut(x))
+    }
+
     /// Indicates if the decomposed matrix is invertible.
     pub fn is_invertible(&self) -> bool {
         assert!(
@@ -0,0 +1,183 @@
+//! Iterative refinement of the solutions of dense linear systems.
+
+use approx::AbsDiffEq;
+use num::Zero;
+use simba::scalar::{ComplexField, RealField, SupersetOf};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, VectorN};
+use crate::dimension::{Dim, U1};
+use crate::storage::Storage;
+
+use crate::linalg::norm_estimate;
+
+/// The maximum number of refinement steps performed for each right-hand side, as in LAPACK.
+const MAX_REFINEMENT_STEPS: usize = 5;
+
+/// The solution of a linear system `a * x = b` improved by iterative refinement, together with
+/// error bounds for each of its columns.
+///
+/// This is returned by the `.solve_refined(a, b)` method of the `LU`, `Cholesky` and `QR`
+/// decompositions. The residuals `b - a * x` are computed with the scalar type of `a` and `b`,
+/// which may have a higher precision than the scalar type of the decomposition. For example, `a`
+/// can be decomposed after being cast to `f32` while the solution is refined up to the accuracy
+/// of `f64`. The error bounds are estimated as with LAPACK's `xGERFS`.
+#[derive(Clone, Debug)]
+pub struct RefinedSolution<N: ComplexField, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N::RealField, C>,
+{
+    /// The refined solution `x`.
+    pub solution: MatrixMN<N, R, C>,
+    /// The estimated bounds on the relative forward error `‖x_j - x_true_j‖∞ / ‖x_j‖∞` of each
+    /// column `x_j` of the solution.
+    pub forward_error: VectorN<N::RealField, C>,
+    /// The componentwise relative backward error of each column `x_j` of the solution, i.e., the
+    /// smallest relative change to the coefficients of `a` and `b_j` making `x_j` an exact solution.
+    pub backward_error: VectorN<N::RealField, C>,
+}
+
+/// Solves `a * x = b` by iterative refinement, in the spirit of LAPACK's `xGERFS`.
+///
+/// The closures `solve` and `ad_solve` must replace their argument by `a⁻¹ * x` and `a⁻ᴴ * x`
+/// computed with a decomposition of `a` in the possibly lower precision `N`, and return `false`
+/// if it is singular. The residuals are computed with the precision of `N2`.
+pub(crate) fn solve_refined<N, N2, D, C2, S, S2, F, G>(
+    a: &Matrix<N2, D, D, S>,
+    b: &Matrix<N2, D, C2, S2>,
+    solve: F,
+    ad_solve: G,
+) -> Option<RefinedSolution<N2, D, C2>>
+where
+    N: ComplexField,
+    N2: ComplexField + SupersetOf<N>,
+    D: Dim,
+    C2: Dim,
+    S: Storage<N2, D, D>,
+    S2: Storage<N2, D, C2>,
+    F: Fn(&mut VectorN<N, D>) -> bool,
+    G: Fn(&mut VectorN<N, D>) -> bool,
+    DefaultAllocator: Allocator<N, D>
+        + Allocator<N2, D>
+        + Allocator<N2, D, C2>
+        + Allocator<N2::RealField, D>
+        + Allocator<N2::RealField, C2>,
+{
+    assert!(a.is_square(), "Refinement: the matrix must be square.");
+    assert_eq!(
+        a.nrows(),
+        b.nrows(),
+        "Refinement: matrix dimension mismatch."
+    );
+
+    let (dim, ncols) = b.data.shape();
+    let eps = N2::RealField::default_epsilon();
+    let nz: N2::RealField = crate::convert((dim.value() + 1) as f64);
+
+    let mut solution = b.clone_owned();
+    let mut forward_error = VectorN::zeros_generic(ncols, U1);
+    let mut backward_error = VectorN::zeros_generic(ncols, U1);
+
+    // Solves with the low-precision decomposition.
+    let low_precision_solve = |x: &mut VectorN<N2, D>, adjoint: bool| {
+        let mut x_low = x.map(|e| crate::convert_unchecked::<N2, N>(e));
+        let ok = if adjoint {
+            ad_solve(&mut x_low)
+        } else {
+            solve(&mut x_low)
+        };
+        *x = x_low.map(crate::convert);
+        ok
+    };
+
+    for j in 0..ncols.value() {
+        let b_j = b.column(j);
+        let mut x = b_j.clone_owned();
+
+        if !low_precision_solve(&mut x, false) {
+            return None;
+        }
+
+        let mut last_berr = None;
+        let mut niter = 0;
+
+        let (residual, weights, berr) = loop {
+            let mut residual = b_j.clone_owned();
+            residual.gemv(-N2::one(), a, &x, N2::one());
+
+            // The componentwise scaling `|a| * |x| + |b|` of the backward error.
+            let weights = VectorN::<N2::RealField, D>::from_fn_generic(dim, U1, |i, _| {
+                a.row(i)
+                    .iter()
+                    .zip(x.iter())
+                    .fold(b_j[i].modulus(), |acc, (a_ik, x_k)| {
+                        acc + a_ik.modulus() * x_k.modulus()
+                    })
+            });
+
+            // NOTE: a zero weight implies that the corresponding residual is exactly zero.
+            let berr = residual
+                .iter()
+                .zip(weights.iter())
+                .filter(|(_, w)| !w.is_zero())
+                .fold(N2::RealField::zero(), |acc, (r, w)| {
+                    acc.max(r.modulus() / *w)
+                });
+
+            // Stop once the backward error is negligible or no longer decreases quickly.
+            let stalled = matches!(last_berr, Some(last_berr) if berr + berr > last_berr);
+
+            if berr <= eps || stalled || niter == MAX_REFINEMENT_STEPS {
+                break (residual, weights, berr);
+            }
+
+            let mut correction = residual;
+            if !low_precision_solve(&mut correction, false) {
+                return None;
+            }
+
+            x += correction;
+            last_berr = Some(berr);
+            niter += 1;
+        };
+
+        // Bound the forward error with `‖ |a⁻¹| * f ‖∞ / ‖x‖∞` where `f` accounts for the
+        // residual and for the rounding errors made while computing it. The numerator is the
+        // ∞-norm of `a⁻¹ * diag(f)`, i.e., the 1-norm of its adjoint `diag(f) * a⁻ᴴ`.
+        let f = VectorN::<N2::RealField, D>::from_fn_generic(dim, U1, |i, _| {
+            residual[i].modulus() + nz * eps * weights[i]
+        });
+        let x_norm = x
+            .iter()
+            .fold(N2::RealField::zero(), |acc, e| acc.max(e.modulus()));
+
+        let ferr = if x_norm.is_zero() {
+            N2::RealField::zero()
+        } else {
+            let numerator = norm_estimate::estimate_inverse_one_norm(
+                dim,
+                |y: &mut VectorN<N2, D>| {
+                    let ok = low_precision_solve(y, true);
+                    y.zip_apply(&f, |e, f_i| e.scale(f_i));
+                    ok
+                },
+                |y: &mut VectorN<N2, D>| {
+                    y.zip_apply(&f, |e, f_i| e.scale(f_i));
+                    low_precision_solve(y, false)
+                },
+            )?;
+
+            numerator / x_norm
+        };
+
+        solution.set_column(j, &x);
+        forward_error[j] = ferr;
+        backward_error[j] = berr;
+    }
+
+    Some(RefinedSolution {
+        solution,
+        forward_error,
+        backward_error,
+    })
+}
@@ -0,0 +1,226 @@
+use num::Zero;
+use num_complex::Complex as NumComplex;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimAdd, DimMin, DimSum};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, MatrixN, SquareMatrix};
+
+use crate::linalg::GeneralizedSchur;
+
+/// # Algebraic Riccati equations
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimAdd<D> + DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimSum<D, D>, DimSum<D, D>>
+        + Allocator<N, DimSum<D, D>>
+        + Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, DimSum<D, D>, DimSum<D, D>>
+        + Allocator<NumComplex<N::RealField>, DimSum<D, D>>
+        + Allocator<(usize, usize), D>,
+{
+    /// Computes the stabilizing solution of the continuous algebraic Riccati equation
+    /// `self.adjoint() * x + x * self - x * b * r⁻¹ * b.adjoint() * x + q = 0`.
+    ///
+    /// The returned solution `x` is hermitian and such that all the eigenvalues of
+    /// `self - b * r⁻¹ * b.adjoint() * x` have negative real parts. Returns `None` if `r` is not
+    /// hermitian definite-positive or if no stabilizing solution exists.
+    pub fn solve_continuous_riccati<C2, S2, S3, S4>(
+        &self,
+        b: &Matrix<N, D, C2, S2>,
+        q: &SquareMatrix<N, D, S3>,
+        r: &SquareMatrix<N, C2, S4>,
+    ) -> Option<MatrixN<N, D>>
+    where
+        C2: Dim,
+        S2: Storage<N, D, C2>,
+        S3: Storage<N, D, D>,
+        S4: Storage<N, C2, C2>,
+        DefaultAllocator: Allocator<N, C2, C2> + Allocator<N, C2, D>,
+    {
+        let (dim, g) = riccati_check_and_gain(self, b, q, r)?;
+        let dim2 = dim.add(dim);
+
+        // The hamiltonian pencil `([A, -G; -Q, -A.adjoint()], I)`.
+        let mut m = MatrixN::zeros_generic(dim2, dim2);
+        m.generic_slice_mut((0, 0), (dim, dim)).copy_from(self);
+        m.generic_slice_mut((0, dim.value()), (dim, dim))
+            .copy_from(&-g);
+        m.generic_slice_mut((dim.value(), 0), (dim, dim))
+            .copy_from(&-q.clone_owned());
+        m.generic_slice_mut((dim.value(), dim.value()), (dim, dim))
+            .copy_from(&-self.adjoint());
+        let l = MatrixN::identity_generic(dim2, dim2);
+
+        stable_deflating_subspace_solution(m, l, dim, false)
+    }
+
+    /// Computes the stabilizing solution of the discrete algebraic Riccati equation
+    /// `self.adjoint() * x * self - x - self.adjoint() * x * b * (r + b.adjoint() * x * b)⁻¹ * b.adjoint() * x * self + q = 0`.
+    ///
+    /// The returned solution `x` is hermitian and such that all the eigenvalues of the closed-loop
+    /// matrix `self - b * (r + b.adjoint() * x * b)⁻¹ * b.adjoint() * x * self` have a modulus
+    /// smaller than 1. Returns `None` if `r` is not hermitian definite-positive or if no
+    /// stabilizing solution exists.
+    pub fn solve_discrete_riccati<C2, S2, S3, S4>(
+        &self,
+        b: &Matrix<N, D, C2, S2>,
+        q: &SquareMatrix<N, D, S3>,
+        r: &SquareMatrix<N, C2, S4>,
+    ) -> Option<MatrixN<N, D>>
+    where
+        C2: Dim,
+        S2: Storage<N, D, C2>,
+        S3: Storage<N, D, D>,
+        S4: Storage<N, C2, C2>,
+        DefaultAllocator: Allocator<N, C2, C2> + Allocator<N, C2, D>,
+    {
+        let (dim, g) = riccati_check_and_gain(self, b, q, r)?;
+        let dim2 = dim.add(dim);
+
+        // The symplectic pencil `([A, 0; -Q, I], [I, G; 0, A.adjoint()])`.
+        let mut m = MatrixN::zeros_generic(dim2, dim2);
+        m.generic_slice_mut((0, 0), (dim, dim)).copy_from(self);
+        m.generic_slice_mut((dim.value(), 0), (dim, dim))
+            .copy_from(&-q.clone_owned());
+        m.generic_slice_mut((dim.value(), dim.value()), (dim, dim))
+            .fill_with_identity();
+
+        let mut l = MatrixN::zeros_generic(dim2, dim2);
+        l.generic_slice_mut((0, 0), (dim, dim)).fill_with_identity();
+        l.generic_slice_mut((0, dim.value()), (dim, dim))
+            .copy_from(&g);
+        l.generic_slice_mut((dim.value(), dim.value()), (dim, dim))
+            .copy_from(&self.adjoint());
+
+        stable_deflating_subspace_solution(m, l, dim, true)
+    }
+}
+
+/// Checks the dimensions of the Riccati equation coefficients and computes the matrix
+/// `G = b * r⁻¹ * b.adjoint()`.
+///
+/// Returns `None` if the Cholesky decomposition of `r` fails.
+fn riccati_check_and_gain<N, D, C2, S1, S2, S3, S4>(
+    a: &SquareMatrix<N, D, S1>,
+    b: &Matrix<N, D, C2, S2>,
+    q: &SquareMatrix<N, D, S3>,
+    r: &SquareMatrix<N, C2, S4>,
+) -> Option<(D, MatrixN<N, D>)>
+where
+    N: ComplexField,
+    D: Dim,
+    C2: Dim,
+    S1: Storage<N, D, D>,
+    S2: Storage<N, D, C2>,
+    S3: Storage<N, D, D>,
+    S4: Storage<N, C2, C2>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, C2, C2> + Allocator<N, C2, D>,
+{
+    assert!(
+        a.is_square(),
+        "Riccati equation: the state matrix must be square."
+    );
+    assert_eq!(
+        q.shape(),
+        a.shape(),
+        "Riccati equation: the state weight matrix has inconsistent dimensions."
+    );
+    assert_eq!(
+        b.nrows(),
+        a.nrows(),
+        "Riccati equation: the input matrix has inconsistent dimensions."
+    );
+    assert_eq!(
+        r.shape(),
+        (b.ncols(), b.ncols()),
+        "Riccati equation: the input weight matrix has inconsistent dimensions."
+    );
+
+    let chol = r.clone_owned().cholesky()?;
+    let g = b * chol.solve(&b.adjoint());
+
+    Some((a.data.shape().0, g))
+}
+
+/// Computes the solution `X = Z21 * Z11⁻¹` of a Riccati equation from the stable deflating
+/// subspace `[Z11; Z21]` of the matrix pencil `(m, l)`.
+///
+/// If `discrete` is `true`, the stable generalized eigenvalues are the ones with a modulus smaller
+/// than 1. Otherwise, they are the ones with a negative real part.
+fn stable_deflating_subspace_solution<N: ComplexField, D>(
+    m: MatrixN<N, DimSum<D, D>>,
+    l: MatrixN<N, DimSum<D, D>>,
+    dim: D,
+    discrete: bool,
+) -> Option<MatrixN<N, D>>
+where
+    D: DimAdd<D> + DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimSum<D, D>, DimSum<D, D>>
+        + Allocator<N, DimSum<D, D>>
+        + Allocator<NumComplex<N::RealField>, D, D>
+        + Allocator<NumComplex<N::RealField>, DimSum<D, D>, DimSum<D, D>>
+        + Allocator<NumComplex<N::RealField>, DimSum<D, D>>
+        + Allocator<(usize, usize), D>,
+{
+    let mut schur = GeneralizedSchur::new(m.clone(), l.clone());
+
+    if let Some(nstable) = schur.reorder(|alpha, beta| is_stable(alpha, beta, discrete)) {
+        return solution_from_subspace(schur.unpack().3, dim, nstable);
+    }
+
+    // The real pencil has complex eigenvalues: compute the deflating subspace in complex
+    // arithmetic. Its real part spans the same subspace as the real solution.
+    let to_complex = |e: N| NumComplex::new(e.real(), e.imaginary());
+    let mut schur = GeneralizedSchur::new(m.map(to_complex), l.map(to_complex));
+    let nstable = schur
+        .reorder(|alpha, beta| is_stable(alpha, beta, discrete))
+        .expect("The complex generalized Schur form is always upper-triangular.");
+    let x = solution_from_subspace(schur.unpack().3, dim, nstable)?;
+
+    Some(x.map(|e| N::from_real(e.re)))
+}
+
+/// Tests if the generalized eigenvalue `alpha / beta` is stable.
+fn is_stable<N: ComplexField>(alpha: N, beta: N, discrete: bool) -> bool {
+    if discrete {
+        alpha.modulus() < beta.modulus()
+    } else {
+        (alpha * beta.conjugate()).real() < N::RealField::zero() && !beta.is_zero()
+    }
+}
+
+/// Computes the hermitian matrix `X = Z21 * Z11⁻¹` where `Z11` and `Z21` are the first `dim`
+/// columns of the top and bottom halves of `z`.
+///
+/// Returns `None` if the stable subspace does not have dimension `dim`, or if `Z11` is singular.
+fn solution_from_subspace<N: ComplexField, D>(
+    z: MatrixN<N, DimSum<D, D>>,
+    dim: D,
+    nstable: usize,
+) -> Option<MatrixN<N, D>>
+where
+    D: DimAdd<D> + DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimSum<D, D>, DimSum<D, D>>
+        + Allocator<(usize, usize), D>,
+{
+    if nstable != dim.value() {
+        return None;
+    }
+
+    let z11 = z.generic_slice((0, 0), (dim, dim)).adjoint();
+    let z21 = z.generic_slice((dim.value(), 0), (dim, dim)).adjoint();
+
+    // X * Z11 = Z21  <=>  Z11.adjoint() * X.adjoint() = Z21.adjoint()
+    let x = z11.lu().solve(&z21)?.adjoint();
+
+    if x.iter().all(|e| e.is_finite()) {
+        Some(x.hermitian_part())
+    } else {
+        None
+    }
+}
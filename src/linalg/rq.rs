@@ -0,0 +1,102 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixMN};
+use crate::dimension::{Dim, DimMin, DimMinimum};
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::LQ;
+
+/// The RQ decomposition of a general matrix.
+///
+/// The matrix `m` is decomposed as `m = R * Q` where `R` is upper trapezoidal and `Q` has
+/// orthonormal rows. If `m` has more rows than columns, only the last rows of `R` form an
+/// upper-triangular matrix.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>>,
+         LQ<N, R, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>>,
+         LQ<N, R, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct RQ<N: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, DimMinimum<R, C>>,
+{
+    // The LQ decomposition of the input matrix with its rows and columns in reverse order.
+    lq: LQ<N, R, C>,
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Copy for RQ<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, DimMinimum<R, C>>,
+    LQ<N, R, C>: Copy,
+{
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> RQ<N, R, C>
+where
+    DefaultAllocator:
+        Allocator<N, R, C> + Allocator<N, R> + Allocator<N, C> + Allocator<N, DimMinimum<R, C>>,
+{
+    /// Computes the RQ decomposition using householder reflections.
+    pub fn new(matrix: MatrixMN<N, R, C>) -> Self {
+        // If `J` denotes the permutation matrices reversing the order of rows or columns and
+        // `J * m * J = L * Q`, then `m = (J * L * J) * (J * Q * J)` where `J * L * J` is upper
+        // trapezoidal.
+        RQ {
+            lq: LQ::new(reverse_rows_and_columns(&matrix)),
+        }
+    }
+
+    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    #[inline]
+    pub fn r(&self) -> MatrixMN<N, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>>,
+    {
+        reverse_rows_and_columns(&self.lq.l())
+    }
+
+    /// Computes the matrix `Q` with orthonormal rows of this decomposition.
+    pub fn q(&self) -> MatrixMN<N, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<N, DimMinimum<R, C>, C>,
+    {
+        reverse_rows_and_columns(&self.lq.q())
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(R, Q)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        MatrixMN<N, R, DimMinimum<R, C>>,
+        MatrixMN<N, DimMinimum<R, C>, C>,
+    )
+    where
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>> + Allocator<N, DimMinimum<R, C>, C>,
+    {
+        (self.r(), self.q())
+    }
+}
+
+fn reverse_rows_and_columns<N: ComplexField, R: Dim, C: Dim>(
+    m: &MatrixMN<N, R, C>,
+) -> MatrixMN<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    let (nrows, ncols) = m.data.shape();
+    let (nr, nc) = (nrows.value(), ncols.value());
+
+    MatrixMN::from_fn_generic(nrows, ncols, |i, j| m[(nr - 1 - i, nc - 1 - j)])
+}
@@ -621,6 +621,22 @@ where
         SVD::new(self.clone_owned(), false, false).singular_values
     }
 
+    /// The spectral norm of this matrix, i.e., its largest singular value.
+    ///
+    /// This is the matrix norm induced by the vector 2-norm.
+    pub fn spectral_norm(&self) -> N::RealField {
+        self.singular_values()
+            .iter()
+            .fold(N::RealField::zero(), |a, s| a.max(*s))
+    }
+
+    /// The nuclear norm of this matrix, i.e., the sum of its singular values.
+    pub fn nuclear_norm(&self) -> N::RealField {
+        self.singular_values()
+            .iter()
+            .fold(N::RealField::zero(), |a, s| a + *s)
+    }
+
     /// Computes the rank of this matrix.
     ///
     /// All singular values below `eps` are considered equal to 0.
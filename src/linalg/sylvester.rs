@@ -0,0 +1,350 @@
+use approx::AbsDiffEq;
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix};
+
+use crate::linalg::Schur;
+
+/// # Sylvester and Lyapunov equations
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimSub<U1>, // For Schur.
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>,
+{
+    /// Solves the Sylvester equation `self * x + x * b = c` using the Bartels-Stewart algorithm.
+    ///
+    /// Returns `None` if the equation does not have a unique solution, i.e., if `self` and `-b`
+    /// have a common eigenvalue.
+    pub fn solve_sylvester<D2, S2, S3>(
+        &self,
+        b: &SquareMatrix<N, D2, S2>,
+        c: &Matrix<N, D, D2, S3>,
+    ) -> Option<MatrixMN<N, D, D2>>
+    where
+        D2: DimSub<U1>, // For Schur.
+        S2: Storage<N, D2, D2>,
+        S3: Storage<N, D, D2>,
+        DefaultAllocator: Allocator<N, D2, DimDiff<D2, U1>>
+            + Allocator<N, DimDiff<D2, U1>>
+            + Allocator<N, D2, D2>
+            + Allocator<N, D2>
+            + Allocator<N, D, D2>,
+    {
+        assert!(
+            self.is_square() && b.is_square(),
+            "Sylvester equation: the coefficient matrices must be square."
+        );
+        assert_eq!(
+            c.shape(),
+            (self.nrows(), b.nrows()),
+            "Sylvester equation: the right-hand side has inconsistent dimensions."
+        );
+
+        let (u, s) = Schur::new(self.clone_owned()).unpack();
+        let (v, t) = Schur::new(b.clone_owned()).unpack();
+
+        let mut y = u.ad_mul(c) * &v;
+
+        if solve_quasi_triangular_sylvester(&s, &t, &mut y, false) {
+            Some(u * y * v.adjoint())
+        } else {
+            None
+        }
+    }
+
+    /// Solves the continuous Lyapunov equation `self * x + x * self.adjoint() = q`.
+    ///
+    /// Returns `None` if the equation does not have a unique solution, i.e., if `self` and
+    /// `-self.adjoint()` have a common eigenvalue.
+    pub fn solve_continuous_lyapunov<S2>(&self, q: &SquareMatrix<N, D, S2>) -> Option<MatrixN<N, D>>
+    where
+        S2: Storage<N, D, D>,
+    {
+        assert_eq!(
+            q.shape(),
+            self.shape(),
+            "Lyapunov equation: the right-hand side has inconsistent dimensions."
+        );
+
+        let (u, s, v, t) = lyapunov_schur(self.clone_owned());
+        let mut y = u.ad_mul(q) * &v;
+
+        if solve_quasi_triangular_sylvester(&s, &t, &mut y, false) {
+            Some(u * y * v.adjoint())
+        } else {
+            None
+        }
+    }
+
+    /// Solves the discrete Lyapunov (or Stein) equation `self * x * self.adjoint() - x + q = 0`.
+    ///
+    /// Returns `None` if the equation does not have a unique solution, i.e., if `self` has two
+    /// eigenvalues `λ1` and `λ2` such that `λ1 * λ2.conjugate() = 1`.
+    pub fn solve_discrete_lyapunov<S2>(&self, q: &SquareMatrix<N, D, S2>) -> Option<MatrixN<N, D>>
+    where
+        S2: Storage<N, D, D>,
+    {
+        assert_eq!(
+            q.shape(),
+            self.shape(),
+            "Lyapunov equation: the right-hand side has inconsistent dimensions."
+        );
+
+        let (u, s, v, t) = lyapunov_schur(self.clone_owned());
+        let mut y = -u.ad_mul(q) * &v;
+
+        if solve_quasi_triangular_sylvester(&s, &t, &mut y, true) {
+            Some(u * y * v.adjoint())
+        } else {
+            None
+        }
+    }
+}
+
+/// Computes the Schur decompositions `a = U * S * U.adjoint()` and `a.adjoint() = V * T * V.adjoint()`
+/// from a single Schur decomposition of `a`.
+///
+/// The returned tuple is `(U, S, V, T)`.
+fn lyapunov_schur<N: ComplexField, D: DimSub<U1>>(
+    a: MatrixN<N, D>,
+) -> (MatrixN<N, D>, MatrixN<N, D>, MatrixN<N, D>, MatrixN<N, D>)
+where
+    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N, D, D>
+        + Allocator<N, D>,
+{
+    let dim = a.data.shape().0;
+    let n = dim.value();
+    let (u, s) = Schur::new(a).unpack();
+
+    // Reversing the order of the rows and columns of the lower-quasitriangular `S.adjoint()`
+    // yields an upper-quasitriangular matrix.
+    let v = MatrixN::from_fn_generic(dim, dim, |i, j| u[(i, n - 1 - j)]);
+    let t = MatrixN::from_fn_generic(dim, dim, |i, j| s[(n - 1 - j, n - 1 - i)].conjugate());
+
+    (u, s, v, t)
+}
+
+/// Solves in-place the Sylvester equation `s * y + y * t = f` (or the discrete Sylvester
+/// equation `s * y * t - y = f` if `discrete` is `true`), where `s` and `t` are
+/// upper-quasitriangular.
+///
+/// The solution `y` is written into `f`. Returns `false` if the equation is singular.
+fn solve_quasi_triangular_sylvester<N: ComplexField, R: Dim, C: Dim>(
+    s: &MatrixN<N, R>,
+    t: &MatrixN<N, C>,
+    f: &mut MatrixMN<N, R, C>,
+    discrete: bool,
+) -> bool
+where
+    DefaultAllocator: Allocator<N, R, R> + Allocator<N, C, C> + Allocator<N, R, C>,
+{
+    let (m, n) = f.shape();
+    let mut j = 0;
+
+    while j < n {
+        let q = if j + 1 < n && !t[(j + 1, j)].is_zero() {
+            2
+        } else {
+            1
+        };
+
+        // Solve for the column block `j..j + q`, by blocks of rows starting from the bottom.
+        let mut end = m;
+
+        while end > 0 {
+            let p = if end > 1 && !s[(end - 1, end - 2)].is_zero() {
+                2
+            } else {
+                1
+            };
+            let i = end - p;
+
+            // Contribution of the rows that are already solved.
+            let mut acc = [[N::zero(); 2]; 2];
+            for a in 0..p {
+                for d in 0..q {
+                    for k in end..m {
+                        acc[a][d] += s[(i + a, k)] * f[(k, j + d)];
+                    }
+                }
+            }
+
+            let mut rhs = [[N::zero(); 2]; 2];
+            for a in 0..p {
+                for b in 0..q {
+                    rhs[a][b] = f[(i + a, j + b)];
+
+                    if discrete {
+                        for d in 0..q {
+                            rhs[a][b] -= acc[a][d] * t[(j + d, j + b)];
+                        }
+                    } else {
+                        rhs[a][b] -= acc[a][b];
+                    }
+                }
+            }
+
+            match solve_block(s, (i, p), t, (j, q), rhs, discrete) {
+                Some(y) => {
+                    for a in 0..p {
+                        for b in 0..q {
+                            f[(i + a, j + b)] = y[a][b];
+                        }
+                    }
+                }
+                None => return false,
+            }
+
+            end = i;
+        }
+
+        // Remove the contribution of the solved columns from the remaining ones.
+        for r in 0..m {
+            let mut coeffs = [N::zero(); 2];
+
+            for (b, coeff) in coeffs.iter_mut().enumerate().take(q) {
+                if discrete {
+                    for k in r.saturating_sub(1)..m {
+                        *coeff += s[(r, k)] * f[(k, j + b)];
+                    }
+                } else {
+                    *coeff = f[(r, j + b)];
+                }
+            }
+
+            for l in j + q..n {
+                for (b, coeff) in coeffs.iter().enumerate().take(q) {
+                    let update = *coeff * t[(j + b, l)];
+                    f[(r, l)] -= update;
+                }
+            }
+        }
+
+        j += q;
+    }
+
+    true
+}
+
+/// Solves the (at most 2x2) Sylvester equation `s_ii * y + y * t_jj = rhs` (or
+/// `s_ii * y * t_jj - y = rhs` if `discrete` is `true`) where `s_ii` is the `p x p` diagonal
+/// block of `s` starting at `i`, and `t_jj` the `q x q` diagonal block of `t` starting at `j`.
+fn solve_block<N: ComplexField, R: Dim, C: Dim>(
+    s: &MatrixN<N, R>,
+    (i, p): (usize, usize),
+    t: &MatrixN<N, C>,
+    (j, q): (usize, usize),
+    rhs: [[N; 2]; 2],
+    discrete: bool,
+) -> Option<[[N; 2]; 2]>
+where
+    DefaultAllocator: Allocator<N, R, R> + Allocator<N, C, C>,
+{
+    // Build the Kronecker form of the equation, where `y` is vectorized column-wise.
+    let dim = p * q;
+    let mut k = [[N::zero(); 4]; 4];
+    let mut x = [N::zero(); 4];
+
+    for a in 0..p {
+        for b in 0..q {
+            let row = a + p * b;
+            x[row] = rhs[a][b];
+
+            for c in 0..p {
+                for d in 0..q {
+                    let col = c + p * d;
+
+                    if discrete {
+                        k[row][col] = s[(i + a, i + c)] * t[(j + d, j + b)];
+
+                        if a == c && b == d {
+                            k[row][col] -= N::one();
+                        }
+                    } else {
+                        if b == d {
+                            k[row][col] += s[(i + a, i + c)];
+                        }
+
+                        if a == c {
+                            k[row][col] += t[(j + d, j + b)];
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if !solve_small_system(&mut k, &mut x, dim) {
+        return None;
+    }
+
+    let mut y = [[N::zero(); 2]; 2];
+    for a in 0..p {
+        for b in 0..q {
+            y[a][b] = x[a + p * b];
+        }
+    }
+
+    Some(y)
+}
+
+/// Solves in-place the `dim x dim` linear system `k * x = rhs` using Gaussian elimination with
+/// partial pivoting.
+///
+/// Returns `false` if the system is numerically singular.
+fn solve_small_system<N: ComplexField>(k: &mut [[N; 4]; 4], x: &mut [N; 4], dim: usize) -> bool {
+    let mut kmax = N::RealField::zero();
+    for row in k.iter().take(dim) {
+        for e in row.iter().take(dim) {
+            kmax = kmax.max(e.norm1());
+        }
+    }
+
+    let eps = N::RealField::default_epsilon() * kmax;
+
+    for c in 0..dim {
+        let mut piv = c;
+        for r in c + 1..dim {
+            if k[r][c].norm1() > k[piv][c].norm1() {
+                piv = r;
+            }
+        }
+
+        if k[piv][c].norm1() <= eps || k[piv][c].is_zero() {
+            return false;
+        }
+
+        k.swap(c, piv);
+        x.swap(c, piv);
+
+        let pivot_row = k[c];
+
+        for r in c + 1..dim {
+            let factor = k[r][c] / pivot_row[c];
+            for (e, p) in k[r][c..dim].iter_mut().zip(&pivot_row[c..dim]) {
+                *e -= factor * *p;
+            }
+            let update = factor * x[c];
+            x[r] -= update;
+        }
+    }
+
+    for c in (0..dim).rev() {
+        for cc in c + 1..dim {
+            let update = k[c][cc] * x[cc];
+            x[c] -= update;
+        }
+        x[c] /= k[c][c];
+    }
+
+    true
+}
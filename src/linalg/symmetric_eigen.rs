@@ -190,7 +190,19 @@ where
                     diag[start + 1],
                 );
                 let eigvals = m.eigenvalues().unwrap();
-                let basis = Vector2::new(eigvals.x - diag[start + 1], off_diag[start]);
+
+                // With `m = [a, b; b, d]`, the eigenvector of `eigvals.x = (a + d) / 2 + r` is
+                // `(eigvals.x - d, b)`, or equivalently `(b, eigvals.x - a)`. Subtracting from
+                // `eigvals.x` cancels catastrophically when `a` and `d` are close, so the first
+                // component is computed as `(a - d) / 2 + r` if `a >= d`, and the second as
+                // `(d - a) / 2 + r` otherwise.
+                let half_diff = (diag[start] - diag[start + 1]) * crate::convert(0.5);
+                let r = (half_diff * half_diff + off_diag[start] * off_diag[start]).sqrt();
+                let basis = if half_diff >= N::RealField::zero() {
+                    Vector2::new(half_diff + r, off_diag[start])
+                } else {
+                    Vector2::new(off_diag[start], r - half_diff)
+                };
 
                 diag[start] = eigvals[0];
                 diag[start + 1] = eigvals[1];
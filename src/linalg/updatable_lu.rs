@@ -0,0 +1,338 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Vector, VectorN};
+use crate::dimension::{Dim, DimMin, U1};
+use crate::storage::{Storage, StorageMut};
+
+use crate::linalg::{PermutationSequence, LU};
+
+/// The LU decomposition of a square matrix, with factors that can be updated efficiently.
+///
+/// This keeps the factors `P` and `L` of an initial `LU` decomposition, and decomposes the
+/// matrix `A` as `A = P⁻¹ * L * E⁻¹ * U * Q⁻¹`, where `E` accumulates the elementary row
+/// operations of the updates, `U` is upper-triangular and `Q` is a column permutation.
+///
+/// The updates follow the Bartels-Golub method: after a rank-one modification or the replacement
+/// of a column of `A`, `U` is brought to upper Hessenberg form, and then reduced back to an
+/// upper-triangular matrix by Gaussian elimination between adjacent rows. The rows are
+/// interchanged whenever this gives a smaller multiplier, so all the multipliers are bounded by
+/// one as with partial pivoting. Each update takes `O(n²)` operations, and so does each solve.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<(usize, usize), D> +
+                           Allocator<usize, D>,
+         MatrixN<N, D>: Serialize,
+         PermutationSequence<D>: Serialize,
+         VectorN<usize, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<(usize, usize), D> +
+                           Allocator<usize, D>,
+         MatrixN<N, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>,
+         VectorN<usize, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct UpdatableLU<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D> + Allocator<usize, D>,
+{
+    l: MatrixN<N, D>,
+    p: PermutationSequence<D>,
+    e: MatrixN<N, D>,
+    u: MatrixN<N, D>,
+    cols: VectorN<usize, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for UpdatableLU<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D> + Allocator<usize, D>,
+    MatrixN<N, D>: Copy,
+    PermutationSequence<D>: Copy,
+    VectorN<usize, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: DimMin<D, Output = D>> UpdatableLU<N, D>
+where
+    DefaultAllocator:
+        Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D> + Allocator<usize, D>,
+{
+    /// Computes the LU decomposition with partial (row) pivoting of `matrix`, with factors that
+    /// can be updated.
+    pub fn new(matrix: MatrixN<N, D>) -> Self {
+        Self::from_lu(&LU::new(matrix))
+    }
+
+    /// Copies the factors of the given LU decomposition into a decomposition that can be updated.
+    pub fn from_lu(lu: &LU<N, D, D>) -> Self {
+        let dim = lu.lu_internal().data.shape().0;
+
+        UpdatableLU {
+            l: lu.l(),
+            p: lu.p().clone(),
+            e: MatrixN::identity_generic(dim, dim),
+            u: lu.u(),
+            cols: VectorN::from_fn_generic(dim, U1, |i, _| i),
+        }
+    }
+
+    /// The upper-triangular matrix `U` of this decomposition.
+    #[inline]
+    pub fn u(&self) -> MatrixN<N, D> {
+        self.u.clone()
+    }
+
+    /// The indices of the columns of the decomposed matrix matching the columns of `U`.
+    ///
+    /// This describes the column permutation `Q`, which is only modified by `replace_column`.
+    #[inline]
+    pub fn column_order(&self) -> &VectorN<usize, D> {
+        &self.cols
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    pub fn is_invertible(&self) -> bool {
+        (0..self.u.nrows()).all(|i| !self.u[(i, i)].is_zero())
+    }
+
+    /// Solves the linear system `matrix * x = b`, where `matrix` is the decomposed matrix and
+    /// `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    pub fn solve<C2: Dim, S2>(&self, b: &Matrix<N, D, C2, S2>) -> Option<MatrixMN<N, D, C2>>
+    where
+        S2: Storage<N, D, C2>,
+        DefaultAllocator: Allocator<N, D, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `matrix * x = b`, where `matrix` is the decomposed matrix and
+    /// `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<C2: Dim, S2>(&self, b: &mut Matrix<N, D, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, D, C2>,
+        DefaultAllocator: Allocator<N, D, C2>,
+    {
+        self.transform_mut(b);
+
+        if !self.u.solve_upper_triangular_mut(b) {
+            return false;
+        }
+
+        let y = b.clone_owned();
+        for (k, &j) in self.cols.iter().enumerate() {
+            b.row_mut(j).copy_from(&y.row(k));
+        }
+
+        true
+    }
+
+    /// Solves the linear system `matrix.adjoint() * x = b`, where `matrix` is the decomposed
+    /// matrix and `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn ad_solve_mut<C2: Dim, S2>(&self, b: &mut Matrix<N, D, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, D, C2>,
+        DefaultAllocator: Allocator<N, D, C2>,
+    {
+        let y = b.clone_owned();
+        for (k, &j) in self.cols.iter().enumerate() {
+            b.row_mut(k).copy_from(&y.row(j));
+        }
+
+        if !self.u.ad_solve_upper_triangular_mut(b) {
+            return false;
+        }
+
+        let y = self.e.ad_mul(&*b);
+        b.copy_from(&y);
+        let _ = self.l.ad_solve_lower_triangular_mut(b);
+        self.p.inv_permute_rows(b);
+
+        true
+    }
+
+    /// Updates this decomposition such that we end up with the decomposition of
+    /// `matrix + u * v.adjoint()`, where `matrix` is the decomposed matrix.
+    pub fn rank_one_update<S1, S2>(&mut self, u: &Vector<N, D, S1>, v: &Vector<N, D, S2>)
+    where
+        S1: Storage<N, D>,
+        S2: Storage<N, D>,
+    {
+        let n = self.u.nrows();
+
+        if n == 0 {
+            return;
+        }
+
+        // Reduce `E * L⁻¹ * P * u` to a multiple of the first canonical basis vector. The same
+        // row operations turn `U` into an upper Hessenberg matrix.
+        let mut w = u.clone_owned();
+        self.transform_mut(&mut w);
+
+        for k in (1..n).rev() {
+            let (swap, m) = pivot(w[k - 1], w[k]);
+
+            if swap {
+                w.swap_rows(k - 1, k);
+            }
+
+            w[k] = N::zero();
+            self.eliminate(k - 1, k - 1, swap, m);
+        }
+
+        for (k, &j) in self.cols.iter().enumerate() {
+            self.u[(0, k)] += w[0] * v[j].conjugate();
+        }
+
+        self.reduce_hessenberg(0);
+    }
+
+    /// Updates this decomposition such that we end up with the decomposition of the decomposed
+    /// matrix with its `j`th column replaced by `col`.
+    ///
+    /// This is the basis update of the simplex method. It moves the column of `U` matching the
+    /// `j`th column of the decomposed matrix to the last position, which changes
+    /// `self.column_order()`.
+    pub fn replace_column<S2>(&mut self, j: usize, col: &Vector<N, D, S2>)
+    where
+        S2: Storage<N, D>,
+    {
+        let n = self.u.ncols();
+        assert!(j < n, "j needs to be within the bound of the matrix.");
+
+        let mut spike = col.clone_owned();
+        self.transform_mut(&mut spike);
+
+        // Shift the columns after the replaced one to the left, and append the new column. `U`
+        // is then upper Hessenberg starting from the `k`th column.
+        let k = self.cols.iter().position(|&c| c == j).unwrap();
+
+        for i in 0..n {
+            for l in k..n - 1 {
+                self.u[(i, l)] = self.u[(i, l + 1)];
+            }
+
+            self.u[(i, n - 1)] = spike[i];
+        }
+
+        self.cols.as_mut_slice()[k..].rotate_left(1);
+        self.reduce_hessenberg(k);
+    }
+
+    /// Computes `E * L⁻¹ * P * b` in-place.
+    fn transform_mut<C2: Dim, S2>(&self, b: &mut Matrix<N, D, C2, S2>)
+    where
+        S2: StorageMut<N, D, C2>,
+        DefaultAllocator: Allocator<N, D, C2>,
+    {
+        self.p.permute_rows(b);
+        let _ = self.l.solve_lower_triangular_mut(b);
+        let y = &self.e * &*b;
+        b.copy_from(&y);
+    }
+
+    /// Reduces `U` to an upper-triangular matrix, assuming that its only non-zero elements below
+    /// the diagonal are on the subdiagonal of the columns after `start`.
+    fn reduce_hessenberg(&mut self, start: usize) {
+        for k in start..self.u.nrows().saturating_sub(1) {
+            let (swap, m) = pivot(self.u[(k, k)], self.u[(k + 1, k)]);
+            self.eliminate(k, k, swap, m);
+            self.u[(k + 1, k)] = N::zero();
+        }
+    }
+
+    /// Applies an elementary row operation to the rows `i` and `i + 1` of `E` and `U`: they are
+    /// interchanged if `swap` is `true`, then the row `i` times `m` is subtracted from the row
+    /// `i + 1`. Both rows of `U` are assumed to be zero on all the columns before `start`.
+    fn eliminate(&mut self, i: usize, start: usize, swap: bool, m: N) {
+        let n = self.u.ncols();
+
+        if swap {
+            self.e.swap_rows(i, i + 1);
+            self.u.columns_range_mut(start..).swap_rows(i, i + 1);
+        }
+
+        if !m.is_zero() {
+            for l in 0..n {
+                let x = self.e[(i, l)];
+                self.e[(i + 1, l)] -= x * m;
+            }
+
+            for l in start..n {
+                let x = self.u[(i, l)];
+                self.u[(i + 1, l)] -= x * m;
+            }
+        }
+    }
+}
+
+/// Returns whether `a` and `b` must be interchanged so that the largest one is the pivot, and the
+/// multiplier eliminating the other one.
+fn pivot<N: ComplexField>(a: N, b: N) -> (bool, N) {
+    let swap = b.norm1() > a.norm1();
+    let (pivot, other) = if swap { (b, a) } else { (a, b) };
+
+    if pivot.is_zero() {
+        (swap, N::zero())
+    } else {
+        (swap, other / pivot)
+    }
+}
+
+/// # Updates
+///
+/// These methods copy the factors of this decomposition into an `UpdatableLU`, update it in
+/// `O(n²)` operations, and return it. Further updates can be applied to it directly.
+impl<N: ComplexField, D: DimMin<D, Output = D>> LU<N, D, D>
+where
+    DefaultAllocator:
+        Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D> + Allocator<usize, D>,
+{
+    /// Computes the decomposition of `matrix + u * v.adjoint()`, where `matrix` is the decomposed
+    /// matrix.
+    pub fn rank_one_update<S1, S2>(
+        &self,
+        u: &Vector<N, D, S1>,
+        v: &Vector<N, D, S2>,
+    ) -> UpdatableLU<N, D>
+    where
+        S1: Storage<N, D>,
+        S2: Storage<N, D>,
+    {
+        let mut res = UpdatableLU::from_lu(self);
+        res.rank_one_update(u, v);
+        res
+    }
+
+    /// Computes the decomposition of the decomposed matrix with its `j`th column replaced by
+    /// `col`.
+    pub fn replace_column<S2>(&self, j: usize, col: &Vector<N, D, S2>) -> UpdatableLU<N, D>
+    where
+        S2: Storage<N, D>,
+    {
+        let mut res = UpdatableLU::from_lu(self);
+        res.replace_column(j, col);
+        res
+    }
+}
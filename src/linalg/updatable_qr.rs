@@ -0,0 +1,472 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, RowVector, Vector, Vector2};
+use crate::dimension::{Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1, U2};
+use crate::storage::Storage;
+
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::QR;
+
+/// The QR decomposition of a general matrix, with explicit factors that can be updated
+/// efficiently.
+///
+/// Unlike `QR` which represents `Q` implicitly with householder reflections, this stores the
+/// full unitary matrix `Q` and the upper trapezoidal matrix `R` such that `matrix = Q * R`. This
+/// allows updating the decomposition with Givens rotations after a rank-one modification, or the
+/// insertion or removal of a row or a column of the decomposed matrix, in `O(nrows * (nrows + ncols))`
+/// operations instead of computing a new decomposition. The update methods of `QR` compute the
+/// explicit factors first, and return an `UpdatableQR`.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, R, R> +
+                           Allocator<N, R, C>,
+         MatrixN<N, R>: Serialize,
+         MatrixMN<N, R, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, R, R> +
+                           Allocator<N, R, C>,
+         MatrixN<N, R>: Deserialize<'de>,
+         MatrixMN<N, R, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct UpdatableQR<N: ComplexField, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, R> + Allocator<N, R, C>,
+{
+    q: MatrixN<N, R>,
+    r: MatrixMN<N, R, C>,
+}
+
+impl<N: ComplexField, R: Dim, C: Dim> Copy for UpdatableQR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, R> + Allocator<N, R, C>,
+    MatrixN<N, R>: Copy,
+    MatrixMN<N, R, C>: Copy,
+{
+}
+
+impl<N: ComplexField, R: Dim, C: Dim> UpdatableQR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, R> + Allocator<N, R, C>,
+{
+    /// Computes the QR decomposition of `matrix` with explicit factors.
+    pub fn new(matrix: MatrixMN<N, R, C>) -> Self
+    where
+        R: DimMin<C>,
+        DefaultAllocator:
+            Allocator<N, R> + Allocator<N, DimMinimum<R, C>> + Allocator<N, DimMinimum<R, C>, C>,
+    {
+        Self::from_qr(&QR::new(matrix))
+    }
+
+    /// Computes the explicit factors of the given QR decomposition.
+    pub fn from_qr(qr: &QR<N, R, C>) -> Self
+    where
+        R: DimMin<C>,
+        DefaultAllocator:
+            Allocator<N, R> + Allocator<N, DimMinimum<R, C>> + Allocator<N, DimMinimum<R, C>, C>,
+    {
+        let (nrows, ncols) = qr.qr_internal().data.shape();
+
+        let mut q = MatrixN::identity_generic(nrows, nrows);
+        qr.q_mul(&mut q);
+
+        let r_thin = qr.r();
+        let r = MatrixMN::from_fn_generic(nrows, ncols, |i, j| {
+            if i < r_thin.nrows() {
+                r_thin[(i, j)]
+            } else {
+                N::zero()
+            }
+        });
+
+        UpdatableQR { q, r }
+    }
+
+    /// The unitary matrix `Q` of this decomposition.
+    #[inline]
+    pub fn q(&self) -> MatrixN<N, R> {
+        self.q.clone()
+    }
+
+    /// The upper trapezoidal matrix `R` of this decomposition.
+    #[inline]
+    pub fn r(&self) -> MatrixMN<N, R, C> {
+        self.r.clone()
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(Q, R)`.
+    #[inline]
+    pub fn unpack(self) -> (MatrixN<N, R>, MatrixMN<N, R, C>) {
+        (self.q, self.r)
+    }
+
+    /// Computes the least squares solution of `matrix * x = b`, where `matrix` is the
+    /// decomposed matrix and `x` is the unknown to be determined.
+    ///
+    /// The decomposed matrix must not have more columns than rows. Returns `None` if it does not
+    /// have full column rank.
+    pub fn solve_least_squares<C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R, C2, S2>,
+    ) -> Option<MatrixMN<N, C, C2>>
+    where
+        S2: Storage<N, R, C2>,
+        DefaultAllocator: Allocator<N, R, C2> + Allocator<N, C, C2>,
+    {
+        let ncols = self.r.data.shape().1;
+        assert!(
+            ncols.value() <= self.r.nrows(),
+            "UpdatableQR least squares: the matrix must not have more columns than rows."
+        );
+
+        let q_ad_b = self.q.ad_mul(b);
+        let mut x = MatrixMN::from_fn_generic(ncols, b.data.shape().1, |i, j| q_ad_b[(i, j)]);
+
+        if self
+            .r
+            .generic_slice((0, 0), (ncols, ncols))
+            .solve_upper_triangular_mut(&mut x)
+        {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Updates this decomposition such that we end up with the decomposition of
+    /// `matrix + u * v.adjoint()`, where `matrix` is the decomposed matrix.
+    pub fn rank_one_update<S1, S2>(&mut self, u: &Vector<N, R, S1>, v: &Vector<N, C, S2>)
+    where
+        S1: Storage<N, R>,
+        S2: Storage<N, C>,
+        DefaultAllocator: Allocator<N, R>,
+    {
+        let (nrows, ncols) = self.r.shape();
+
+        if nrows == 0 {
+            return;
+        }
+
+        // Reduce `Q.adjoint() * u` to a multiple of the first canonical basis vector. The same
+        // rotations turn `R` into an upper Hessenberg matrix.
+        let mut w = self.q.ad_mul(u);
+
+        for k in (1..nrows).rev() {
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&Vector2::new(w[k - 1], w[k])) {
+                w[k - 1] = norm;
+                w[k] = N::zero();
+                self.rotate(&rot, k - 1, k, k - 1);
+            }
+        }
+
+        for j in 0..ncols {
+            self.r[(0, j)] += w[0] * v[j].conjugate();
+        }
+
+        // Restore the upper trapezoidal form of `R`.
+        for k in 0..(nrows - 1).min(ncols) {
+            self.cancel_entry(k, k + 1, k);
+        }
+    }
+
+    /// Updates this decomposition such that we get the decomposition of the decomposed matrix
+    /// with the column `col` inserted at the `j`th position.
+    pub fn insert_column<S2>(
+        &self,
+        j: usize,
+        col: &Vector<N, R, S2>,
+    ) -> UpdatableQR<N, R, DimSum<C, U1>>
+    where
+        C: DimAdd<U1>,
+        S2: Storage<N, R>,
+        DefaultAllocator: Allocator<N, R> + Allocator<N, R, DimSum<C, U1>>,
+    {
+        let (nrows, ncols) = self.r.data.shape();
+        assert!(
+            j <= ncols.value(),
+            "j needs to be within the bound of the new matrix."
+        );
+
+        let w = self.q.ad_mul(col);
+        let r = MatrixMN::from_fn_generic(nrows, ncols.add(U1), |i, k| {
+            if k < j {
+                self.r[(i, k)]
+            } else if k == j {
+                w[i]
+            } else {
+                self.r[(i, k - 1)]
+            }
+        });
+
+        let mut res = UpdatableQR {
+            q: self.q.clone(),
+            r,
+        };
+
+        // Cancel the inserted column below the diagonal, from the bottom up.
+        for k in (j + 1..nrows.value()).rev() {
+            res.cancel_entry(k - 1, k, j);
+        }
+
+        res
+    }
+
+    /// Updates this decomposition such that we get the decomposition of the decomposed matrix
+    /// with its `j`th column removed.
+    pub fn remove_column(&self, j: usize) -> UpdatableQR<N, R, DimDiff<C, U1>>
+    where
+        C: DimSub<U1>,
+        DefaultAllocator: Allocator<N, R, DimDiff<C, U1>>,
+    {
+        let (nrows, ncols) = self.r.data.shape();
+        assert!(ncols.value() > 0, "The matrix needs at least one column.");
+        assert!(
+            j < ncols.value(),
+            "j needs to be within the bound of the matrix."
+        );
+
+        let r = MatrixMN::from_fn_generic(nrows, ncols.sub(U1), |i, k| {
+            if k < j {
+                self.r[(i, k)]
+            } else {
+                self.r[(i, k + 1)]
+            }
+        });
+
+        let mut res = UpdatableQR {
+            q: self.q.clone(),
+            r,
+        };
+
+        // The columns after `j` now have one non-zero subdiagonal element.
+        let new_ncols = ncols.value() - 1;
+        for k in j..new_ncols.min(nrows.value().saturating_sub(1)) {
+            res.cancel_entry(k, k + 1, k);
+        }
+
+        res
+    }
+
+    /// Updates this decomposition such that we get the decomposition of the decomposed matrix
+    /// with the row `row` inserted at the `i`th position.
+    pub fn insert_row<S2>(
+        &self,
+        i: usize,
+        row: &RowVector<N, C, S2>,
+    ) -> UpdatableQR<N, DimSum<R, U1>, C>
+    where
+        R: DimAdd<U1>,
+        S2: Storage<N, U1, C>,
+        DefaultAllocator:
+            Allocator<N, DimSum<R, U1>, DimSum<R, U1>> + Allocator<N, DimSum<R, U1>, C>,
+    {
+        let (nrows, ncols) = self.r.data.shape();
+        let m = nrows.value();
+        assert!(i <= m, "i needs to be within the bound of the new matrix.");
+
+        // Append the new row to `R`, and extend `Q` such that it maps it back to the `i`th row.
+        let r = MatrixMN::from_fn_generic(nrows.add(U1), ncols, |k, l| {
+            if k < m {
+                self.r[(k, l)]
+            } else {
+                row[l]
+            }
+        });
+        let q = MatrixN::from_fn_generic(nrows.add(U1), nrows.add(U1), |k, l| {
+            if k == i {
+                if l == m {
+                    N::one()
+                } else {
+                    N::zero()
+                }
+            } else if l == m {
+                N::zero()
+            } else if k < i {
+                self.q[(k, l)]
+            } else {
+                self.q[(k - 1, l)]
+            }
+        });
+
+        let mut res = UpdatableQR { q, r };
+
+        // Cancel the appended row with the diagonal elements of `R`.
+        for k in 0..m.min(ncols.value()) {
+            res.cancel_entry(k, m, k);
+        }
+
+        res
+    }
+
+    /// Updates this decomposition such that we get the decomposition of the decomposed matrix
+    /// with its `i`th row removed.
+    pub fn remove_row(&self, i: usize) -> UpdatableQR<N, DimDiff<R, U1>, C>
+    where
+        R: DimSub<U1>,
+        DefaultAllocator:
+            Allocator<N, DimDiff<R, U1>, DimDiff<R, U1>> + Allocator<N, DimDiff<R, U1>, C>,
+    {
+        let (nrows, ncols) = self.r.data.shape();
+        let m = nrows.value();
+        assert!(m > 0, "The matrix needs at least one row.");
+        assert!(i < m, "i needs to be within the bound of the matrix.");
+
+        let mut res = self.clone();
+
+        // Reduce the `i`th row of `Q` to a multiple of the first canonical basis vector. The same
+        // rotations turn `R` into an upper Hessenberg matrix. Then, the first column of `Q` is a
+        // multiple of the `i`th canonical basis vector, and the first row of `R` only contributes
+        // to the `i`th row of the decomposed matrix.
+        for k in (1..m).rev() {
+            let v = Vector2::new(res.q[(i, k - 1)].conjugate(), res.q[(i, k)].conjugate());
+
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                res.rotate(&rot, k - 1, k, k - 1);
+            }
+        }
+
+        let q = MatrixN::from_fn_generic(nrows.sub(U1), nrows.sub(U1), |k, l| {
+            if k < i {
+                res.q[(k, l + 1)]
+            } else {
+                res.q[(k + 1, l + 1)]
+            }
+        });
+        let r = MatrixMN::from_fn_generic(nrows.sub(U1), ncols, |k, l| res.r[(k + 1, l)]);
+
+        UpdatableQR { q, r }
+    }
+
+    /// Applies the rotation `rot` to the rows `i < j` of `R`, starting at the column `start`, and
+    /// its adjoint to the columns `i` and `j` of `Q` so that `Q * R` is unchanged.
+    fn rotate(&mut self, rot: &GivensRotation<N>, i: usize, j: usize, start: usize) {
+        let step = j - i - 1;
+        let start = start.min(self.r.ncols());
+        rot.rotate(
+            &mut self
+                .r
+                .fixed_rows_with_step_mut::<U2>(i, step)
+                .columns_range_mut(start..),
+        );
+        rot.inverse()
+            .rotate_rows(&mut self.q.fixed_columns_with_step_mut::<U2>(i, step));
+    }
+
+    /// Cancels the element of `R` at `(j, col)` by combining the rows `i` and `j`, assuming that
+    /// both rows are zero on all the columns before `col`.
+    fn cancel_entry(&mut self, i: usize, j: usize, col: usize) {
+        let v = Vector2::new(self.r[(i, col)], self.r[(j, col)]);
+
+        if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+            self.r[(i, col)] = norm;
+            self.r[(j, col)] = N::zero();
+            self.rotate(&rot, i, j, col + 1);
+        }
+    }
+}
+
+/// # Updates
+///
+/// `Q` is represented implicitly by Householder reflections, which can't be updated with Givens
+/// rotations. These methods thus compute the explicit factors of this decomposition with
+/// `UpdatableQR::from_qr` and return the updated `UpdatableQR`. Building the explicit `Q` takes
+/// `O(nrows² * ncols)` operations, which is no cheaper than computing a new decomposition: to
+/// apply repeated updates, keep the returned `UpdatableQR` (or start from `matrix.updatable_qr()`)
+/// and update it directly in `O(nrows * (nrows + ncols))` operations each.
+impl<N: ComplexField, R: DimMin<C>, C: Dim> QR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R, R>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>, C>,
+{
+    /// Computes the decomposition of `matrix + u * v.adjoint()`, where `matrix` is the decomposed
+    /// matrix.
+    ///
+    /// This builds the explicit `Q` first, at the cost of a new decomposition. Use
+    /// `UpdatableQR::rank_one_update` for repeated updates.
+    pub fn rank_one_update<S1, S2>(
+        &self,
+        u: &Vector<N, R, S1>,
+        v: &Vector<N, C, S2>,
+    ) -> UpdatableQR<N, R, C>
+    where
+        S1: Storage<N, R>,
+        S2: Storage<N, C>,
+    {
+        let mut res = UpdatableQR::from_qr(self);
+        res.rank_one_update(u, v);
+        res
+    }
+
+    /// Computes the decomposition of the decomposed matrix with the column `col` inserted at the
+    /// `j`th position.
+    ///
+    /// This builds the explicit `Q` first, at the cost of a new decomposition. Use
+    /// `UpdatableQR::insert_column` for repeated updates.
+    pub fn insert_column<S2>(
+        &self,
+        j: usize,
+        col: &Vector<N, R, S2>,
+    ) -> UpdatableQR<N, R, DimSum<C, U1>>
+    where
+        C: DimAdd<U1>,
+        S2: Storage<N, R>,
+        DefaultAllocator: Allocator<N, R, DimSum<C, U1>>,
+    {
+        UpdatableQR::from_qr(self).insert_column(j, col)
+    }
+
+    /// Computes the decomposition of the decomposed matrix with its `j`th column removed.
+    ///
+    /// This builds the explicit `Q` first, at the cost of a new decomposition. Use
+    /// `UpdatableQR::remove_column` for repeated updates.
+    pub fn remove_column(&self, j: usize) -> UpdatableQR<N, R, DimDiff<C, U1>>
+    where
+        C: DimSub<U1>,
+        DefaultAllocator: Allocator<N, R, DimDiff<C, U1>>,
+    {
+        UpdatableQR::from_qr(self).remove_column(j)
+    }
+
+    /// Computes the decomposition of the decomposed matrix with the row `row` inserted at the
+    /// `i`th position.
+    ///
+    /// This builds the explicit `Q` first, at the cost of a new decomposition. Use
+    /// `UpdatableQR::insert_row` for repeated updates.
+    pub fn insert_row<S2>(
+        &self,
+        i: usize,
+        row: &RowVector<N, C, S2>,
+    ) -> UpdatableQR<N, DimSum<R, U1>, C>
+    where
+        R: DimAdd<U1>,
+        S2: Storage<N, U1, C>,
+        DefaultAllocator:
+            Allocator<N, DimSum<R, U1>, DimSum<R, U1>> + Allocator<N, DimSum<R, U1>, C>,
+    {
+        UpdatableQR::from_qr(self).insert_row(i, row)
+    }
+
+    /// Computes the decomposition of the decomposed matrix with its `i`th row removed.
+    ///
+    /// This builds the explicit `Q` first, at the cost of a new decomposition. Use
+    /// `UpdatableQR::remove_row` for repeated updates.
+    pub fn remove_row(&self, i: usize) -> UpdatableQR<N, DimDiff<R, U1>, C>
+    where
+        R: DimSub<U1>,
+        DefaultAllocator:
+            Allocator<N, DimDiff<R, U1>, DimDiff<R, U1>> + Allocator<N, DimDiff<R, U1>, C>,
+    {
+        UpdatableQR::from_qr(self).remove_row(i)
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{Isometry3, Rotation2, Rotation3, UnitQuaternion, Vector3, Vector6, U3};
+
+use crate::proptest::*;
+use proptest::collection::vec;
+use proptest::strategy::Strategy;
+use proptest::{prop_assert, prop_assert_eq, proptest};
+
+/// Rotations within an angle of about one radian of `center`, with their weights.
+fn clustered_rotations() -> impl Strategy<Value = (Vec<UnitQuaternion<f64>>, Vec<f64>)> {
+    (
+        unit_quaternion(),
+        vec((vector(-0.5..0.5f64, U3), 0.1..1.0f64), 1..10),
+    )
+        .prop_map(|(center, perturbations)| {
+            perturbations
+                .into_iter()
+                .map(|(omega, w)| (center * UnitQuaternion::from_scaled_axis(omega), w))
+                .unzip()
+        })
+}
+
+#[test]
+fn averaging_degenerate_inputs() {
+    let q = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+
+    assert_eq!(UnitQuaternion::<f64>::mean(&[], None), None);
+    assert_eq!(Rotation3::<f64>::geodesic_mean(&[], None), None);
+    assert_eq!(Isometry3::<f64>::mean(&[], None), None);
+    assert_eq!(UnitQuaternion::mean(&[q, q], Some(&[0.0, 0.0])), None);
+
+    assert_relative_eq!(
+        UnitQuaternion::mean(&[q], None).unwrap().angle_to(&q),
+        0.0,
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        UnitQuaternion::geodesic_mean(&[q], None)
+            .unwrap()
+            .angle_to(&q),
+        0.0,
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+#[should_panic]
+fn averaging_weights_length_mismatch() {
+    let _ = Rotation3::mean(&[Rotation3::identity()], Some(&[1.0, 2.0]));
+}
+
+proptest!(
+    #[test]
+    fn chordal_mean_2d(angles in vec((-3.0..3.0f64, 0.1..1.0f64), 1..10)) {
+        let rotations: Vec<_> = angles.iter().map(|a| Rotation2::new(a.0)).collect();
+        let weights: Vec<_> = angles.iter().map(|a| a.1).collect();
+        let (sin, cos) = angles
+            .iter()
+            .fold((0.0, 0.0), |(s, c), a| (s + a.0.sin() * a.1, c + a.0.cos() * a.1));
+
+        if let Some(mean) = Rotation2::mean(&rotations, Some(&weights)) {
+            prop_assert!(relative_eq!(mean, Rotation2::new(sin.atan2(cos)), epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn chordal_mean_quaternion_matches_matrix(
+        rotations in vec((unit_quaternion(), 0.0..1.0f64, proptest::bool::ANY), 1..10)
+    ) {
+        // The sign of the quaternions doesn't matter.
+        let quaternions: Vec<_> = rotations
+            .iter()
+            .map(|r| if r.2 { r.0 } else { UnitQuaternion::new_unchecked(-r.0.into_inner()) })
+            .collect();
+        let matrices: Vec<_> = rotations.iter().map(|r| r.0.to_rotation_matrix()).collect();
+        let weights: Vec<_> = rotations.iter().map(|r| r.1).collect();
+
+        let q = UnitQuaternion::mean(&quaternions, Some(&weights));
+        let m = Rotation3::mean(&matrices, Some(&weights));
+        prop_assert_eq!(q.is_some(), m.is_some());
+
+        if let (Some(q), Some(m)) = (q, m) {
+            // The chordal mean minimizes the sum of the squared Frobenius distances.
+            let cost = |r: &Rotation3<f64>| -> f64 {
+                matrices
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(m, w)| (m.matrix() - r.matrix()).norm_squared() * w)
+                    .sum()
+            };
+            prop_assert!(relative_eq!(cost(&q.to_rotation_matrix()), cost(&m), epsilon = 1.0e-7));
+
+            for r in &matrices {
+                prop_assert!(cost(&m) <= cost(r) + 1.0e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn geodesic_mean_is_stationary((rotations, weights) in clustered_rotations()) {
+        let mean = UnitQuaternion::geodesic_mean(&rotations, Some(&weights)).unwrap();
+        let gradient = rotations
+            .iter()
+            .zip(weights.iter())
+            .fold(Vector3::zeros(), |acc, (q, w)| acc + (mean.inverse() * q).log() * *w);
+        prop_assert!(relative_eq!(gradient, Vector3::zeros(), epsilon = 1.0e-9));
+
+        let matrices: Vec<_> = rotations.iter().map(|q| q.to_rotation_matrix()).collect();
+        let mean_matrix = Rotation3::geodesic_mean(&matrices, Some(&weights)).unwrap();
+        prop_assert!(relative_eq!(mean.to_rotation_matrix(), mean_matrix, epsilon = 1.0e-7));
+
+        // The geodesic mean minimizes the sum of the squared angles.
+        let cost = |r: &UnitQuaternion<f64>| -> f64 {
+            rotations.iter().zip(weights.iter()).map(|(q, w)| r.angle_to(q).powi(2) * w).sum()
+        };
+        let chordal = UnitQuaternion::mean(&rotations, Some(&weights)).unwrap();
+        prop_assert!(cost(&mean) <= cost(&chordal) + 1.0e-10);
+    }
+
+    #[test]
+    fn pose_means(
+        (rotations, weights) in clustered_rotations(),
+        translations in vec(vector3(), 10)
+    ) {
+        let poses: Vec<_> = rotations
+            .iter()
+            .zip(translations.iter())
+            .map(|(q, t)| Isometry3::from_parts((*t).into(), *q))
+            .collect();
+        let total: f64 = weights.iter().sum();
+
+        let chordal = Isometry3::mean(&poses, Some(&weights)).unwrap();
+        let translation = poses
+            .iter()
+            .zip(weights.iter())
+            .fold(Vector3::zeros(), |acc, (p, w)| acc + p.translation.vector * *w / total);
+        prop_assert!(relative_eq!(chordal.translation.vector, translation, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(
+            chordal.rotation,
+            UnitQuaternion::mean(&rotations, Some(&weights)).unwrap(),
+            epsilon = 1.0e-7
+        ));
+
+        let geodesic = Isometry3::geodesic_mean(&poses, Some(&weights)).unwrap();
+        let gradient = poses
+            .iter()
+            .zip(weights.iter())
+            .fold(Vector6::zeros(), |acc, (p, w)| acc + geodesic.inv_mul(p).log() * *w);
+        prop_assert!(relative_eq!(gradient, Vector6::zeros(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(
+            geodesic.rotation.angle_to(&UnitQuaternion::geodesic_mean(&rotations, Some(&weights)).unwrap()),
+            0.0,
+            epsilon = 1.0e-7
+        ));
+    }
+
+    #[test]
+    fn pose_geodesic_mean_far_from_origin(
+        (rotations, weights) in clustered_rotations(),
+        translations in vec(vector3(), 10),
+        offset in vector3()
+    ) {
+        // Far from the origin, the twists can't be computed to machine precision.
+        let offset = offset * 100.0;
+        let poses: Vec<_> = rotations
+            .iter()
+            .zip(translations.iter())
+            .map(|(q, t)| Isometry3::from_parts((t + offset).into(), *q))
+            .collect();
+
+        let geodesic = Isometry3::geodesic_mean(&poses, Some(&weights)).unwrap();
+        let gradient = poses
+            .iter()
+            .zip(weights.iter())
+            .fold(Vector6::zeros(), |acc, (p, w)| acc + geodesic.inv_mul(p).log() * *w);
+        prop_assert!(gradient.norm() <= 1.0e-7 * offset.norm().max(1.0));
+    }
+);
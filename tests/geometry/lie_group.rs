@@ -0,0 +1,155 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{
+    Isometry2, Isometry3, Matrix3, Matrix6, Rotation2, Rotation3, UnitComplex, UnitDualQuaternion,
+    UnitQuaternion, Vector3, Vector6, U3, U6,
+};
+
+use crate::proptest::*;
+use proptest::strategy::Strategy;
+use proptest::{prop_assert, proptest};
+
+/// Rotation vectors with a norm smaller than π.
+fn omega() -> impl Strategy<Value = Vector3<f64>> {
+    vector(-1.8..1.8f64, U3)
+}
+
+/// Rotation vectors with a norm around the threshold of the Taylor expansions.
+fn small_omega() -> impl Strategy<Value = Vector3<f64>> {
+    let scale = proptest::sample::select(vec![0.0, 1.0e-9, 1.0e-5, 1.0e-4, 1.0e-3]);
+    (vector(-1.0..1.0f64, U3), scale).prop_map(|(v, scale)| v * scale)
+}
+
+fn se3_twist() -> impl Strategy<Value = Vector6<f64>> {
+    (vector(-10.0..10.0f64, U3), omega())
+        .prop_map(|(rho, omega)| Vector6::new(rho.x, rho.y, rho.z, omega.x, omega.y, omega.z))
+}
+
+fn se2_twist() -> impl Strategy<Value = Vector3<f64>> {
+    (vector(-10.0..10.0f64, U3), -3.1..3.1f64)
+        .prop_map(|(rho, theta)| Vector3::new(rho.x, rho.y, theta))
+}
+
+fn delta3() -> impl Strategy<Value = Vector3<f64>> {
+    vector(-1.0e-6..1.0e-6f64, U3)
+}
+
+fn delta6() -> impl Strategy<Value = Vector6<f64>> {
+    vector(-1.0e-6..1.0e-6f64, U6)
+}
+
+proptest!(
+    #[test]
+    fn so2_exp_log(angle in -3.1..3.1f64) {
+        prop_assert!(relative_eq!(Rotation2::exp(angle).log(), angle, epsilon = 1.0e-10));
+        prop_assert!(relative_eq!(UnitComplex::exp(angle).log(), angle, epsilon = 1.0e-10));
+        prop_assert!(relative_eq!(Rotation2::exp(angle), UnitComplex::exp(angle).to_rotation_matrix(), epsilon = 1.0e-10));
+        prop_assert!(Rotation2::left_jacobian(angle) == 1.0 && UnitComplex::right_jacobian_inverse(angle) == 1.0);
+    }
+
+    #[test]
+    fn so3_exp_log(omega in omega()) {
+        let rot = Rotation3::exp(omega);
+        prop_assert!(relative_eq!(rot.log(), omega, epsilon = 1.0e-10));
+        prop_assert!(relative_eq!(UnitQuaternion::from_scaled_axis(omega).log(), omega, epsilon = 1.0e-10));
+        prop_assert!(relative_eq!(UnitQuaternion::from_rotation_matrix(&rot).adjoint(), rot.adjoint(), epsilon = 1.0e-10));
+    }
+
+    #[test]
+    fn so3_log_small_angles(r in rotation3(), small in small_omega()) {
+        prop_assert!(relative_eq!(Rotation3::exp(small).log(), small, epsilon = 1.0e-15));
+        prop_assert!(relative_eq!((r.inverse() * r).log(), Vector3::zeros(), epsilon = 1.0e-15));
+    }
+
+    #[test]
+    fn so3_jacobians(omega in omega(), small in small_omega(), delta in delta3()) {
+        for omega in [omega, small].iter().cloned() {
+            let jl = Rotation3::left_jacobian(omega);
+            let jr = Rotation3::right_jacobian(omega);
+            let expected = Rotation3::exp(omega + delta);
+
+            prop_assert!(relative_eq!(Rotation3::exp(jl * delta) * Rotation3::exp(omega), expected, epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(Rotation3::exp(omega) * Rotation3::exp(jr * delta), expected, epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(jl * Rotation3::left_jacobian_inverse(omega), Matrix3::identity(), epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(jr * Rotation3::right_jacobian_inverse(omega), Matrix3::identity(), epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(UnitQuaternion::left_jacobian(omega), jl, epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(UnitQuaternion::right_jacobian_inverse(omega), Rotation3::right_jacobian_inverse(omega), epsilon = 1.0e-10));
+        }
+    }
+
+    #[test]
+    fn so3_adjoint(r in rotation3(), omega in omega()) {
+        prop_assert!(relative_eq!(r * Rotation3::exp(omega) * r.inverse(), Rotation3::exp(r.adjoint() * omega), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn se2_exp_log(twist in se2_twist()) {
+        let iso = Isometry2::exp(twist);
+        prop_assert!(relative_eq!(iso.log(), twist, epsilon = 1.0e-9));
+        prop_assert!(relative_eq!(Isometry2::exp(iso.log()), iso, epsilon = 1.0e-9));
+    }
+
+    #[test]
+    fn se2_jacobians(twist in se2_twist(), small in -1.0e-3..1.0e-3f64, delta in delta3()) {
+        let mut small_twist = twist;
+        small_twist.z = small;
+
+        for twist in [twist, small_twist].iter().cloned() {
+            let jl = Isometry2::left_jacobian(twist);
+            let jr = Isometry2::right_jacobian(twist);
+            let expected = Isometry2::exp(twist + delta);
+
+            prop_assert!(relative_eq!(Isometry2::exp(jl * delta) * Isometry2::exp(twist), expected, epsilon = 1.0e-9));
+            prop_assert!(relative_eq!(Isometry2::exp(twist) * Isometry2::exp(jr * delta), expected, epsilon = 1.0e-9));
+            prop_assert!(relative_eq!(jl * Isometry2::left_jacobian_inverse(twist), Matrix3::identity(), epsilon = 1.0e-9));
+            prop_assert!(relative_eq!(jr * Isometry2::right_jacobian_inverse(twist), Matrix3::identity(), epsilon = 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn se2_adjoint(iso in isometry2(), twist in se2_twist()) {
+        prop_assert!(relative_eq!(
+            iso * Isometry2::exp(twist) * iso.inverse(),
+            Isometry2::exp(iso.adjoint() * twist),
+            epsilon = 1.0e-7
+        ));
+    }
+
+    #[test]
+    fn se3_exp_log(twist in se3_twist()) {
+        let iso = Isometry3::exp(twist);
+        prop_assert!(relative_eq!(iso.log(), twist, epsilon = 1.0e-9));
+        prop_assert!(relative_eq!(Isometry3::exp(iso.log()), iso, epsilon = 1.0e-9));
+
+        let dq = UnitDualQuaternion::exp(twist);
+        prop_assert!(relative_eq!(dq.to_isometry(), iso, epsilon = 1.0e-9));
+        prop_assert!(relative_eq!(dq.log(), twist, epsilon = 1.0e-9));
+        prop_assert!(relative_eq!(dq.adjoint(), iso.adjoint(), epsilon = 1.0e-9));
+    }
+
+    #[test]
+    fn se3_jacobians(twist in se3_twist(), small in small_omega(), delta in delta6()) {
+        let mut small_twist = twist;
+        small_twist.fixed_rows_mut::<U3>(3).copy_from(&small);
+
+        for twist in [twist, small_twist].iter().cloned() {
+            let jl = Isometry3::left_jacobian(twist);
+            let jr = Isometry3::right_jacobian(twist);
+            let expected = Isometry3::exp(twist + delta);
+
+            prop_assert!(relative_eq!(Isometry3::exp(jl * delta) * Isometry3::exp(twist), expected, epsilon = 1.0e-9));
+            prop_assert!(relative_eq!(Isometry3::exp(twist) * Isometry3::exp(jr * delta), expected, epsilon = 1.0e-9));
+            prop_assert!(relative_eq!(jl * Isometry3::left_jacobian_inverse(twist), Matrix6::identity(), epsilon = 1.0e-9));
+            prop_assert!(relative_eq!(jr * Isometry3::right_jacobian_inverse(twist), Matrix6::identity(), epsilon = 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn se3_adjoint(iso in isometry3(), twist in se3_twist()) {
+        prop_assert!(relative_eq!(
+            iso * Isometry3::exp(twist) * iso.inverse(),
+            Isometry3::exp(iso.adjoint() * twist),
+            epsilon = 1.0e-7
+        ));
+    }
+);
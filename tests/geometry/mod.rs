@@ -1,8 +1,12 @@
+mod averaging;
 mod dual_quaternion;
 mod isometry;
+mod lie_group;
+mod point_registration;
 mod point;
 mod projection;
 mod quaternion;
 mod rotation;
+mod scale;
 mod similarity;
 mod unit_complex;
@@ -0,0 +1,162 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{
+    Isometry, Isometry2, Isometry3, IsometryMatrix3, Matrix4, Point, Point2, Point3, Rotation,
+    Similarity3, SimilarityMatrix3, Translation, Vector2, Vector4, U4,
+};
+
+use crate::proptest::*;
+use proptest::collection::vec;
+use proptest::{prop_assert, prop_assert_eq, proptest};
+
+type IsometryMatrix4 = Isometry<f64, U4, Rotation<f64, U4>>;
+
+#[test]
+fn registration_degenerate_inputs() {
+    let from = [Point2::new(1.0, 2.0), Point2::new(3.0, 4.0)];
+    let to = [Point2::new(0.0, 1.0), Point2::new(2.0, 1.0)];
+
+    assert_eq!(
+        Isometry2::<f64>::from_point_correspondences(&[], &[], None),
+        None
+    );
+    assert_eq!(
+        Isometry2::from_point_correspondences(&from, &to, Some(&[0.0, 0.0])),
+        None
+    );
+
+    // With a single point, the rotation is arbitrary but the point is matched exactly.
+    let (iso, residuals) =
+        Isometry2::from_point_correspondences(&from, &to, Some(&[0.0, 1.0])).unwrap();
+    assert_relative_eq!(iso * from[1], to[1], epsilon = 1.0e-10);
+    assert_relative_eq!(residuals.rmsd, 0.0, epsilon = 1.0e-10);
+    assert!(residuals.max > 0.0);
+
+    // All the target points coincide: the scaling factor would be zero.
+    let to = [Point3::new(1.0, 1.0, 1.0); 3];
+    let from = [
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    assert_eq!(Similarity3::umeyama(&from, &to, None), None);
+    assert_eq!(Similarity3::umeyama(&to, &from, None), None);
+}
+
+#[test]
+#[should_panic]
+fn registration_length_mismatch() {
+    let from = [Point2::new(1.0, 2.0), Point2::new(3.0, 4.0)];
+    let _ = Isometry2::from_point_correspondences(&from, &from[..1], None);
+}
+
+#[test]
+fn registration_reflection() {
+    // The target is the mirror image of the source. The best rotation must not be a reflection.
+    let from = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 3.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let to: Vec<_> = from.iter().map(|p| Point3::new(-p.x, p.y, p.z)).collect();
+
+    let (iso, residuals) = IsometryMatrix3::from_point_correspondences(&from, &to, None).unwrap();
+    assert_relative_eq!(iso.rotation.matrix().determinant(), 1.0, epsilon = 1.0e-10);
+    assert!(residuals.rmsd > 0.1);
+
+    let (iso, _) = Isometry3::from_point_correspondences(&from, &to, None).unwrap();
+    assert_relative_eq!(iso.rotation.norm(), 1.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn registration_4d() {
+    let (s1, c1) = 0.3f64.sin_cos();
+    let (s2, c2) = 1.2f64.sin_cos();
+    #[rustfmt::skip]
+    let m = Matrix4::new(
+        c1, -s1, 0.0, 0.0,
+        s1, c1, 0.0, 0.0,
+        0.0, 0.0, c2, -s2,
+        0.0, 0.0, s2, c2,
+    );
+    let iso = IsometryMatrix4::from_parts(
+        Translation::from(Vector4::new(1.0, -2.0, 3.0, -4.0)),
+        Rotation::from_matrix_unchecked(m),
+    );
+    let from: Vec<Point<f64, U4>> = (0..6)
+        .map(|i| {
+            let i = i as f64;
+            Point::from(Vector4::new(i, i * i, (i * 0.7).sin(), (i * 1.3).cos()))
+        })
+        .collect();
+    let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+
+    let (fit, residuals) = IsometryMatrix4::from_point_correspondences(&from, &to, None).unwrap();
+    assert_relative_eq!(fit, iso, epsilon = 1.0e-10);
+    assert_relative_eq!(residuals.max, 0.0, epsilon = 1.0e-10);
+}
+
+proptest!(
+    #[test]
+    fn registration_recovers_isometry(iso in isometry3(), from in vec(point3(), 4..10)) {
+        let to: Vec<_> = from.iter().map(|p| iso * p).collect();
+
+        let (fit, residuals) = Isometry3::from_point_correspondences(&from, &to, None).unwrap();
+        prop_assert!(relative_eq!(fit, iso, epsilon = 1.0e-7));
+        prop_assert!(residuals.max < 1.0e-7);
+
+        let (fit, _) = IsometryMatrix3::from_point_correspondences(&from, &to, None).unwrap();
+        prop_assert!(relative_eq!(fit.to_homogeneous(), iso.to_homogeneous(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn registration_recovers_similarity(
+        iso in isometry3(),
+        scaling in 0.1..10.0f64,
+        from in vec(point3(), 4..10)
+    ) {
+        let sim = Similarity3::from_isometry(iso, scaling);
+        let to: Vec<_> = from.iter().map(|p| sim * p).collect();
+
+        let (fit, residuals) = Similarity3::umeyama(&from, &to, None).unwrap();
+        prop_assert!(relative_eq!(fit.scaling(), scaling, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(fit, sim, epsilon = 1.0e-6));
+        prop_assert!(residuals.max < 1.0e-6);
+
+        let (fit, _) = SimilarityMatrix3::umeyama(&from, &to, None).unwrap();
+        prop_assert!(relative_eq!(fit.to_homogeneous(), sim.to_homogeneous(), epsilon = 1.0e-6));
+    }
+
+    #[test]
+    fn registration_is_optimal(
+        iso in isometry2(),
+        perturbation in isometry2(),
+        points in vec((point2(), -1.0..1.0f64, -1.0..1.0f64, 0.0..1.0f64), 3..10)
+    ) {
+        let from: Vec<_> = points.iter().map(|p| p.0).collect();
+        let to: Vec<_> = points.iter().map(|p| iso * p.0 + Vector2::new(p.1, p.2)).collect();
+        let weights: Vec<_> = points.iter().map(|p| p.3).collect();
+
+        if let Some((fit, residuals)) =
+            Isometry2::from_point_correspondences(&from, &to, Some(&weights))
+        {
+            let total: f64 = weights.iter().sum();
+            let rmsd = |t: Isometry2<f64>| {
+                let sum: f64 = from
+                    .iter()
+                    .zip(to.iter())
+                    .zip(weights.iter())
+                    .map(|((p, q), w)| (t * p - q).norm_squared() * w)
+                    .sum();
+                (sum / total).sqrt()
+            };
+
+            prop_assert!(relative_eq!(residuals.rmsd, rmsd(fit), epsilon = 1.0e-7));
+            prop_assert!(residuals.rmsd <= rmsd(iso) + 1.0e-7);
+            prop_assert!(residuals.rmsd <= rmsd(perturbation * fit) + 1.0e-7);
+        } else {
+            prop_assert_eq!(weights.iter().sum::<f64>(), 0.0);
+        }
+    }
+);
@@ -1,10 +1,39 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{Unit, UnitQuaternion};
+use na::{EulerAxes, EulerSequence, Rotation3, Unit, UnitQuaternion, Vector3};
+use std::f64::consts::{FRAC_PI_2, PI};
 
 use crate::proptest::*;
-use proptest::{prop_assert, proptest};
+use proptest::strategy::Strategy;
+use proptest::{prop_assert, prop_assert_eq, proptest};
+
+fn euler_sequence() -> impl Strategy<Value = EulerSequence> {
+    let axes = vec![
+        EulerAxes::XYZ,
+        EulerAxes::XZY,
+        EulerAxes::YXZ,
+        EulerAxes::YZX,
+        EulerAxes::ZXY,
+        EulerAxes::ZYX,
+        EulerAxes::XYX,
+        EulerAxes::XZX,
+        EulerAxes::YXY,
+        EulerAxes::YZY,
+        EulerAxes::ZXZ,
+        EulerAxes::ZYZ,
+    ];
+    let sequences: Vec<_> = axes
+        .into_iter()
+        .flat_map(|axes| {
+            vec![
+                EulerSequence::intrinsic(axes),
+                EulerSequence::extrinsic(axes),
+            ]
+        })
+        .collect();
+    proptest::sample::select(sequences)
+}
 
 proptest!(
     /*
@@ -41,6 +70,79 @@ proptest!(
         ))
     }
 
+    #[test]
+    fn from_euler_sequence(seq in euler_sequence(), a in PROPTEST_F64, b in PROPTEST_F64, c in PROPTEST_F64) {
+        let [i, j, k] = seq.axes().indices();
+        let elementary = |axis: usize, angle: f64| UnitQuaternion::new(Vector3::ith(axis, angle));
+        let expected = if seq.is_intrinsic() {
+            elementary(i, a) * elementary(j, b) * elementary(k, c)
+        } else {
+            elementary(k, c) * elementary(j, b) * elementary(i, a)
+        };
+
+        let q = UnitQuaternion::from_euler_sequence(seq, a, b, c);
+        prop_assert!(relative_eq!(q, expected, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(UnitQuaternion::from_euler_sequence(seq.reversed(), c, b, a), q, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(
+            Rotation3::from_euler_sequence(seq, a, b, c),
+            q.to_rotation_matrix(),
+            epsilon = 1.0e-7
+        ));
+    }
+
+    #[test]
+    fn euler_sequence_roll_pitch_yaw(r in PROPTEST_F64, p in PROPTEST_F64, y in PROPTEST_F64) {
+        prop_assert!(relative_eq!(
+            UnitQuaternion::from_euler_sequence(EulerSequence::ROLL_PITCH_YAW, r, p, y),
+            UnitQuaternion::from_euler_angles(r, p, y),
+            epsilon = 1.0e-7
+        ))
+    }
+
+    #[test]
+    fn euler_sequence_angles_round_trip(seq in euler_sequence(), q in unit_quaternion()) {
+        let (a, b, c) = q.euler_sequence_angles(seq);
+
+        prop_assert!(a.abs() <= PI && c.abs() <= PI);
+        if seq.is_proper() {
+            prop_assert!(b >= 0.0 && b <= PI);
+        } else {
+            prop_assert!(b.abs() <= FRAC_PI_2);
+        }
+
+        prop_assert!(relative_eq!(UnitQuaternion::from_euler_sequence(seq, a, b, c), q, epsilon = 1.0e-7));
+
+        let (ra, rb, rc) = q.to_rotation_matrix().euler_sequence_angles(seq);
+        prop_assert!(relative_eq!(Rotation3::from_euler_sequence(seq, ra, rb, rc), q.to_rotation_matrix(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn euler_sequence_angles_recovered(seq in euler_sequence(), a in -3.1..3.1f64, b in 0.01..3.13f64, c in -3.1..3.1f64) {
+        let b = if seq.is_proper() { b } else { b - FRAC_PI_2 };
+        let q = UnitQuaternion::from_euler_sequence(seq, a, b, c);
+        let (ea, eb, ec) = q.euler_sequence_angles(seq);
+
+        prop_assert!(relative_eq!(ea, a, epsilon = 1.0e-6));
+        prop_assert!(relative_eq!(eb, b, epsilon = 1.0e-6));
+        prop_assert!(relative_eq!(ec, c, epsilon = 1.0e-6));
+    }
+
+    #[test]
+    fn euler_sequence_angles_gimbal_lock(seq in euler_sequence(), a in PROPTEST_F64, c in PROPTEST_F64, upper in proptest::bool::ANY) {
+        let b = match (seq.is_proper(), upper) {
+            (true, false) => 0.0,
+            (true, true) => PI,
+            (false, false) => -FRAC_PI_2,
+            (false, true) => FRAC_PI_2,
+        };
+        let q = UnitQuaternion::from_euler_sequence(seq, a, b, c);
+        let (ea, eb, ec) = q.euler_sequence_angles(seq);
+
+        prop_assert_eq!(ec, 0.0);
+        prop_assert!(relative_eq!(eb, b, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(UnitQuaternion::from_euler_sequence(seq, ea, eb, ec), q, epsilon = 1.0e-7));
+    }
+
     /*
      *
      * From/to rotation matrix.
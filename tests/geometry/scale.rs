@@ -0,0 +1,150 @@
+#![cfg(feature = "proptest-support")]
+#![allow(non_snake_case)]
+
+use na::{Point3, Scale2, Scale3, Vector3};
+
+use crate::proptest::*;
+use proptest::{prop_assert, prop_assert_eq, proptest};
+
+#[test]
+fn scale_inverse_and_homogeneous() {
+    let s = Scale2::new(2.0, -4.0);
+    assert_eq!(s.try_inverse(), Some(Scale2::new(0.5, -0.25)));
+    assert_eq!(Scale2::new(0.0, 1.0).try_inverse(), None);
+    assert_eq!(
+        Scale2::new(0.0, 4.0).pseudo_inverse(),
+        Scale2::new(0.0, 0.25)
+    );
+
+    let h = s.to_homogeneous();
+    assert_eq!(h.diagonal(), Vector3::new(2.0, -4.0, 1.0));
+    assert_eq!(h.trace(), -1.0);
+
+    let converted: Option<Scale2<f64>> = na::try_convert(h);
+    assert_eq!(converted, Some(s));
+    let mut not_a_scale = h;
+    not_a_scale[(0, 1)] = 1.0;
+    assert_eq!(na::try_convert::<_, Scale2<f64>>(not_a_scale), None);
+}
+
+proptest!(
+    #[test]
+    fn scale_inverse_is_identity(s in scale3(), p in point3()) {
+        if let Some(inv) = s.try_inverse() {
+            prop_assert!(relative_eq!(s * inv, Scale3::identity(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(inv * s, Scale3::identity(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(
+                s.try_inverse_transform_point(&(s * p)).unwrap(),
+                p,
+                epsilon = 1.0e-7
+            ));
+        }
+    }
+
+    #[test]
+    fn scale_mul_is_homogeneous_mul(
+        s in scale3(),
+        t in translation3(),
+        r in rotation3(),
+        uq in unit_quaternion(),
+        i in isometry3(),
+        p in point3(),
+        v in vector3()
+    ) {
+        let hs = s.to_homogeneous();
+
+        prop_assert_eq!(s.transform_point(&p), s * p);
+        prop_assert_eq!(s * p, Point3::from_homogeneous(hs * p.to_homogeneous()).unwrap());
+        prop_assert_eq!((s * v).push(0.0), hs * v.push(0.0));
+        prop_assert_eq!((s * s).to_homogeneous(), hs * hs);
+
+        prop_assert!(relative_eq!((s * t).into_inner(), hs * t.to_homogeneous(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((t * s).into_inner(), t.to_homogeneous() * hs, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((s * r).into_inner(), hs * r.to_homogeneous(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((r * s).into_inner(), r.to_homogeneous() * hs, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((s * uq).into_inner(), hs * uq.to_homogeneous(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((uq * s).into_inner(), uq.to_homogeneous() * hs, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((s * i).into_inner(), hs * i.to_homogeneous(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((i * s).into_inner(), i.to_homogeneous() * hs, epsilon = 1.0e-7));
+
+        // The composition applies the scale last.
+        prop_assert!(relative_eq!((s * i) * p, s * (i * p), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((i * s) * p, i * (s * p), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn all_op_exist(
+        s in scale3(),
+        t in translation3(),
+        r in rotation3(),
+        uq in unit_quaternion(),
+        i in isometry3(),
+        p in point3(),
+        v in vector3()
+    ) {
+        let sMs = s * s;
+        let sMp = s * p;
+        let sMv = s * v;
+
+        let sMt = s * t;
+        let tMs = t * s;
+        let sMr = s * r;
+        let rMs = r * s;
+        let sMuq = s * uq;
+        let uqMs = uq * s;
+        let sMi = s * i;
+        let iMs = i * s;
+
+        let mut sMs1 = s;
+        let mut sMs2 = s;
+        sMs1 *= s;
+        sMs2 *= &s;
+
+        prop_assert_eq!(sMs1, sMs);
+        prop_assert_eq!(sMs2, sMs);
+
+        prop_assert_eq!(&s * &s, sMs);
+        prop_assert_eq!(s * &s, sMs);
+        prop_assert_eq!(&s * s, sMs);
+
+        prop_assert_eq!(&s * &p, sMp);
+        prop_assert_eq!(s * &p, sMp);
+        prop_assert_eq!(&s * p, sMp);
+
+        prop_assert_eq!(&s * &v, sMv);
+        prop_assert_eq!(s * &v, sMv);
+        prop_assert_eq!(&s * v, sMv);
+
+        prop_assert_eq!(&s * &t, sMt);
+        prop_assert_eq!(s * &t, sMt);
+        prop_assert_eq!(&s * t, sMt);
+
+        prop_assert_eq!(&t * &s, tMs);
+        prop_assert_eq!(t * &s, tMs);
+        prop_assert_eq!(&t * s, tMs);
+
+        prop_assert_eq!(&s * &r, sMr);
+        prop_assert_eq!(s * &r, sMr);
+        prop_assert_eq!(&s * r, sMr);
+
+        prop_assert_eq!(&r * &s, rMs);
+        prop_assert_eq!(r * &s, rMs);
+        prop_assert_eq!(&r * s, rMs);
+
+        prop_assert_eq!(&s * &uq, sMuq);
+        prop_assert_eq!(s * &uq, sMuq);
+        prop_assert_eq!(&s * uq, sMuq);
+
+        prop_assert_eq!(&uq * &s, uqMs);
+        prop_assert_eq!(uq * &s, uqMs);
+        prop_assert_eq!(&uq * s, uqMs);
+
+        prop_assert_eq!(&s * &i, sMi);
+        prop_assert_eq!(s * &i, sMi);
+        prop_assert_eq!(&s * i, sMi);
+
+        prop_assert_eq!(&i * &s, iMs);
+        prop_assert_eq!(i * &s, iMs);
+        prop_assert_eq!(&i * s, iMs);
+    }
+);
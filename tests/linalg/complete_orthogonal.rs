@@ -0,0 +1,105 @@
+#[cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{Matrix3x4, Vector3, Vector4, U2};
+
+#[test]
+fn complete_orthogonal_rank_deficient() {
+    // The third row is the sum of the first two.
+    let m = Matrix3x4::new(1.0, 2.0, 0.0, -1.0, 0.0, 1.0, 3.0, 2.0, 1.0, 3.0, 3.0, 1.0);
+    let cod = m.complete_orthogonal_decomposition(1.0e-10);
+    assert_eq!(cod.rank(), 2);
+
+    let mut qtz = cod.q() * cod.t() * cod.z();
+    cod.p().inv_permute_columns(&mut qtz);
+    assert!(relative_eq!(m, qtz, epsilon = 1.0e-7));
+
+    // The minimum-norm solution satisfies the normal equations and lies in the row space of `m`.
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let x: Vector4<f64> = cod.solve_least_squares(&b);
+    let rows = m.fixed_rows::<U2>(0);
+    let projected = rows.transpose() * (rows * rows.transpose()).try_inverse().unwrap() * rows * x;
+    assert!(relative_eq!(
+        m.transpose() * (m * x - b),
+        Vector4::zeros(),
+        epsilon = 1.0e-7
+    ));
+    assert!(relative_eq!(x, projected, epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr ,$scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, Vector3};
+                use std::cmp;
+
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandComplex, RandScalar};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+
+                proptest! {
+                    #[test]
+                    fn complete_orthogonal(m in dmatrix_($scalar)) {
+                        let cod = m.clone().complete_orthogonal_decomposition(1.0e-12);
+                        let z = cod.z();
+                        let mut qtz = cod.q() * cod.t() * &z;
+                        cod.p().inv_permute_columns(&mut qtz);
+
+                        prop_assert!(relative_eq!(m, qtz, epsilon = 1.0e-7));
+                        prop_assert!((&z * z.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn complete_orthogonal_solve_rank_deficient(
+                        nrows in PROPTEST_MATRIX_DIM,
+                        ncols in PROPTEST_MATRIX_DIM,
+                        rank in PROPTEST_MATRIX_DIM,
+                        nb in PROPTEST_MATRIX_DIM
+                    ) {
+                        let nrows = cmp::max(1, cmp::min(nrows, 20)); // To avoid slowing down the test too much.
+                        let ncols = cmp::max(1, cmp::min(ncols, 20)); // To avoid slowing down the test too much.
+                        let rank = cmp::max(1, cmp::min(rank, cmp::min(nrows, ncols)));
+                        let nb = cmp::max(1, cmp::min(nb, 10));
+
+                        let a = DMatrix::<$scalar_type>::new_random(nrows, rank).map(|e| e.0);
+                        let c = DMatrix::<$scalar_type>::new_random(rank, ncols).map(|e| e.0);
+                        let m = &a * &c;
+                        let b = DMatrix::<$scalar_type>::new_random(nrows, nb).map(|e| e.0);
+
+                        let cod = m.clone().complete_orthogonal_decomposition(1.0e-10);
+                        let x = cod.solve_least_squares(&b);
+
+                        // The minimum-norm solution satisfies the normal equations and lies in
+                        // the row space of `m`, which is also the row space of `c`.
+                        let normal_residual = m.adjoint() * (&m * &x - &b);
+                        let q = c.adjoint().qr().q();
+                        let projected = &q * q.ad_mul(&x);
+
+                        prop_assert_eq!(cod.rank(), rank);
+                        prop_assert!(normal_residual.norm() <= 1.0e-7 * m.norm() * (m.norm() * x.norm() + b.norm()));
+                        prop_assert!((&x - projected).norm() <= 1.0e-7 * x.norm());
+                    }
+
+                    #[test]
+                    fn complete_orthogonal_solve_underdetermined_static(m in matrix3x5_($scalar)) {
+                        let b = Vector3::<$scalar_type>::new_random().map(|e| e.0);
+                        let cod = m.complete_orthogonal_decomposition(1.0e-12);
+
+                        if cod.rank() == 3 {
+                            let x = cod.solve_least_squares(&b);
+                            let expected = m.adjoint() * (m * m.adjoint()).try_inverse().unwrap() * b;
+
+                            prop_assert!(relative_eq!(m * x, b, epsilon = 1.0e-6));
+                            prop_assert!(relative_eq!(x, expected, epsilon = 1.0e-6));
+                        }
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
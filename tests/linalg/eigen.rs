@@ -1,4 +1,4 @@
-use na::DMatrix;
+use na::{DMatrix, Matrix2, Matrix3, Matrix4};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -116,88 +116,135 @@ fn symmetric_eigen_singular_24x24() {
     );
 }
 
-//  #[cfg(feature = "arbitrary")]
-//  quickcheck! {
-// TODO: full eigendecomposition is not implemented yet because of its complexity when some
-// eigenvalues have multiplicity > 1.
-//
-//    /*
-//     * NOTE: for the following tests, we use only upper-triangular matrices.
-//     * Thes ensures the schur decomposition will work, and allows use to test the eigenvector
-//     * computation.
-//     */
-//    fn eigen(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_adjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some adjascent diagonal elements.
-//        for i in 0 .. n / 2 {
-//            m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_nonadjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(3, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some diagonal elements.
-//        for i in n / 2 .. n {
-//            m[(i, i)] = m[(i - n / 2, i - n / 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_4x4(m: Matrix4<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_3x3(m: Matrix3<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_2x2(m: Matrix2<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        println!("{}", m);
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//  }
-//
-// fn verify_eigenvectors<D: Dim>(m: MatrixN<f64, D>, mut eig: RealEigen<f64, D>) -> bool
-//     where DefaultAllocator: Allocator<f64, D, D>   +
-//                             Allocator<f64, D>      +
-//                             Allocator<usize, D, D> +
-//                             Allocator<usize, D>,
-//           MatrixN<f64, D>: Display,
-//           VectorN<f64, D>: Display {
-//     let mv = &m * &eig.eigenvectors;
-//
-//     println!("eigenvalues: {}eigenvectors: {}", eig.eigenvalues, eig.eigenvectors);
-//
-//     let dim = m.nrows();
-//     for i in 0 .. dim {
-//         let mut col = eig.eigenvectors.column_mut(i);
-//         col *= eig.eigenvalues[i];
-//     }
-//
-//     println!("{}{:.5}{:.5}", m, mv, eig.eigenvectors);
-//
-//     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
-// }
+#[test]
+fn symmetric_eigen_close_diagonal_entries() {
+    // The eigenvectors are `(1, ±1) / sqrt(2)`, even though the eigenvalues are very close to the
+    // diagonal entries.
+    let m = Matrix2::new(1.0f64, 1.0e-9, 1.0e-9, 1.0);
+    let eig = m.symmetric_eigen();
+
+    for v in eig.eigenvectors.column_iter() {
+        assert_relative_eq!(v[0].abs(), v[1].abs(), epsilon = 1.0e-15);
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_general_eigen_tests {
+    use na::DMatrix;
+    use std::cmp;
+
+    use crate::core::helper::{RandComplex, RandScalar};
+    use crate::proptest::*;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn complex_eigen(n in PROPTEST_MATRIX_DIM) {
+            let n = cmp::max(1, cmp::min(n, 10));
+            let m = DMatrix::<RandScalar<f64>>::new_random(n, n).map(|e| e.0);
+            let eig = m.clone().complex_eigen();
+            let mc = m.map(|e| na::Complex::new(e, 0.0));
+            let lambda = DMatrix::from_diagonal(&eig.eigenvalues);
+
+            prop_assert!(relative_eq!(&mc * &eig.eigenvectors, &eig.eigenvectors * &lambda, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(eig.left_eigenvectors.adjoint() * &mc, &lambda * eig.left_eigenvectors.adjoint(), epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn eigen_complex_matrix(n in PROPTEST_MATRIX_DIM) {
+            let n = cmp::max(1, cmp::min(n, 10));
+            let m = DMatrix::<RandComplex<f64>>::new_random(n, n).map(|e| e.0);
+            let eig = m.clone().eigen().unwrap();
+            let lambda = DMatrix::from_diagonal(&eig.eigenvalues);
+
+            prop_assert!(relative_eq!(&m * &eig.eigenvectors, &eig.eigenvectors * &lambda, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(eig.left_eigenvectors.adjoint() * &m, &lambda * eig.left_eigenvectors.adjoint(), epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn eigen_static_square_4x4(m in matrix4()) {
+            let m = m.upper_triangle();
+            let eig = m.eigen().unwrap();
+
+            for i in 0..4 {
+                let v = eig.eigenvectors.column(i);
+                prop_assert!(relative_eq!(m * v, v * eig.eigenvalues[i], epsilon = 1.0e-7 * m.norm()));
+            }
+        }
+
+        #[test]
+        fn eigen_static_square_3x3(m in matrix3()) {
+            let eig = m.complex_eigen();
+
+            for i in 0..3 {
+                let v = eig.eigenvectors.column(i);
+                let mv = m.map(|e| na::Complex::new(e, 0.0)) * v;
+                prop_assert!(relative_eq!(mv, v * eig.eigenvalues[i], epsilon = 1.0e-7 * m.norm()));
+            }
+        }
+    }
+}
+
+#[test]
+fn eigen_real_matrix_with_complex_eigenvalues() {
+    // A rotation by 90 degrees has the eigenvalues ±i.
+    let m = Matrix2::new(0.0, -1.0, 1.0, 0.0);
+    assert!(m.eigen().is_none());
+
+    let eig = m.complex_eigen();
+    let mut ims: Vec<f64> = eig.eigenvalues.iter().map(|e| e.im).collect();
+    ims.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert_relative_eq!(ims[0], -1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(ims[1], 1.0, epsilon = 1.0e-7);
+    assert!(eig.eigenvalues.iter().all(|e| e.re.abs() < 1.0e-7));
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_with_repeated_eigenvalues() {
+    let m = Matrix4::new(
+        2.0, 1.0, 0.0, 3.0,
+        0.0, 5.0, 4.0, 1.0,
+        0.0, 0.0, 2.0, 7.0,
+        0.0, 0.0, 0.0, 5.0);
+
+    let eig = m.eigen().unwrap();
+    for i in 0..4 {
+        let v = eig.eigenvectors.column(i);
+        let u = eig.left_eigenvectors.column(i);
+        assert_relative_eq!(v.norm(), 1.0, epsilon = 1.0e-7);
+        assert_relative_eq!(m * v, v * eig.eigenvalues[i], epsilon = 1.0e-7);
+        assert_relative_eq!(u.transpose() * m, u.transpose() * eig.eigenvalues[i], epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_defective_matrix() {
+    // Jordan block: only one eigenvector exists for the eigenvalue 3.
+    let m = Matrix3::new(
+        3.0, 1.0, 0.0,
+        0.0, 3.0, 1.0,
+        0.0, 0.0, 3.0);
+
+    let eig = m.eigen().unwrap();
+    for i in 0..3 {
+        let v = eig.eigenvectors.column(i);
+        assert_relative_eq!(eig.eigenvalues[i], 3.0);
+        assert!(v.iter().all(|e: &f64| e.is_finite()));
+        assert_relative_eq!(m * v, v * 3.0, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn eigen_diagonal_dynamic() {
+    let m = DMatrix::from_diagonal(&na::DVector::from_vec(vec![1.0, -2.0, 1.0, 0.0]));
+    let eig = m.clone().eigen().unwrap();
+
+    assert_relative_eq!(
+        &m * &eig.eigenvectors,
+        &eig.eigenvectors * DMatrix::from_diagonal(&eig.eigenvalues),
+        epsilon = 1.0e-7
+    );
+}
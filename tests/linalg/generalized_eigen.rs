@@ -0,0 +1,205 @@
+use na::{DMatrix, Matrix2, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn generalized_eigen_real_matrix() {
+    let a = Matrix3::new(2.0, 1.0, 0.0,
+                         1.0, 3.0, 1.0,
+                         0.0, 1.0, 4.0);
+    let b = Matrix3::new(1.0, 0.0, 1.0,
+                         0.0, 2.0, 0.0,
+                         0.0, 0.0, 1.0);
+
+    let eig = a.generalized_eigen(b).unwrap();
+
+    for i in 0..3 {
+        let v = eig.eigenvectors.column(i);
+        let u = eig.left_eigenvectors.column(i);
+        let (alpha, beta) = (eig.alphas[i], eig.betas[i]);
+
+        assert!(relative_eq!(a * v * beta, b * v * alpha, epsilon = 1.0e-7));
+        assert!(relative_eq!(u.transpose() * a * beta, u.transpose() * b * alpha, epsilon = 1.0e-7));
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_eigen_infinite_eigenvalue() {
+    let a = Matrix2::new(1.0f64, 2.0,
+                         3.0,    4.0);
+    let b = Matrix2::new(1.0f64, 0.0,
+                         0.0,    0.0);
+
+    let eig = a.generalized_eigen(b).unwrap();
+    let ninfinite = eig.betas.iter().filter(|b| b.abs() < 1.0e-7).count();
+    assert_eq!(ninfinite, 1);
+
+    for i in 0..2 {
+        let v = eig.eigenvectors.column(i);
+        assert!(relative_eq!(a * v * eig.betas[i], b * v * eig.alphas[i], epsilon = 1.0e-7));
+    }
+
+    // The finite eigenvalue is det(a) / a[(1, 1)].
+    let finite = eig.eigenvalues().iter().cloned().find(|e| e.is_finite()).unwrap();
+    assert_relative_eq!(finite, -0.5, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_eigen_real_matrix_with_complex_eigenvalues() {
+    let a = Matrix2::new(0.0, -2.0,
+                         1.0,  0.0);
+    let b = Matrix2::new(1.0,  0.0,
+                         0.0,  2.0);
+
+    assert!(a.generalized_eigen(b).is_none());
+
+    let eig = a.complex_generalized_eigen(b);
+    let ac = a.map(|e| na::Complex::new(e, 0.0));
+    let bc = b.map(|e| na::Complex::new(e, 0.0));
+
+    for i in 0..2 {
+        let v = eig.eigenvectors.column(i);
+        assert!(relative_eq!(ac * v * eig.betas[i], bc * v * eig.alphas[i], epsilon = 1.0e-7));
+        assert_relative_eq!((eig.alphas[i] / eig.betas[i]).norm(), 1.0, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn symmetric_generalized_eigen_mat3() {
+    let a = Matrix3::new(4.0, 1.0, 2.0,
+                         1.0, 3.0, 0.0,
+                         2.0, 0.0, 5.0);
+    let b = Matrix3::new(2.0, 1.0, 0.0,
+                         1.0, 2.0, 1.0,
+                         0.0, 1.0, 2.0);
+
+    let eig = a.symmetric_generalized_eigen(b).unwrap();
+    let v = eig.eigenvectors;
+
+    assert!(relative_eq!(v.transpose() * b * v, Matrix3::identity(), epsilon = 1.0e-7));
+    assert!(relative_eq!(v.transpose() * a * v, Matrix3::from_diagonal(&eig.eigenvalues), epsilon = 1.0e-7));
+}
+
+#[test]
+fn symmetric_generalized_eigen_not_definite() {
+    let a = Matrix2::new(1.0, 0.0, 0.0, 1.0);
+    let b = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+
+    assert!(a.symmetric_generalized_eigen(b).is_none());
+}
+
+#[test]
+fn symmetric_generalized_eigen_matches_generalized_eigen() {
+    let n = 6;
+    let a = DMatrix::<f64>::new_random(n, n).symmetric_part();
+    let c = DMatrix::<f64>::new_random(n, n);
+    let b = &c * c.transpose() + DMatrix::identity(n, n);
+
+    let mut expected: Vec<f64> = a
+        .clone()
+        .generalized_eigen(b.clone())
+        .unwrap()
+        .eigenvalues()
+        .iter()
+        .cloned()
+        .collect();
+    let mut vals: Vec<f64> = a
+        .symmetric_generalized_eigen(b)
+        .unwrap()
+        .eigenvalues
+        .iter()
+        .cloned()
+        .collect();
+
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    for (e, v) in expected.iter().zip(vals.iter()) {
+        assert_relative_eq!(e, v, epsilon = 1.0e-7);
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn symmetric_generalized_eigen(n in PROPTEST_MATRIX_DIM) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let c = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = &c * c.adjoint() + DMatrix::identity(n, n);
+                        let eig = a.clone().symmetric_generalized_eigen(b.clone()).unwrap();
+
+                        for i in 0..n {
+                            let v = eig.eigenvectors.column(i);
+                            prop_assert!(relative_eq!(&a * v, (&b * v).scale(eig.eigenvalues[i]), epsilon = 1.0e-7));
+                        }
+                    }
+
+                    #[test]
+                    fn symmetric_generalized_eigen_static_mat3(a in matrix3_($scalar), c in matrix3_($scalar)) {
+                        let a = a.hermitian_part();
+                        let b = c * c.adjoint() + na::Matrix3::identity();
+                        let eig = a.symmetric_generalized_eigen(b).unwrap();
+                        let v = eig.eigenvectors;
+
+                        prop_assert!(relative_eq!(v.adjoint() * b * v, na::Matrix3::identity(), epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+    mod proptest_general_eigen_tests {
+        use crate::core::helper::RandComplex;
+        use crate::proptest::*;
+        use na::DMatrix;
+        use proptest::{prop_assert, proptest};
+
+        proptest! {
+            #[test]
+            fn complex_generalized_eigen(a in dmatrix(), b in dmatrix()) {
+                let n = a.nrows().min(b.nrows());
+                let a = a.resize(n, n, 0.0);
+                let b = b.resize(n, n, 0.0);
+                let eig = a.clone().complex_generalized_eigen(b.clone());
+                let ac = a.map(|e| na::Complex::new(e, 0.0));
+                let bc = b.map(|e| na::Complex::new(e, 0.0));
+
+                for i in 0..n {
+                    let v = eig.eigenvectors.column(i);
+                    prop_assert!(relative_eq!(&ac * v * eig.betas[i], &bc * v * eig.alphas[i], epsilon = 1.0e-6));
+                }
+            }
+
+            #[test]
+            fn generalized_eigen_complex_matrix(n in PROPTEST_MATRIX_DIM) {
+                let a = DMatrix::<RandComplex<f64>>::new_random(n, n).map(|e| e.0);
+                let b = DMatrix::<RandComplex<f64>>::new_random(n, n).map(|e| e.0);
+                let eig = a.clone().generalized_eigen(b.clone()).unwrap();
+
+                for i in 0..n {
+                    let v = eig.eigenvectors.column(i);
+                    let u = eig.left_eigenvectors.column(i);
+                    let (alpha, beta) = (eig.alphas[i], eig.betas[i]);
+
+                    prop_assert!(relative_eq!(&a * v * beta, &b * v * alpha, epsilon = 1.0e-6));
+                    prop_assert!(relative_eq!(u.adjoint() * &a * beta, u.adjoint() * &b * alpha, epsilon = 1.0e-6));
+                }
+            }
+        }
+    }
+}
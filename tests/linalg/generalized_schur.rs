@@ -0,0 +1,119 @@
+use na::{DMatrix, Matrix3, Matrix4};
+
+#[test]
+#[rustfmt::skip]
+fn generalized_schur_simpl_mat3() {
+    let a = Matrix3::new(-2.0, -4.0, 2.0,
+                         -2.0,  1.0, 2.0,
+                          4.0,  2.0, 5.0);
+    let b = Matrix3::new( 1.0,  2.0, 0.0,
+                          0.5,  3.0, 1.0,
+                         -1.0,  0.0, 2.0);
+
+    let (q, s, t, z) = a.generalized_schur(b).unpack();
+
+    assert!(relative_eq!(q * s * z.transpose(), a, epsilon = 1.0e-7));
+    assert!(relative_eq!(q * t * z.transpose(), b, epsilon = 1.0e-7));
+    assert_eq!(t.lower_triangle(), Matrix3::from_diagonal(&t.diagonal()));
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_schur_complex_eigenvalues() {
+    // The pencil (a, b) has the eigenvalues ±i and 2.
+    let a = Matrix3::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 4.0);
+    let b = Matrix3::new(1.0,  0.0, 0.0,
+                         0.0,  1.0, 0.0,
+                         0.0,  0.0, 2.0);
+
+    let schur = a.generalized_schur(b);
+    assert!(schur.eigenvalues().is_none());
+
+    let (alphas, betas) = schur.complex_eigenvalues();
+    let mut vals: Vec<_> = alphas.iter().zip(betas.iter()).map(|(a, b)| a / b).collect();
+    vals.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+
+    assert!(relative_eq!(vals[0], na::Complex::new(0.0, -1.0), epsilon = 1.0e-7));
+    assert!(relative_eq!(vals[1], na::Complex::new(0.0, 1.0), epsilon = 1.0e-7));
+    assert!(relative_eq!(vals[2], na::Complex::new(2.0, 0.0), epsilon = 1.0e-7));
+}
+
+#[test]
+#[rustfmt::skip]
+fn generalized_schur_singular_b() {
+    let a = Matrix4::new(1.0f64, 2.0, 3.0, 4.0,
+                         4.0, 1.0, 0.0, 2.0,
+                         0.0, 3.0, 1.0, 1.0,
+                         2.0, 0.0, 1.0, 5.0);
+    let b = Matrix4::new(1.0, 0.0, 0.0, 0.0,
+                         0.0, 0.0, 0.0, 0.0,
+                         0.0, 0.0, 2.0, 0.0,
+                         0.0, 0.0, 0.0, 0.0);
+
+    let (q, s, t, z) = a.generalized_schur(b).unpack();
+
+    assert!(relative_eq!(q * s * z.transpose(), a, epsilon = 1.0e-7));
+    assert!(relative_eq!(q * t * z.transpose(), b, epsilon = 1.0e-7));
+
+    // Two infinite eigenvalues.
+    let nzeros = t.diagonal().iter().filter(|e| e.abs() < 1.0e-7).count();
+    assert_eq!(nzeros, 2);
+}
+
+#[test]
+fn generalized_schur_identity_b() {
+    let a = DMatrix::<f64>::new_random(7, 7);
+    let b = DMatrix::<f64>::identity(7, 7);
+
+    let (q, s, t, z) = a.clone().generalized_schur(b.clone()).unpack();
+
+    assert!(relative_eq!(&q * s * z.transpose(), a, epsilon = 1.0e-7));
+    assert!(relative_eq!(&q * t * z.transpose(), b, epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+
+                proptest! {
+                    #[test]
+                    fn generalized_schur(n in PROPTEST_MATRIX_DIM) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let (q, s, t, z) = a.clone().generalized_schur(b.clone()).unpack();
+
+                        prop_assert!(relative_eq!(&q * s * z.adjoint(), a, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(&q * &t * z.adjoint(), b, epsilon = 1.0e-7));
+                        prop_assert_eq!(t.lower_triangle(), DMatrix::from_diagonal(&t.diagonal()));
+                    }
+
+                    #[test]
+                    fn generalized_schur_static_mat3(a in matrix3_($scalar), b in matrix3_($scalar)) {
+                        let (q, s, t, z) = a.generalized_schur(b).unpack();
+                        prop_assert!(relative_eq!(q * s * z.adjoint(), a, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(q * t * z.adjoint(), b, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn generalized_schur_static_mat4(a in matrix4_($scalar), b in matrix4_($scalar)) {
+                        let (q, s, t, z) = a.generalized_schur(b).unpack();
+                        prop_assert!(relative_eq!(q * s * z.adjoint(), a, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(q * t * z.adjoint(), b, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
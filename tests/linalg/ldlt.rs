@@ -0,0 +1,150 @@
+use na::{DMatrix, DVector, Matrix3, Matrix4, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_simple() {
+    let m = Matrix3::new(
+        2.0, -1.0,  0.0,
+       -1.0,  2.0, -1.0,
+        0.0, -1.0,  2.0);
+
+    let (p, l, d) = m.ldlt().unpack();
+    let mut pm = m;
+    p.permute_rows(&mut pm);
+    p.permute_columns(&mut pm);
+
+    assert!(relative_eq!(pm, l * d * l.transpose(), epsilon = 1.0e-7));
+}
+
+#[test]
+#[rustfmt::skip]
+fn ldlt_saddle_point() {
+    // A KKT system with a zero block, rejected by the Cholesky decomposition.
+    let m = Matrix4::new(
+        4.0, 1.0, 1.0, 0.0,
+        1.0, 3.0, 0.0, 1.0,
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0);
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert!(m.cholesky().is_none());
+
+    let ldlt = m.ldlt();
+    let x = ldlt.solve(&b).unwrap();
+
+    assert!(relative_eq!(m * x, b, epsilon = 1.0e-7));
+    assert_eq!(ldlt.inertia(), (2, 2, 0));
+    assert_relative_eq!(ldlt.determinant(), m.determinant(), epsilon = 1.0e-7);
+    assert!(relative_eq!(ldlt.inverse().unwrap() * m, Matrix4::identity(), epsilon = 1.0e-7));
+}
+
+#[test]
+fn ldlt_zero_diagonal_needs_2x2_pivot() {
+    let m = Matrix3::new(0.0, 1.0, 2.0, 1.0, 0.0, 3.0, 2.0, 3.0, 0.0);
+    let ldlt = m.ldlt();
+
+    let (p, l, d) = ldlt.clone().unpack();
+    let mut pm = m;
+    p.permute_rows(&mut pm);
+    p.permute_columns(&mut pm);
+
+    assert!(relative_eq!(pm, l * d * l.transpose(), epsilon = 1.0e-7));
+    assert_relative_eq!(ldlt.determinant(), m.determinant(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn ldlt_singular() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0);
+    let ldlt = m.ldlt();
+
+    assert!(!ldlt.is_invertible());
+    assert!(ldlt.solve(&Matrix3::identity()).is_none());
+    assert_eq!(ldlt.inertia(), (1, 0, 2));
+}
+
+#[test]
+fn ldlt_inertia_matches_eigenvalues() {
+    let n = 10;
+    let m = DMatrix::<f64>::new_random(n, n).symmetric_part() - DMatrix::identity(n, n) * 0.5;
+    let eigenvalues = m.clone().symmetric_eigenvalues();
+    let npos = eigenvalues.iter().filter(|e| **e > 0.0).count();
+
+    assert_eq!(m.ldlt().inertia(), (npos, n - npos, 0));
+}
+
+#[test]
+fn ldlt_solve_dynamic() {
+    let n = 15;
+    let m = DMatrix::<f64>::new_random(n, n).symmetric_part() - DMatrix::identity(n, n) * 0.5;
+    let b = DVector::<f64>::new_random(n);
+    let x = m.clone().ldlt().solve(&b).unwrap();
+
+    assert!(relative_eq!(m * x, b, epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{ComplexField, DMatrix, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn ldlt(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let (p, l, d) = m.clone().ldlt().unpack();
+                        let mut pm = m.clone();
+                        p.permute_rows(&mut pm);
+                        p.permute_columns(&mut pm);
+
+                        prop_assert!(relative_eq!(pm, &l * d * l.adjoint(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(l.upper_triangle(), DMatrix::identity(m.nrows(), m.ncols())));
+                    }
+
+                    #[test]
+                    fn ldlt_static_mat4(m in matrix4_($scalar)) {
+                        let m = m.hermitian_part();
+                        let (p, l, d) = m.ldlt().unpack();
+                        let mut pm = m;
+                        p.permute_rows(&mut pm);
+                        p.permute_columns(&mut pm);
+
+                        prop_assert!(relative_eq!(pm, l * d * l.adjoint(), epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn ldlt_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                        let ldlt = m.clone().ldlt();
+
+                        if let Some(x) = ldlt.solve(&b) {
+                            prop_assert!(relative_eq!(&m * x, b, epsilon = 1.0e-6));
+                        }
+                    }
+
+                    #[test]
+                    fn ldlt_inverse(m in matrix4_($scalar)) {
+                        let m = m.hermitian_part();
+                        let ldlt = m.ldlt();
+
+                        if let Some(inv) = ldlt.inverse() {
+                            prop_assert!(relative_eq!(m * inv, Matrix4::identity(), epsilon = 1.0e-7));
+                            // NOTE: the determinant computed by LU may have a small spurious imaginary part.
+                            let det = m.determinant();
+                            prop_assert!((ldlt.determinant() - det).modulus() <= 1.0e-7 * (1.0 + det.modulus()));
+                        }
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,87 @@
+#[cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::Matrix3x4;
+
+#[test]
+fn lq_rq() {
+    let m = Matrix3x4::new(
+        1.0, -1.0, 2.0, 1.0, -1.0, 3.0, -1.0, -1.0, 3.0, -5.0, 5.0, 3.0,
+    );
+
+    let (l, q) = m.lq().unpack();
+    assert!(relative_eq!(m, l * q, epsilon = 1.0e-7));
+    assert_eq!(l.lower_triangle(), l);
+
+    let (r, q) = m.rq().unpack();
+    assert!(relative_eq!(m, r * q, epsilon = 1.0e-7));
+    assert_eq!(r.upper_triangle(), r);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr) => {
+            mod $module {
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandComplex, RandScalar};
+                use crate::proptest::*;
+                use proptest::{prop_assert, prop_assert_eq, proptest};
+
+                proptest! {
+                    #[test]
+                    fn lq(m in dmatrix_($scalar)) {
+                        let (l, q) = m.clone().lq().unpack();
+
+                        prop_assert!(relative_eq!(m, &l * &q, epsilon = 1.0e-7));
+                        prop_assert!((&q * q.adjoint()).is_identity(1.0e-7));
+                        prop_assert_eq!(l.lower_triangle(), l);
+                    }
+
+                    #[test]
+                    fn lq_static_5_3(m in matrix5x3_($scalar)) {
+                        let (l, q) = m.lq().unpack();
+
+                        prop_assert!(relative_eq!(m, l * q, epsilon = 1.0e-7));
+                        prop_assert!((q * q.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn lq_static_3_5(m in matrix3x5_($scalar)) {
+                        let (l, q) = m.lq().unpack();
+
+                        prop_assert!(relative_eq!(m, l * q, epsilon = 1.0e-7));
+                        prop_assert!((q * q.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn rq(m in dmatrix_($scalar)) {
+                        let (r, q) = m.clone().rq().unpack();
+
+                        prop_assert!(relative_eq!(m, &r * &q, epsilon = 1.0e-7));
+                        prop_assert!((&q * q.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn rq_static_5_3(m in matrix5x3_($scalar)) {
+                        let (r, q) = m.rq().unpack();
+
+                        prop_assert!(relative_eq!(m, r * q, epsilon = 1.0e-7));
+                        prop_assert!((q * q.adjoint()).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn rq_static_3_5(m in matrix3x5_($scalar)) {
+                        let (r, q) = m.rq().unpack();
+
+                        prop_assert!(relative_eq!(m, r * q, epsilon = 1.0e-7));
+                        prop_assert!((q * q.adjoint()).is_identity(1.0e-7));
+                        prop_assert_eq!(r.upper_triangle(), r);
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64());
+    gen_tests!(f64, PROPTEST_F64);
+}
@@ -0,0 +1,170 @@
+use na::{DMatrix, Matrix2, Matrix3, Matrix4};
+use num_complex::Complex;
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_known_values() {
+    let m = Matrix2::new(33.0, 24.0,
+                         48.0, 57.0);
+    let s = m.sqrt().unwrap();
+    assert_relative_eq!(s, Matrix2::new(5.0, 2.0, 4.0, 7.0), epsilon = 1.0e-10);
+
+    // Negative real eigenvalues have no real principal square root.
+    assert!(Matrix2::new(-1.0, 0.0, 0.0, 4.0).sqrt().is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrt_complex_eigenvalues() {
+    // A rotation matrix: its square root is the rotation of half the angle.
+    let (s, c) = 1.2f64.sin_cos();
+    let (hs, hc) = 0.6f64.sin_cos();
+    let m = Matrix3::new(c,  -s,  0.0,
+                         s,   c,  0.0,
+                         0.0, 0.0, 2.0);
+    let expected = Matrix3::new(hc, -hs,  0.0,
+                                hs,  hc,  0.0,
+                                0.0, 0.0, 2.0f64.sqrt());
+
+    assert_relative_eq!(m.sqrt().unwrap(), expected, epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn ln_exp_round_trip() {
+    let m = Matrix4::new(
+        0.1, 0.5, -0.2, 0.0,
+        -0.3, 0.2, 0.4, 0.1,
+        0.0, -0.6, 0.3, 0.2,
+        0.2, 0.1, -0.1, -0.4);
+
+    assert_relative_eq!(m.exp().ln().unwrap(), m, epsilon = 1.0e-10);
+    assert_relative_eq!(m.exp().ln().unwrap().exp(), m.exp(), epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn ln_defective() {
+    let m = Matrix3::new(
+        2.0, 1.0, 0.0,
+        0.0, 2.0, 1.0,
+        0.0, 0.0, 2.0);
+
+    assert_relative_eq!(m.ln().unwrap().exp(), m, epsilon = 1.0e-10);
+    assert!(Matrix2::new(1.0, 2.0, 2.0, 4.0).ln().is_none());
+}
+
+#[test]
+fn powf_matches_sqrt_and_pow() {
+    let m = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0);
+
+    assert_relative_eq!(m.powf(0.5).unwrap(), m.sqrt().unwrap(), epsilon = 1.0e-10);
+    assert_relative_eq!(m.powf(3.0).unwrap(), m * m * m, epsilon = 1.0e-9);
+}
+
+#[test]
+fn sin_cos_identities() {
+    let m = Matrix3::new(1.0, 2.0, -0.5, 0.3, -1.5, 2.0, 0.7, 0.1, 3.0);
+    let (sin, cos) = m.sin_cos();
+
+    assert_relative_eq!(sin * sin + cos * cos, Matrix3::identity(), epsilon = 1.0e-9);
+    // sin(2A) = 2 sin(A) cos(A)
+    assert_relative_eq!((m * 2.0).sin(), sin * cos * 2.0, epsilon = 1.0e-9);
+
+    // cos(A) = (exp(iA) + exp(-iA)) / 2
+    let im = m.map(|e| Complex::new(0.0, e));
+    let expected = ((&im).exp() + (-im).exp()) * Complex::new(0.5, 0.0);
+    assert_relative_eq!(
+        cos.map(|e| Complex::new(e, 0.0)),
+        expected,
+        epsilon = 1.0e-9
+    );
+}
+
+#[test]
+fn sin_cos_non_finite() {
+    let (sin, cos) = Matrix2::new(f64::NAN, 0.0, 0.0, 1.0).sin_cos();
+    assert!(sin.iter().chain(cos.iter()).all(|e| e.is_nan()));
+
+    let (sin, cos) = Matrix2::new(f64::INFINITY, 0.0, 0.0, 1.0).sin_cos();
+    assert!(sin.iter().chain(cos.iter()).all(|e| e.is_nan()));
+}
+
+#[test]
+fn apply_function_matches_exp() {
+    let m = Matrix3::new(1.0f64, 2.0, 3.0, 0.0, 0.5, 1.0, 0.0, 0.0, -1.0);
+    let f = m.apply_function(|e| e.exp()).unwrap();
+    assert_relative_eq!(f, m.exp(), epsilon = 1.0e-9);
+
+    // Complex eigenvalues can be handled by working with a complex matrix.
+    let rot = Matrix2::new(0.0f64, -1.0, 1.0, 0.0);
+    assert!(rot.apply_function(|e| e.exp()).is_none());
+    let rot = rot.map(|e| Complex::new(e, 0.0));
+    let f = rot.apply_function(|e| e.exp()).unwrap();
+    assert_relative_eq!(f, rot.exp(), epsilon = 1.0e-9);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use std::cmp;
+
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn sqrt(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 8));
+                        // Shifting the diagonal guarantees that all eigenvalues have a positive real part.
+                        let mut m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        for i in 0..n {
+                            m[(i, i)] += n as f64;
+                        }
+
+                        let s = m.sqrt().unwrap();
+                        prop_assert!(relative_eq!(&s * &s, m, epsilon = 1.0e-8));
+                    }
+
+                    #[test]
+                    fn ln_exp_round_trip(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 8));
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let l = m.exp().ln().unwrap();
+                        prop_assert!(relative_eq!(l.exp(), m.exp(), epsilon = 1.0e-8));
+                    }
+
+                    #[test]
+                    fn sqrt_static_mat3(m in matrix3_($scalar)) {
+                        let m = m * m.adjoint() + na::Matrix3::identity();
+                        let s = m.sqrt().unwrap();
+                        prop_assert!(relative_eq!(s * s, m, epsilon = 1.0e-7 * m.norm()));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
+
+#[test]
+fn sqrt_dynamic_identity() {
+    let m = DMatrix::<f64>::identity(5, 5) * 9.0;
+    assert_relative_eq!(
+        m.sqrt().unwrap(),
+        DMatrix::identity(5, 5) * 3.0,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        m.ln().unwrap(),
+        DMatrix::identity(5, 5) * 9.0f64.ln(),
+        epsilon = 1.0e-12
+    );
+}
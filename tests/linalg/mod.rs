@@ -2,16 +2,29 @@ mod balancing;
 mod bidiagonal;
 mod cholesky;
 mod col_piv_qr;
+mod complete_orthogonal;
 mod convolution;
 mod eigen;
 mod exp;
 mod full_piv_lu;
+mod generalized_eigen;
+mod generalized_schur;
 mod hessenberg;
 mod inverse;
+mod ldlt;
+mod lq;
 mod lu;
+mod matrix_functions;
+mod polar;
 mod qr;
+mod rcond;
+mod refinement;
+mod riccati;
 mod schur;
 mod solve;
 mod svd;
+mod sylvester;
 mod tridiagonal;
 mod udu;
+mod updatable_lu;
+mod updatable_qr;
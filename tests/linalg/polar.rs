@@ -0,0 +1,100 @@
+#[cfg_attr(rustfmt, rustfmt_skip)]
+
+use na::{Matrix3, Rotation3, Vector3};
+
+#[test]
+fn polar_singular() {
+    // A rank-deficient matrix.
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let (u, h) = m.polar().unpack();
+
+    assert!(relative_eq!(m, u * h, epsilon = 1.0e-7));
+    assert!(u.is_orthogonal(1.0e-7));
+    assert!(h.symmetric_eigenvalues().iter().all(|e| *e >= -1.0e-7));
+}
+
+#[test]
+fn polar_converged_on_last_iteration() {
+    // The first iteration already reaches the tolerance, the second one confirms it.
+    let m = Matrix3::identity() * (1.0 + 1.0e-9);
+    let (u, h) = m.try_polar(1.0e-6, 1).unwrap().unpack();
+
+    assert!(relative_eq!(u, Matrix3::identity(), epsilon = 1.0e-7));
+    assert!(relative_eq!(h, m, epsilon = 1.0e-7));
+}
+
+#[test]
+fn rotation_from_matrix_polar() {
+    let rot = Rotation3::from_axis_angle(&Vector3::y_axis(), 0.7);
+    let stretch = Matrix3::new(2.0, 0.1, 0.0, 0.1, 1.0, 0.2, 0.0, 0.2, 0.5);
+
+    let from_polar = Rotation3::from_matrix_polar(&(rot.matrix() * stretch));
+    assert!(relative_eq!(from_polar, rot, epsilon = 1.0e-7));
+
+    // The closest rotation to a reflection.
+    let reflection = Matrix3::from_diagonal(&Vector3::new(1.0, 1.0, -1.0));
+    let from_polar = Rotation3::from_matrix_polar(&(rot.matrix() * reflection * stretch));
+    assert!(relative_eq!(
+        from_polar.matrix().determinant(),
+        1.0,
+        epsilon = 1.0e-7
+    ));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr) => {
+            mod $module {
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandComplex, RandScalar};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn polar(m in dmatrix_($scalar)) {
+                        let (u, h) = m.clone().polar().unpack();
+
+                        prop_assert!(relative_eq!(m, &u * &h, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(h.adjoint(), h, epsilon = 1.0e-7));
+
+                        if m.nrows() >= m.ncols() {
+                            prop_assert!(u.ad_mul(&u).is_identity(1.0e-7));
+                        } else {
+                            prop_assert!((&u * u.adjoint()).is_identity(1.0e-7));
+                        }
+                    }
+
+                    #[test]
+                    fn polar_static_square(m in matrix4_($scalar)) {
+                        let (u, h) = m.polar().unpack();
+
+                        prop_assert!(relative_eq!(m, u * h, epsilon = 1.0e-7));
+                        prop_assert!(u.is_orthogonal(1.0e-7));
+                        prop_assert!(h.symmetric_eigenvalues().iter().all(|e| *e >= -1.0e-7));
+                    }
+
+                    #[test]
+                    fn polar_static_5_3(m in matrix5x3_($scalar)) {
+                        let (u, h) = m.polar().unpack();
+
+                        prop_assert!(relative_eq!(m, u * h, epsilon = 1.0e-7));
+                        prop_assert!(u.ad_mul(&u).is_identity(1.0e-7));
+                    }
+
+                    #[test]
+                    fn polar_static_3_5(m in matrix3x5_($scalar)) {
+                        let (u, h) = m.polar().unpack();
+
+                        prop_assert!(relative_eq!(m, u * h, epsilon = 1.0e-7));
+                        prop_assert!((u * u.adjoint()).is_identity(1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64());
+    gen_tests!(f64, PROPTEST_F64);
+}
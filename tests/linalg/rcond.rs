@@ -0,0 +1,124 @@
+use na::{DMatrix, Matrix2, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn induced_norms() {
+    let m = Matrix3::new(
+        1.0, -2.0,  3.0,
+       -4.0,  5.0, -6.0,
+        7.0, -8.0, 10.0);
+
+    assert_eq!(m.one_norm(), 19.0);
+    assert_eq!(m.infinity_norm(), 25.0);
+    assert_eq!(m.transpose().one_norm(), 25.0);
+}
+
+#[test]
+fn spectral_and_nuclear_norms() {
+    let m = Matrix2::new(3.0, 0.0, 0.0, -4.0);
+    assert_relative_eq!(m.spectral_norm(), 4.0, epsilon = 1.0e-7);
+    assert_relative_eq!(m.nuclear_norm(), 7.0, epsilon = 1.0e-7);
+
+    let m = DMatrix::<f64>::zeros(3, 2);
+    assert_eq!(m.spectral_norm(), 0.0);
+    assert_eq!(m.nuclear_norm(), 0.0);
+}
+
+#[test]
+#[rustfmt::skip]
+fn rcond_singular() {
+    let m = Matrix3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        0.0, 0.0, 0.0);
+
+    assert_eq!(m.lu().rcond(m.one_norm()), 0.0);
+    assert_eq!(m.qr().rcond(m.one_norm()), 0.0);
+    assert_eq!(Matrix3::<f64>::identity().lu().rcond(0.0), 0.0);
+}
+
+#[test]
+fn rcond_identity() {
+    let m = Matrix3::<f64>::identity();
+    assert_relative_eq!(m.lu().rcond(m.one_norm()), 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(m.qr().rcond(m.one_norm()), 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        m.cholesky().unwrap().rcond(m.one_norm()),
+        1.0,
+        epsilon = 1.0e-7
+    );
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use na::{ComplexField, DMatrix};
+                use proptest::{prop_assert, proptest};
+
+                // The exact reciprocal condition number in the 1-norm, and whether the estimate
+                // `rcond` is within the bounds expected from the Hager-Higham estimator.
+                fn check_rcond<N: ComplexField<RealField = f64>>(m: &DMatrix<N>, rcond: f64) -> bool {
+                    let exact = match m.clone().try_inverse() {
+                        Some(inv) => 1.0 / (m.one_norm() * inv.one_norm()),
+                        None => return rcond == 0.0,
+                    };
+
+                    rcond >= exact * (1.0 - 1.0e-7) && rcond <= exact * 10.0
+                }
+
+                proptest! {
+                    #[test]
+                    fn norms(m in dmatrix_($scalar)) {
+                        let spectral = m.spectral_norm();
+                        let nuclear = m.nuclear_norm();
+                        let frobenius = m.norm();
+                        let tol = 1.0e-7 * frobenius;
+
+                        prop_assert!(spectral <= frobenius + tol);
+                        prop_assert!(frobenius <= nuclear + tol);
+                        prop_assert!(spectral <= (m.one_norm() * m.infinity_norm()).sqrt() + tol);
+                        prop_assert!(m.adjoint().one_norm() == m.infinity_norm());
+                    }
+
+                    #[test]
+                    fn lu_rcond(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let lu = m.clone().lu();
+                        prop_assert!(check_rcond(&m, lu.rcond(m.one_norm())));
+
+                        let b = DMatrix::<$scalar_type>::new_random(n, 2).map(|e| e.0);
+                        let mut x = b.clone();
+                        prop_assert!(!lu.ad_solve_mut(&mut x) || relative_eq!(m.ad_mul(&x), b, epsilon = 1.0e-6));
+                    }
+
+                    #[test]
+                    fn qr_rcond(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let qr = m.clone().qr();
+                        prop_assert!(check_rcond(&m, qr.rcond(m.one_norm())));
+
+                        let b = DMatrix::<$scalar_type>::new_random(n, 2).map(|e| e.0);
+                        let mut x = b.clone();
+                        prop_assert!(!qr.ad_solve_mut(&mut x) || relative_eq!(m.ad_mul(&x), b, epsilon = 1.0e-6));
+                    }
+
+                    #[test]
+                    fn cholesky_rcond(n in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let m = m.ad_mul(&m) + DMatrix::identity(n, n);
+                        let rcond = m.clone().cholesky().unwrap().rcond(m.one_norm());
+                        prop_assert!(check_rcond(&m, rcond));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,107 @@
+use na::{Matrix4, Vector4};
+
+#[test]
+fn lu_solve_refined_mixed_precision() {
+    // A Hilbert matrix, with a condition number of about 1.5e4.
+    let a = Matrix4::<f64>::from_fn(|i, j| 1.0 / (i + j + 1) as f64);
+    let x = Vector4::new(1.0, -2.0, 3.0, -4.0);
+    let b = a * x;
+
+    let lu = a.cast::<f32>().lu();
+    let unrefined = lu.solve(&b.cast::<f32>()).unwrap().cast::<f64>();
+    let refined = lu.solve_refined(&a, &b).unwrap();
+
+    let unrefined_error = (unrefined - x).amax() / x.amax();
+    let refined_error = (refined.solution - x).amax() / x.amax();
+
+    assert!(unrefined_error > 1.0e-5);
+    assert!(refined_error < 1.0e-10);
+    assert!(refined_error <= refined.forward_error[0]);
+    assert!(refined.forward_error[0] < 1.0e-8);
+    assert!(refined.backward_error[0] < 1.0e-15);
+}
+
+#[test]
+fn solve_refined_singular() {
+    let a = Matrix4::<f64>::from_fn(|i, j| if i == 3 { 0.0 } else { (i * j + 1) as f64 });
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert!(a.lu().solve_refined(&a, &b).is_none());
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty, $scalar_type: ty, $low_precision: ty) => {
+            mod $module {
+                use na::{DMatrix, RefinedSolution, Dynamic};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                // Checks that the refined solution reaches the accuracy of `f64` and that the
+                // error bounds hold.
+                fn check_refined(
+                    x: &DMatrix<$scalar>,
+                    refined: &RefinedSolution<$scalar, Dynamic, Dynamic>,
+                ) -> bool {
+                    (0..x.ncols()).all(|j| {
+                        let x_j = x.column(j);
+                        let error = (refined.solution.column(j) - x_j).camax() / x_j.camax();
+
+                        refined.backward_error[j] < 1.0e-14
+                            && refined.forward_error[j] < 1.0e-10
+                            && error <= refined.forward_error[j]
+                    })
+                }
+
+                proptest! {
+                    #[test]
+                    fn lu_solve_refined(n in PROPTEST_MATRIX_DIM, nb in 1..=3usize) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let a = a + DMatrix::identity(n, n).scale(n as f64);
+                        let x = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                        let b = &a * &x;
+
+                        let lu = a.clone().cast::<$low_precision>().lu();
+                        let refined = lu.solve_refined(&a, &b).unwrap();
+                        prop_assert!(check_refined(&x, &refined));
+                    }
+
+                    #[test]
+                    fn qr_solve_refined(n in PROPTEST_MATRIX_DIM, nb in 1..=3usize) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let a = a + DMatrix::identity(n, n).scale(n as f64);
+                        let x = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                        let b = &a * &x;
+
+                        let qr = a.clone().cast::<$low_precision>().qr();
+                        let refined = qr.solve_refined(&a, &b).unwrap();
+                        prop_assert!(check_refined(&x, &refined));
+                    }
+
+                    #[test]
+                    fn cholesky_solve_refined(n in PROPTEST_MATRIX_DIM, nb in 1..=3usize) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let a = m.ad_mul(&m) + DMatrix::identity(n, n).scale(n as f64);
+                        let x = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                        let b = &a * &x;
+
+                        let chol = a.clone().cast::<$low_precision>().cholesky().unwrap();
+                        let refined = chol.solve_refined(&a, &b).unwrap();
+                        prop_assert!(check_refined(&x, &refined));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(
+        complex,
+        na::Complex<f64>,
+        RandComplex<f64>,
+        na::Complex<f32>
+    );
+    gen_tests!(f64, f64, RandScalar<f64>, f32);
+}
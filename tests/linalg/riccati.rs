@@ -0,0 +1,131 @@
+use na::{Matrix1, Matrix2, Matrix4, Matrix4x2};
+
+#[test]
+fn continuous_riccati_scalar() {
+    // 2x - x² + 1 = 0
+    let one = Matrix1::new(1.0f64);
+    let x = one.solve_continuous_riccati(&one, &one, &one).unwrap();
+
+    assert_relative_eq!(x[0], 1.0 + 2.0f64.sqrt(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn discrete_riccati_scalar() {
+    // x² - x - 1 = 0
+    let one = Matrix1::new(1.0f64);
+    let x = one.solve_discrete_riccati(&one, &one, &one).unwrap();
+
+    assert_relative_eq!(x[0], (1.0 + 5.0f64.sqrt()) / 2.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn riccati_r_not_definite_positive() {
+    let a = Matrix2::new(1.0, 2.0, 0.0, 3.0);
+    let b = Matrix2::identity();
+    let r = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+
+    assert!(a.solve_continuous_riccati(&b, &b, &r).is_none());
+    assert!(a.solve_discrete_riccati(&b, &b, &r).is_none());
+}
+
+#[test]
+fn riccati_not_stabilizable() {
+    // The unstable mode of `a` is not controlled by `b`.
+    let a = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+    let b = Matrix2::new(0.0, 0.0, 0.0, 1.0);
+    let q = Matrix2::zeros();
+    let r = Matrix2::identity();
+
+    assert!(a.solve_continuous_riccati(&b, &q, &r).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn continuous_riccati_static_mat4() {
+    let a = Matrix4::new(0.0, 1.0, 0.0, 0.0,
+                         0.0, 0.0, 1.0, 0.0,
+                         0.0, 0.0, 0.0, 1.0,
+                         2.0, -1.0, 3.0, 0.5);
+    let b = Matrix4x2::new(0.0, 0.0,
+                           0.0, 1.0,
+                           0.0, 0.0,
+                           1.0, 0.0);
+    let q = Matrix4::identity();
+    let r = Matrix2::new(2.0, 0.5,
+                         0.5, 1.0);
+
+    let x = a.solve_continuous_riccati(&b, &q, &r).unwrap();
+    let rinv = r.try_inverse().unwrap();
+    let residual = a.transpose() * x + x * a - x * b * rinv * b.transpose() * x + q;
+
+    assert!(relative_eq!(residual, Matrix4::zeros(), epsilon = 1.0e-7));
+    assert!(relative_eq!(x, x.transpose(), epsilon = 1.0e-7));
+
+    // The closed loop is stable.
+    let closed_loop = a - b * rinv * b.transpose() * x;
+    let eigvals = closed_loop.complex_eigenvalues();
+    assert!(eigvals.iter().all(|e| e.re < 0.0));
+}
+
+#[test]
+#[rustfmt::skip]
+fn discrete_riccati_static_mat4() {
+    let a = Matrix4::new(1.0, 0.1, 0.0, 0.0,
+                         0.0, 1.0, 0.1, 0.0,
+                         0.0, -0.2, 1.1, 0.1,
+                         0.3, 0.0, 0.0, 0.9);
+    let b = Matrix4x2::new(0.0, 0.0,
+                           0.0, 0.1,
+                           0.0, 0.0,
+                           0.1, 0.0);
+    let q = Matrix4::identity();
+    let r = Matrix2::identity();
+
+    let x = a.solve_discrete_riccati(&b, &q, &r).unwrap();
+    let gain = (r + b.transpose() * x * b).try_inverse().unwrap() * b.transpose() * x * a;
+    let residual = a.transpose() * x * a - x - a.transpose() * x * b * gain + q;
+
+    assert!(relative_eq!(residual, Matrix4::zeros(), epsilon = 1.0e-7));
+
+    let closed_loop = a - b * gain;
+    let eigvals = closed_loop.complex_eigenvalues();
+    assert!(eigvals.iter().all(|e| e.norm() < 1.0));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use na::DMatrix;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn continuous_riccati(n in PROPTEST_MATRIX_DIM, m in PROPTEST_MATRIX_DIM) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let b = DMatrix::<f64>::new_random(n, m);
+            let q = DMatrix::<f64>::identity(n, n);
+            let r = DMatrix::<f64>::identity(m, m);
+            let x = a.solve_continuous_riccati(&b, &q, &r).unwrap();
+            let g = &b * b.transpose();
+            let residual = a.transpose() * &x + &x * &a - &x * &g * &x + &q;
+
+            // The solution may be very large with few inputs, so check the relative residual.
+            let scale = 2.0 * a.norm() * x.norm() + x.norm_squared() * g.norm() + q.norm();
+            prop_assert!(residual.norm() <= 1.0e-12 * scale);
+        }
+
+        #[test]
+        fn discrete_riccati(n in PROPTEST_MATRIX_DIM, m in PROPTEST_MATRIX_DIM) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let b = DMatrix::<f64>::new_random(n, m);
+            let q = DMatrix::<f64>::identity(n, n);
+            let r = DMatrix::<f64>::identity(m, m);
+            let x = a.solve_discrete_riccati(&b, &q, &r).unwrap();
+            let gain = (r + b.transpose() * &x * &b).try_inverse().unwrap() * b.transpose() * &x * &a;
+            let residual = a.transpose() * &x * &a - &x - a.transpose() * &x * &b * gain + &q;
+
+            let scale = a.norm_squared() * x.norm() * (1.0 + x.norm() * b.norm_squared()) + x.norm() + q.norm();
+            prop_assert!(residual.norm() <= 1.0e-12 * scale);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use na::{DMatrix, Matrix2, Matrix3, Matrix3x2, Matrix6};
+
+#[test]
+#[rustfmt::skip]
+fn sylvester_simple() {
+    let a = Matrix3::new(1.0, 2.0, 0.0,
+                         0.0, 3.0, 1.0,
+                        -1.0, 0.0, 4.0);
+    let b = Matrix2::new(2.0, -1.0,
+                         1.0,  2.0);
+    let c = Matrix3x2::new(1.0, 2.0,
+                           3.0, 4.0,
+                           5.0, 6.0);
+
+    let x = a.solve_sylvester(&b, &c).unwrap();
+    assert!(relative_eq!(a * x + x * b, c, epsilon = 1.0e-7));
+}
+
+#[test]
+fn sylvester_singular() {
+    // `a` and `-b` share the eigenvalue 1.
+    let a = Matrix2::new(1.0, 0.0, 0.0, 2.0);
+    let b = Matrix2::new(-1.0, 0.0, 0.0, 5.0);
+
+    assert!(a.solve_sylvester(&b, &Matrix2::identity()).is_none());
+}
+
+#[test]
+fn continuous_lyapunov_static_mat6() {
+    let a = Matrix6::<f64>::new_random() - Matrix6::identity() * 6.0;
+    let q = Matrix6::<f64>::new_random();
+
+    let x = a.solve_continuous_lyapunov(&q).unwrap();
+    assert!(relative_eq!(a * x + x * a.transpose(), q, epsilon = 1.0e-7));
+}
+
+#[test]
+fn discrete_lyapunov_static_mat6() {
+    let a = Matrix6::<f64>::new_random() / 12.0;
+    let q = Matrix6::<f64>::new_random();
+
+    let x = a.solve_discrete_lyapunov(&q).unwrap();
+    assert!(relative_eq!(
+        a * x * a.transpose() - x + q,
+        Matrix6::zeros(),
+        epsilon = 1.0e-7
+    ));
+}
+
+#[test]
+fn continuous_lyapunov_complex_eigenvalues() {
+    let a = Matrix2::new(-1.0, -3.0, 3.0, -1.0);
+    let q = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+
+    let x = a.solve_continuous_lyapunov(&q).unwrap();
+    assert!(relative_eq!(a * x + x * a.transpose(), q, epsilon = 1.0e-7));
+}
+
+#[test]
+fn sylvester_dynamic() {
+    let a = DMatrix::<f64>::new_random(7, 7) + DMatrix::identity(7, 7) * 7.0;
+    let b = DMatrix::<f64>::new_random(4, 4) + DMatrix::identity(4, 4) * 4.0;
+    let c = DMatrix::<f64>::new_random(7, 4);
+
+    let x = a.solve_sylvester(&b, &c).unwrap();
+    assert!(relative_eq!(&a * &x + &x * &b, c, epsilon = 1.0e-7));
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::allocator::Allocator;
+    use na::storage::Storage;
+    use na::{ComplexField, DefaultAllocator, Dim, Matrix, MatrixMN};
+
+    /// Adds `shift` to the diagonal of `m`.
+    fn shift_diagonal<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>>(
+        m: &Matrix<N, R, C, S>,
+        shift: f64,
+    ) -> MatrixMN<N, R, C>
+    where
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        m.map_with_location(|i, j, e| if i == j { e + na::convert(shift) } else { e })
+    }
+
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn sylvester(m in PROPTEST_MATRIX_DIM, n in PROPTEST_MATRIX_DIM) {
+                        // Diagonal dominance ensures the eigenvalues of `a` and `b` have positive real parts.
+                        let a = super::shift_diagonal(&DMatrix::<$scalar_type>::new_random(m, m).map(|e| e.0), 2.0 * m as f64);
+                        let b = super::shift_diagonal(&DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0), 2.0 * n as f64);
+                        let c = DMatrix::<$scalar_type>::new_random(m, n).map(|e| e.0);
+                        let x = a.solve_sylvester(&b, &c).unwrap();
+
+                        prop_assert!(relative_eq!(&a * &x + &x * &b, c, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn continuous_lyapunov(n in PROPTEST_MATRIX_DIM) {
+                        let a = super::shift_diagonal(&DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0), -2.0 * n as f64);
+                        let q = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let x = a.solve_continuous_lyapunov(&q).unwrap();
+
+                        prop_assert!(relative_eq!(&a * &x + &x * a.adjoint(), q, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn discrete_lyapunov(n in PROPTEST_MATRIX_DIM) {
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).unscale(4.0 * n as f64);
+                        let q = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let x = a.solve_discrete_lyapunov(&q).unwrap();
+
+                        prop_assert!(relative_eq!(&a * &x * a.adjoint() - &x + q, DMatrix::zeros(n, n), epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn continuous_lyapunov_static_mat4(a in matrix4_($scalar), q in matrix4_($scalar)) {
+                        let a = super::shift_diagonal(&a, -1000.0);
+                        let x = a.solve_continuous_lyapunov(&q).unwrap();
+
+                        prop_assert!(relative_eq!(a * x + x * a.adjoint(), q, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,108 @@
+use na::{Matrix4, Matrix4x2, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn updatable_lu_static() {
+    let m = Matrix4::new(
+        5.0, -2.0,  1.0,  0.0,
+        1.0,  3.0, -4.0,  2.0,
+       -2.0,  1.0,  6.0,  1.0,
+        0.0,  4.0,  2.0, -3.0);
+    let b = Matrix4x2::new(
+        1.0,  0.0,
+        2.0, -1.0,
+        0.0,  3.0,
+       -1.0,  2.0);
+
+    let mut lu = m.updatable_lu();
+    assert_relative_eq!(m * lu.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    let col = Vector4::new(0.0, 1.0, 1.0, 2.0);
+    let mut expected = m;
+    expected.set_column(0, &col);
+    lu.replace_column(0, &col);
+    assert_eq!(lu.u().upper_triangle(), lu.u());
+    assert_eq!(lu.column_order(), &Vector4::new(1, 2, 3, 0));
+    assert_relative_eq!(expected * lu.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    let mut x = b;
+    assert!(lu.ad_solve_mut(&mut x));
+    assert_relative_eq!(expected.transpose() * x, b, epsilon = 1.0e-7);
+
+    let u = Vector4::new(1.0, 0.0, -2.0, 0.5);
+    let v = Vector4::new(0.0, 1.0, 1.0, -1.0);
+    lu.rank_one_update(&u, &v);
+    expected += u * v.transpose();
+    assert_eq!(lu.u().upper_triangle(), lu.u());
+    assert_relative_eq!(expected * lu.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    // Duplicating a column makes the matrix singular.
+    let lu = m.lu().replace_column(2, &m.column(1));
+    assert!(!lu.is_invertible());
+    assert!(lu.solve(&b).is_none());
+
+    let lu = m.lu().rank_one_update(&u, &v);
+    assert_relative_eq!((m + u * v.transpose()) * lu.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::{DMatrix, DVector};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn updatable_lu_replace_column(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM, cols in proptest::collection::vec(0..20usize, 1..10)) {
+                        let mut m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                        let mut lu = m.clone().updatable_lu();
+
+                        // Successive basis changes, as in the simplex method.
+                        for j in cols.into_iter().filter(|_| n > 0) {
+                            let j = j % n;
+                            let col = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                            m.set_column(j, &col);
+                            lu.replace_column(j, &col);
+
+                            let u = lu.u();
+                            prop_assert!(u.upper_triangle() == u);
+
+                            let sol = lu.solve(&b);
+                            prop_assert!(sol.is_none() || relative_eq!(&m * sol.unwrap(), b, epsilon = 1.0e-6));
+
+                            let mut x = b.clone();
+                            prop_assert!(!lu.ad_solve_mut(&mut x) || relative_eq!(m.adjoint() * x, b, epsilon = 1.0e-6));
+                        }
+                    }
+
+                    #[test]
+                    fn updatable_lu_rank_one_update(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                        let u = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let v = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+
+                        let mut lu = m.clone().updatable_lu();
+                        lu.rank_one_update(&u, &v);
+                        let m = m + u * v.adjoint();
+
+                        let u = lu.u();
+                        prop_assert!(u.upper_triangle() == u);
+
+                        let sol = lu.solve(&b);
+                        prop_assert!(sol.is_none() || relative_eq!(&m * sol.unwrap(), b, epsilon = 1.0e-6));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
@@ -0,0 +1,131 @@
+use na::{Matrix4x3, RowVector3, Vector3, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn updatable_qr_static() {
+    let m = Matrix4x3::new(
+        5.0, -2.0,  1.0,
+        1.0,  3.0, -4.0,
+       -2.0,  1.0,  6.0,
+        0.0,  4.0,  2.0);
+
+    let qr = m.updatable_qr();
+    let (q, r) = qr.unpack();
+    assert_relative_eq!(q * r, m, epsilon = 1.0e-7);
+    assert!(q.is_orthogonal(1.0e-7));
+    assert_eq!(r.upper_triangle(), r);
+
+    let row = RowVector3::new(1.0, 2.0, 3.0);
+    let mut expected = m.insert_row(2, 0.0);
+    expected.set_row(2, &row);
+    let qr_rows = qr.insert_row(2, &row);
+    assert_relative_eq!(qr_rows.q() * qr_rows.r(), expected, epsilon = 1.0e-7);
+
+    let qr_rows = qr_rows.remove_row(2);
+    assert_relative_eq!(qr_rows.q() * qr_rows.r(), m, epsilon = 1.0e-7);
+
+    let col = Vector4::new(-1.0, 0.5, 2.0, 1.0);
+    let mut expected = m.insert_column(1, 0.0);
+    expected.set_column(1, &col);
+    let (q, r) = qr.insert_column(1, &col).unpack();
+    assert_relative_eq!(q * r, expected, epsilon = 1.0e-7);
+    assert_eq!(r.upper_triangle(), r);
+
+    let x = Vector3::new(1.0, -1.0, 2.0);
+    assert_relative_eq!(qr.solve_least_squares(&(m * x)).unwrap(), x, epsilon = 1.0e-7);
+
+    // The updates of `QR` return the updated explicit factors.
+    let u = Vector4::new(1.0, 0.0, -2.0, 0.5);
+    let (q, r) = m.qr().rank_one_update(&u, &x).unpack();
+    assert_relative_eq!(q * r, m + u * x.transpose(), epsilon = 1.0e-7);
+    assert_eq!(r.upper_triangle(), r);
+
+    let qr_cols = m.qr().insert_column(1, &col);
+    assert_relative_eq!(qr_cols.q() * qr_cols.r(), expected, epsilon = 1.0e-7);
+    let qr_cols = m.qr().remove_column(0);
+    assert_relative_eq!(qr_cols.q() * qr_cols.r(), m.remove_column(0), epsilon = 1.0e-7);
+    let qr_rows = m.qr().remove_row(3);
+    assert_relative_eq!(qr_rows.q() * qr_rows.r(), m.remove_row(3), epsilon = 1.0e-7);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use approx::RelativeEq;
+                use na::allocator::Allocator;
+                use na::{ComplexField, DMatrix, DVector, DefaultAllocator, Dim, UpdatableQR};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                // Checks that `Q` is unitary and that `R` is upper trapezoidal.
+                fn is_valid<N: ComplexField + RelativeEq<Epsilon = f64>, R: Dim, C: Dim>(qr: &UpdatableQR<N, R, C>) -> bool
+                where
+                    DefaultAllocator: Allocator<N, R, R> + Allocator<N, R, C>,
+                {
+                    let r = qr.r();
+                    qr.q().is_orthogonal(1.0e-7) && r.upper_triangle() == r
+                }
+
+                proptest! {
+                    #[test]
+                    fn updatable_qr(m in dmatrix_($scalar)) {
+                        let qr = m.clone().updatable_qr();
+                        prop_assert!(is_valid(&qr));
+                        prop_assert!(relative_eq!(qr.q() * qr.r(), m, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn updatable_qr_rank_one_update(m in dmatrix_($scalar)) {
+                        let u = DVector::<$scalar_type>::new_random(m.nrows()).map(|e| e.0);
+                        let v = DVector::<$scalar_type>::new_random(m.ncols()).map(|e| e.0);
+
+                        let mut qr = m.clone().updatable_qr();
+                        qr.rank_one_update(&u, &v);
+
+                        prop_assert!(is_valid(&qr));
+                        prop_assert!(relative_eq!(qr.q() * qr.r(), m + u * v.adjoint(), epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn updatable_qr_insert_remove_column(m in dmatrix_($scalar), j in 0..=20usize) {
+                        let j = j % (m.ncols() + 1);
+                        let col = DVector::<$scalar_type>::new_random(m.nrows()).map(|e| e.0);
+                        let mut expected = m.clone().insert_column(j, na::zero());
+                        expected.set_column(j, &col);
+
+                        let qr = m.clone().updatable_qr().insert_column(j, &col);
+                        prop_assert!(is_valid(&qr));
+                        prop_assert!(relative_eq!(qr.q() * qr.r(), expected, epsilon = 1.0e-7));
+
+                        let qr = qr.remove_column(j);
+                        prop_assert!(is_valid(&qr));
+                        prop_assert!(relative_eq!(qr.q() * qr.r(), m, epsilon = 1.0e-7));
+                    }
+
+                    #[test]
+                    fn updatable_qr_insert_remove_row(m in dmatrix_($scalar), i in 0..=20usize) {
+                        let i = i % (m.nrows() + 1);
+                        let row = DMatrix::<$scalar_type>::new_random(1, m.ncols()).map(|e| e.0);
+                        let mut expected = m.clone().insert_row(i, na::zero());
+                        expected.set_row(i, &row.row(0));
+
+                        let qr = m.clone().updatable_qr().insert_row(i, &row.row(0));
+                        prop_assert!(is_valid(&qr));
+                        prop_assert!(relative_eq!(qr.q() * qr.r(), expected, epsilon = 1.0e-7));
+
+                        let qr = qr.remove_row(i);
+                        prop_assert!(is_valid(&qr));
+                        prop_assert!(relative_eq!(qr.q() * qr.r(), m, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
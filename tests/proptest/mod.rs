@@ -7,8 +7,9 @@ use nalgebra::base::dimension::*;
 use nalgebra::proptest::{DimRange, MatrixStrategy};
 use nalgebra::{
     DMatrix, DVector, DefaultAllocator, Dim, DualQuaternion, Isometry2, Isometry3, Matrix3,
-    MatrixMN, Point2, Point3, Quaternion, Rotation2, Rotation3, Scalar, Similarity3, Translation2,
-    Translation3, UnitComplex, UnitDualQuaternion, UnitQuaternion, Vector3, U2, U3, U4, U7, U8,
+    MatrixMN, Point2, Point3, Quaternion, Rotation2, Rotation3, Scalar, Scale3, Similarity3,
+    Translation2, Translation3, UnitComplex, UnitDualQuaternion, UnitQuaternion, Vector3, U2, U3,
+    U4, U7, U8,
 };
 use num_complex::Complex;
 use proptest::prelude::*;
@@ -37,6 +38,10 @@ pub fn translation3() -> impl Strategy<Value = Translation3<f64>> {
     vector3().prop_map(|v| Translation3::from(v))
 }
 
+pub fn scale3() -> impl Strategy<Value = Scale3<f64>> {
+    vector3().prop_map(|v| Scale3::from(v))
+}
+
 pub fn rotation2() -> impl Strategy<Value = Rotation2<f64>> {
     PROPTEST_F64.prop_map(|v| Rotation2::new(v))
 }